@@ -31,21 +31,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process;
 use walkdir::WalkDir;
-
-/// The controlled list of manually-maintained types.
-/// These are "Complex Domain" types with physics/relationships/business logic
-/// that cannot be automatically generated from schemas.
-const MANUAL_TYPES: &[&str] = &[
-    // Symmetric Seven - Core Physics Entities (NEVER generate)
-    "Moment", "Pulse", "Thread", "Bond", "Filament", "Focus", "Intent", "Motif",
-    // Orchestration Entities (NEVER generate)
-    "Course", "Shuttle",
-    // Components with #[serde(flatten)] (Manual until typify supports)
-    "Identity", "FieldExcitation", "QuantumState", "ContentPayload",
-    "BondPhysics", "CognitiveOptics", "EmotionalState", "Timestamps",
-    // Complex Types with nested structures
-    "WeaveUnit", "Weave", "PhysicsHint", "RequestContext",
-];
+use familiar_drift_internals::MANUAL_TYPES;
 
 /// Whether to fail on drift or just warn
 const FAIL_ON_DRIFT: bool = false;
@@ -370,7 +356,7 @@ fn check_drift(version: &str) {
         return;
     }
     
-    match familiar_drift_internals::check_drift_multi(&schemas_dir, &existing_dirs, MANUAL_TYPES) {
+    match familiar_drift_internals::check_drift_multi(&schemas_dir, &existing_dirs, MANUAL_TYPES, false) {
         Ok(()) => {
             println!("cargo:warning=Drift check passed for {} manual types (version {})", MANUAL_TYPES.len(), version);
         }
@@ -9,6 +9,7 @@
 //! - `generate_node!` - Generate a Node struct from an ECS node schema
 //! - `generate_system!` - Generate a System implementation from a system schema
 //! - `generate_type!` - Generate a simple data type from a JSON schema
+//! - `#[derive(DriftCheck)]` - Allow `#[drift(...)]` annotations on manual types
 //!
 //! ## Schema Path Configuration
 //!
@@ -25,6 +26,9 @@ mod node;
 mod system;
 mod types;
 
+#[cfg(test)]
+mod test_support;
+
 /// Generate a Node struct from a JSON schema file.
 ///
 /// The path is relative to the schema directory configured in `schema.lock`.
@@ -41,6 +45,9 @@ pub fn generate_node(input: TokenStream) -> TokenStream {
 
 /// Generate a System implementation from a JSON schema file.
 ///
+/// Systems with `dispatch` rules also get a `dispatch` method that matches
+/// the trigger and evaluates the rule's routing policy.
+///
 /// # Example
 ///
 /// ```ignore
@@ -65,3 +72,21 @@ pub fn generate_type(input: TokenStream) -> TokenStream {
     types::generate(input)
 }
 
+/// Registers the `#[drift(...)]` field attribute read by the drift checker.
+///
+/// Generates no code; it only lets manually-maintained types carry drift
+/// annotations, e.g. to keep a Rust-only field out of the schema comparison:
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize, DriftCheck)]
+/// pub struct Moment {
+///     pub id: MomentId,
+///     #[serde(default)]
+///     #[drift(ignore)]
+///     pub relevance_cache: Option<f64>,
+/// }
+/// ```
+#[proc_macro_derive(DriftCheck, attributes(drift))]
+pub fn derive_drift_check(_input: TokenStream) -> TokenStream {
+    TokenStream::new()
+}
@@ -97,6 +97,8 @@ fn generate_node_struct(schema: &serde_json::Value) -> TokenStream2 {
         .map(|f| syn::Ident::new(f, proc_macro2::Span::call_site()))
         .collect();
     
+    let builder_name = syn::Ident::new(&format!("{}Builder", name), proc_macro2::Span::call_site());
+    
     quote! {
         /// Generated Node struct from ECS schema
         #[derive(Debug)]
@@ -105,10 +107,60 @@ fn generate_node_struct(schema: &serde_json::Value) -> TokenStream2 {
             #(pub #system_field_idents: #system_types,)*
         }
         
+        /// Builder for the generated Node; every schema component and system is required
+        #[derive(Debug, Default)]
+        pub struct #builder_name {
+            #(#component_field_idents: Option<std::sync::Arc<#component_types>>,)*
+            #(#system_field_idents: Option<#system_types>,)*
+        }
+        
+        impl #builder_name {
+            #(
+                pub fn #component_field_idents(mut self, value: impl Into<std::sync::Arc<#component_types>>) -> Self {
+                    self.#component_field_idents = Some(value.into());
+                    self
+                }
+            )*
+            
+            #(
+                pub fn #system_field_idents(mut self, value: #system_types) -> Self {
+                    self.#system_field_idents = Some(value);
+                    self
+                }
+            )*
+            
+            /// Build the Node, failing on the first required field left unset
+            pub fn build(self) -> Result<#struct_name, crate::runtime::NodeBuildError> {
+                Ok(#struct_name {
+                    #(
+                        #component_field_idents: self.#component_field_idents.ok_or(
+                            crate::runtime::NodeBuildError::MissingField {
+                                node: #name,
+                                field: #component_fields,
+                            },
+                        )?,
+                    )*
+                    #(
+                        #system_field_idents: self.#system_field_idents.ok_or(
+                            crate::runtime::NodeBuildError::MissingField {
+                                node: #name,
+                                field: #system_fields,
+                            },
+                        )?,
+                    )*
+                })
+            }
+        }
+        
         impl #struct_name {
             /// The Temporal task queue this node processes
             pub const QUEUE: &'static str = #queue_lit;
             
+            /// Start building this Node field by field
+            pub fn builder() -> #builder_name {
+                #builder_name::default()
+            }
+            
             /// Register all systems with a Temporal worker
             pub fn register<W>(&self, _worker: &mut W) {
                 // Registration logic will be implemented by the consumer
@@ -134,3 +186,90 @@ fn to_snake_case(s: &str) -> String {
     result
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_schema() -> serde_json::Value {
+        serde_json::json!({
+            "name": "FatesDaemon",
+            "queue": "daemon-queue",
+            "systems": ["FatesGate"],
+            "components": ["ContractEnforcer"]
+        })
+    }
+
+    #[test]
+    fn test_generates_builder() {
+        let file: syn::File = syn::parse2(generate_node_struct(&example_schema())).unwrap();
+
+        let builder = file.items.iter().find_map(|item| match item {
+            syn::Item::Impl(imp) if quote!(#imp).to_string().starts_with("impl FatesDaemonBuilder") => Some(imp),
+            _ => None,
+        });
+        let methods: Vec<String> = builder
+            .expect("FatesDaemonBuilder impl")
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::ImplItem::Fn(f) => Some(f.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(methods, ["contract_enforcer", "fates_gate", "build"]);
+    }
+
+    #[test]
+    fn test_generated_builder_rejects_missing_field() {
+        let program = format!(r#"
+mod runtime {{
+    #[derive(Debug)]
+    pub enum NodeBuildError {{
+        MissingField {{ node: &'static str, field: &'static str }},
+    }}
+}}
+
+#[derive(Debug)]
+pub struct ContractEnforcer;
+
+#[derive(Debug)]
+pub struct FatesGate;
+
+{}
+
+fn main() {{
+    let missing_system = FatesDaemon::builder().contract_enforcer(ContractEnforcer).build();
+    println!("{{:?}}", missing_system.map(|_| ()));
+    let missing_component = FatesDaemon::builder().fates_gate(FatesGate).build();
+    println!("{{:?}}", missing_component.map(|_| ()));
+    let complete = FatesDaemon::builder().fates_gate(FatesGate).contract_enforcer(ContractEnforcer).build();
+    println!("{{:?}} {{}}", complete.map(|node| node.fates_gate), FatesDaemon::QUEUE);
+}}
+"#, generate_node_struct(&example_schema()));
+
+        let stdout = crate::test_support::run(&program);
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(lines, [
+            r#"Err(MissingField { node: "FatesDaemon", field: "fates_gate" })"#,
+            r#"Err(MissingField { node: "FatesDaemon", field: "contract_enforcer" })"#,
+            "Ok(FatesGate) daemon-queue",
+        ]);
+    }
+
+    #[test]
+    fn test_build_reports_missing_required_field() {
+        let generated = generate_node_struct(&example_schema()).to_string();
+
+        // Each required field is checked, naming the node and the field
+        for field in ["contract_enforcer", "fates_gate"] {
+            let check = quote! {
+                crate::runtime::NodeBuildError::MissingField {
+                    node: "FatesDaemon",
+                    field: #field,
+                }
+            };
+            assert!(generated.contains(&check.to_string()), "no check for {}", field);
+        }
+    }
+}
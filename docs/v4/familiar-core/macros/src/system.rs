@@ -71,6 +71,7 @@ fn generate_system_impl(schema: &serde_json::Value) -> TokenStream2 {
     );
     
     let doc_comment = format!("{}\n\nResource class: {}", description, resource_class);
+    let dispatch_fn = generate_dispatch(schema);
     
     quote! {
         #[doc = #doc_comment]
@@ -86,6 +87,8 @@ fn generate_system_impl(schema: &serde_json::Value) -> TokenStream2 {
             pub fn new() -> Self {
                 Self {}
             }
+            
+            #dispatch_fn
         }
         
         #[async_trait::async_trait]
@@ -109,6 +112,68 @@ fn generate_system_impl(schema: &serde_json::Value) -> TokenStream2 {
     }
 }
 
+/// Generate `dispatch` from the schema's `dispatch` rules, one match arm per
+/// trigger. Each arm reads the payload as the rule's `input_key` and
+/// evaluates its `routing_policy` to pick the pool.
+///
+/// Emits nothing for systems without dispatch rules, and `compile_error!`
+/// for rules xtask would reject (missing trigger, unknown `input_key`).
+fn generate_dispatch(schema: &serde_json::Value) -> TokenStream2 {
+    let Some(rules) = schema.get("dispatch").and_then(|v| v.as_array()) else {
+        return TokenStream2::new();
+    };
+    let inputs = schema.get("inputs").and_then(|v| v.as_object());
+    
+    let mut arms = Vec::new();
+    for (i, rule) in rules.iter().enumerate() {
+        let Some(trigger) = rule.get("trigger").and_then(|v| v.as_str()) else {
+            let error_msg = format!("dispatch[{}] has no trigger", i);
+            return quote! { compile_error!(#error_msg); };
+        };
+        let Some(input_key) = rule.get("input_key").and_then(|v| v.as_str()) else {
+            let error_msg = format!("dispatch[{}] has no input_key", i);
+            return quote! { compile_error!(#error_msg); };
+        };
+        let Some(input) = inputs.and_then(|inputs| inputs.get(input_key)) else {
+            let error_msg = format!("dispatch[{}].input_key '{}' not found in inputs", i, input_key);
+            return quote! { compile_error!(#error_msg); };
+        };
+        
+        let input_schema = match input.get("$ref").and_then(|v| v.as_str()) {
+            Some(reference) => quote! { Some(#reference) },
+            None => quote! { None },
+        };
+        let pool = match rule.get("routing_policy").and_then(|v| v.as_str()) {
+            Some(policy) => quote! {
+                Some(ctx.evaluator.evaluate_routing_policy(#policy, ctx.input)?)
+            },
+            None => quote! { None },
+        };
+        
+        arms.push(quote! {
+            #trigger => Ok(crate::runtime::DispatchTarget {
+                trigger: #trigger,
+                input_key: #input_key,
+                input_schema: #input_schema,
+                pool: #pool,
+            }),
+        });
+    }
+    
+    quote! {
+        /// Route a message by the schema's dispatch rules
+        pub fn dispatch(
+            &self,
+            ctx: &crate::runtime::DispatchContext<'_>,
+        ) -> Result<crate::runtime::DispatchTarget, crate::runtime::SystemError> {
+            match ctx.trigger {
+                #(#arms)*
+                other => Err(crate::runtime::SystemError::UnknownTrigger(other.to_string())),
+            }
+        }
+    }
+}
+
 fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
     for (i, c) in s.chars().enumerate() {
@@ -124,3 +189,127 @@ fn to_snake_case(s: &str) -> String {
     result
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_schema() -> serde_json::Value {
+        serde_json::from_str(include_str!("../tests/schemas/fates_gate.system.json")).unwrap()
+    }
+
+    #[test]
+    fn test_example_schema_generates_valid_rust() {
+        let file: syn::File = syn::parse2(generate_system_impl(&example_schema())).unwrap();
+
+        let dispatch = file.items.iter().find_map(|item| match item {
+            syn::Item::Impl(imp) if imp.trait_.is_none() => imp.items.iter().find_map(|item| match item {
+                syn::ImplItem::Fn(f) if f.sig.ident == "dispatch" => Some(f),
+                _ => None,
+            }),
+            _ => None,
+        });
+        let arms = dispatch
+            .expect("dispatch fn")
+            .block
+            .stmts
+            .iter()
+            .find_map(|stmt| match stmt {
+                syn::Stmt::Expr(syn::Expr::Match(m), None) => Some(m.arms.len()),
+                _ => None,
+            });
+        // One arm per dispatch rule plus the unknown-trigger fallback
+        assert_eq!(arms, Some(3));
+    }
+
+    #[test]
+    fn test_dispatch_arm_wiring() {
+        let generated = generate_dispatch(&example_schema()).to_string();
+        let weave = quote! {
+            "kafka:commands.weave" => Ok(crate::runtime::DispatchTarget {
+                trigger: "kafka:commands.weave",
+                input_key: "weave_request",
+                input_schema: Some("../../domains/windmill/ConciergeInput.schema.json"),
+                pool: Some(ctx.evaluator.evaluate_routing_policy(
+                    "input.content_length > 10000 ? 'high-memory-pool' : 'standard-pool'",
+                    ctx.input
+                )?),
+            }),
+        };
+        assert!(generated.contains(&weave.to_string()), "{}", generated);
+    }
+
+    #[test]
+    fn test_generated_dispatch_runs() {
+        // The generated fn against a runtime shaped like familiar-core's
+        let program = format!(r#"
+mod runtime {{
+    pub trait PolicyEvaluator {{
+        fn evaluate_routing_policy(&self, expression: &str, input: &str) -> Result<String, SystemError>;
+    }}
+    pub struct DispatchContext<'a> {{
+        pub trigger: &'a str,
+        pub input: &'a str,
+        pub evaluator: &'a dyn PolicyEvaluator,
+    }}
+    #[derive(Debug)]
+    pub struct DispatchTarget {{
+        pub trigger: &'static str,
+        pub input_key: &'static str,
+        pub input_schema: Option<&'static str>,
+        pub pool: Option<String>,
+    }}
+    #[derive(Debug)]
+    pub enum SystemError {{
+        UnknownTrigger(String),
+    }}
+}}
+
+struct LargeInputs;
+
+impl runtime::PolicyEvaluator for LargeInputs {{
+    fn evaluate_routing_policy(&self, expression: &str, input: &str) -> Result<String, runtime::SystemError> {{
+        assert!(expression.starts_with("input.content_length > 10000"));
+        Ok(format!("high-memory-pool for {{}}", input))
+    }}
+}}
+
+struct FatesGate;
+
+impl FatesGate {{
+    {}
+}}
+
+fn main() {{
+    for trigger in ["kafka:commands.weave", "kafka:commands.classify", "kafka:commands.unknown"] {{
+        let ctx = runtime::DispatchContext {{ trigger, input: "payload", evaluator: &LargeInputs }};
+        println!("{{:?}}", FatesGate.dispatch(&ctx));
+    }}
+}}
+"#, generate_dispatch(&example_schema()));
+
+        let stdout = crate::test_support::run(&program);
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(lines, [
+            r#"Ok(DispatchTarget { trigger: "kafka:commands.weave", input_key: "weave_request", input_schema: Some("../../domains/windmill/ConciergeInput.schema.json"), pool: Some("high-memory-pool for payload") })"#,
+            r#"Ok(DispatchTarget { trigger: "kafka:commands.classify", input_key: "classify_request", input_schema: Some("../../primitives/Segment.schema.json"), pool: None })"#,
+            r#"Err(UnknownTrigger("kafka:commands.unknown"))"#,
+        ]);
+    }
+
+    #[test]
+    fn test_unknown_input_key_is_rejected() {
+        let mut schema = example_schema();
+        schema["dispatch"][1]["input_key"] = "missing_request".into();
+        let generated = generate_dispatch(&schema).to_string();
+        assert!(generated.starts_with("compile_error !"), "{}", generated);
+        assert!(generated.contains("dispatch[1].input_key 'missing_request'"));
+    }
+
+    #[test]
+    fn test_no_dispatch_without_rules() {
+        let mut schema = example_schema();
+        schema.as_object_mut().unwrap().remove("dispatch");
+        assert!(generate_dispatch(&schema).is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+//! Helpers for tests that compile and run generated code.
+
+use std::process::Command;
+
+/// Compile `program` as a standalone binary with rustc, run it and return
+/// its stdout. Panics with the compiler output if it doesn't compile.
+pub fn run(program: &str) -> String {
+    run_with(program, &[])
+}
+
+/// [`run`], with `crates` from this crate's own dependencies (e.g. `serde`)
+/// available to the program as externs.
+pub fn run_with(program: &str, crates: &[&str]) -> String {
+    static RUNS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+    let run = RUNS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let dir = std::env::temp_dir().join(format!("familiar-macros-{}-{}", std::process::id(), run));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("main.rs"), program).unwrap();
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let mut command = Command::new(rustc);
+    if !crates.is_empty() {
+        let deps = std::env::current_exe().unwrap().parent().unwrap().to_path_buf();
+        command.arg("-L").arg(format!("dependency={}", deps.display()));
+        for name in crates {
+            command.arg("--extern").arg(format!("{}={}", name, rlib(&deps, name).display()));
+        }
+    }
+    let compiled = command
+        .args(["--edition", "2021", "-o"])
+        .arg(dir.join("program"))
+        .arg(dir.join("main.rs"))
+        .output()
+        .unwrap();
+    let output = compiled.status.success().then(|| Command::new(dir.join("program")).output().unwrap());
+    std::fs::remove_dir_all(&dir).ok();
+
+    let output = output.unwrap_or_else(|| panic!("{}", String::from_utf8_lossy(&compiled.stderr)));
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// The compiled rlib of dependency `name` in the test binary's deps dir
+fn rlib(deps: &std::path::Path, name: &str) -> std::path::PathBuf {
+    let prefix = format!("lib{}-", name);
+    std::fs::read_dir(deps)
+        .unwrap()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .find(|path| {
+            let file = path.file_name().unwrap().to_string_lossy();
+            file.starts_with(&prefix) && file.ends_with(".rlib")
+        })
+        .unwrap_or_else(|| panic!("no rlib for `{}` in {}", name, deps.display()))
+}
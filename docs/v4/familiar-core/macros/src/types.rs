@@ -16,6 +16,13 @@
 //!
 //! If a schema uses these patterns, the macro emits `compile_error!` to force
 //! the type into the `MANUAL_TYPES` list.
+//!
+//! ## Defaults
+//!
+//! Properties carrying an `x-familiar-default` facet are handed to typify
+//! with that value as their JSON Schema `default`, so Rust defaults can't
+//! drift from the schema and a value that doesn't fit its type fails the
+//! build.
 
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
@@ -140,8 +147,15 @@ fn generate_with_typify(schema: &serde_json::Value, schema_path: &str) -> TokenS
     // Create a typify TypeSpace with our settings
     let mut type_space = typify::TypeSpace::new(&settings);
     
+    let mut schema = schema.clone();
+    if let Err(error_msg) = apply_default_facets(&mut schema) {
+        return quote! {
+            compile_error!(#error_msg);
+        };
+    }
+    
     // Convert serde_json::Value to schemars::schema::RootSchema
-    let root_schema: schemars::schema::RootSchema = match serde_json::from_value(schema.clone()) {
+    let root_schema: schemars::schema::RootSchema = match serde_json::from_value(schema) {
         Ok(s) => s,
         Err(e) => {
             let error_msg = format!("Failed to convert schema to RootSchema: {}", e);
@@ -151,7 +165,8 @@ fn generate_with_typify(schema: &serde_json::Value, schema_path: &str) -> TokenS
         }
     };
     
-    // Add the schema to typify's type space
+    // Add the schema to typify's type space. This also checks each default
+    // against its property's type.
     if let Err(e) = type_space.add_root_schema(root_schema) {
         let error_msg = format!("typify failed to process schema '{}': {}", schema_path, e);
         return quote! {
@@ -161,7 +176,126 @@ fn generate_with_typify(schema: &serde_json::Value, schema_path: &str) -> TokenS
     
     // Generate the Rust code
     // typify already generates Serialize/Deserialize derives
-    let generated = type_space.to_stream();
+    type_space.to_stream()
+}
+
+/// Turn the `x-familiar-default` facets on the schema's properties into
+/// standard `default` keywords, and drop those properties from `required`.
+///
+/// typify then gives each such field a serde default and, once every field
+/// has one, a `Default` impl for the type. Optional fields without a facet
+/// default to `None`. A required field without one is an error, since the
+/// type would have no value to fall back on.
+fn apply_default_facets(schema: &mut serde_json::Value) -> Result<(), String> {
+    let title = schema.get("title").and_then(|v| v.as_str()).unwrap_or("Unknown").to_string();
+    let Some(properties) = schema.get_mut("properties").and_then(|v| v.as_object_mut()) else {
+        return Ok(());
+    };
+    
+    let mut defaulted = Vec::new();
+    for (name, property) in properties.iter_mut() {
+        let Some(property) = property.as_object_mut() else { continue };
+        if let Some(default) = property.remove("x-familiar-default") {
+            property.insert("default".to_string(), default);
+            defaulted.push(name.clone());
+        }
+    }
+    if defaulted.is_empty() {
+        return Ok(());
+    }
     
-    generated
+    let Some(required) = schema.get_mut("required").and_then(|v| v.as_array_mut()) else {
+        return Ok(());
+    };
+    required.retain(|name| !name.as_str().is_some_and(|name| defaulted.iter().any(|d| d == name)));
+    match required.first().and_then(|v| v.as_str()) {
+        Some(name) => Err(format!(
+            "Schema '{}' has x-familiar-default facets but required property '{}' has none",
+            title, name
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defaulted_schema() -> serde_json::Value {
+        serde_json::json!({
+            "title": "RetryPolicy",
+            "type": "object",
+            "required": ["max_attempts", "backoff"],
+            "properties": {
+                "max_attempts": { "type": "integer", "format": "uint32", "x-familiar-default": 3 },
+                "backoff": { "$ref": "#/definitions/BackoffMs", "x-familiar-default": 250 },
+                "jitter": { "type": "boolean" }
+            },
+            "definitions": {
+                "BackoffMs": { "type": "integer", "format": "uint64", "minimum": 1 }
+            }
+        })
+    }
+
+    #[test]
+    fn test_default_facets_become_schema_defaults() {
+        let mut schema = defaulted_schema();
+        apply_default_facets(&mut schema).unwrap();
+        assert_eq!(schema["properties"]["max_attempts"], serde_json::json!({ "type": "integer", "format": "uint32", "default": 3 }));
+        assert_eq!(schema["properties"]["backoff"]["default"], 250);
+        assert_eq!(schema["properties"]["jitter"], serde_json::json!({ "type": "boolean" }));
+        assert_eq!(schema["required"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_schema_without_facets_is_unchanged() {
+        let mut schema = defaulted_schema();
+        for property in schema["properties"].as_object_mut().unwrap().values_mut() {
+            property.as_object_mut().unwrap().remove("x-familiar-default");
+        }
+        let original = schema.clone();
+        apply_default_facets(&mut schema).unwrap();
+        assert_eq!(schema, original);
+    }
+
+    #[test]
+    fn test_required_field_without_default_is_an_error() {
+        let mut schema = defaulted_schema();
+        schema["properties"]["max_attempts"].as_object_mut().unwrap().remove("x-familiar-default");
+        let generated = generate_with_typify(&schema, "RetryPolicy.schema.json").to_string();
+        assert!(generated.starts_with("compile_error !"), "{}", generated);
+        assert!(generated.contains("max_attempts"));
+    }
+
+    #[test]
+    fn test_default_that_doesnt_fit_its_type_is_an_error() {
+        let mut schema = defaulted_schema();
+        schema["properties"]["max_attempts"]["x-familiar-default"] = serde_json::json!("three");
+        let generated = generate_with_typify(&schema, "RetryPolicy.schema.json").to_string();
+        assert!(generated.starts_with("compile_error !"), "{}", generated);
+
+        let mut schema = defaulted_schema();
+        schema["properties"]["jitter"]["x-familiar-default"] = serde_json::json!("sometimes");
+        let generated = generate_with_typify(&schema, "RetryPolicy.schema.json").to_string();
+        assert!(generated.starts_with("compile_error !"), "{}", generated);
+    }
+
+    #[test]
+    fn test_generated_default_compiles() {
+        let generated = generate_with_typify(&defaulted_schema(), "RetryPolicy.schema.json");
+        let program = format!(
+            "{}\n{}",
+            generated,
+            r#"
+            fn main() {
+                let policy = RetryPolicy::default();
+                println!("{} {} {:?}", policy.max_attempts, *policy.backoff, policy.jitter);
+                let parsed: RetryPolicy = ::serde_json::from_str("{\"jitter\": true}").unwrap();
+                println!("{} {} {:?}", parsed.max_attempts, *parsed.backoff, parsed.jitter);
+            }
+            "#
+        );
+        let stdout = crate::test_support::run_with(&program, &["serde", "serde_json"]);
+        assert_eq!(stdout, "3 250 None\n3 250 Some(true)\n");
+    }
 }
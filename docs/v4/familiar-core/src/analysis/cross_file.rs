@@ -972,6 +972,54 @@ impl CrossFileAnalyzer {
         
         issues
     }
+    
+    /// Detect dependency cycles in the schema graph.
+    ///
+    /// Each recursive component is reported once, together with one shortest
+    /// cycle through it so the reader knows which `$ref` to break.
+    pub fn detect_schema_cycles(&self) -> Vec<Issue> {
+        let Some(schema_dir) = schema_lock::find_schema_dir(&self.root) else {
+            return Vec::new();
+        };
+        
+        let graph = match SchemaGraph::from_directory_with_depth(&schema_dir, 10) {
+            Ok(g) => g,
+            Err(_) => return Vec::new(),
+        };
+        
+        graph.scc_groups()
+            .into_iter()
+            .map(|group| {
+                let cycle = group.shortest_cycle();
+                let file = cycle.first()
+                    .map(|id| id.split('#').next().unwrap_or(id))
+                    .unwrap_or_default();
+                let path = cycle.iter()
+                    .chain(cycle.first())
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                
+                Issue {
+                    file: PathBuf::from(file),
+                    line: 0,
+                    message: format!(
+                        "Recursive schemas ({} in cycle group): {}",
+                        group.members.len(), path
+                    ),
+                    kind: IssueKind::RecursiveSchemaCycle {
+                        members: group.members,
+                        cycle,
+                    },
+                    severity: Severity::Warning,
+                    fix: Some(Fix {
+                        description: "Break the cycle by removing or boxing one of the $refs along this path".to_string(),
+                        replacement: None,
+                    }),
+                }
+            })
+            .collect()
+    }
 }
 
 /// Suggest which schemas should reference this type based on naming patterns.
@@ -279,6 +279,14 @@ pub enum IssueKind {
         recommendation: OrphanRecommendation,
     },
     
+    /// Schemas that reference each other in a cycle (mutually recursive types)
+    RecursiveSchemaCycle {
+        /// Every schema in the strongly-connected component
+        members: Vec<String>,
+        /// One shortest cycle through the component, in reference order
+        cycle: Vec<String>,
+    },
+    
     // === Missing Schemas (Rust types without JSON schema) ===
     /// Rust type exists in familiar-core/src/types/ but has no corresponding JSON schema
     MissingJsonSchema {
@@ -377,7 +377,11 @@ fn run_analyze(args: &AnalyzeArgs, start: Instant) {
         let isolated_issues = analyzer.detect_isolated_schemas(args.orphan_filter.as_deref());
         let isolated_count = isolated_issues.len();
         
-        if !args.quiet && (missing_count > 0 || isolated_count > 0) {
+        // And dependency cycles between schemas
+        let cycle_issues = analyzer.detect_schema_cycles();
+        let cycle_count = cycle_issues.len();
+        
+        if !args.quiet && (missing_count > 0 || isolated_count > 0 || cycle_count > 0) {
             println!("{}", style("📋 Additional Schema Issues").cyan().bold());
             if missing_count > 0 {
                 println!("   {} {} {}", 
@@ -393,6 +397,13 @@ fn run_analyze(args: &AnalyzeArgs, start: Instant) {
                     style("(schemas not referenced)").dim()
                 );
             }
+            if cycle_count > 0 {
+                println!("   {} {} {}", 
+                    style("→ Recursive schema cycles:").yellow(), 
+                    cycle_count, 
+                    style("(mutually referencing schemas)").dim()
+                );
+            }
             println!();
         }
         
@@ -400,6 +411,7 @@ fn run_analyze(args: &AnalyzeArgs, start: Instant) {
         if let Some(ref mut existing) = combined_report {
             existing.issues.extend(missing_schema_issues);
             existing.issues.extend(isolated_issues);
+            existing.issues.extend(cycle_issues);
             existing.stats.missing_json_schemas = missing_count;
             existing.stats.isolated_schemas = isolated_count;
             existing.stats.issues_found = existing.issues.len();
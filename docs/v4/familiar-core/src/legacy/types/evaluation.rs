@@ -9,6 +9,7 @@
 //! - The `data` field carries opaque context for the next step
 
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 // ============================================================================
 // EvaluationStep - Typed Enum for Next Actions
@@ -21,8 +22,11 @@ use serde::{Deserialize, Serialize};
 /// - Type safety in Rust
 /// - Dropdown selection in Windmill
 /// - Compile-time validation
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+///
+/// Steps serialize as their SCREAMING_SNAKE_CASE names. A flow-specific
+/// branch that isn't one of the known steps round-trips as `Custom`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum EvaluationStep {
     /// Process through the Loom (AI pipeline)
     Loom,
@@ -38,10 +42,19 @@ pub enum EvaluationStep {
     Complete,
     /// Skip this step (no action needed)
     Skip,
+    /// A branch name the known steps don't cover
+    Custom(String),
 }
 
+/// Alias matching the `next_step` field name
+pub type NextStep = EvaluationStep;
+
 impl EvaluationStep {
-    pub fn as_str(&self) -> &'static str {
+    /// Steps Windmill flows branch on, in wire form
+    pub const KNOWN: [&'static str; 7] =
+        ["LOOM", "DIRECT", "REJECT", "RETRY", "ESCALATE", "COMPLETE", "SKIP"];
+
+    pub fn as_str(&self) -> &str {
         match self {
             Self::Loom => "LOOM",
             Self::Direct => "DIRECT",
@@ -50,6 +63,7 @@ impl EvaluationStep {
             Self::Escalate => "ESCALATE",
             Self::Complete => "COMPLETE",
             Self::Skip => "SKIP",
+            Self::Custom(step) => step,
         }
     }
     
@@ -64,12 +78,170 @@ impl EvaluationStep {
     }
 }
 
+impl From<String> for EvaluationStep {
+    fn from(step: String) -> Self {
+        match step.as_str() {
+            "LOOM" => Self::Loom,
+            "DIRECT" => Self::Direct,
+            "REJECT" => Self::Reject,
+            "RETRY" => Self::Retry,
+            "ESCALATE" => Self::Escalate,
+            "COMPLETE" => Self::Complete,
+            "SKIP" => Self::Skip,
+            _ => Self::Custom(step),
+        }
+    }
+}
+
+impl From<&str> for EvaluationStep {
+    fn from(step: &str) -> Self {
+        Self::from(step.to_string())
+    }
+}
+
+impl From<EvaluationStep> for String {
+    fn from(step: EvaluationStep) -> Self {
+        match step {
+            EvaluationStep::Custom(step) => step,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl schemars::JsonSchema for EvaluationStep {
+    fn schema_name() -> String {
+        "EvaluationStep".to_string()
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        use schemars::schema::{InstanceType, Metadata, SchemaObject, SubschemaValidation};
+
+        // The known steps as an enum (for Windmill's dropdown), or any custom branch
+        let known = SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            enum_values: Some(Self::KNOWN.iter().map(|step| (*step).into()).collect()),
+            ..Default::default()
+        };
+        let custom = SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            ..Default::default()
+        };
+        SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                description: Some("The next step Windmill branches on".to_string()),
+                ..Default::default()
+            })),
+            subschemas: Some(Box::new(SubschemaValidation {
+                any_of: Some(vec![known.into(), custom.into()]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
 impl std::fmt::Display for EvaluationStep {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_str())
     }
 }
 
+// ============================================================================
+// RetryPolicy - How the Orchestrator Retries
+// ============================================================================
+
+/// How the orchestrator should re-invoke a step after a `RETRY` result
+///
+/// Attempt counts derive from the `systems.*.retries` config slots (retries
+/// plus the first attempt), so retry behavior stays in config rather than
+/// in each Windmill flow.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct RetryPolicy {
+    /// Total attempts allowed, including the first
+    pub max_attempts: u32,
+    /// Base delay, doubled after each attempt
+    pub backoff_ms: u64,
+    /// Upper bound on any single delay
+    pub max_backoff_ms: u64,
+    /// Randomize each delay between zero and the backoff ("full jitter")
+    pub jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Delay before retrying after `attempt` attempts have failed.
+    /// The first retry (`attempt == 1`) waits up to `backoff_ms`; each later
+    /// one doubles that, up to `max_backoff_ms`.
+    pub fn next_delay(&self, attempt: u32) -> Duration {
+        if attempt == 0 {
+            return Duration::ZERO;
+        }
+        let ceiling = self
+            .backoff_ms
+            .saturating_mul(1u64 << (attempt - 1).min(63))
+            .min(self.max_backoff_ms);
+        let delay = if self.jitter {
+            use rand::Rng;
+            rand::thread_rng().gen_range(0..=ceiling)
+        } else {
+            ceiling
+        };
+        Duration::from_millis(delay)
+    }
+
+    /// Whether another attempt is allowed after `attempt` attempts
+    pub fn should_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_attempts
+    }
+}
+
+// ============================================================================
+// RejectionData - Structured REJECT Payload
+// ============================================================================
+
+/// Why an input was rejected, as a stable code the UI can localize
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RejectionCode {
+    /// The input failed validation
+    InvalidInput,
+    /// Too many requests; try again later
+    RateLimited,
+    /// The caller isn't allowed to do this
+    Unauthorized,
+    /// The input breaks a tenant or platform policy
+    PolicyViolation,
+    /// A referenced entity doesn't exist
+    NotFound,
+    /// The input conflicts with existing state
+    Conflict,
+    /// The worker itself failed
+    Internal,
+}
+
+impl RejectionCode {
+    /// Whether the same input may succeed if tried again later
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::RateLimited | Self::Internal)
+    }
+}
+
+/// The `data` of a `REJECT` result
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+pub struct RejectionData {
+    pub code: RejectionCode,
+    /// Human-readable explanation (same as the result's `reason`)
+    pub message: String,
+    pub retryable: bool,
+}
+
+impl RejectionData {
+    /// Rejection whose retryability follows from its code
+    pub fn new(code: RejectionCode, message: impl Into<String>) -> Self {
+        Self { code, message: message.into(), retryable: code.is_retryable() }
+    }
+}
+
 // ============================================================================
 // EvaluationResult - The Evaluator Output
 // ============================================================================
@@ -95,6 +267,9 @@ pub struct EvaluationResult {
     pub reason: String,
     /// Opaque data for the next step (context, extracted fields, etc.)
     pub data: serde_json::Value,
+    /// How to retry, for `RETRY` results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
 }
 
 impl EvaluationResult {
@@ -104,6 +279,7 @@ impl EvaluationResult {
             next_step,
             reason: reason.into(),
             data: serde_json::Value::Null,
+            retry: None,
         }
     }
     
@@ -113,6 +289,7 @@ impl EvaluationResult {
             next_step,
             reason: reason.into(),
             data,
+            retry: None,
         }
     }
     
@@ -126,9 +303,23 @@ impl EvaluationResult {
         Self::new(EvaluationStep::Direct, reason)
     }
     
-    /// Reject the input
-    pub fn reject(reason: impl Into<String>) -> Self {
-        Self::new(EvaluationStep::Reject, reason)
+    /// Reject the input, with a [`RejectionData`] payload in `data`
+    pub fn reject(code: RejectionCode, message: impl Into<String>) -> Self {
+        Self::rejection(RejectionData::new(code, message))
+    }
+
+    /// Reject the input with an explicit payload
+    pub fn rejection(rejection: RejectionData) -> Self {
+        let data = serde_json::to_value(&rejection).expect("RejectionData serializes");
+        Self::with_data(EvaluationStep::Reject, rejection.message, data)
+    }
+
+    /// The rejection payload, if this is a structured `REJECT` result
+    pub fn rejection_data(&self) -> Option<RejectionData> {
+        if self.next_step != EvaluationStep::Reject {
+            return None;
+        }
+        serde_json::from_value(self.data.clone()).ok()
     }
     
     /// Retry the operation
@@ -150,6 +341,62 @@ impl EvaluationResult {
     pub fn skip(reason: impl Into<String>) -> Self {
         Self::new(EvaluationStep::Skip, reason)
     }
+
+    /// Start building a result; `build` fails without a step and a reason
+    pub fn builder() -> EvaluationResultBuilder {
+        EvaluationResultBuilder::default()
+    }
+}
+
+/// Builder for [`EvaluationResult`]
+#[derive(Debug, Clone, Default)]
+pub struct EvaluationResultBuilder {
+    next_step: Option<EvaluationStep>,
+    reason: Option<String>,
+    data: serde_json::Value,
+    retry: Option<RetryPolicy>,
+}
+
+impl EvaluationResultBuilder {
+    pub fn next_step(mut self, next_step: impl Into<EvaluationStep>) -> Self {
+        self.next_step = Some(next_step.into());
+        self
+    }
+
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    pub fn data(mut self, data: serde_json::Value) -> Self {
+        self.data = data;
+        self
+    }
+
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Finish the result. A blank reason counts as missing.
+    pub fn build(self) -> Result<EvaluationResult, EvaluationResultError> {
+        let next_step = self.next_step.ok_or(EvaluationResultError::MissingNextStep)?;
+        let reason = self
+            .reason
+            .filter(|reason| !reason.trim().is_empty())
+            .ok_or(EvaluationResultError::MissingReason)?;
+
+        Ok(EvaluationResult { next_step, reason, data: self.data, retry: self.retry })
+    }
+}
+
+/// Why an [`EvaluationResultBuilder`] couldn't build a result
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EvaluationResultError {
+    #[error("evaluation result has no next_step")]
+    MissingNextStep,
+    #[error("evaluation result has no reason")]
+    MissingReason,
 }
 
 #[cfg(test)]
@@ -166,6 +413,135 @@ mod tests {
         assert_eq!(parsed, EvaluationStep::Direct);
     }
 
+    #[test]
+    fn test_evaluation_step_roundtrip() {
+        let steps = [
+            (EvaluationStep::Loom, "\"LOOM\""),
+            (EvaluationStep::Direct, "\"DIRECT\""),
+            (EvaluationStep::Reject, "\"REJECT\""),
+            (EvaluationStep::Retry, "\"RETRY\""),
+            (EvaluationStep::Escalate, "\"ESCALATE\""),
+            (EvaluationStep::Complete, "\"COMPLETE\""),
+            (EvaluationStep::Skip, "\"SKIP\""),
+            (EvaluationStep::Custom("NOTIFY_OWNER".to_string()), "\"NOTIFY_OWNER\""),
+        ];
+        for (step, json) in steps {
+            assert_eq!(serde_json::to_string(&step).unwrap(), json);
+            assert_eq!(serde_json::from_str::<EvaluationStep>(json).unwrap(), step);
+        }
+
+        // Unknown and miscased names are custom branches, not errors
+        let parsed: EvaluationStep = serde_json::from_str("\"loom\"").unwrap();
+        assert_eq!(parsed, EvaluationStep::Custom("loom".to_string()));
+    }
+
+    #[test]
+    fn test_evaluation_result_builder() {
+        let result = EvaluationResult::builder()
+            .next_step(EvaluationStep::Direct)
+            .reason("Simple storage")
+            .data(serde_json::json!({"email": "test@example.com"}))
+            .build()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::json!({
+                "next_step": "DIRECT",
+                "reason": "Simple storage",
+                "data": {"email": "test@example.com"}
+            })
+        );
+
+        let missing = EvaluationResult::builder().next_step("LOOM").build();
+        assert_eq!(missing.unwrap_err(), EvaluationResultError::MissingReason);
+        let blank = EvaluationResult::builder().next_step("LOOM").reason("  ").build();
+        assert_eq!(blank.unwrap_err(), EvaluationResultError::MissingReason);
+        let no_step = EvaluationResult::builder().reason("Why").build();
+        assert_eq!(no_step.unwrap_err(), EvaluationResultError::MissingNextStep);
+    }
+
+    fn policy(jitter: bool) -> RetryPolicy {
+        RetryPolicy { max_attempts: 4, backoff_ms: 100, max_backoff_ms: 500, jitter }
+    }
+
+    #[test]
+    fn test_retry_backoff_schedule() {
+        let fixed = policy(false);
+        let delays: Vec<u128> = (0..6).map(|attempt| fixed.next_delay(attempt).as_millis()).collect();
+        assert_eq!(delays, [0, 100, 200, 400, 500, 500]);
+        assert_eq!(fixed.next_delay(u32::MAX), Duration::from_millis(500));
+
+        assert!(fixed.should_retry(3));
+        assert!(!fixed.should_retry(4));
+
+        let jittered = policy(true);
+        for attempt in 1..6 {
+            let ceiling = fixed.next_delay(attempt);
+            for _ in 0..50 {
+                assert!(jittered.next_delay(attempt) <= ceiling);
+            }
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_serde_shape() {
+        let result = EvaluationResult::builder()
+            .next_step(EvaluationStep::Retry)
+            .reason("Database unavailable")
+            .retry(policy(true))
+            .build()
+            .unwrap();
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(
+            json["retry"],
+            serde_json::json!({
+                "max_attempts": 4,
+                "backoff_ms": 100,
+                "max_backoff_ms": 500,
+                "jitter": true
+            })
+        );
+        let parsed: EvaluationResult = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.retry, Some(policy(true)));
+
+        // Results without a policy keep the existing wire shape
+        let json = serde_json::to_value(EvaluationResult::loom("AI needed")).unwrap();
+        assert!(json.get("retry").is_none());
+        let parsed: EvaluationResult =
+            serde_json::from_str(r#"{"next_step": "RETRY", "reason": "r", "data": null}"#).unwrap();
+        assert_eq!(parsed.retry, None);
+    }
+
+    #[test]
+    fn test_rejection_serialization() {
+        let result = EvaluationResult::reject(RejectionCode::RateLimited, "Too many signups");
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            serde_json::json!({
+                "next_step": "REJECT",
+                "reason": "Too many signups",
+                "data": {
+                    "code": "RATE_LIMITED",
+                    "message": "Too many signups",
+                    "retryable": true
+                }
+            })
+        );
+        assert_eq!(result.rejection_data().unwrap().code, RejectionCode::RateLimited);
+
+        let result = EvaluationResult::reject(RejectionCode::PolicyViolation, "Blocked domain");
+        assert_eq!(result.data["code"], "POLICY_VIOLATION");
+        assert_eq!(result.data["retryable"], false);
+
+        // Other steps keep their opaque data
+        let result = EvaluationResult::with_data(
+            EvaluationStep::Direct,
+            "Simple storage",
+            serde_json::json!({"code": "INVALID_INPUT", "message": "m", "retryable": false}),
+        );
+        assert_eq!(result.rejection_data(), None);
+    }
+
     #[test]
     fn test_evaluation_result_creation() {
         let result = EvaluationResult::loom("Input requires classification");
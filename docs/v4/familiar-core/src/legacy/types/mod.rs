@@ -71,7 +71,10 @@ pub use self::relationship_type::RelationshipType;
 pub use self::moment_type::MomentType;
 pub use self::course_status::CourseStatus;
 pub use self::shuttle_status::ShuttleStatus;
-pub use self::evaluation::{EvaluationStep, EvaluationResult};
+pub use self::evaluation::{
+    EvaluationStep, NextStep, EvaluationResult, EvaluationResultBuilder, EvaluationResultError,
+    RetryPolicy, RejectionCode, RejectionData,
+};
 pub use self::db_tables::{DbEntityTable, DbComponentTable};
 pub use self::db_error::DbStoreError;
 
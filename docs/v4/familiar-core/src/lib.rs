@@ -132,7 +132,7 @@ pub use internal::{
 };
 
 // Re-export validation (ContractEnforcer)
-pub use validation::{ContractEnforcer, ContractError};
+pub use validation::{ContractEnforcer, ContractError, ValidationIssue};
 
 // Re-export config (includes SystemManifest)
 pub use config::*;
@@ -145,11 +145,12 @@ pub use infrastructure::TigerDataStore;
 
 // Re-export simulation (Spawning + Physics + Heddle)
 pub use simulation::{
-    spawn_from_weave_unit, spawn_from_weave_units,
+    spawn_from_weave_unit, spawn_from_weave_units, spawn_from_weave_units_iter, SpawnError, SpawnOptions,
     entity_id, entity_type_name, entity_content, entity_physics, entity_physics_values,
-    EntitySpawn, get_heddle_manifest, DEFAULT_COLLAPSE_THRESHOLD,
-    get_field_potential_manifest, minimize_action, calculate_field_potential,
+    EntitySpawn, get_heddle_manifest, CollapseThresholds, DEFAULT_COLLAPSE_THRESHOLD,
+    get_field_potential_manifest, minimize_action, minimize_action_verbose, calculate_field_potential,
+    MinimizationResult, MinimizerSettings,
 };
 
 // Re-export runtime (System trait)
-pub use runtime::{System, SystemError};
+pub use runtime::{NodeBuildError, System, SystemError};
@@ -0,0 +1,41 @@
+//! Dispatch types for generated System routing.
+//!
+//! `generate_system!` turns a system schema's `dispatch` rules into a
+//! `dispatch` method: it matches the incoming trigger, reads the payload as
+//! the rule's declared `input_key` and evaluates the rule's `routing_policy`
+//! against it. CEL evaluation is left to the caller through [`PolicyEvaluator`].
+
+use super::SystemError;
+
+/// Evaluates `routing_policy` expressions for generated dispatch
+pub trait PolicyEvaluator {
+    /// Evaluate `expression` with the payload bound as `input`, returning
+    /// the pool the message should be routed to
+    fn evaluate_routing_policy(
+        &self,
+        expression: &str,
+        input: &serde_json::Value,
+    ) -> Result<String, SystemError>;
+}
+
+/// A message handed to a System's `dispatch`
+pub struct DispatchContext<'a> {
+    /// The trigger the message arrived on, e.g. `kafka:commands.weave`
+    pub trigger: &'a str,
+    /// The message payload
+    pub input: &'a serde_json::Value,
+    pub evaluator: &'a dyn PolicyEvaluator,
+}
+
+/// Where dispatch sends a message next
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DispatchTarget {
+    /// The dispatch rule's trigger
+    pub trigger: &'static str,
+    /// Key into the schema's `inputs` the payload was read as
+    pub input_key: &'static str,
+    /// `$ref` of the input's schema, when the schema declares one
+    pub input_schema: Option<&'static str>,
+    /// Pool chosen by the rule's routing policy; `None` when it has none
+    pub pool: Option<String>,
+}
@@ -3,7 +3,11 @@
 //! This module provides the core abstractions for executing Systems (activities/tools)
 //! with zero-copy JSON parsing support.
 
+mod dispatch;
+mod node;
 mod system;
 
+pub use dispatch::{DispatchContext, DispatchTarget, PolicyEvaluator};
+pub use node::NodeBuildError;
 pub use system::{System, SystemError};
 
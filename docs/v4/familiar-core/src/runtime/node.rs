@@ -0,0 +1,28 @@
+//! Errors for Nodes built through their generated builders.
+//!
+//! `generate_node!` emits a `<Node>Builder` alongside each Node struct; its
+//! `build()` reports fields the schema requires but the caller never set.
+
+use thiserror::Error;
+
+/// Errors that can occur when building a Node
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum NodeBuildError {
+    /// A field the node schema requires was never set on the builder
+    #[error("{node} is missing required field `{field}`")]
+    MissingField {
+        node: &'static str,
+        field: &'static str,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_build_error_display() {
+        let err = NodeBuildError::MissingField { node: "FatesDaemon", field: "fates_gate" };
+        assert_eq!(err.to_string(), "FatesDaemon is missing required field `fates_gate`");
+    }
+}
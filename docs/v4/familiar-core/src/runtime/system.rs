@@ -33,6 +33,10 @@ pub enum SystemError {
     /// System is not available
     #[error("System unavailable: {0}")]
     Unavailable(String),
+    
+    /// No dispatch rule matches the trigger
+    #[error("No dispatch rule for trigger: {0}")]
+    UnknownTrigger(String),
 }
 
 impl From<serde_json::Error> for SystemError {
@@ -26,7 +26,7 @@
 //! ## Usage
 //!
 //! ```ignore
-//! use familiar_core::schemas::graph::{SchemaGraph, EdgeKind};
+//! use familiar_core::schemas::graph::{SchemaGraph, EdgeKind, MermaidOpts};
 //!
 //! let graph = SchemaGraph::from_directory(&schema_dir)?;
 //!
@@ -41,13 +41,16 @@
 //!
 //! // Export to DOT format for visualization
 //! let dot = graph.to_dot();
+//!
+//! // Or as Mermaid, for the docs site
+//! let mermaid = graph.to_mermaid(MermaidOpts { group_by_directory: true });
 //! ```
 
 use petgraph::graph::{DiGraph, NodeIndex};
-use petgraph::visit::{Dfs, EdgeRef};
+use petgraph::visit::{Dfs, EdgeFiltered, EdgeRef};
 use petgraph::Direction;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -213,6 +216,91 @@ pub struct OrphanInfo {
     pub has_outgoing: bool,
 }
 
+/// A strongly-connected component of the schema graph.
+///
+/// Every schema in the group can reach every other one, so together they
+/// form one or more dependency cycles.
+#[derive(Debug, Clone)]
+pub struct SccGroup {
+    /// Schema identifiers in the component, sorted
+    pub members: Vec<String>,
+    /// Edges between members of the component
+    edges: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl SccGroup {
+    /// Find one shortest cycle through the component.
+    ///
+    /// The path lists each schema once, starting from the smallest identifier
+    /// on the cycle; the last schema references the first one again.
+    pub fn shortest_cycle(&self) -> Vec<String> {
+        let mut best: Option<Vec<String>> = None;
+
+        for start in &self.members {
+            // BFS from `start` until an edge leads back to it
+            let mut parents: HashMap<&str, &str> = HashMap::new();
+            let mut queue = VecDeque::from([start.as_str()]);
+
+            'search: while let Some(current) = queue.pop_front() {
+                for next in self.edges.get(current).into_iter().flatten() {
+                    if next == start {
+                        let mut path = vec![current.to_string()];
+                        let mut at = current;
+                        while at != start {
+                            at = parents[at];
+                            path.push(at.to_string());
+                        }
+                        path.reverse();
+
+                        if best.as_ref().is_none_or(|b| path.len() < b.len()) {
+                            best = Some(path);
+                        }
+                        break 'search;
+                    }
+                    if !parents.contains_key(next.as_str()) {
+                        parents.insert(next, current);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        best.unwrap_or_default()
+    }
+}
+
+/// The schema graph's type dependencies contain a cycle.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("dependency cycle between schemas: {}", members.join(", "))]
+pub struct CycleError {
+    /// Schemas in the offending strongly-connected component, sorted
+    pub members: Vec<String>,
+}
+
+/// Error reloading a schema file into the graph.
+#[derive(Debug, thiserror::Error)]
+pub enum LoadError {
+    #[error("failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Options for [`SchemaGraph::to_mermaid`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MermaidOpts {
+    /// Wrap schemas in one `subgraph` per directory (e.g. "entities")
+    pub group_by_directory: bool,
+}
+
 /// A dependency graph of JSON schemas.
 ///
 /// Nodes are schema identifiers (relative paths like "entities/Moment.schema.json").
@@ -223,6 +311,10 @@ pub struct SchemaGraph {
     graph: DiGraph<SchemaNode, EdgeKind>,
     /// Map from schema identifier to node index
     node_map: HashMap<String, NodeIndex>,
+    /// Directory the graph was loaded from (used by `reload_file`)
+    schema_dir: Option<PathBuf>,
+    /// Property traversal depth the graph was loaded with
+    depth: usize,
 }
 
 impl SchemaGraph {
@@ -231,6 +323,8 @@ impl SchemaGraph {
         Self {
             graph: DiGraph::new(),
             node_map: HashMap::new(),
+            schema_dir: None,
+            depth: 0,
         }
     }
 
@@ -260,6 +354,8 @@ impl SchemaGraph {
     /// A `SchemaGraph` with all schemas as nodes and typed edges.
     pub fn from_directory_with_depth(schema_dir: &Path, depth: usize) -> Result<Self, std::io::Error> {
         let mut graph = Self::new();
+        graph.schema_dir = Some(schema_dir.to_path_buf());
+        graph.depth = depth;
         
         // First pass: discover all schema files and create nodes with metadata
         let schema_files = discover_schema_files(schema_dir)?;
@@ -301,32 +397,94 @@ impl SchemaGraph {
                 .map(|p| p.to_string_lossy().to_string())
                 .unwrap_or_else(|_| path.file_name().unwrap().to_string_lossy().to_string());
             
-            // 1. Extract edges from x-familiar-* extensions (infrastructure)
-            let familiar_refs = extract_typed_refs(json);
-            for (ref_path, edge_kind) in familiar_refs {
-                let normalized = normalize_ref(&relative, &ref_path);
-                if !normalized.is_empty() && graph.node_map.contains_key(&normalized) {
-                    graph.add_typed_edge(&relative, &normalized, edge_kind);
-                }
+            graph.add_schema_edges(json, &relative, depth);
+        }
+        
+        Ok(graph)
+    }
+
+    /// Add the outgoing edges of one parsed schema file.
+    fn add_schema_edges(&mut self, json: &serde_json::Value, relative: &str, depth: usize) {
+        // 1. Extract edges from x-familiar-* extensions (infrastructure)
+        let familiar_refs = extract_typed_refs(json);
+        for (ref_path, edge_kind) in familiar_refs {
+            let normalized = normalize_ref(relative, &ref_path);
+            if !normalized.is_empty() && self.node_map.contains_key(&normalized) {
+                self.add_typed_edge(relative, &normalized, edge_kind);
             }
-            
-            // 2. Extract edges from schema composition (allOf, oneOf, anyOf, etc.)
-            let composition_refs = extract_all_type_refs(json, &relative, depth, 0);
-            for (target, edge_kind) in composition_refs {
-                // Check if target exists in graph (it might be a local def or external file)
-                if graph.node_map.contains_key(&target) {
-                    // Avoid duplicate edges
-                    if !graph.has_edge(&relative, &target) {
-                        graph.add_typed_edge(&relative, &target, edge_kind);
-                    }
+        }
+        
+        // 2. Extract edges from schema composition (allOf, oneOf, anyOf, etc.)
+        let composition_refs = extract_all_type_refs(json, relative, depth, 0);
+        for (target, edge_kind) in composition_refs {
+            // Check if target exists in graph (it might be a local def or external file)
+            if self.node_map.contains_key(&target) {
+                // Avoid duplicate edges
+                if !self.has_edge(relative, &target) {
+                    self.add_typed_edge(relative, &target, edge_kind);
                 }
             }
-            
-            // 3. Add edges FROM local definitions to their references
-            Self::add_definition_edges(&mut graph, json, &relative, depth);
         }
         
-        Ok(graph)
+        // 3. Add edges FROM local definitions to their references
+        Self::add_definition_edges(self, json, relative, depth);
+    }
+
+    /// Re-parse a single changed schema file and update the graph in place.
+    ///
+    /// `path` is either absolute or relative to the directory the graph was
+    /// loaded from. The file's node metadata and the outgoing edges of the
+    /// file and its local definitions are rebuilt; edges elsewhere in the graph
+    /// are left as they are. Definitions that were removed from the file keep
+    /// their (now edge-less) nodes.
+    ///
+    /// Returns the schemas that transitively depend on the changed file, sorted.
+    pub fn reload_file(&mut self, path: &Path) -> Result<Vec<String>, LoadError> {
+        let full_path = match &self.schema_dir {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path.to_path_buf(),
+        };
+        let relative = self.schema_dir
+            .as_deref()
+            .and_then(|dir| full_path.strip_prefix(dir).ok())
+            .unwrap_or(&full_path)
+            .to_string_lossy()
+            .to_string();
+        
+        let content = fs::read_to_string(&full_path)
+            .map_err(|source| LoadError::Io { path: full_path.clone(), source })?;
+        let json: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|source| LoadError::Parse { path: full_path.clone(), source })?;
+        
+        // Refresh node metadata, creating the node if the file is new
+        let idx = self.add_node(&relative);
+        self.graph[idx].kind = json.get("x-familiar-kind")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        self.graph[idx].title = json.get("title")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        Self::add_definition_nodes(self, &json, &relative);
+        
+        // Drop the file's outgoing edges (including from its definitions) and rebuild them
+        let file_nodes: HashSet<NodeIndex> = self.graph
+            .node_indices()
+            .filter(|&n| self.graph[n].file_path == relative)
+            .collect();
+        self.graph.retain_edges(|g, edge| {
+            g.edge_endpoints(edge).is_none_or(|(source, _)| !file_nodes.contains(&source))
+        });
+        self.add_schema_edges(&json, &relative, self.depth);
+        
+        let mut dirty: Vec<String> = file_nodes
+            .iter()
+            .flat_map(|&n| self.reverse_transitive_deps(&self.graph[n].id, &[]))
+            .filter(|id| self.node_map.get(id).is_some_and(|n| !file_nodes.contains(n)))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        dirty.sort();
+        Ok(dirty)
     }
 
     /// Add nodes for all definitions/$defs in a schema
@@ -475,6 +633,46 @@ impl SchemaGraph {
             .and_then(|&idx| self.graph[idx].kind.as_deref())
     }
 
+    /// Extract the schemas of one `x-familiar-kind` into a new graph.
+    ///
+    /// With `include_deps`, the schemas they reference directly (one hop out)
+    /// are kept as well. Every edge between kept schemas is carried over.
+    pub fn subgraph_by_kind(&self, kind: &str, include_deps: bool) -> SchemaGraph {
+        let mut keep: HashSet<NodeIndex> = self.graph
+            .node_indices()
+            .filter(|&idx| self.graph[idx].kind.as_deref() == Some(kind))
+            .collect();
+        
+        if include_deps {
+            let deps: Vec<NodeIndex> = keep
+                .iter()
+                .flat_map(|&idx| self.graph.neighbors_directed(idx, Direction::Outgoing))
+                .collect();
+            keep.extend(deps);
+        }
+        
+        let mut subgraph = SchemaGraph::new();
+        
+        // Walk in index order so the subgraph keeps the original node order
+        for idx in self.graph.node_indices().filter(|idx| keep.contains(idx)) {
+            let node = self.graph[idx].clone();
+            let new_idx = subgraph.graph.add_node(node);
+            subgraph.node_map.insert(self.graph[idx].id.clone(), new_idx);
+        }
+        
+        for edge in self.graph.edge_references() {
+            if keep.contains(&edge.source()) && keep.contains(&edge.target()) {
+                subgraph.add_typed_edge(
+                    &self.graph[edge.source()].id,
+                    &self.graph[edge.target()].id,
+                    *edge.weight(),
+                );
+            }
+        }
+        
+        subgraph
+    }
+
     /// Get all transitive dependencies for a set of root schemas.
     ///
     /// This uses an **iterative DFS** (stack-based) approach as recommended
@@ -611,6 +809,42 @@ impl SchemaGraph {
         affected
     }
 
+    /// Find how `from` comes to depend on `to` (shortest path, BFS).
+    ///
+    /// Each step is the schema reached and the kind of edge that reached it,
+    /// so the last entry is `to` itself. Returns an empty path when
+    /// `from == to` and `None` when `to` isn't reachable.
+    pub fn path_between(&self, from: &str, to: &str) -> Option<Vec<(String, EdgeKind)>> {
+        let &start = self.node_map.get(from)?;
+        let &goal = self.node_map.get(to)?;
+        
+        let mut came_from: HashMap<NodeIndex, (NodeIndex, EdgeKind)> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        
+        while let Some(current) = queue.pop_front() {
+            if current == goal {
+                let mut path = Vec::new();
+                let mut at = goal;
+                while let Some(&(prev, kind)) = came_from.get(&at) {
+                    path.push((self.graph[at].id.clone(), kind));
+                    at = prev;
+                }
+                path.reverse();
+                return Some(path);
+            }
+            
+            for edge in self.graph.edges_directed(current, Direction::Outgoing) {
+                let next = edge.target();
+                if next != start && !came_from.contains_key(&next) {
+                    came_from.insert(next, (current, *edge.weight()));
+                    queue.push_back(next);
+                }
+            }
+        }
+        
+        None
+    }
+
     /// Get direct dependencies (schemas this one references).
     pub fn dependencies(&self, schema_id: &str) -> Vec<&str> {
         self.node_map
@@ -806,15 +1040,7 @@ impl SchemaGraph {
         for idx in self.graph.node_indices() {
             let node = &self.graph[idx];
             let label = node.title.as_deref().unwrap_or(&node.id);
-            let color = match node.kind.as_deref() {
-                Some("node") => "#2196F3",
-                Some("system") => "#4CAF50",
-                Some("resource") => "#FF9800",
-                Some("queue") => "#9C27B0",
-                Some("entity") => "#00BCD4",
-                Some("primitive") => "#607D8B",
-                _ => "#9E9E9E",
-            };
+            let color = kind_color(node.kind.as_deref());
             dot.push_str(&format!("  \"{}\" [label=\"{}\", fillcolor=\"{}\", style=filled];\n", 
                 node.id, label, color));
         }
@@ -833,6 +1059,64 @@ impl SchemaGraph {
         dot
     }
 
+    /// Export the graph as a Mermaid `graph LR` diagram.
+    ///
+    /// Node colors and edge labels/colors match [`SchemaGraph::to_dot`], so
+    /// both renderings read the same way.
+    pub fn to_mermaid(&self, opts: MermaidOpts) -> String {
+        let mut out = String::from("graph LR\n");
+        let node_line = |idx: NodeIndex| {
+            let node = &self.graph[idx];
+            let label = node.title.as_deref().unwrap_or(&node.id).replace('"', "#quot;");
+            format!("n{}[\"{}\"]", idx.index(), label)
+        };
+        
+        // Add nodes, optionally grouped into one subgraph per directory
+        if opts.group_by_directory {
+            let mut by_dir: BTreeMap<&str, Vec<NodeIndex>> = BTreeMap::new();
+            for idx in self.graph.node_indices() {
+                let file_path = &self.graph[idx].file_path;
+                let dir = file_path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+                by_dir.entry(dir).or_default().push(idx);
+            }
+            
+            for (i, (dir, nodes)) in by_dir.iter().enumerate() {
+                if dir.is_empty() {
+                    for &idx in nodes {
+                        out.push_str(&format!("  {}\n", node_line(idx)));
+                    }
+                    continue;
+                }
+                out.push_str(&format!("  subgraph dir{}[\"{}\"]\n", i, dir));
+                for &idx in nodes {
+                    out.push_str(&format!("    {}\n", node_line(idx)));
+                }
+                out.push_str("  end\n");
+            }
+        } else {
+            for idx in self.graph.node_indices() {
+                out.push_str(&format!("  {}\n", node_line(idx)));
+            }
+        }
+        
+        // Node colors by kind
+        for idx in self.graph.node_indices() {
+            let color = kind_color(self.graph[idx].kind.as_deref());
+            out.push_str(&format!("  style n{} fill:{}\n", idx.index(), color));
+        }
+        
+        // Edges with labels, colored by position (Mermaid styles links by index)
+        for edge in self.graph.edge_references() {
+            out.push_str(&format!("  n{} -->|{}| n{}\n",
+                edge.source().index(), edge.weight().label(), edge.target().index()));
+        }
+        for (i, edge) in self.graph.edge_references().enumerate() {
+            out.push_str(&format!("  linkStyle {} stroke:{}\n", i, edge.weight().color()));
+        }
+        
+        out
+    }
+
     /// Export the graph in DOT format filtered by edge types.
     pub fn to_dot_filtered(&self, edge_kinds: &[EdgeKind]) -> String {
         let kind_set: HashSet<_> = edge_kinds.iter().collect();
@@ -872,20 +1156,79 @@ impl SchemaGraph {
 
     /// Perform topological sort for codegen ordering.
     ///
-    /// Returns schemas in an order where dependencies come before dependents.
-    /// This is useful for generating a single file where types are defined
-    /// in the correct order.
+    /// Returns schemas in an order where dependencies come before dependents,
+    /// so generators for order-sensitive targets (TypeScript, Python) never
+    /// emit a forward reference. Only type dependencies are followed
+    /// (`TypeRef`, `Extends`, `FieldType`); infrastructure edges don't
+    /// constrain declaration order.
     ///
-    /// Returns `None` if there are cycles in the graph.
-    pub fn topological_order(&self) -> Option<Vec<&str>> {
-        petgraph::algo::toposort(&self.graph, None)
-            .ok()
-            .map(|sorted| {
-                sorted
+    /// Returns a [`CycleError`] naming the strongly-connected component when
+    /// those edges form a cycle.
+    pub fn topological_order(&self) -> Result<Vec<String>, CycleError> {
+        let deps = EdgeFiltered::from_fn(&self.graph, |edge| {
+            matches!(edge.weight(), EdgeKind::TypeRef | EdgeKind::Extends | EdgeKind::FieldType)
+        });
+        
+        match petgraph::algo::toposort(&deps, None) {
+            // toposort puts referencing schemas first; codegen wants the reverse
+            Ok(sorted) => Ok(sorted
+                .into_iter()
+                .rev()
+                .map(|idx| self.graph[idx].id.clone())
+                .collect()),
+            Err(cycle) => {
+                let component = petgraph::algo::tarjan_scc(&deps)
                     .into_iter()
-                    .map(|idx| self.graph[idx].id.as_str())
-                    .collect()
+                    .find(|component| component.contains(&cycle.node_id()))
+                    .unwrap_or_else(|| vec![cycle.node_id()]);
+                let mut members: Vec<String> = component
+                    .into_iter()
+                    .map(|idx| self.graph[idx].id.clone())
+                    .collect();
+                members.sort();
+                Err(CycleError { members })
+            }
+        }
+    }
+
+    /// Find the strongly-connected components that form dependency cycles.
+    ///
+    /// Only recursive components are returned: groups of two or more schemas,
+    /// or a single schema that references itself. Groups are sorted by their
+    /// first member.
+    pub fn scc_groups(&self) -> Vec<SccGroup> {
+        let mut groups: Vec<SccGroup> = petgraph::algo::tarjan_scc(&self.graph)
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1 || self.graph.contains_edge(component[0], component[0])
             })
+            .map(|component| {
+                let in_group: HashSet<NodeIndex> = component.iter().copied().collect();
+                let mut edges: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+
+                for &idx in &component {
+                    for neighbor in self.graph.neighbors_directed(idx, Direction::Outgoing) {
+                        if in_group.contains(&neighbor) {
+                            edges
+                                .entry(self.graph[idx].id.clone())
+                                .or_default()
+                                .insert(self.graph[neighbor].id.clone());
+                        }
+                    }
+                }
+
+                let mut members: Vec<String> = component
+                    .iter()
+                    .map(|&idx| self.graph[idx].id.clone())
+                    .collect();
+                members.sort();
+
+                SccGroup { members, edges }
+            })
+            .collect();
+
+        groups.sort_by(|a, b| a.members.cmp(&b.members));
+        groups
     }
 }
 
@@ -895,6 +1238,19 @@ impl Default for SchemaGraph {
     }
 }
 
+/// Fill color for a node of the given `x-familiar-kind` (DOT and Mermaid).
+fn kind_color(kind: Option<&str>) -> &'static str {
+    match kind {
+        Some("node") => "#2196F3",
+        Some("system") => "#4CAF50",
+        Some("resource") => "#FF9800",
+        Some("queue") => "#9C27B0",
+        Some("entity") => "#00BCD4",
+        Some("primitive") => "#607D8B",
+        _ => "#9E9E9E",
+    }
+}
+
 /// Discover all JSON schema files in a directory (recursively).
 fn discover_schema_files(dir: &Path) -> Result<Vec<PathBuf>, std::io::Error> {
     let mut files = Vec::new();
@@ -1434,5 +1790,179 @@ mod tests {
         let data_edges = graph.edges_of_kind(&[EdgeKind::Reads, EdgeKind::Writes]);
         assert_eq!(data_edges.len(), 2);
     }
+
+    #[test]
+    fn test_to_mermaid() {
+        let mut graph = SchemaGraph::new();
+        graph.add_node_with_metadata("entities/Moment.schema.json", Some("entity".to_string()), Some("Moment".to_string()));
+        graph.add_node_with_metadata("primitives/Timestamp.schema.json", Some("primitive".to_string()), None);
+        graph.add_node("Base.schema.json");
+        graph.add_typed_edge("entities/Moment.schema.json", "primitives/Timestamp.schema.json", EdgeKind::FieldType);
+        graph.add_typed_edge("entities/Moment.schema.json", "Base.schema.json", EdgeKind::Extends);
+        
+        assert_eq!(graph.to_mermaid(MermaidOpts::default()), "\
+graph LR
+  n0[\"Moment\"]
+  n1[\"primitives/Timestamp.schema.json\"]
+  n2[\"Base.schema.json\"]
+  style n0 fill:#00BCD4
+  style n1 fill:#607D8B
+  style n2 fill:#9E9E9E
+  n0 -->|field| n1
+  n0 -->|extends| n2
+  linkStyle 0 stroke:#9E9E9E
+  linkStyle 1 stroke:#4CAF50
+");
+        
+        let grouped = graph.to_mermaid(MermaidOpts { group_by_directory: true });
+        assert_eq!(grouped, "\
+graph LR
+  n2[\"Base.schema.json\"]
+  subgraph dir1[\"entities\"]
+    n0[\"Moment\"]
+  end
+  subgraph dir2[\"primitives\"]
+    n1[\"primitives/Timestamp.schema.json\"]
+  end
+  style n0 fill:#00BCD4
+  style n1 fill:#607D8B
+  style n2 fill:#9E9E9E
+  n0 -->|field| n1
+  n0 -->|extends| n2
+  linkStyle 0 stroke:#9E9E9E
+  linkStyle 1 stroke:#4CAF50
+");
+    }
+
+    #[test]
+    fn test_topological_order() {
+        let mut graph = SchemaGraph::new();
+        graph.add_node("entities/Moment.schema.json");
+        graph.add_node("primitives/Timestamp.schema.json");
+        graph.add_node("systems/Gate.system.json");
+        graph.add_typed_edge("entities/Moment.schema.json", "primitives/Timestamp.schema.json", EdgeKind::FieldType);
+        graph.add_typed_edge("systems/Gate.system.json", "entities/Moment.schema.json", EdgeKind::Reads);
+        graph.add_typed_edge("entities/Moment.schema.json", "systems/Gate.system.json", EdgeKind::RunsOn);
+        
+        // The Reads/RunsOn loop is infrastructure and doesn't count as a cycle
+        let order = graph.topological_order().unwrap();
+        let position = |id: &str| order.iter().position(|s| s == id).unwrap();
+        assert!(position("primitives/Timestamp.schema.json") < position("entities/Moment.schema.json"));
+        
+        graph.add_typed_edge("primitives/Timestamp.schema.json", "entities/Moment.schema.json", EdgeKind::TypeRef);
+        let err = graph.topological_order().unwrap_err();
+        assert_eq!(err.members, vec!["entities/Moment.schema.json", "primitives/Timestamp.schema.json"]);
+    }
+
+    #[test]
+    fn test_subgraph_by_kind() {
+        let mut graph = SchemaGraph::new();
+        graph.add_node_with_metadata("entities/Moment.schema.json", Some("entity".to_string()), None);
+        graph.add_node_with_metadata("entities/Pulse.schema.json", Some("entity".to_string()), None);
+        graph.add_node_with_metadata("primitives/Timestamp.schema.json", Some("primitive".to_string()), None);
+        graph.add_node_with_metadata("primitives/UUID.schema.json", Some("primitive".to_string()), None);
+        graph.add_node_with_metadata("systems/Gate.system.json", Some("system".to_string()), None);
+        graph.add_typed_edge("entities/Moment.schema.json", "primitives/Timestamp.schema.json", EdgeKind::FieldType);
+        graph.add_typed_edge("entities/Pulse.schema.json", "entities/Moment.schema.json", EdgeKind::TypeRef);
+        graph.add_typed_edge("primitives/Timestamp.schema.json", "primitives/UUID.schema.json", EdgeKind::TypeRef);
+        graph.add_typed_edge("systems/Gate.system.json", "entities/Moment.schema.json", EdgeKind::Reads);
+        
+        let entities = graph.subgraph_by_kind("entity", false);
+        assert_eq!(entities.all_schemas(), vec!["entities/Moment.schema.json", "entities/Pulse.schema.json"]);
+        assert_eq!(entities.edge_count(), 1);
+        assert_eq!(entities.kind("entities/Pulse.schema.json"), Some("entity"));
+        
+        // One hop out: Timestamp is pulled in, but not UUID behind it or the system reading Moment
+        let with_deps = graph.subgraph_by_kind("entity", true);
+        assert_eq!(with_deps.node_count(), 3);
+        assert!(with_deps.has_edge("entities/Moment.schema.json", "primitives/Timestamp.schema.json"));
+        assert_eq!(with_deps.kind("primitives/Timestamp.schema.json"), Some("primitive"));
+        assert!(with_deps.dependencies("primitives/Timestamp.schema.json").is_empty());
+    }
+
+    #[test]
+    fn test_path_between() {
+        let mut graph = SchemaGraph::new();
+        
+        // Gate -> Moment -> Timestamp, plus a longer detour via Pulse
+        graph.add_typed_edge("systems/Gate.system.json", "entities/Pulse.schema.json", EdgeKind::Writes);
+        graph.add_typed_edge("entities/Pulse.schema.json", "entities/Moment.schema.json", EdgeKind::TypeRef);
+        graph.add_typed_edge("systems/Gate.system.json", "entities/Moment.schema.json", EdgeKind::Reads);
+        graph.add_typed_edge("entities/Moment.schema.json", "primitives/Timestamp.schema.json", EdgeKind::FieldType);
+        graph.add_node("primitives/UUID.schema.json");
+        
+        let path = graph.path_between("systems/Gate.system.json", "primitives/Timestamp.schema.json").unwrap();
+        assert_eq!(path, vec![
+            ("entities/Moment.schema.json".to_string(), EdgeKind::Reads),
+            ("primitives/Timestamp.schema.json".to_string(), EdgeKind::FieldType),
+        ]);
+        
+        assert_eq!(graph.path_between("entities/Moment.schema.json", "entities/Moment.schema.json"), Some(vec![]));
+        assert!(graph.path_between("primitives/Timestamp.schema.json", "entities/Moment.schema.json").is_none());
+        assert!(graph.path_between("entities/Moment.schema.json", "primitives/UUID.schema.json").is_none());
+    }
+
+    #[test]
+    fn test_reload_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |path: &str, json: serde_json::Value| {
+            let full = dir.path().join(path);
+            fs::create_dir_all(full.parent().unwrap()).unwrap();
+            fs::write(full, json.to_string()).unwrap();
+        };
+        write("primitives/UUID.schema.json", serde_json::json!({ "type": "string" }));
+        write("primitives/Timestamp.schema.json", serde_json::json!({ "type": "string" }));
+        write("entities/Moment.schema.json", serde_json::json!({
+            "type": "object",
+            "properties": { "id": { "$ref": "../primitives/UUID.schema.json" } }
+        }));
+        write("entities/Pulse.schema.json", serde_json::json!({
+            "type": "object",
+            "properties": {
+                "moment": { "$ref": "../entities/Moment.schema.json" },
+                "id": { "$ref": "../primitives/UUID.schema.json" }
+            }
+        }));
+        
+        let mut graph = SchemaGraph::from_directory(dir.path()).unwrap();
+        assert!(graph.has_edge("entities/Moment.schema.json", "primitives/UUID.schema.json"));
+        let edges_before = graph.edge_count();
+        
+        // Moment now references Timestamp instead of UUID
+        write("entities/Moment.schema.json", serde_json::json!({
+            "type": "object",
+            "title": "Moment",
+            "properties": { "at": { "$ref": "../primitives/Timestamp.schema.json" } }
+        }));
+        let dirty = graph.reload_file(Path::new("entities/Moment.schema.json")).unwrap();
+        
+        assert_eq!(dirty, vec!["entities/Pulse.schema.json"]);
+        assert!(!graph.has_edge("entities/Moment.schema.json", "primitives/UUID.schema.json"));
+        assert!(graph.has_edge("entities/Moment.schema.json", "primitives/Timestamp.schema.json"));
+        assert!(graph.has_edge("entities/Pulse.schema.json", "entities/Moment.schema.json"));
+        assert!(graph.has_edge("entities/Pulse.schema.json", "primitives/UUID.schema.json"));
+        assert_eq!(graph.edge_count(), edges_before);
+        assert_eq!(graph.graph[graph.node_map["entities/Moment.schema.json"]].title.as_deref(), Some("Moment"));
+    }
+
+    #[test]
+    fn test_scc_shortest_cycle() {
+        let mut graph = SchemaGraph::new();
+
+        // A -> B -> C -> A, with D hanging off the cycle
+        graph.add_edge("A", "B");
+        graph.add_edge("B", "C");
+        graph.add_edge("C", "A");
+        graph.add_edge("C", "D");
+
+        let groups = graph.scc_groups();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].members, vec!["A", "B", "C"]);
+
+        let cycle = groups[0].shortest_cycle();
+        assert_eq!(cycle.len(), 3);
+        assert_eq!(cycle, vec!["A", "B", "C"]);
+        assert!(!cycle.contains(&"D".to_string()));
+    }
 }
 
@@ -12,7 +12,7 @@ pub mod generated_version;
 pub mod graph;
 
 pub use generated_version::{SCHEMA_HASH, SCHEMA_VERSION};
-pub use graph::SchemaGraph;
+pub use graph::{CycleError, LoadError, MermaidOpts, SccGroup, SchemaGraph};
 
 // Get schemas from familiar-contracts
 pub use familiar_contracts::SCHEMAS;
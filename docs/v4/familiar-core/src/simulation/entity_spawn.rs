@@ -2,7 +2,9 @@
 //!
 //! Defines the possible entities that can be spawned from the Heddle classification process.
 
-use crate::{Moment, Intent, Thread, Bond, Pulse, Motif, Filament, Focus};
+use crate::{Moment, Intent, Thread, Bond, Pulse, Motif, Filament, Focus, HeddleEntityType};
+use super::spawner::heddle_type_name;
+use std::collections::HashMap;
 
 /// An entity spawned from the Heddle collapse process.
 /// Wraps all possible entity types in a single enum.
@@ -34,4 +36,65 @@ pub fn get_heddle_manifest() -> crate::config::SystemManifest {
 }
 
 /// Default collapse threshold (70% confidence)
+///
+/// The fallback of `CollapseThresholds::default()`; services configure it as
+/// `systems.heddle.default_collapse_threshold`.
 pub const DEFAULT_COLLAPSE_THRESHOLD: f64 = 0.7;
+
+/// Collapse threshold per entity type, keyed by entity type name
+/// (as returned by `entity_type_name`, matched case-insensitively).
+/// Types without an entry use the fallback threshold.
+///
+/// Services fill it from `systems.heddle`, either through `with` and
+/// `with_default` or, with the `config` feature, `from_config`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollapseThresholds {
+    default: f64,
+    thresholds: HashMap<String, f64>,
+}
+
+impl Default for CollapseThresholds {
+    fn default() -> Self {
+        Self {
+            default: DEFAULT_COLLAPSE_THRESHOLD,
+            thresholds: HashMap::new(),
+        }
+    }
+}
+
+impl CollapseThresholds {
+    /// Load from `systems.heddle`
+    #[cfg(feature = "config")]
+    pub fn from_config(config: &familiar_config::GlobalConfig) -> Self {
+        let heddle = &config.systems.heddle;
+        let fallback = Self::default().with_default(heddle.default_collapse_threshold);
+        heddle.collapse_thresholds
+            .iter()
+            .fold(fallback, |thresholds, (name, threshold)| thresholds.with(name, *threshold))
+    }
+
+    /// Set the threshold for one entity type
+    pub fn with(mut self, entity_type_name: &str, threshold: f64) -> Self {
+        self.thresholds.insert(entity_type_name.to_lowercase(), threshold);
+        self
+    }
+
+    /// Set the threshold for entity types without their own entry
+    pub fn with_default(mut self, threshold: f64) -> Self {
+        self.default = threshold;
+        self
+    }
+
+    /// The threshold for an entity type, falling back to the default
+    pub fn for_type(&self, entity_type: HeddleEntityType) -> f64 {
+        self.thresholds
+            .get(&heddle_type_name(entity_type).to_lowercase())
+            .copied()
+            .unwrap_or(self.default)
+    }
+
+    /// Whether a classification of `weight` collapses into an entity
+    pub fn collapses(&self, entity_type: HeddleEntityType, weight: f64) -> bool {
+        weight >= self.for_type(entity_type)
+    }
+}
@@ -13,16 +13,19 @@ pub mod resolver;
 
 // Re-export spawner functions
 pub use spawner::{
-    spawn_from_weave_unit, spawn_from_weave_units,
+    spawn_from_weave_unit, spawn_from_weave_units, spawn_from_weave_units_iter, SpawnError, SpawnOptions,
     entity_id, entity_type_name, entity_content, entity_physics, entity_physics_values,
     generate_physics,
 };
 
 // Re-export physics functions
-pub use physics::{get_field_potential_manifest, minimize_action, calculate_field_potential};
+pub use physics::{
+    get_field_potential_manifest, minimize_action, minimize_action_verbose, calculate_field_potential,
+    MinimizationResult, MinimizerSettings,
+};
 
 // Re-export entity spawn types
-pub use entity_spawn::{EntitySpawn, get_heddle_manifest, DEFAULT_COLLAPSE_THRESHOLD};
+pub use entity_spawn::{EntitySpawn, get_heddle_manifest, CollapseThresholds, DEFAULT_COLLAPSE_THRESHOLD};
 
 // Re-export resolver types
 pub use resolver::{ThreadResolver, ResolverConfig, ResolverError, ResolverResult};
@@ -25,6 +25,9 @@
 //! for e in &mut excitations { e.dehydrate(); }
 //! db.save_all(&excitations)?;
 //! ```
+//!
+//! To relax an excitation until it settles, use `minimize_action_verbose`,
+//! which reports whether it converged within `systems.physics` limits.
 
 use familiar_contracts::FieldExcitation;
 use crate::config::{SystemManifest, SystemDomain, SystemTrigger};
@@ -32,6 +35,46 @@ use crate::config::{SystemManifest, SystemDomain, SystemTrigger};
 /// Default simulation timestep (seconds)
 pub const DEFAULT_DT: f64 = 0.016; // ~60 FPS
 
+/// Iteration limits for `minimize_action_verbose`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimizerSettings {
+    /// Most steps to take before giving up
+    pub max_iterations: u32,
+    /// Gradient magnitude and speed at which the excitation counts as settled
+    pub tolerance: f64,
+}
+
+impl MinimizerSettings {
+    /// Load from `systems.physics`
+    #[cfg(feature = "config")]
+    pub fn from_config(config: &familiar_config::GlobalConfig) -> Self {
+        Self {
+            max_iterations: config.systems.physics.max_iterations,
+            tolerance: config.systems.physics.tolerance,
+        }
+    }
+}
+
+/// The `systems.physics` defaults from familiar-config
+impl Default for MinimizerSettings {
+    fn default() -> Self {
+        Self { max_iterations: 1000, tolerance: 1e-6 }
+    }
+}
+
+/// Outcome of `minimize_action_verbose`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinimizationResult {
+    /// Field potential at the final position
+    pub value: f64,
+    /// Steps taken
+    pub iterations: u32,
+    /// Whether the residual and speed reached tolerance before the iteration cap
+    pub converged: bool,
+    /// Field gradient magnitude at the final position
+    pub final_residual: f64,
+}
+
 /// QFT-based system: Excitations move to minimize the "Action" of the field.
 /// In Quantum Field Theory, particles follow the "Path of Least Action" by moving
 /// down the gradient of the field potential.
@@ -93,6 +136,75 @@ pub fn minimize_action(
     target.apply_velocity(dt);
 }
 
+/// Step an excitation with `minimize_action` until it comes to rest: both the
+/// field gradient at its position and its speed are within tolerance, or the
+/// iteration cap is hit.
+///
+/// Velocity is zeroed whenever it points uphill, so the excitation loses
+/// energy on each overshoot and settles into the nearest minimum instead of
+/// oscillating around it.
+///
+/// **Requires:** `target.hydrate()` must be called before simulation. An
+/// unhydrated target is left alone and reported as not converged, with NaN
+/// value and residual.
+///
+/// # Arguments
+/// * `target` - The excitation to update (must be hydrated)
+/// * `neighbors` - Other excitations that contribute to the field
+/// * `dt` - Timestep in seconds (use DEFAULT_DT for standard tick)
+/// * `settings` - Iteration cap and tolerance (see `MinimizerSettings`)
+pub fn minimize_action_verbose(
+    target: &mut FieldExcitation,
+    neighbors: &[FieldExcitation],
+    dt: f64,
+    settings: &MinimizerSettings,
+) -> MinimizationResult {
+    debug_assert!(target.is_hydrated(), "Call hydrate() before simulation");
+
+    let Some(mut position) = target.position_f64() else {
+        return MinimizationResult {
+            value: f64::NAN,
+            iterations: 0,
+            converged: false,
+            final_residual: f64::NAN,
+        };
+    };
+
+    let mut gradient = calculate_field_gradient(position, neighbors);
+    let mut residual = magnitude(gradient);
+    let mut velocity = target.velocity_f64().unwrap_or([0.0; 3]);
+    let settled = |residual: f64, velocity: [f64; 3]| {
+        residual <= settings.tolerance && magnitude(velocity) <= settings.tolerance
+    };
+    let mut iterations = 0;
+    while !settled(residual, velocity) && iterations < settings.max_iterations {
+        minimize_action(target, gradient, dt);
+        iterations += 1;
+
+        position = target.position_f64().unwrap_or(position);
+        gradient = calculate_field_gradient(position, neighbors);
+        residual = magnitude(gradient);
+        velocity = target.velocity_f64().unwrap_or(velocity);
+        // Moving uphill (against -gradient): stop, and fall from here
+        let uphill = velocity.iter().zip(gradient).map(|(v, g)| v * g).sum::<f64>() > 0.0;
+        if uphill {
+            velocity = [0.0; 3];
+            target.set_velocity_f64(velocity);
+        }
+    }
+
+    MinimizationResult {
+        value: calculate_field_potential(position, neighbors),
+        iterations,
+        converged: settled(residual, velocity),
+        final_residual: residual,
+    }
+}
+
+fn magnitude(v: [f64; 3]) -> f64 {
+    (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+}
+
 /// Calculate the field gradient at a position in the VAE manifold.
 /// 
 /// The gradient points "uphill" - excitations will move in the opposite direction.
@@ -172,3 +284,74 @@ pub fn calculate_field_potential(
     potential
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn excitation(position: [f64; 3], velocity: [f64; 3]) -> FieldExcitation {
+        let mut e = FieldExcitation::new([0, 0, 0], [0, 0, 0], 0.5, 0.5, 0.0).unwrap();
+        e.hydrate();
+        e.set_position_f64(position);
+        e.set_velocity_f64(velocity);
+        e
+    }
+
+    #[test]
+    fn test_minimizer_settles_at_the_minimum() {
+        // Between two equal neighbors the pull along y cancels at the
+        // midpoint, so the field's minimum on that line is the origin
+        let neighbors = [
+            excitation([-1.0, 0.0, 0.0], [0.0; 3]),
+            excitation([1.0, 0.0, 0.0], [0.0; 3]),
+        ];
+        let mut target = excitation([0.0, 0.5, 0.0], [0.0; 3]);
+        let settings = MinimizerSettings { max_iterations: 1000, tolerance: 1e-6 };
+
+        let result = minimize_action_verbose(&mut target, &neighbors, DEFAULT_DT, &settings);
+
+        assert!(result.converged);
+        assert!(result.iterations > 0 && result.iterations < 1000);
+        assert!(result.final_residual <= 1e-6);
+        let position = target.position_f64().unwrap();
+        assert_eq!(position[0], 0.0);
+        assert!(position[1].abs() < 1e-5, "{:?}", position);
+        assert_eq!(position[2], 0.0);
+        assert!(magnitude(target.velocity_f64().unwrap()) <= 1e-6);
+        assert!((result.value - calculate_field_potential([0.0; 3], &neighbors)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_minimizer_moving_through_a_flat_field_is_not_settled() {
+        // No gradient anywhere, but the excitation never comes to rest
+        let mut target = excitation([0.0; 3], [1.0, 0.0, 0.0]);
+        let settings = MinimizerSettings { max_iterations: 50, tolerance: 1e-6 };
+
+        let result = minimize_action_verbose(&mut target, &[], DEFAULT_DT, &settings);
+
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 50);
+        assert_eq!(result.final_residual, 0.0);
+    }
+
+    #[test]
+    fn test_minimizer_reports_iteration_cap() {
+        let neighbors = [excitation([0.0, 0.0, 0.0], [0.0; 3])];
+        let mut target = excitation([0.5, 0.0, 0.0], [0.0; 3]);
+        let settings = MinimizerSettings { max_iterations: 5, tolerance: 1e-6 };
+
+        let result = minimize_action_verbose(&mut target, &neighbors, DEFAULT_DT, &settings);
+
+        assert!(!result.converged);
+        assert_eq!(result.iterations, 5);
+        assert!(result.final_residual > 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "config")]
+    fn test_minimizer_settings_from_config() {
+        let mut config = familiar_config::GlobalConfig::default();
+        config.systems.physics.max_iterations = 50;
+        assert_eq!(MinimizerSettings::from_config(&config).max_iterations, 50);
+        assert_eq!(MinimizerSettings::default().tolerance, config.systems.physics.tolerance);
+    }
+}
@@ -7,10 +7,65 @@ use crate::{
     UUID, Timestamp, QuantizedCoord,
     HeddleEntityType, RawPhysicsHint,
     Identity, ContentPayload, FieldExcitation, QuantumState, CognitiveOptics,
-    WeaveUnit, TaskDynamics, RelationalDynamics, BondPhysics,
+    WeaveUnit, WeaveUnitClassification, TaskDynamics, RelationalDynamics, BondPhysics,
     Moment, Intent, Thread, Bond, Pulse, Motif, Filament, Focus,
 };
-use super::entity_spawn::EntitySpawn;
+use super::entity_spawn::{CollapseThresholds, EntitySpawn};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Options for entity spawning
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpawnOptions {
+    /// Derive entity IDs from this seed (and the unit index) instead of
+    /// generating random ones, so a run can be reproduced
+    pub rng_seed: Option<u64>,
+    /// Creation time for spawned entities; `None` uses the current time.
+    /// Pin it alongside `rng_seed` for byte-identical output.
+    pub created_at: Option<Timestamp>,
+}
+
+/// Namespace for seeded entity IDs (UUIDv5)
+const SEEDED_ID_NAMESPACE: Uuid = Uuid::from_u128(0x6f1d_2c5e_8a43_4b7e_9d0f_3e52_a1c8_b746);
+
+/// Issues IDs and timestamps for the entities spawned from one unit
+struct EntityIds {
+    seed: Option<u64>,
+    unit_index: u64,
+    issued: u64,
+    created_at: Timestamp,
+}
+
+impl EntityIds {
+    fn for_unit(unit: &WeaveUnit, options: SpawnOptions) -> Self {
+        Self {
+            seed: options.rng_seed,
+            unit_index: unit.index as u64,
+            issued: 0,
+            created_at: options.created_at.unwrap_or_else(Timestamp::now),
+        }
+    }
+
+    /// Random, or UUIDv5 of (seed, unit index, issue order) when seeded
+    fn next(&mut self) -> UUID {
+        let Some(seed) = self.seed else {
+            return UUID::new();
+        };
+        let name = [seed, self.unit_index, self.issued].map(u64::to_be_bytes).concat();
+        self.issued += 1;
+        UUID::from_uuid(Uuid::new_v5(&SEEDED_ID_NAMESPACE, &name))
+    }
+}
+
+/// Errors that stop a single WeaveUnit from spawning
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum SpawnError {
+    #[error("No physics hint for weave unit {unit_index}")]
+    MissingPhysicsHint { unit_index: usize },
+
+    #[error("Invalid physics hint for weave unit {unit_index}: {reason}")]
+    InvalidPhysicsHint { unit_index: usize, reason: String },
+}
 
 // ============================================================================
 // Physics Generation from Hints (Schema-Driven)
@@ -19,12 +74,36 @@ use super::entity_spawn::EntitySpawn;
 /// Generate physics components from raw LLM hints.
 /// Uses "Vacuum State" defaults for missing values.
 pub fn generate_physics(hint: &Option<RawPhysicsHint>, _tenant_id: UUID) -> (FieldExcitation, QuantumState, CognitiveOptics) {
+    let physics = field_excitation(hint).unwrap_or_else(|_| {
+        // Vacuum state fallback
+        FieldExcitation::new([0, 0, 0], [0, 0, 0], 0.5, 0.5, 0.5).unwrap()
+    });
+
+    // Quantum state (simple default embedding for now)
+    // In production, this would come from an LLM embedding
+    let quantum = QuantumState::from_embedding(vec![0.0; 8]);
+
+    // Cognitive optics from physics hints
+    let intrusiveness = hint.as_ref().and_then(|h| h.intrusiveness).unwrap_or(0.0);
+    let clarity = hint.as_ref().and_then(|h| h.clarity).unwrap_or(0.5);
+    let optics = CognitiveOptics::new(
+        intrusiveness,  // Emissivity = intrusiveness
+        clarity,        // Albedo = clarity (memorable)
+        0.5,            // Neutral roughness
+        0.0,            // Transparent
+    ).unwrap_or_default();
+
+    (physics, quantum, optics)
+}
+
+/// Map raw LLM hints onto a FieldExcitation, failing on out-of-range values.
+/// Uses "Vacuum State" defaults for missing values.
+fn field_excitation(hint: &Option<RawPhysicsHint>) -> Result<FieldExcitation, String> {
     // Extract values with vacuum state defaults
     let valence = hint.as_ref().and_then(|h| h.valence).unwrap_or(0.0);
     let arousal = hint.as_ref().and_then(|h| h.arousal).unwrap_or(0.5);
     let significance = hint.as_ref().and_then(|h| h.significance).unwrap_or(0.5);
     let clarity = hint.as_ref().and_then(|h| h.clarity).unwrap_or(0.5);
-    let intrusiveness = hint.as_ref().and_then(|h| h.intrusiveness).unwrap_or(0.0);
     let volatility = hint.as_ref().and_then(|h| h.volatility).unwrap_or(0.5);
 
     // Map to VAE coordinates (i64 for QuantizedCoord)
@@ -35,30 +114,13 @@ pub fn generate_physics(hint: &Option<RawPhysicsHint>, _tenant_id: UUID) -> (Fie
     let a_coord = QuantizedCoord::from_normalized(arousal * 2.0 - 1.0).value(); // Map 0-1 to -1 to +1
     let e_coord = QuantizedCoord::from_normalized(clarity * 2.0 - 1.0).value();
     
-    let physics = FieldExcitation::new(
+    FieldExcitation::new(
         [v_coord, a_coord, e_coord],  // position
         [0, 0, 0],                     // velocity (at rest initially)
         significance,                  // amplitude = significance
         arousal,                       // energy = arousal
         volatility,                    // temperature = volatility
-    ).unwrap_or_else(|_| {
-        // Vacuum state fallback
-        FieldExcitation::new([0, 0, 0], [0, 0, 0], 0.5, 0.5, 0.5).unwrap()
-    });
-
-    // Quantum state (simple default embedding for now)
-    // In production, this would come from an LLM embedding
-    let quantum = QuantumState::from_embedding(vec![0.0; 8]);
-
-    // Cognitive optics from physics hints
-    let optics = CognitiveOptics::new(
-        intrusiveness,  // Emissivity = intrusiveness
-        clarity,        // Albedo = clarity (memorable)
-        0.5,            // Neutral roughness
-        0.0,            // Transparent
-    ).unwrap_or_default();
-
-    (physics, quantum, optics)
+    )
 }
 
 // ============================================================================
@@ -72,11 +134,12 @@ fn spawn_entity(
     tenant_id: UUID,
     physics: FieldExcitation,
     quantum: QuantumState,
+    ids: &mut EntityIds,
 ) -> EntitySpawn {
     let identity = Identity {
-        id: UUID::new(),
+        id: ids.next(),
         tenant_id,
-        created_at: Timestamp::now(),
+        created_at: ids.created_at,
     };
 
     let content_payload = ContentPayload {
@@ -112,8 +175,8 @@ fn spawn_entity(
             bond_physics: BondPhysics::default(),
             content: content_payload,
             dynamics: RelationalDynamics::default(),
-            head_thread_id: UUID::new(), // Placeholder: Needs Stitch resolution
-            tail_thread_id: UUID::new(),
+            head_thread_id: ids.next(), // Placeholder: Needs Stitch resolution
+            tail_thread_id: ids.next(),
         }),
         HeddleEntityType::PULSE => EntitySpawn::Pulse(Pulse {
             identity,
@@ -140,57 +203,151 @@ fn spawn_entity(
             physics,
             quantum,
             content: content_payload,
-            active_since: Timestamp::now(),
+            active_since: ids.created_at,
         }),
     }
 }
 
 /// Spawn entities from a WeaveUnit based on its classifications.
-/// Returns spawned entities. Only LOG purpose units spawn entities.
+/// Returns spawned entities. Only LOG purpose units spawn entities;
+/// an out-of-range physics hint fails the unit.
 pub fn spawn_from_weave_unit(
     unit: &WeaveUnit,
     physics_hint: &Option<RawPhysicsHint>,
     tenant_id: UUID,
-    threshold: f64,
-) -> Vec<EntitySpawn> {
-    // Only spawn for units with LOG purpose
-    if !unit.should_spawn() {
-        return vec![];
-    }
-    
-    let (physics, quantum, _optics) = generate_physics(physics_hint, tenant_id);
-    
-    unit.classifications_above(threshold)
-        .into_iter()
-        .map(|c| spawn_entity(
-            c.entity_type,
-            &unit.content,
-            tenant_id,
-            physics.clone(),
-            quantum.clone(),
-        ))
-        .collect()
+    thresholds: &CollapseThresholds,
+    options: SpawnOptions,
+) -> Result<Vec<EntitySpawn>, SpawnError> {
+    spawn_unit(unit.index, unit, Some(physics_hint), tenant_id, thresholds, options)
 }
 
 /// Spawn entities from multiple WeaveUnits with their physics hints.
-/// Returns (unit_index, spawned_entities) for each unit that produced entities.
+/// Returns (unit_index, result) for each unit that produced entities or
+/// failed; a failed unit doesn't stop the rest.
+///
+/// A spawning unit with no entry in `physics_hints` fails with
+/// `MissingPhysicsHint` (it is not skipped), and an out-of-range hint fails
+/// with `InvalidPhysicsHint` rather than falling back to vacuum-state
+/// physics as `generate_physics` does. Pass `None` for units without hints.
 pub fn spawn_from_weave_units(
     units: &[WeaveUnit],
     physics_hints: &[Option<RawPhysicsHint>],
     tenant_id: UUID,
-    threshold: f64,
-) -> Vec<(usize, Vec<EntitySpawn>)> {
-    units.iter()
-        .zip(physics_hints.iter())
+    thresholds: &CollapseThresholds,
+    options: SpawnOptions,
+) -> Vec<(usize, Result<Vec<EntitySpawn>, SpawnError>)> {
+    units
+        .iter()
         .enumerate()
-        .map(|(idx, (unit, hint))| {
-            let entities = spawn_from_weave_unit(unit, hint, tenant_id, threshold);
-            (idx, entities)
+        .filter_map(|(idx, unit)| {
+            match spawn_unit(idx, unit, physics_hints.get(idx), tenant_id, thresholds, options) {
+                Ok(entities) if entities.is_empty() => None,
+                spawned => Some((idx, spawned)),
+            }
         })
-        .filter(|(_, entities)| !entities.is_empty())
         .collect()
 }
 
+/// Spawn every collapsed classification of one unit
+fn spawn_unit(
+    unit_index: usize,
+    unit: &WeaveUnit,
+    physics_hint: Option<&Option<RawPhysicsHint>>,
+    tenant_id: UUID,
+    thresholds: &CollapseThresholds,
+    options: SpawnOptions,
+) -> Result<Vec<EntitySpawn>, SpawnError> {
+    let Some((physics, quantum)) = unit_physics(unit_index, unit, physics_hint)? else {
+        return Ok(vec![]);
+    };
+    let mut ids = EntityIds::for_unit(unit, options);
+
+    Ok(collapsed(unit, thresholds)
+        .map(|c| spawn_entity(
+            c.entity_type,
+            &unit.content,
+            tenant_id,
+            physics.clone(),
+            quantum.clone(),
+            &mut ids,
+        ))
+        .collect())
+}
+
+/// Lazily spawn entities from WeaveUnits, one at a time.
+///
+/// Units are pulled from `units` only as entities are requested, so large
+/// weaves can stream into the physics step without buffering. A unit that
+/// fails yields a single `Err` in its place and the remaining units still spawn.
+/// `physics_hints` is indexed by the unit's position in `units`; hints are
+/// handled as in `spawn_from_weave_units`.
+pub fn spawn_from_weave_units_iter<'a>(
+    units: impl IntoIterator<Item = &'a WeaveUnit> + 'a,
+    physics_hints: &'a [Option<RawPhysicsHint>],
+    tenant_id: UUID,
+    thresholds: &'a CollapseThresholds,
+    options: SpawnOptions,
+) -> impl Iterator<Item = Result<EntitySpawn, SpawnError>> + 'a {
+    spawn_indexed(units, physics_hints, tenant_id, thresholds, options).map(|(_, entity)| entity)
+}
+
+/// Lazily spawn entities, tagging each with its unit's position
+fn spawn_indexed<'a>(
+    units: impl IntoIterator<Item = &'a WeaveUnit> + 'a,
+    physics_hints: &'a [Option<RawPhysicsHint>],
+    tenant_id: UUID,
+    thresholds: &'a CollapseThresholds,
+    options: SpawnOptions,
+) -> impl Iterator<Item = (usize, Result<EntitySpawn, SpawnError>)> + 'a {
+    units.into_iter().enumerate().flat_map(move |(idx, unit)| {
+        let (error, physics) = match unit_physics(idx, unit, physics_hints.get(idx)) {
+            Ok(physics) => (None, physics),
+            Err(e) => (Some(e), None),
+        };
+        let entities = physics.into_iter().flat_map(move |(physics, quantum)| {
+            let mut ids = EntityIds::for_unit(unit, options);
+            collapsed(unit, thresholds)
+                .map(move |c| Ok(spawn_entity(
+                    c.entity_type,
+                    &unit.content,
+                    tenant_id,
+                    physics.clone(),
+                    quantum.clone(),
+                    &mut ids,
+                )))
+        });
+        error.map(Err).into_iter().chain(entities).map(move |entity| (idx, entity))
+    })
+}
+
+/// Classifications of a unit that meet their entity type's collapse threshold
+fn collapsed<'a>(
+    unit: &'a WeaveUnit,
+    thresholds: &'a CollapseThresholds,
+) -> impl Iterator<Item = &'a WeaveUnitClassification> + 'a {
+    unit.classifications
+        .iter()
+        .filter(move |c| thresholds.collapses(c.entity_type, c.weight.value()))
+}
+
+/// Physics for a unit that should spawn, `None` for units that don't
+fn unit_physics(
+    unit_index: usize,
+    unit: &WeaveUnit,
+    physics_hint: Option<&Option<RawPhysicsHint>>,
+) -> Result<Option<(FieldExcitation, QuantumState)>, SpawnError> {
+    // Only spawn for units with LOG purpose
+    if !unit.should_spawn() {
+        return Ok(None);
+    }
+
+    let hint = physics_hint.ok_or(SpawnError::MissingPhysicsHint { unit_index })?;
+    let physics = field_excitation(hint)
+        .map_err(|reason| SpawnError::InvalidPhysicsHint { unit_index, reason })?;
+    let quantum = QuantumState::from_embedding(vec![0.0; 8]);
+    Ok(Some((physics, quantum)))
+}
+
 /// Get the entity ID from an EntitySpawn
 pub fn entity_id(spawn: &EntitySpawn) -> UUID {
     match spawn {
@@ -207,15 +364,29 @@ pub fn entity_id(spawn: &EntitySpawn) -> UUID {
 
 /// Get the entity type name from an EntitySpawn
 pub fn entity_type_name(spawn: &EntitySpawn) -> &'static str {
-    match spawn {
-        EntitySpawn::Moment(_) => "Moment",
-        EntitySpawn::Intent(_) => "Intent",
-        EntitySpawn::Thread(_) => "Thread",
-        EntitySpawn::Bond(_) => "Bond",
-        EntitySpawn::Pulse(_) => "Pulse",
-        EntitySpawn::Motif(_) => "Motif",
-        EntitySpawn::Filament(_) => "Filament",
-        EntitySpawn::Focus(_) => "Focus",
+    heddle_type_name(match spawn {
+        EntitySpawn::Moment(_) => HeddleEntityType::MOMENT,
+        EntitySpawn::Intent(_) => HeddleEntityType::INTENT,
+        EntitySpawn::Thread(_) => HeddleEntityType::THREAD,
+        EntitySpawn::Bond(_) => HeddleEntityType::BOND,
+        EntitySpawn::Pulse(_) => HeddleEntityType::PULSE,
+        EntitySpawn::Motif(_) => HeddleEntityType::MOTIF,
+        EntitySpawn::Filament(_) => HeddleEntityType::FILAMENT,
+        EntitySpawn::Focus(_) => HeddleEntityType::FOCUS,
+    })
+}
+
+/// Entity type name for a classification, as `entity_type_name` reports it
+pub(crate) fn heddle_type_name(entity_type: HeddleEntityType) -> &'static str {
+    match entity_type {
+        HeddleEntityType::MOMENT => "Moment",
+        HeddleEntityType::INTENT => "Intent",
+        HeddleEntityType::THREAD => "Thread",
+        HeddleEntityType::BOND => "Bond",
+        HeddleEntityType::PULSE => "Pulse",
+        HeddleEntityType::MOTIF => "Motif",
+        HeddleEntityType::FILAMENT => "Filament",
+        HeddleEntityType::FOCUS => "Focus",
     }
 }
 
@@ -264,3 +435,164 @@ pub fn entity_physics_values(spawn: &EntitySpawn) -> ([f64; 3], f64, f64, f64) {
     )
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::entity_spawn::DEFAULT_COLLAPSE_THRESHOLD;
+    use std::cell::Cell;
+
+    fn log_unit(index: usize, content: &str) -> WeaveUnit {
+        let mut unit = WeaveUnit::new(index, content);
+        unit.add_classification(HeddleEntityType::MOMENT, 0.9).unwrap();
+        unit
+    }
+
+    #[test]
+    fn test_spawn_iter_is_lazy() {
+        let units: Vec<WeaveUnit> = (0..3).map(|i| log_unit(i, "walked the dog")).collect();
+        let hints = vec![None; 3];
+        let thresholds = CollapseThresholds::default();
+        let pulled = Cell::new(0);
+
+        let mut spawns = spawn_from_weave_units_iter(
+            units.iter().inspect(|_| pulled.set(pulled.get() + 1)),
+            &hints,
+            UUID::new(),
+            &thresholds,
+            SpawnOptions::default(),
+        );
+        assert_eq!(pulled.get(), 0);
+
+        assert!(spawns.next().unwrap().is_ok());
+        assert_eq!(pulled.get(), 1);
+
+        assert_eq!(spawns.count(), 2);
+        assert_eq!(pulled.get(), 3);
+    }
+
+    #[test]
+    fn test_bad_unit_does_not_stop_the_rest() {
+        let units = vec![log_unit(0, "first"), log_unit(1, "second"), log_unit(2, "third")];
+        let bad_hint = RawPhysicsHint { significance: Some(4.2), ..Default::default() };
+        let hints = vec![None, Some(bad_hint), None];
+        let thresholds = CollapseThresholds::default();
+
+        let results: Vec<_> = spawn_from_weave_units_iter(&units, &hints, UUID::new(), &thresholds, SpawnOptions::default()).collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(entity_content(results[0].as_ref().unwrap()), "first");
+        assert!(matches!(results[1], Err(SpawnError::InvalidPhysicsHint { unit_index: 1, .. })));
+        assert_eq!(entity_content(results[2].as_ref().unwrap()), "third");
+
+        // The eager form reports the failure in its unit's slot
+        let spawned = spawn_from_weave_units(&units, &hints, UUID::new(), &thresholds, SpawnOptions::default());
+        let indices: Vec<usize> = spawned.iter().map(|(idx, _)| *idx).collect();
+        assert_eq!(indices, [0, 1, 2]);
+        assert_eq!(spawned[1].1.as_ref().unwrap_err(), results[1].as_ref().unwrap_err());
+        assert_eq!(entity_content(&spawned[2].1.as_ref().unwrap()[0]), "third");
+
+        let missing = spawn_from_weave_units(&units, &[None], UUID::new(), &thresholds, SpawnOptions::default());
+        assert!(missing[0].1.is_ok());
+        assert_eq!(missing[1].1.as_ref().unwrap_err(), &SpawnError::MissingPhysicsHint { unit_index: 1 });
+        assert_eq!(missing[2].1.as_ref().unwrap_err(), &SpawnError::MissingPhysicsHint { unit_index: 2 });
+
+        // A single unit rejects the same hint
+        let single = spawn_from_weave_unit(&units[1], &hints[1], UUID::new(), &thresholds, SpawnOptions::default());
+        assert!(matches!(single, Err(SpawnError::InvalidPhysicsHint { unit_index: 1, .. })));
+    }
+
+    #[test]
+    fn test_batch_spawn_rejects_hints_generate_physics_tolerates() {
+        let units = vec![log_unit(0, "first"), log_unit(1, "second")];
+        let thresholds = CollapseThresholds::default();
+        let bad_hint = Some(RawPhysicsHint { arousal: Some(-3.0), ..Default::default() });
+
+        // generate_physics keeps its vacuum-state fallback...
+        let (vacuum, _, _) = generate_physics(&bad_hint, UUID::new());
+        assert_eq!(vacuum.energy.value(), 0.5);
+
+        // ...but spawning fails the unit instead of spawning vacuum physics
+        let spawned = spawn_from_weave_units(&units, &[None, bad_hint], UUID::new(), &thresholds, SpawnOptions::default());
+        assert!(spawned[0].1.is_ok());
+        assert!(matches!(spawned[1].1, Err(SpawnError::InvalidPhysicsHint { unit_index: 1, .. })));
+
+        // A short hint slice fails the unhinted units rather than dropping them
+        let lazy: Vec<_> = spawn_from_weave_units_iter(&units, &[], UUID::new(), &thresholds, SpawnOptions::default()).collect();
+        assert_eq!(lazy.len(), 2);
+        assert_eq!(lazy[0].as_ref().unwrap_err(), &SpawnError::MissingPhysicsHint { unit_index: 0 });
+        assert_eq!(lazy[1].as_ref().unwrap_err(), &SpawnError::MissingPhysicsHint { unit_index: 1 });
+    }
+
+    #[test]
+    fn test_collapse_thresholds_per_entity_type() {
+        let mut unit = WeaveUnit::new(0, "coffee with Sam");
+        unit.add_classification(HeddleEntityType::MOMENT, 0.6).unwrap();
+        unit.add_classification(HeddleEntityType::THREAD, 0.6).unwrap();
+        let hint = None;
+
+        let thresholds = CollapseThresholds::default()
+            .with("Moment", 0.5)
+            .with("thread", 0.8);
+        assert_eq!(thresholds.for_type(HeddleEntityType::MOMENT), 0.5);
+        assert_eq!(thresholds.for_type(HeddleEntityType::THREAD), 0.8);
+        assert_eq!(thresholds.for_type(HeddleEntityType::BOND), DEFAULT_COLLAPSE_THRESHOLD);
+
+        // The same weight collapses a Moment but not a Thread
+        let spawned: Vec<&str> = spawn_from_weave_unit(&unit, &hint, UUID::new(), &thresholds, SpawnOptions::default())
+            .unwrap()
+            .iter()
+            .map(entity_type_name)
+            .collect();
+        assert_eq!(spawned, ["Moment"]);
+
+        // With no configuration both fall short of the default
+        let spawned = spawn_from_weave_unit(&unit, &hint, UUID::new(), &CollapseThresholds::default(), SpawnOptions::default());
+        assert!(spawned.unwrap().is_empty());
+
+        // Lowering the configured default collapses the unlisted Moment too
+        let thresholds = CollapseThresholds::default().with_default(0.5).with("thread", 0.8);
+        assert_eq!(thresholds.for_type(HeddleEntityType::BOND), 0.5);
+        let spawned = spawn_from_weave_unit(&unit, &hint, UUID::new(), &thresholds, SpawnOptions::default()).unwrap();
+        assert_eq!(spawned.iter().map(entity_type_name).collect::<Vec<_>>(), ["Moment"]);
+    }
+
+    #[test]
+    #[cfg(feature = "config")]
+    fn test_collapse_thresholds_from_config() {
+        let mut config = familiar_config::GlobalConfig::default();
+        assert_eq!(config.systems.heddle.default_collapse_threshold, DEFAULT_COLLAPSE_THRESHOLD);
+
+        config.systems.heddle.default_collapse_threshold = 0.4;
+        config.systems.heddle.collapse_thresholds.insert("Thread".to_string(), 0.9);
+        let thresholds = CollapseThresholds::from_config(&config);
+        assert_eq!(thresholds.for_type(HeddleEntityType::MOMENT), 0.4);
+        assert_eq!(thresholds.for_type(HeddleEntityType::THREAD), 0.9);
+    }
+
+    #[test]
+    fn test_seeded_spawn_is_reproducible() {
+        let mut units = vec![log_unit(0, "met Sam for coffee"), log_unit(1, "felt calm")];
+        units[0].add_classification(HeddleEntityType::BOND, 0.8).unwrap();
+        let hints = vec![None, None];
+        let tenant_id = UUID::new();
+        let thresholds = CollapseThresholds::default();
+        let spawn = |options| {
+            let spawned = spawn_from_weave_units(&units, &hints, tenant_id, &thresholds, options);
+            format!("{:?}", spawned)
+        };
+
+        let seeded = SpawnOptions { rng_seed: Some(42), created_at: Some(Timestamp::now()) };
+        assert_eq!(spawn(seeded), spawn(seeded));
+        assert_ne!(spawn(seeded), spawn(SpawnOptions { rng_seed: Some(43), ..seeded }));
+
+        // Unseeded runs stay random
+        let unseeded = SpawnOptions { created_at: seeded.created_at, ..Default::default() };
+        assert_ne!(spawn(unseeded), spawn(unseeded));
+
+        // A unit's IDs depend only on the seed and its index
+        let lazy: Vec<UUID> = spawn_from_weave_units_iter(&units[1..], &hints, tenant_id, &thresholds, seeded)
+            .map(|entity| entity_id(&entity.unwrap()))
+            .collect();
+        let eager = spawn_from_weave_unit(&units[1], &None, tenant_id, &thresholds, seeded).unwrap();
+        assert_eq!(lazy, eager.iter().map(entity_id).collect::<Vec<_>>());
+    }
+}
@@ -10,7 +10,8 @@
 //!   familiar-schemas/json-schema/ ──▶ include_dir! ──▶ Binary
 //!
 //! Runtime:
-//!   Binary ──▶ ContractEnforcer::new() ──▶ HashMap<String, Validator>
+//!   Binary ──▶ ContractEnforcer::new() ──▶ HashMap<String, schema>
+//!   first use / warm_all() ──▶ compiled Validator
 //!
 //! Usage:
 //!   enforcer.unpack::<T>("message.type", &bytes) ──▶ T
@@ -20,7 +21,7 @@
 //!
 //! | Operation                    | Time   | Notes                         |
 //! |------------------------------|--------|-------------------------------|
-//! | Schema compilation (startup) | ~100ms | Once, from embedded files     |
+//! | Schema compilation (warm_all)| ~100ms | Once, from embedded files     |
 //! | unpack<T>() per message      | ~15μs  | SIMD parse + validate + deser |
 //! | Validation alone             | ~10μs  | Compiled DFA validation       |
 //! | SIMD JSON parse              | ~5μs   | For typical payloads          |
@@ -31,17 +32,36 @@
 //! use familiar_core::validation::{ContractEnforcer, ContractError};
 //! use std::sync::Arc;
 //!
-//! // Create once at startup (compiles embedded schemas)
+//! // Create once at startup; long-lived services compile every schema up
+//! // front, one-shot callers let schemas compile on first use
 //! let enforcer = Arc::new(ContractEnforcer::new());
+//! enforcer.warm_all()?;
 //!
 //! // Validate and deserialize in one step
 //! let signup: SignupRequest = enforcer.unpack("contracts.SignupRequest", &payload_bytes)?;
 //! ```
+//!
+//! ## Draft Pinning
+//!
+//! Each schema is compiled against the draft named in its own `$schema`
+//! (draft-04, draft-06, draft-07, 2019-09 or 2020-12). Schemas without a
+//! `$schema` are treated as draft-07, which the registry is authored in.
+//! Any other `$schema` value is rejected rather than silently compiled
+//! under the wrong draft.
+//!
+//! ## Validation Issues
+//!
+//! A failed validation carries one [`ValidationIssue`] per violation, each
+//! with an RFC 6901 JSON Pointer to the offending field. A missing required
+//! property points at the property itself (e.g. `/profile/email`), not at
+//! the object that lacks it.
 
-use jsonschema::Validator;
+use jsonschema::error::ValidationErrorKind;
+use jsonschema::{Draft, Validator};
 use serde::de::DeserializeOwned;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
 
 use familiar_contracts::SCHEMAS;
 
@@ -53,51 +73,201 @@ use familiar_contracts::SCHEMAS;
 /// ## Key Features
 ///
 /// - **Embedded Schemas**: No external file dependencies at runtime
-/// - **Compiled Validators**: Schemas compiled to DFA at startup for O(1) validation
+/// - **Compiled Validators**: Schemas compiled to DFA once, on first use or by `warm_all`
 /// - **SIMD Parsing**: Uses simd-json for maximum throughput
 /// - **Single API**: `unpack<T>()` handles validation and deserialization
 pub struct ContractEnforcer {
-    /// Map of message type identifiers to compiled validators
+    /// Map of message type identifiers to their schemas
     /// e.g., "contracts.SignupRequest" -> Validator
-    validators: HashMap<String, Arc<Validator>>,
-    /// Count of successfully compiled schemas
+    validators: HashMap<String, LazyValidator>,
+    /// Draft each validator was compiled against, keyed like `validators`
+    drafts: HashMap<String, Draft>,
+    /// Count of registered schemas
     schema_count: usize,
+    /// Number of schema compilations so far
+    compilations: AtomicUsize,
+    /// Embedded schemas that couldn't be registered, by message type
+    rejected: Vec<(String, ContractError)>,
+}
+
+/// A schema that compiles on first use
+struct LazyValidator {
+    schema: serde_json::Value,
+    compiled: OnceLock<Result<Arc<Validator>, String>>,
 }
 
 impl ContractEnforcer {
-    /// Create a new ContractEnforcer with compiled embedded schemas
+    /// Create a new ContractEnforcer from the embedded schemas
     ///
-    /// This is expensive (~100ms) but only done once at startup.
-    /// Panics if embedded schemas are malformed (compile-time guarantee).
+    /// Schemas compile on first use; call [`Self::warm_all`] to compile them
+    /// all up front. Schemas with invalid JSON or an unsupported draft are
+    /// skipped.
     pub fn new() -> Self {
-        let mut validators = HashMap::new();
-        let mut compiled_count = 0;
-        let mut errors: Vec<String> = Vec::new();
+        let mut enforcer = Self::empty();
 
         // Recursively find all .schema.json files in embedded directory
-        Self::compile_schemas_recursive(&SCHEMAS, "", &mut validators, &mut compiled_count, &mut errors);
+        enforcer.compile_schemas_recursive(&SCHEMAS, "");
 
-        // Log any compilation errors (for debugging)
+        // Log any registration errors (for debugging)
         #[cfg(debug_assertions)]
-        for err in &errors {
-            eprintln!("[ContractEnforcer] {}", err);
+        for (message_type, err) in &enforcer.rejected {
+            eprintln!("[ContractEnforcer] {}: {}", message_type, err);
         }
 
+        enforcer
+    }
+
+    fn empty() -> Self {
         Self {
-            validators,
-            schema_count: compiled_count,
+            validators: HashMap::new(),
+            drafts: HashMap::new(),
+            schema_count: 0,
+            compilations: AtomicUsize::new(0),
+            rejected: Vec::new(),
+        }
+    }
+
+    /// Read the draft a schema declares via `$schema`
+    ///
+    /// Schemas without `$schema` default to draft-07. Unknown drafts and
+    /// custom meta-schemas return `ContractError::UnsupportedDraft`.
+    pub fn declared_draft(schema_json: &serde_json::Value) -> Result<Draft, ContractError> {
+        let Some(declared) = schema_json.get("$schema") else {
+            return Ok(Draft::Draft7);
+        };
+        let uri = declared.as_str()
+            .ok_or_else(|| ContractError::UnsupportedDraft(declared.to_string()))?;
+
+        // Draft URIs appear with and without the trailing '#' and in http/https
+        let normalized = uri.trim_end_matches('#').replacen("https://", "http://", 1);
+        match normalized.as_str() {
+            "http://json-schema.org/draft-04/schema" => Ok(Draft::Draft4),
+            "http://json-schema.org/draft-06/schema" => Ok(Draft::Draft6),
+            "http://json-schema.org/draft-07/schema" => Ok(Draft::Draft7),
+            "http://json-schema.org/draft/2019-09/schema" => Ok(Draft::Draft201909),
+            "http://json-schema.org/draft/2020-12/schema" => Ok(Draft::Draft202012),
+            _ => Err(ContractError::UnsupportedDraft(uri.to_string())),
+        }
+    }
+
+    /// Compile a schema under its declared draft and register it for `message_type`
+    ///
+    /// Returns the draft the schema was compiled against.
+    pub fn register_schema(
+        &mut self,
+        message_type: &str,
+        schema_json: &serde_json::Value,
+    ) -> Result<Draft, ContractError> {
+        let draft = Self::declared_draft(schema_json)?;
+        let compiled = self.compile(schema_json, draft)
+            .map_err(|error| ContractError::CompileError {
+                message_type: message_type.to_string(),
+                error,
+            })?;
+
+        let lazy = LazyValidator { schema: schema_json.clone(), compiled: OnceLock::new() };
+        let _ = lazy.compiled.set(Ok(compiled));
+        self.insert(message_type, lazy, draft);
+        Ok(draft)
+    }
+
+    /// Register a schema to be compiled on first use
+    ///
+    /// Only the declared draft is checked here; compile errors surface when
+    /// the schema is first used or warmed.
+    fn register_lazy(
+        &mut self,
+        message_type: &str,
+        schema_json: serde_json::Value,
+    ) -> Result<Draft, ContractError> {
+        let draft = Self::declared_draft(&schema_json)?;
+        let lazy = LazyValidator { schema: schema_json, compiled: OnceLock::new() };
+        self.insert(message_type, lazy, draft);
+        Ok(draft)
+    }
+
+    fn insert(&mut self, message_type: &str, lazy: LazyValidator, draft: Draft) {
+        if self.validators.insert(message_type.to_string(), lazy).is_none() {
+            self.schema_count += 1;
+        }
+        self.drafts.insert(message_type.to_string(), draft);
+    }
+
+    fn compile(&self, schema_json: &serde_json::Value, draft: Draft) -> Result<Arc<Validator>, String> {
+        self.compilations.fetch_add(1, Ordering::Relaxed);
+        jsonschema::options()
+            .with_draft(draft)
+            .build(schema_json)
+            .map(Arc::new)
+            .map_err(|e| e.to_string())
+    }
+
+    /// The compiled validator for a message type, compiling it on first use
+    fn validator(&self, message_type: &str) -> Result<Arc<Validator>, ContractError> {
+        let lazy = self.validators.get(message_type)
+            .ok_or_else(|| ContractError::UnknownType(message_type.to_string()))?;
+        let draft = self.drafts[message_type];
+
+        lazy.compiled
+            .get_or_init(|| self.compile(&lazy.schema, draft))
+            .clone()
+            .map_err(|error| ContractError::CompileError {
+                message_type: message_type.to_string(),
+                error,
+            })
+    }
+
+    /// Compile every registered schema that hasn't been compiled yet
+    ///
+    /// Long-lived services call this at startup so no request pays for
+    /// compilation. Returns the number of schemas compiled by this call, or
+    /// the first schema that fails to compile.
+    pub fn warm_all(&self) -> Result<usize, ContractError> {
+        let before = self.compilations();
+        let mut message_types: Vec<&String> = self.validators.keys().collect();
+        message_types.sort();
+        for message_type in message_types {
+            self.validator(message_type)?;
+        }
+        Ok(self.compilations() - before)
+    }
+
+    /// Compile every registered schema and report all that fail, by message type
+    ///
+    /// Unlike [`Self::warm_all`] this doesn't stop at the first failure.
+    /// Schemas skipped when the enforcer was created (invalid JSON or an
+    /// unsupported `$schema`) were never accepted and aren't reported here;
+    /// see [`Self::rejected`]. Schemas that compile stay compiled.
+    pub fn self_check(&self) -> Result<(), Vec<(String, ContractError)>> {
+        let mut failures = Vec::new();
+        for message_type in self.validators.keys() {
+            if let Err(e) = self.validator(message_type) {
+                failures.push((message_type.clone(), e));
+            }
+        }
+
+        if failures.is_empty() {
+            return Ok(());
         }
+        failures.sort_by(|a, b| a.0.cmp(&b.0));
+        Err(failures)
     }
 
+    /// Embedded schemas that couldn't be registered, by message type
+    ///
+    /// These are skipped rather than served, so callers typically log them
+    /// as warnings.
+    pub fn rejected(&self) -> &[(String, ContractError)] {
+        &self.rejected
+    }
+
+    /// Number of schema compilations performed so far
+    pub fn compilations(&self) -> usize {
+        self.compilations.load(Ordering::Relaxed)
+    }
 
     /// Recursively compile schemas from embedded directory
-    fn compile_schemas_recursive(
-        dir: &include_dir::Dir,
-        prefix: &str,
-        validators: &mut HashMap<String, Arc<Validator>>,
-        count: &mut usize,
-        errors: &mut Vec<String>,
-    ) {
+    fn compile_schemas_recursive(&mut self, dir: &include_dir::Dir, prefix: &str) {
         // Process files in this directory
         for file in dir.files() {
             let path = file.path().to_string_lossy();
@@ -107,27 +277,22 @@ impl ContractEnforcer {
                 continue;
             }
 
+            // Derive message type from path
+            let message_type = Self::derive_message_type(&path, prefix);
+
             // Parse the embedded JSON
             let schema_json: serde_json::Value = match serde_json::from_slice(file.contents()) {
                 Ok(v) => v,
                 Err(e) => {
-                    errors.push(format!("Invalid JSON in {}: {}", path, e));
+                    let error = ContractError::ParseError(format!("Invalid JSON in {}: {}", path, e));
+                    self.rejected.push((message_type, error));
                     continue;
                 }
             };
 
-            // Derive message type from path
-            let message_type = Self::derive_message_type(&path, prefix);
-
-            // Compile the schema
-            match Validator::new(&schema_json) {
-                Ok(compiled) => {
-                    validators.insert(message_type.clone(), Arc::new(compiled));
-                    *count += 1;
-                }
-                Err(e) => {
-                    errors.push(format!("Failed to compile {}: {}", message_type, e));
-                }
+            // Register for compilation against its declared draft
+            if let Err(e) = self.register_lazy(&message_type, schema_json) {
+                self.rejected.push((message_type, e));
             }
         }
 
@@ -143,7 +308,7 @@ impl ContractEnforcer {
                 format!("{}.{}", prefix, subdir_name)
             };
 
-            Self::compile_schemas_recursive(subdir, &new_prefix, validators, count, errors);
+            self.compile_schemas_recursive(subdir, &new_prefix);
         }
     }
 
@@ -221,25 +386,14 @@ impl ContractEnforcer {
         payload_json: &mut [u8],
     ) -> Result<T, ContractError> {
         // 1. Lookup validator
-        let validator = self.validators.get(message_type)
-            .ok_or_else(|| ContractError::UnknownType(message_type.to_string()))?;
+        let validator = self.validator(message_type)?;
 
         // 2. SIMD parse to Value (in-place, zero-copy)
         let value: serde_json::Value = simd_json::from_slice(payload_json)
             .map_err(|e| ContractError::ParseError(e.to_string()))?;
 
         // 3. Validate against compiled schema
-        let errors: Vec<String> = validator
-            .iter_errors(&value)
-            .map(|e| e.to_string())
-            .collect();
-
-        if !errors.is_empty() {
-            return Err(ContractError::ValidationFailed {
-                message_type: message_type.to_string(),
-                errors,
-            });
-        }
+        Self::check(&validator, message_type, &value)?;
 
         // 4. Deserialize to target type
         serde_json::from_value(value)
@@ -256,28 +410,36 @@ impl ContractEnforcer {
     ///
     /// Useful when you only need to check validity without parsing to a struct.
     pub fn validate_mut(&self, message_type: &str, payload_json: &mut [u8]) -> Result<(), ContractError> {
-        let validator = self.validators.get(message_type)
-            .ok_or_else(|| ContractError::UnknownType(message_type.to_string()))?;
+        let validator = self.validator(message_type)?;
 
         let value: serde_json::Value = simd_json::from_slice(payload_json)
             .map_err(|e| ContractError::ParseError(e.to_string()))?;
 
-        let errors: Vec<String> = validator
-            .iter_errors(&value)
-            .map(|e| e.to_string())
+        Self::check(&validator, message_type, &value)
+    }
+
+    /// Validate a parsed payload, collecting every violation
+    fn check(
+        validator: &Validator,
+        message_type: &str,
+        value: &serde_json::Value,
+    ) -> Result<(), ContractError> {
+        let issues: Vec<ValidationIssue> = validator
+            .iter_errors(value)
+            .map(|e| ValidationIssue::from(&e))
             .collect();
 
-        if !errors.is_empty() {
+        if !issues.is_empty() {
             return Err(ContractError::ValidationFailed {
                 message_type: message_type.to_string(),
-                errors,
+                issues,
             });
         }
 
         Ok(())
     }
 
-    /// Get the number of compiled schemas
+    /// Get the number of registered schemas
     pub fn schema_count(&self) -> usize {
         self.schema_count
     }
@@ -292,6 +454,11 @@ impl ContractEnforcer {
         self.validators.contains_key(message_type)
     }
 
+    /// Get the draft a message type's schema was compiled against
+    pub fn draft_for(&self, message_type: &str) -> Option<Draft> {
+        self.drafts.get(message_type).copied()
+    }
+
     // ========================================================================
     // Fast Parsing Methods (No Validation)
     // ========================================================================
@@ -351,15 +518,78 @@ pub enum ContractError {
     UnknownType(String),
 
     /// Schema validation failed
-    #[error("Contract violation for {message_type}: {errors:?}")]
+    #[error("Contract violation for {message_type}: {}", summarize(.issues))]
     ValidationFailed {
         message_type: String,
-        errors: Vec<String>,
+        issues: Vec<ValidationIssue>,
     },
 
     /// JSON parsing failed
     #[error("Parse error: {0}")]
     ParseError(String),
+
+    /// Schema declares a `$schema` draft the enforcer doesn't support
+    #[error("Unsupported JSON Schema draft: {0}")]
+    UnsupportedDraft(String),
+
+    /// Schema failed to compile under its declared draft
+    #[error("Failed to compile schema for {message_type}: {error}")]
+    CompileError {
+        message_type: String,
+        error: String,
+    },
+}
+
+impl ContractError {
+    /// The schema violations behind a `ValidationFailed`, empty otherwise
+    pub fn issues(&self) -> &[ValidationIssue] {
+        match self {
+            ContractError::ValidationFailed { issues, .. } => issues,
+            _ => &[],
+        }
+    }
+}
+
+/// One schema violation in a payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// RFC 6901 JSON Pointer to the offending field (`""` for the whole payload)
+    pub pointer: String,
+    pub message: String,
+}
+
+impl From<&jsonschema::ValidationError<'_>> for ValidationIssue {
+    fn from(error: &jsonschema::ValidationError<'_>) -> Self {
+        let mut pointer = error.instance_path.to_string();
+        // Required errors are reported on the parent object; point at the
+        // missing property instead
+        if let ValidationErrorKind::Required { property } = &error.kind {
+            if let Some(name) = property.as_str() {
+                pointer.push('/');
+                pointer.push_str(&name.replace('~', "~0").replace('/', "~1"));
+            }
+        }
+        Self { pointer, message: error.to_string() }
+    }
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.pointer.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.pointer, self.message)
+        }
+    }
+}
+
+/// First issue plus a count of the rest, to keep error lines short
+fn summarize(issues: &[ValidationIssue]) -> String {
+    match issues {
+        [] => "no issues reported".to_string(),
+        [only] => only.to_string(),
+        [first, rest @ ..] => format!("{} (and {} more)", first, rest.len()),
+    }
 }
 
 // ============================================================================
@@ -396,10 +626,207 @@ mod tests {
         );
     }
 
+    fn empty_enforcer() -> ContractEnforcer {
+        ContractEnforcer::empty()
+    }
+
+    /// `body` with `$schema` set to `uri`
+    fn declaring(uri: &str, body: serde_json::Value) -> serde_json::Value {
+        let mut schema = body;
+        schema["$schema"] = serde_json::Value::from(uri);
+        schema
+    }
+
+    #[test]
+    fn test_schema_compiles_under_declared_draft() {
+        const DRAFT7: &str = "http://json-schema.org/draft-07/schema#";
+        const DRAFT2020: &str = "https://json-schema.org/draft/2020-12/schema";
+        let mut enforcer = empty_enforcer();
+
+        // Keywords next to `$ref` are ignored up to draft-07 and applied from
+        // 2019-09 on, so the same instance passes one draft and fails the other
+        let sibling = serde_json::json!({
+            "$ref": "#/definitions/name",
+            "definitions": { "name": { "type": "string" } },
+            "maxLength": 2
+        });
+        assert_eq!(enforcer.register_schema("test.Draft7", &declaring(DRAFT7, sibling.clone())).unwrap(), Draft::Draft7);
+        assert_eq!(enforcer.register_schema("test.Draft2020", &declaring(DRAFT2020, sibling)).unwrap(), Draft::Draft202012);
+        assert_eq!(enforcer.draft_for("test.Draft7"), Some(Draft::Draft7));
+        assert_eq!(enforcer.draft_for("test.Draft2020"), Some(Draft::Draft202012));
+        assert!(enforcer.validate("test.Draft7", br#""abc""#).is_ok());
+        assert!(enforcer.validate("test.Draft2020", br#""abc""#).is_err());
+
+        // The array form of `items` is a tuple up to 2019-09 and no longer a
+        // valid schema in 2020-12
+        let tuple = serde_json::json!({ "type": "array", "items": [{ "type": "string" }] });
+        enforcer.register_schema("test.Tuple", &declaring(DRAFT7, tuple.clone())).unwrap();
+        assert!(enforcer.validate("test.Tuple", br#"["a", 1]"#).is_ok());
+        assert!(enforcer.validate("test.Tuple", br#"[1, "a"]"#).is_err());
+        assert!(matches!(
+            enforcer.register_schema("test.Tuple2020", &declaring(DRAFT2020, tuple)),
+            Err(ContractError::CompileError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_draft_is_rejected() {
+        let mut enforcer = empty_enforcer();
+
+        // Relative and custom meta-schemas are rejected, never compiled
+        // under a fallback draft
+        for uri in [
+            "../architecture/meta/Type.meta.schema.json",
+            "https://familiar.dev/meta/Type.meta.schema.json",
+            "http://json-schema.org/draft-05/schema#",
+        ] {
+            let schema = declaring(uri, serde_json::json!({ "type": "object" }));
+            assert!(matches!(
+                enforcer.register_schema("test.Custom", &schema),
+                Err(ContractError::UnsupportedDraft(ref declared)) if declared == uri
+            ));
+        }
+        assert!(matches!(
+            enforcer.register_lazy("test.Custom", serde_json::json!({ "$schema": 7 })),
+            Err(ContractError::UnsupportedDraft(_))
+        ));
+        assert!(!enforcer.has_schema("test.Custom"));
+        assert_eq!(enforcer.compilations(), 0);
+    }
+
+    #[test]
+    fn test_warm_all_compiles_once() {
+        let mut enforcer = empty_enforcer();
+        let signup = serde_json::json!({
+            "type": "object",
+            "properties": { "email": { "type": "string" } },
+            "required": ["email"]
+        });
+        let pulse = serde_json::json!({ "type": "integer" });
+        enforcer.register_lazy("contracts.SignupRequest", signup).unwrap();
+        enforcer.register_lazy("entities.pulse", pulse).unwrap();
+        assert_eq!(enforcer.compilations(), 0);
+
+        assert_eq!(enforcer.warm_all().unwrap(), 2);
+        assert_eq!(enforcer.compilations(), 2);
+
+        // Validating after warm-up reuses the compiled validators
+        assert!(enforcer.validate("contracts.SignupRequest", br#"{"email": "a@b.c"}"#).is_ok());
+        assert!(enforcer.validate("contracts.SignupRequest", br#"{}"#).is_err());
+        assert!(enforcer.validate("entities.pulse", b"7").is_ok());
+        assert_eq!(enforcer.compilations(), 2);
+        assert_eq!(enforcer.warm_all().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_warm_all_reports_compile_errors() {
+        let mut enforcer = empty_enforcer();
+        let broken = serde_json::json!({ "type": "object", "minProperties": "three" });
+        enforcer.register_lazy("contracts.Broken", broken).unwrap();
+
+        assert!(matches!(
+            enforcer.warm_all(),
+            Err(ContractError::CompileError { ref message_type, .. }) if message_type == "contracts.Broken"
+        ));
+        // The failure is remembered rather than recompiled on every use
+        assert!(enforcer.validate("contracts.Broken", b"{}").is_err());
+        assert_eq!(enforcer.compilations(), 1);
+    }
+
+    #[test]
+    fn test_self_check_reports_every_broken_schema() {
+        use include_dir::{Dir, DirEntry, File};
+
+        static EMBEDDED: Dir = Dir::new("", &[
+            DirEntry::File(File::new("Broken.schema.json", br#"{"type": "object", "minProperties": "three"}"#)),
+            DirEntry::File(File::new("Custom.schema.json", br#"{"$schema": "../meta/Type.meta.schema.json"}"#)),
+            DirEntry::File(File::new("Invalid.schema.json", br#"{"type": "object", "required": "id"}"#)),
+            DirEntry::File(File::new("Signup.schema.json", br#"{"type": "object"}"#)),
+            DirEntry::File(File::new("Truncated.schema.json", b"{")),
+        ]);
+
+        let mut enforcer = empty_enforcer();
+        enforcer.compile_schemas_recursive(&EMBEDDED, "contracts");
+        let pulse = serde_json::json!({ "type": "integer" });
+        enforcer.register_lazy("entities.pulse", pulse).unwrap();
+
+        // Only accepted schemas that don't compile fail the check
+        let failures = enforcer.self_check().unwrap_err();
+        let failed: Vec<&str> = failures.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(failed, ["contracts.Broken", "contracts.Invalid"]);
+        assert!(failures.iter().all(|(_, e)| matches!(e, ContractError::CompileError { .. })));
+
+        // Schemas that were never accepted are reported separately
+        let rejected: Vec<&str> = enforcer.rejected().iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(rejected, ["contracts.Custom", "contracts.Truncated"]);
+        assert!(matches!(enforcer.rejected()[0].1, ContractError::UnsupportedDraft(_)));
+        assert!(matches!(enforcer.rejected()[1].1, ContractError::ParseError(_)));
+
+        // The schemas that compiled stay compiled
+        assert!(enforcer.validate("contracts.Signup", b"{}").is_ok());
+        assert!(enforcer.validate("entities.pulse", b"7").is_ok());
+        assert_eq!(enforcer.compilations(), 4);
+
+        let mut healthy = empty_enforcer();
+        healthy.compile_schemas_recursive(&Dir::new("", &[
+            DirEntry::File(File::new("Custom.schema.json", br#"{"$schema": "https://familiar.dev/meta/Type"}"#)),
+        ]), "contracts");
+        healthy.register_lazy("entities.pulse", serde_json::json!({ "type": "integer" })).unwrap();
+        assert!(healthy.self_check().is_ok());
+        assert_eq!(healthy.rejected().len(), 1);
+    }
+
+    #[test]
+    fn test_validation_issue_pointers() {
+        let mut enforcer = empty_enforcer();
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "age": { "type": "integer" },
+                "profile": {
+                    "type": "object",
+                    "properties": { "email": { "type": "string" } },
+                    "required": ["email"]
+                }
+            },
+            "required": ["profile"]
+        });
+        enforcer.register_schema("contracts.CreateUserInput", &schema).unwrap();
+
+        let err = enforcer
+            .validate("contracts.CreateUserInput", br#"{"profile": {}}"#)
+            .unwrap_err();
+        assert_eq!(err.issues().len(), 1);
+        assert_eq!(err.issues()[0].pointer, "/profile/email");
+        assert_eq!(
+            err.to_string(),
+            r#"Contract violation for contracts.CreateUserInput: /profile/email: "email" is a required property"#
+        );
+
+        let err = enforcer
+            .validate("contracts.CreateUserInput", br#"{"age": "ten"}"#)
+            .unwrap_err();
+        let mut pointers: Vec<&str> = err.issues().iter().map(|i| i.pointer.as_str()).collect();
+        pointers.sort();
+        assert_eq!(pointers, ["/age", "/profile"]);
+        assert!(err.to_string().ends_with("(and 1 more)"));
+
+        assert!(ContractError::UnknownType("x".to_string()).issues().is_empty());
+    }
+
     #[test]
     fn test_enforcer_creation() {
         // This test verifies that embedded schemas compile successfully
         let enforcer = ContractEnforcer::new();
+        for (message_type, err) in enforcer.rejected() {
+            eprintln!("  ~ skipped {}: {}", message_type, err);
+        }
+        if let Err(failures) = enforcer.self_check() {
+            for (message_type, err) in &failures {
+                eprintln!("  ! {}: {}", message_type, err);
+            }
+            panic!("{} embedded schemas failed self-check", failures.len());
+        }
         
         // Should have at least some schemas embedded
         // (exact count depends on familiar-schemas content)
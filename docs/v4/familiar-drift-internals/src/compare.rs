@@ -1,8 +1,10 @@
 //! Comparison logic for detecting drift between Rust types and JSON schemas.
 
 use std::fmt;
-use crate::parser::RustTypeInfo;
-use crate::schema::{JsonSchemaInfo, JsonType};
+use serde::Serialize;
+use crate::newtypes::{NewtypeMap, UnderlyingType};
+use crate::parser::{RustFieldInfo, RustTypeInfo};
+use crate::schema::{JsonPropertyInfo, JsonSchemaInfo, JsonType};
 
 /// A report of all drift errors found
 #[derive(Debug)]
@@ -23,11 +25,27 @@ impl fmt::Display for DriftReport {
     }
 }
 
+impl DriftReport {
+    /// Machine-readable form for CI: an array of `{type, field, kind, detail}`
+    /// objects, where `kind` is the snake_case error variant and `detail` the
+    /// human-readable message.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::Value::Array(self.errors.iter().map(|error| {
+            serde_json::json!({
+                "type": error.type_name(),
+                "field": error.field(),
+                "kind": error.kind(),
+                "detail": error.to_string(),
+            })
+        }).collect())
+    }
+}
+
 impl std::error::Error for DriftReport {}
 impl std::error::Error for DriftError {}
 
 /// Individual drift errors
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum DriftError {
     /// Field exists in schema but not in Rust
     MissingInRust {
@@ -39,6 +57,11 @@ pub enum DriftError {
         type_name: String,
         field: String,
     },
+    /// Field exists in Rust but not in schema (opt-in bidirectional check)
+    ExtraInRust {
+        type_name: String,
+        field: String,
+    },
     /// Field type mismatch
     TypeMismatch {
         type_name: String,
@@ -46,6 +69,16 @@ pub enum DriftError {
         rust_type: String,
         schema_type: String,
     },
+    /// Enum variant exists in schema but not in Rust
+    MissingVariantInRust {
+        type_name: String,
+        variant: String,
+    },
+    /// Enum variant exists in Rust but not in schema
+    MissingVariantInSchema {
+        type_name: String,
+        variant: String,
+    },
     /// Optionality mismatch
     OptionalityMismatch {
         type_name: String,
@@ -61,6 +94,53 @@ pub enum DriftError {
     ParseError(String),
 }
 
+impl DriftError {
+    /// Stable snake_case discriminant, e.g. `missing_in_rust`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DriftError::MissingInRust { .. } => "missing_in_rust",
+            DriftError::MissingInSchema { .. } => "missing_in_schema",
+            DriftError::ExtraInRust { .. } => "extra_in_rust",
+            DriftError::MissingVariantInRust { .. } => "missing_variant_in_rust",
+            DriftError::MissingVariantInSchema { .. } => "missing_variant_in_schema",
+            DriftError::TypeMismatch { .. } => "type_mismatch",
+            DriftError::OptionalityMismatch { .. } => "optionality_mismatch",
+            DriftError::TypeNotFound(_) => "type_not_found",
+            DriftError::SchemaNotFound(_) => "schema_not_found",
+            DriftError::ParseError(_) => "parse_error",
+        }
+    }
+    
+    /// The type the error is about, if known
+    pub fn type_name(&self) -> Option<&str> {
+        match self {
+            DriftError::MissingInRust { type_name, .. }
+            | DriftError::MissingInSchema { type_name, .. }
+            | DriftError::ExtraInRust { type_name, .. }
+            | DriftError::MissingVariantInRust { type_name, .. }
+            | DriftError::MissingVariantInSchema { type_name, .. }
+            | DriftError::TypeMismatch { type_name, .. }
+            | DriftError::OptionalityMismatch { type_name, .. } => Some(type_name),
+            DriftError::TypeNotFound(name) | DriftError::SchemaNotFound(name) => Some(name),
+            DriftError::ParseError(_) => None,
+        }
+    }
+    
+    /// The field (or enum variant) the error is about, if any
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            DriftError::MissingInRust { field, .. }
+            | DriftError::MissingInSchema { field, .. }
+            | DriftError::ExtraInRust { field, .. }
+            | DriftError::TypeMismatch { field, .. }
+            | DriftError::OptionalityMismatch { field, .. } => Some(field),
+            DriftError::MissingVariantInRust { variant, .. }
+            | DriftError::MissingVariantInSchema { variant, .. } => Some(variant),
+            DriftError::TypeNotFound(_) | DriftError::SchemaNotFound(_) | DriftError::ParseError(_) => None,
+        }
+    }
+}
+
 impl fmt::Display for DriftError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -70,6 +150,15 @@ impl fmt::Display for DriftError {
             DriftError::MissingInSchema { type_name, field } => {
                 write!(f, "Type '{}': Field '{}' exists in Rust struct but not in JSON schema", type_name, field)
             }
+            DriftError::ExtraInRust { type_name, field } => {
+                write!(f, "Type '{}': Field '{}' is declared in Rust struct but not in JSON schema", type_name, field)
+            }
+            DriftError::MissingVariantInRust { type_name, variant } => {
+                write!(f, "Type '{}': Variant '{}' exists in JSON schema but not in Rust enum", type_name, variant)
+            }
+            DriftError::MissingVariantInSchema { type_name, variant } => {
+                write!(f, "Type '{}': Variant '{}' exists in Rust enum but not in JSON schema", type_name, variant)
+            }
             DriftError::TypeMismatch { type_name, field, rust_type, schema_type } => {
                 write!(f, "Type '{}': Field '{}' has type '{}' in Rust but '{}' in schema", 
                     type_name, field, rust_type, schema_type)
@@ -94,13 +183,13 @@ impl fmt::Display for DriftError {
 }
 
 /// Information about a single field drift
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct FieldDrift {
     pub field_name: String,
     pub drift_type: FieldDriftType,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum FieldDriftType {
     MissingInRust,
     MissingInSchema,
@@ -114,6 +203,39 @@ pub fn compare_types(
     rust_info: &RustTypeInfo,
     schema_info: &JsonSchemaInfo,
 ) -> Result<(), DriftError> {
+    compare_types_with(type_name, rust_info, schema_info, false)
+}
+
+/// Compare a Rust type against its JSON schema.
+///
+/// With `report_extra_fields`, Rust fields the schema doesn't declare are
+/// reported as [`DriftError::ExtraInRust`].
+pub fn compare_types_with(
+    type_name: &str,
+    rust_info: &RustTypeInfo,
+    schema_info: &JsonSchemaInfo,
+    report_extra_fields: bool,
+) -> Result<(), DriftError> {
+    compare_types_with_newtypes(type_name, rust_info, schema_info, report_extra_fields, &NewtypeMap::default())
+}
+
+/// Compare a Rust type against its JSON schema, resolving field types in
+/// `newtypes` to the schema type they serialize as before comparing.
+pub fn compare_types_with_newtypes(
+    type_name: &str,
+    rust_info: &RustTypeInfo,
+    schema_info: &JsonSchemaInfo,
+    report_extra_fields: bool,
+    newtypes: &NewtypeMap,
+) -> Result<(), DriftError> {
+    if report_extra_fields {
+        check_extra_fields(type_name, rust_info, schema_info)?;
+    }
+    
+    if !rust_info.variants.is_empty() {
+        return compare_variants(type_name, rust_info, schema_info);
+    }
+    
     // Note: If the Rust type uses #[serde(flatten)], we need to be lenient
     // because the flattened fields come from other types
     if rust_info.has_flattened {
@@ -133,7 +255,7 @@ pub fn compare_types(
     }
     
     // Check for fields missing in schema (strict check for non-flattened fields)
-    for (rust_field, _field_info) in &rust_info.fields {
+    for (rust_field, _field_info) in rust_info.checked_fields() {
         if !schema_info.properties.contains_key(rust_field) {
             return Err(DriftError::MissingInSchema {
                 type_name: type_name.to_string(),
@@ -143,7 +265,7 @@ pub fn compare_types(
     }
     
     // Check type compatibility for common fields
-    for (field_name, rust_field) in &rust_info.fields {
+    for (field_name, rust_field) in rust_info.checked_fields() {
         if let Some(schema_prop) = schema_info.properties.get(field_name) {
             // Check optionality
             let schema_required = schema_info.required.contains(field_name);
@@ -156,14 +278,25 @@ pub fn compare_types(
                 });
             }
             
+            // A payload may omit a non-required field, which only deserializes
+            // if Rust can fill it in
+            if !rust_field.is_optional && !schema_required && !rust_field.has_default {
+                return Err(DriftError::OptionalityMismatch {
+                    type_name: type_name.to_string(),
+                    field: field_name.clone(),
+                    rust_optional: false,
+                    schema_required: false,
+                });
+            }
+            
             // Type checking is complex due to the variety of ways types can be represented
             // For now, we do basic compatibility checks
-            if !is_type_compatible(&rust_field.type_string, &schema_prop.json_type, &schema_prop.format) {
+            if !is_field_compatible(rust_field, schema_prop, newtypes) {
                 return Err(DriftError::TypeMismatch {
                     type_name: type_name.to_string(),
                     field: field_name.clone(),
                     rust_type: rust_field.type_string.clone(),
-                    schema_type: format_json_type(&schema_prop.json_type, &schema_prop.format),
+                    schema_type: format_property(schema_prop),
                 });
             }
         }
@@ -172,6 +305,61 @@ pub fn compare_types(
     Ok(())
 }
 
+/// Check that every Rust field (other than #[serde(skip)] ones, which the
+/// parser drops) has a schema property
+fn check_extra_fields(
+    type_name: &str,
+    rust_info: &RustTypeInfo,
+    schema_info: &JsonSchemaInfo,
+) -> Result<(), DriftError> {
+    let mut extra: Vec<_> = rust_info.checked_fields()
+        .map(|(field, _)| field)
+        .filter(|field| !schema_info.properties.contains_key(*field))
+        .collect();
+    extra.sort();
+    
+    match extra.first() {
+        Some(field) => Err(DriftError::ExtraInRust {
+            type_name: type_name.to_string(),
+            field: field.to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Check that a Rust enum and its schema declare the same variants
+fn compare_variants(
+    type_name: &str,
+    rust_info: &RustTypeInfo,
+    schema_info: &JsonSchemaInfo,
+) -> Result<(), DriftError> {
+    if schema_info.variants.is_empty() {
+        // Schema shapes we can't read variants from need manual verification
+        eprintln!("  Warning: Could not extract variants from schema for Rust enum '{}'", type_name);
+        return Ok(());
+    }
+    
+    for variant in &schema_info.variants {
+        if !rust_info.variants.contains(variant) {
+            return Err(DriftError::MissingVariantInRust {
+                type_name: type_name.to_string(),
+                variant: variant.clone(),
+            });
+        }
+    }
+    
+    for variant in &rust_info.variants {
+        if !schema_info.variants.contains(variant) {
+            return Err(DriftError::MissingVariantInSchema {
+                type_name: type_name.to_string(),
+                variant: variant.clone(),
+            });
+        }
+    }
+    
+    Ok(())
+}
+
 /// Check only non-flattened fields for types that use #[serde(flatten)]
 fn check_non_flattened_fields(
     type_name: &str,
@@ -179,7 +367,7 @@ fn check_non_flattened_fields(
     schema_info: &JsonSchemaInfo,
 ) -> Result<(), DriftError> {
     // For flattened types, just verify the direct fields exist in the schema
-    for (rust_field, _field_info) in &rust_info.fields {
+    for (rust_field, _field_info) in rust_info.checked_fields() {
         if !schema_info.properties.contains_key(rust_field) {
             return Err(DriftError::MissingInSchema {
                 type_name: type_name.to_string(),
@@ -191,8 +379,64 @@ fn check_non_flattened_fields(
     Ok(())
 }
 
+/// Rust primitives that serialize as JSON integers
+const INTEGER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize",
+    "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// Check a Rust field against a schema property, comparing the element type
+/// the parser found for `Vec<T>` and `Option<Vec<T>>` fields with `items`
+fn is_field_compatible(field: &RustFieldInfo, prop: &JsonPropertyInfo, newtypes: &NewtypeMap) -> bool {
+    if !is_type_compatible(&field.type_string, &prop.json_type, &prop.format, newtypes) {
+        return false;
+    }
+    
+    match (&prop.items, &field.element_type) {
+        (Some(items), Some(element)) => is_element_compatible(element, items, newtypes),
+        _ => true,
+    }
+}
+
+/// Check a Rust type against a schema property, descending into array `items`
+fn is_property_compatible(rust_type: &str, prop: &JsonPropertyInfo, newtypes: &NewtypeMap) -> bool {
+    if !is_type_compatible(rust_type, &prop.json_type, &prop.format, newtypes) {
+        return false;
+    }
+    
+    match (&prop.items, vec_element_type(rust_type)) {
+        (Some(items), Some(element)) => is_element_compatible(element, items, newtypes),
+        _ => true,
+    }
+}
+
+/// Check an array element against `items`. Elements must agree on
+/// nullability: `Option<T>` elements serialize `null`, which only nullable
+/// `items` accept, and nullable `items` only deserialize into `Option<T>`.
+fn is_element_compatible(element: &str, items: &JsonPropertyInfo, newtypes: &NewtypeMap) -> bool {
+    element.starts_with("Option<") == is_nullable(&items.json_type)
+        && is_property_compatible(element, items, newtypes)
+}
+
+/// Whether a JSON type admits `null`
+fn is_nullable(json_type: &JsonType) -> bool {
+    match json_type {
+        JsonType::Null => true,
+        JsonType::Union(types) => types.iter().any(is_nullable),
+        _ => false,
+    }
+}
+
+/// Element type of a `Vec<T>` or `Option<Vec<T>>`
+fn vec_element_type(rust_type: &str) -> Option<&str> {
+    let inner = rust_type.strip_prefix("Option<")
+        .and_then(|t| t.strip_suffix('>'))
+        .unwrap_or(rust_type);
+    inner.strip_prefix("Vec<")?.strip_suffix('>')
+}
+
 /// Check if a Rust type is compatible with a JSON Schema type
-fn is_type_compatible(rust_type: &str, json_type: &JsonType, format: &Option<String>) -> bool {
+fn is_type_compatible(rust_type: &str, json_type: &JsonType, format: &Option<String>, newtypes: &NewtypeMap) -> bool {
     // Strip Option wrapper if present
     let inner_rust_type = if rust_type.starts_with("Option<") {
         &rust_type[7..rust_type.len()-1]
@@ -200,12 +444,20 @@ fn is_type_compatible(rust_type: &str, json_type: &JsonType, format: &Option<Str
         rust_type
     };
     
+    // Newtypes compare as the type they wrap. Unions are narrowed below and
+    // refs name the newtype itself, so both go through the usual checks.
+    if !matches!(json_type, JsonType::Union(_) | JsonType::Ref(_)) {
+        if let Some(underlying) = newtypes.resolve(inner_rust_type) {
+            return is_underlying_compatible(underlying, json_type, format);
+        }
+    }
+    
     // Core Primitive mappings
-    if inner_rust_type == "Timestamp" || inner_rust_type == "DateTime<Utc>" {
+    if inner_rust_type == "DateTime<Utc>" {
         return matches!(json_type, JsonType::String) && 
                (format.as_deref() == Some("date-time") || format.is_none());
     }
-    if inner_rust_type == "UUID" || inner_rust_type == "Uuid" {
+    if inner_rust_type == "Uuid" {
         return matches!(json_type, JsonType::String) && 
                (format.as_deref() == Some("uuid") || format.is_none());
     }
@@ -218,17 +470,18 @@ fn is_type_compatible(rust_type: &str, json_type: &JsonType, format: &Option<Str
                 _ => inner_rust_type == "String" || inner_rust_type == "str" || inner_rust_type == "Timestamp" || inner_rust_type == "UUID",
             }
         }
-        JsonType::Integer => {
-            inner_rust_type.starts_with("i") || inner_rust_type.starts_with("u") ||
-            inner_rust_type == "isize" || inner_rust_type == "usize"
-        }
+        JsonType::Integer => INTEGER_TYPES.contains(&inner_rust_type),
         JsonType::Number => {
-            inner_rust_type.starts_with("f") || inner_rust_type == "f32" || inner_rust_type == "f64" ||
-            inner_rust_type.starts_with("i") || inner_rust_type.starts_with("u") ||
+            inner_rust_type == "f32" || inner_rust_type == "f64" ||
+            INTEGER_TYPES.contains(&inner_rust_type) ||
             inner_rust_type == "NormalizedFloat"
         }
         JsonType::Boolean => inner_rust_type == "bool",
-        JsonType::Array => inner_rust_type.starts_with("Vec<") || inner_rust_type.starts_with("["),
+        JsonType::Array => {
+            inner_rust_type.starts_with("Vec<") ||
+            inner_rust_type.starts_with("std::vec::Vec<") ||
+            inner_rust_type.starts_with("[")
+        }
         JsonType::Object => {
             inner_rust_type.starts_with("HashMap") || 
             inner_rust_type.starts_with("BTreeMap") ||
@@ -245,7 +498,7 @@ fn is_type_compatible(rust_type: &str, json_type: &JsonType, format: &Option<Str
                 .collect();
             
             if non_null_types.len() == 1 {
-                is_type_compatible(inner_rust_type, non_null_types[0], format)
+                is_type_compatible(inner_rust_type, non_null_types[0], format, newtypes)
             } else {
                 true // Complex unions need manual verification
             }
@@ -267,6 +520,23 @@ fn is_type_compatible(rust_type: &str, json_type: &JsonType, format: &Option<Str
     }
 }
 
+/// Check a newtype's underlying type against a JSON Schema type. Integers
+/// are also numbers; a schema format, when given, must be the newtype's.
+fn is_underlying_compatible(underlying: &UnderlyingType, json_type: &JsonType, format: &Option<String>) -> bool {
+    let type_matches = underlying.json_type == *json_type
+        || (*json_type == JsonType::Number && underlying.json_type == JsonType::Integer);
+    
+    type_matches && (format.is_none() || *format == underlying.format)
+}
+
+/// Format a schema property for display, including array element types
+fn format_property(prop: &JsonPropertyInfo) -> String {
+    match &prop.items {
+        Some(items) => format!("array<{}>", format_property(items)),
+        None => format_json_type(&prop.json_type, &prop.format),
+    }
+}
+
 /// Format a JSON type for display
 fn format_json_type(json_type: &JsonType, format: &Option<String>) -> String {
     let base = match json_type {
@@ -295,26 +565,187 @@ fn format_json_type(json_type: &JsonType, format: &Option<String>) -> String {
 mod tests {
     use super::*;
     use std::collections::HashMap;
-    use crate::parser::RustFieldInfo;
     use crate::schema::JsonPropertyInfo;
     
     #[test]
     fn test_type_compatibility_string() {
-        assert!(is_type_compatible("String", &JsonType::String, &None));
-        assert!(is_type_compatible("Option<String>", &JsonType::String, &None));
+        assert!(is_type_compatible("String", &JsonType::String, &None, &NewtypeMap::default()));
+        assert!(is_type_compatible("Option<String>", &JsonType::String, &None, &NewtypeMap::default()));
     }
     
     #[test]
     fn test_type_compatibility_uuid() {
-        assert!(is_type_compatible("Uuid", &JsonType::String, &Some("uuid".to_string())));
-        assert!(is_type_compatible("uuid::Uuid", &JsonType::String, &Some("uuid".to_string())));
+        assert!(is_type_compatible("Uuid", &JsonType::String, &Some("uuid".to_string()), &NewtypeMap::default()));
+        assert!(is_type_compatible("uuid::Uuid", &JsonType::String, &Some("uuid".to_string()), &NewtypeMap::default()));
     }
     
     #[test]
     fn test_type_compatibility_integer() {
-        assert!(is_type_compatible("i32", &JsonType::Integer, &None));
-        assert!(is_type_compatible("u64", &JsonType::Integer, &None));
-        assert!(is_type_compatible("i64", &JsonType::Integer, &None));
+        assert!(is_type_compatible("i32", &JsonType::Integer, &None, &NewtypeMap::default()));
+        assert!(is_type_compatible("u64", &JsonType::Integer, &None, &NewtypeMap::default()));
+        assert!(is_type_compatible("i64", &JsonType::Integer, &None, &NewtypeMap::default()));
+        assert!(!is_type_compatible("Identity", &JsonType::Integer, &None, &NewtypeMap::default()));
+    }
+    
+    fn compare_sources(rust: &str, schema: &str) -> Result<(), DriftError> {
+        let rust_info = crate::parser::parse_type_from_source(rust, "Test").unwrap().unwrap();
+        let schema_info = crate::schema::parse_schema(schema, "Test").unwrap();
+        compare_types("Test", &rust_info, &schema_info)
+    }
+    
+    #[test]
+    fn test_string_integer_mismatch() {
+        let result = compare_sources(
+            "pub struct Test { pub count: String }",
+            r#"{ "properties": { "count": { "type": "integer" } }, "required": ["count"] }"#,
+        );
+        assert!(matches!(
+            result,
+            Err(DriftError::TypeMismatch { ref field, ref rust_type, ref schema_type, .. })
+                if field == "count" && rust_type == "String" && schema_type == "integer"
+        ));
+        
+        let result = compare_sources(
+            "pub struct Test { pub counts: Vec<String> }",
+            r#"{ "properties": { "counts": { "type": "array", "items": { "type": "integer" } } }, "required": ["counts"] }"#,
+        );
+        assert!(matches!(
+            result,
+            Err(DriftError::TypeMismatch { ref schema_type, .. }) if schema_type == "array<integer>"
+        ));
+        
+        let result = compare_sources(
+            "pub struct Test { pub counts: Vec<i64> }",
+            r#"{ "properties": { "counts": { "type": "array", "items": { "type": "integer" } } }, "required": ["counts"] }"#,
+        );
+        assert!(result.is_ok());
+    }
+    
+    #[test]
+    fn test_array_element_types() {
+        let strings = r#"{ "properties": { "tags": { "type": "array", "items": { "type": "string" } } }, "required": ["tags"] }"#;
+        let integers = r#"{ "properties": { "tags": { "type": "array", "items": { "type": "integer" } } }, "required": ["tags"] }"#;
+        
+        assert!(compare_sources("pub struct Test { pub tags: Vec<String> }", strings).is_ok());
+        assert!(compare_sources("pub struct Test { pub tags: std::vec::Vec<String> }", strings).is_ok());
+        
+        let result = compare_sources("pub struct Test { pub tags: Vec<String> }", integers);
+        assert!(matches!(
+            result,
+            Err(DriftError::TypeMismatch { ref field, ref rust_type, ref schema_type, .. })
+                if field == "tags" && rust_type == "Vec<String>" && schema_type == "array<integer>"
+        ));
+        
+        // An optional array must not be required by the schema
+        let result = compare_sources("pub struct Test { pub tags: Option<Vec<String>> }", strings);
+        assert!(matches!(
+            result,
+            Err(DriftError::OptionalityMismatch { rust_optional: true, schema_required: true, .. })
+        ));
+        
+        let nullable = r#"{ "properties": { "tags": { "type": ["array", "null"], "items": { "type": "string" } } } }"#;
+        assert!(compare_sources("pub struct Test { pub tags: Option<Vec<String>> }", nullable).is_ok());
+        let result = compare_sources("pub struct Test { pub tags: Option<Vec<i32>> }", nullable);
+        assert!(matches!(result, Err(DriftError::TypeMismatch { .. })));
+    }
+    
+    #[test]
+    fn test_array_element_nullability() {
+        let nullable_items = r#"{ "properties": { "tags": { "type": "array", "items": { "type": ["string", "null"] } } }, "required": ["tags"] }"#;
+        let strings = r#"{ "properties": { "tags": { "type": "array", "items": { "type": "string" } } }, "required": ["tags"] }"#;
+        
+        assert!(compare_sources("pub struct Test { pub tags: Vec<Option<String>> }", nullable_items).is_ok());
+        
+        let result = compare_sources("pub struct Test { pub tags: Vec<String> }", nullable_items);
+        assert!(matches!(
+            result,
+            Err(DriftError::TypeMismatch { ref schema_type, .. }) if schema_type == "array<string | null>"
+        ));
+        
+        let result = compare_sources("pub struct Test { pub tags: Vec<Option<String>> }", strings);
+        assert!(matches!(result, Err(DriftError::TypeMismatch { .. })));
+        
+        // Nested arrays are compared element by element
+        let nested = r#"{ "properties": { "grid": { "type": "array", "items": { "type": "array", "items": { "type": "integer" } } } }, "required": ["grid"] }"#;
+        assert!(compare_sources("pub struct Test { pub grid: Vec<Vec<u8>> }", nested).is_ok());
+        assert!(compare_sources("pub struct Test { pub grid: Vec<Vec<f64>> }", nested).is_err());
+    }
+    
+    #[test]
+    fn test_newtypes_compare_as_their_underlying_type() {
+        let schema = r#"{
+            "properties": {
+                "created_at": { "type": "string", "format": "date-time" },
+                "weight": { "type": "number" },
+                "x": { "type": "integer" },
+                "tenant_id": { "type": "string", "format": "uuid" },
+                "tags": { "type": "array", "items": { "type": "string", "format": "uuid" } }
+            },
+            "required": ["created_at", "weight", "x", "tenant_id", "tags"]
+        }"#;
+        let rust = r#"
+            pub struct Test {
+                pub created_at: Timestamp,
+                pub weight: NormalizedFloat,
+                pub x: QuantizedCoord,
+                pub tenant_id: familiar_primitives::TenantId,
+                pub tags: Vec<ThreadId>,
+            }
+        "#;
+        assert!(compare_sources(rust, schema).is_ok());
+        
+        let result = compare_sources(
+            "pub struct Test { pub created_at: Timestamp }",
+            r#"{ "properties": { "created_at": { "type": "string", "format": "uuid" } }, "required": ["created_at"] }"#,
+        );
+        assert!(matches!(
+            result,
+            Err(DriftError::TypeMismatch { ref rust_type, ref schema_type, .. })
+                if rust_type == "Timestamp" && schema_type == "string(uuid)"
+        ));
+        
+        let result = compare_sources(
+            "pub struct Test { pub weight: NormalizedFloat }",
+            r#"{ "properties": { "weight": { "type": "integer" } }, "required": ["weight"] }"#,
+        );
+        assert!(matches!(result, Err(DriftError::TypeMismatch { .. })));
+    }
+    
+    #[test]
+    fn test_custom_newtype_map() {
+        let rust_info = crate::parser::parse_type_from_source(
+            "pub struct Test { pub rank: Rank }",
+            "Test",
+        ).unwrap().unwrap();
+        let schema_info = crate::schema::parse_schema(
+            r#"{ "properties": { "rank": { "type": "integer" } }, "required": ["rank"] }"#,
+            "Test",
+        ).unwrap();
+        
+        assert!(compare_types("Test", &rust_info, &schema_info).is_err());
+        
+        let newtypes = NewtypeMap::default().with("Rank", JsonType::Integer, None);
+        assert!(compare_types_with_newtypes("Test", &rust_info, &schema_info, false, &newtypes).is_ok());
+    }
+    
+    #[test]
+    fn test_required_optional_mismatch() {
+        let schema = r#"{ "properties": { "label": { "type": "string" } }, "required": ["label"] }"#;
+        let result = compare_sources("pub struct Test { pub label: Option<String> }", schema);
+        assert!(matches!(
+            result,
+            Err(DriftError::OptionalityMismatch { rust_optional: true, schema_required: true, .. })
+        ));
+        
+        let schema = r#"{ "properties": { "label": { "type": "string" } } }"#;
+        let result = compare_sources("pub struct Test { pub label: String }", schema);
+        assert!(matches!(
+            result,
+            Err(DriftError::OptionalityMismatch { rust_optional: false, schema_required: false, .. })
+        ));
+        
+        let result = compare_sources("pub struct Test { #[serde(default)] pub label: String }", schema);
+        assert!(result.is_ok());
     }
     
     #[test]
@@ -326,13 +757,18 @@ mod tests {
                 m.insert("id".to_string(), RustFieldInfo {
                     type_string: "String".to_string(),
                     is_optional: false,
+                    element_type: None,
                     serde_rename: None,
                     is_flattened: false,
+                    is_skipped: false,
+                    has_default: false,
+                    drift_ignored: false,
                 });
                 m
             },
             has_flattened: false,
             flattened_types: vec![],
+            variants: vec![],
         };
         
         let schema_info = JsonSchemaInfo {
@@ -344,14 +780,86 @@ mod tests {
                     format: None,
                     ref_type: None,
                     description: None,
+                    items: None,
                 });
                 m
             },
             required: vec!["id".to_string()],
+            variants: vec![],
         };
         
         let result = compare_types("Test", &rust_info, &schema_info);
         assert!(result.is_ok());
     }
+    
+    #[test]
+    fn test_extra_rust_field_is_opt_in() {
+        let rust_info = crate::parser::parse_type_from_source(r#"
+            pub struct Test {
+                pub id: String,
+                pub cached_label: String,
+                #[serde(skip)]
+                pub scratch: String,
+            }
+        "#, "Test").unwrap().unwrap();
+        let schema_info = crate::schema::parse_schema(
+            r#"{ "title": "Test", "properties": { "id": { "type": "string" } }, "required": ["id"] }"#,
+            "Test",
+        ).unwrap();
+        
+        let result = compare_types_with("Test", &rust_info, &schema_info, true);
+        assert!(matches!(
+            result,
+            Err(DriftError::ExtraInRust { ref field, .. }) if field == "cached_label"
+        ));
+        
+        // Without opting in, the field is still reported the way it always was
+        let result = compare_types("Test", &rust_info, &schema_info);
+        assert!(matches!(result, Err(DriftError::MissingInSchema { .. })));
+    }
+    
+    #[test]
+    fn test_drift_ignored_field_is_not_compared() {
+        let rust = r#"
+            pub struct Test {
+                pub id: String,
+                #[drift(ignore)]
+                pub cached_label: String,
+            }
+        "#;
+        let schema = r#"{ "properties": { "id": { "type": "string" } }, "required": ["id"] }"#;
+        let rust_info = crate::parser::parse_type_from_source(rust, "Test").unwrap().unwrap();
+        let schema_info = crate::schema::parse_schema(schema, "Test").unwrap();
+        
+        assert!(compare_types("Test", &rust_info, &schema_info).is_ok());
+        assert!(compare_types_with("Test", &rust_info, &schema_info, true).is_ok());
+    }
+    
+    #[test]
+    fn test_compare_enum_missing_variant() {
+        let rust_info = crate::parser::parse_type_from_source(r#"
+            #[derive(Serialize, Deserialize)]
+            #[serde(rename_all = "lowercase")]
+            pub enum InviteRole { Admin, Member }
+        "#, "InviteRole").unwrap().unwrap();
+        let schema_info = crate::schema::parse_schema(
+            r#"{ "title": "InviteRole", "type": "string", "enum": ["admin", "member", "guest"] }"#,
+            "InviteRole",
+        ).unwrap();
+        
+        let result = compare_types("InviteRole", &rust_info, &schema_info);
+        assert!(matches!(
+            result,
+            Err(DriftError::MissingVariantInRust { ref variant, .. }) if variant == "guest"
+        ));
+        
+        let mut rust_info = rust_info;
+        rust_info.variants.extend(["guest".to_string(), "owner".to_string()]);
+        let result = compare_types("InviteRole", &rust_info, &schema_info);
+        assert!(matches!(
+            result,
+            Err(DriftError::MissingVariantInSchema { ref variant, .. }) if variant == "owner"
+        ));
+    }
 }
 
@@ -10,14 +10,31 @@
 pub mod parser;
 pub mod schema;
 pub mod compare;
+pub mod newtypes;
 
 pub use parser::RustTypeInfo;
 pub use schema::JsonSchemaInfo;
 pub use compare::{DriftReport, DriftError, FieldDrift};
+pub use newtypes::NewtypeMap;
 
 use std::path::Path;
 use thiserror::Error;
 
+/// The controlled list of manually-maintained familiar-core types.
+/// These are "Complex Domain" types with physics/relationships/business logic
+/// that cannot be automatically generated from schemas.
+pub const MANUAL_TYPES: &[&str] = &[
+    // Symmetric Seven - Core Physics Entities (NEVER generate)
+    "Moment", "Pulse", "Thread", "Bond", "Filament", "Focus", "Intent", "Motif",
+    // Orchestration Entities (NEVER generate)
+    "Course", "Shuttle",
+    // Components with #[serde(flatten)] (Manual until typify supports)
+    "Identity", "FieldExcitation", "QuantumState", "ContentPayload",
+    "BondPhysics", "CognitiveOptics", "EmotionalState", "Timestamps",
+    // Complex Types with nested structures
+    "WeaveUnit", "Weave", "PhysicsHint", "RequestContext",
+];
+
 /// Errors that can occur during drift checking
 #[derive(Error, Debug)]
 pub enum CheckError {
@@ -53,7 +70,7 @@ pub fn check_drift(
     rust_types_dir: &Path,
     manual_types: &[&str],
 ) -> Result<(), DriftReport> {
-    check_drift_multi(schemas_dir, &[rust_types_dir], manual_types)
+    check_drift_multi(schemas_dir, &[rust_types_dir], manual_types, false)
 }
 
 /// Check for drift between manually-maintained Rust types and JSON schemas.
@@ -63,6 +80,8 @@ pub fn check_drift(
 /// * `schemas_dir` - Path to the directory containing JSON schemas
 /// * `rust_dirs` - Multiple directories to search for Rust type definitions
 /// * `manual_types` - List of type names that should be drift-checked
+/// * `report_extra_fields` - Also report Rust fields the schema doesn't declare
+///   as `DriftError::ExtraInRust`
 ///
 /// # Returns
 /// * `Ok(())` if no drift detected
@@ -71,11 +90,24 @@ pub fn check_drift_multi(
     schemas_dir: &Path,
     rust_dirs: &[&Path],
     manual_types: &[&str],
+    report_extra_fields: bool,
+) -> Result<(), DriftReport> {
+    check_drift_with_newtypes(schemas_dir, rust_dirs, manual_types, report_extra_fields, &NewtypeMap::default())
+}
+
+/// Check for drift like [`check_drift_multi`], seeing through the newtype
+/// wrappers in `newtypes` (the familiar-primitives set by default).
+pub fn check_drift_with_newtypes(
+    schemas_dir: &Path,
+    rust_dirs: &[&Path],
+    manual_types: &[&str],
+    report_extra_fields: bool,
+    newtypes: &NewtypeMap,
 ) -> Result<(), DriftReport> {
     let mut drift_errors: Vec<DriftError> = Vec::new();
     
     for type_name in manual_types {
-        match check_single_type_multi(schemas_dir, rust_dirs, type_name) {
+        match check_single_type_multi(schemas_dir, rust_dirs, type_name, report_extra_fields, newtypes) {
             Ok(()) => {}
             Err(e) => drift_errors.push(e),
         }
@@ -93,6 +125,8 @@ fn check_single_type_multi(
     schemas_dir: &Path,
     rust_dirs: &[&Path],
     type_name: &str,
+    report_extra_fields: bool,
+    newtypes: &NewtypeMap,
 ) -> Result<(), DriftError> {
     // Find the JSON schema file
     let schema_info = schema::find_and_parse_schema(schemas_dir, type_name)?;
@@ -101,13 +135,30 @@ fn check_single_type_multi(
     let rust_info = parser::find_and_parse_rust_type_multi(rust_dirs, type_name)?;
     
     // Compare the two
-    compare::compare_types(type_name, &rust_info, &schema_info)
+    compare::compare_types_with_newtypes(type_name, &rust_info, &schema_info, report_extra_fields, newtypes)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_drift_report_json() {
+        let report = DriftReport {
+            errors: vec![
+                DriftError::MissingInRust {
+                    type_name: "Moment".to_string(),
+                    field: "new_field".to_string(),
+                },
+            ],
+        };
+        let json = report.to_json();
+        assert_eq!(json[0]["kind"], "missing_in_rust");
+        assert_eq!(json[0]["type"], "Moment");
+        assert_eq!(json[0]["field"], "new_field");
+        assert!(json[0]["detail"].as_str().unwrap().contains("new_field"));
+    }
+    
     #[test]
     fn test_drift_report_display() {
         let report = DriftReport {
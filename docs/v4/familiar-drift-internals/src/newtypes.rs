@@ -0,0 +1,112 @@
+//! Transparent newtype wrappers and the schema types they serialize as.
+//! Lets the comparer see through `NormalizedFloat`, `Timestamp`, the `*Id`
+//! types and the like instead of reporting them as mismatches.
+
+use std::collections::HashMap;
+
+use crate::schema::JsonType;
+
+/// The schema type a newtype serializes as
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnderlyingType {
+    pub json_type: JsonType,
+    /// Format the wrapped value carries (e.g. "uuid"), if any
+    pub format: Option<String>,
+}
+
+/// Map of newtype name -> the schema type it serializes as.
+///
+/// A name starting with `*` matches by suffix, so `*Id` covers `TenantId`,
+/// `UserId` and the rest. Exact names win over suffixes, and longer suffixes
+/// over shorter ones.
+#[derive(Debug, Clone)]
+pub struct NewtypeMap {
+    exact: HashMap<String, UnderlyingType>,
+    suffixes: Vec<(String, UnderlyingType)>,
+}
+
+impl Default for NewtypeMap {
+    /// The familiar-primitives wrappers
+    fn default() -> Self {
+        Self::empty()
+            .with("*Id", JsonType::String, Some("uuid"))
+            .with("UUID", JsonType::String, Some("uuid"))
+            .with("NormalizedFloat", JsonType::Number, None)
+            .with("SignedNormalizedFloat", JsonType::Number, None)
+            .with("QuantizedCoord", JsonType::Integer, None)
+            .with("Timestamp", JsonType::String, Some("date-time"))
+    }
+}
+
+impl NewtypeMap {
+    /// A map that resolves nothing
+    pub fn empty() -> Self {
+        Self {
+            exact: HashMap::new(),
+            suffixes: Vec::new(),
+        }
+    }
+
+    /// Map `name` (or a `*Suffix` pattern) to the schema type it serializes as
+    pub fn with(mut self, name: &str, json_type: JsonType, format: Option<&str>) -> Self {
+        let underlying = UnderlyingType {
+            json_type,
+            format: format.map(String::from),
+        };
+
+        match name.strip_prefix('*') {
+            Some(suffix) => {
+                self.suffixes.retain(|(existing, _)| existing != suffix);
+                self.suffixes.push((suffix.to_string(), underlying));
+                self.suffixes.sort_by_key(|(suffix, _)| std::cmp::Reverse(suffix.len()));
+            }
+            None => {
+                self.exact.insert(name.to_string(), underlying);
+            }
+        }
+        self
+    }
+
+    /// The schema type `rust_type` serializes as, if it's a known newtype.
+    /// Paths are matched on their last segment (`familiar_primitives::UserId`).
+    pub fn resolve(&self, rust_type: &str) -> Option<&UnderlyingType> {
+        let name = rust_type.rsplit("::").next().unwrap_or(rust_type);
+
+        self.exact.get(name).or_else(|| {
+            self.suffixes.iter()
+                .find(|(suffix, _)| name.len() > suffix.len() && name.ends_with(suffix.as_str()))
+                .map(|(_, underlying)| underlying)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_default_primitives() {
+        let newtypes = NewtypeMap::default();
+
+        let tenant = newtypes.resolve("TenantId").unwrap();
+        assert_eq!(tenant.json_type, JsonType::String);
+        assert_eq!(tenant.format.as_deref(), Some("uuid"));
+        assert!(newtypes.resolve("familiar_primitives::UserId").is_some());
+
+        assert_eq!(newtypes.resolve("QuantizedCoord").unwrap().json_type, JsonType::Integer);
+        assert_eq!(newtypes.resolve("Timestamp").unwrap().format.as_deref(), Some("date-time"));
+
+        // The suffix alone, or generic wrappers, aren't newtypes
+        assert!(newtypes.resolve("Id").is_none());
+        assert!(newtypes.resolve("String").is_none());
+        assert!(NewtypeMap::empty().resolve("TenantId").is_none());
+    }
+
+    #[test]
+    fn test_exact_names_override_suffixes() {
+        let newtypes = NewtypeMap::default().with("LegacyId", JsonType::Integer, None);
+
+        assert_eq!(newtypes.resolve("LegacyId").unwrap().json_type, JsonType::Integer);
+        assert_eq!(newtypes.resolve("ChannelId").unwrap().json_type, JsonType::String);
+    }
+}
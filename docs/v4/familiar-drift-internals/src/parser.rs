@@ -1,5 +1,6 @@
 //! Rust source code parser using syn.
-//! Extracts struct definitions and their fields for comparison with JSON schemas.
+//! Extracts struct definitions and their fields, and enum definitions and their
+//! variants, for comparison with JSON schemas.
 
 use std::collections::HashMap;
 use std::fs;
@@ -9,10 +10,10 @@ use walkdir::WalkDir;
 
 use crate::compare::DriftError;
 
-/// Information extracted from a Rust struct definition
+/// Information extracted from a Rust struct or enum definition
 #[derive(Debug, Clone)]
 pub struct RustTypeInfo {
-    /// The name of the struct
+    /// The name of the struct or enum
     pub name: String,
     /// Map of field name -> field type (as string)
     pub fields: HashMap<String, RustFieldInfo>,
@@ -20,6 +21,8 @@ pub struct RustTypeInfo {
     pub has_flattened: bool,
     /// Names of flattened types (for recursive analysis)
     pub flattened_types: Vec<String>,
+    /// Serialized variant names, in declaration order (empty for structs)
+    pub variants: Vec<String>,
 }
 
 /// Information about a single Rust struct field
@@ -29,12 +32,26 @@ pub struct RustFieldInfo {
     pub type_string: String,
     /// Whether this field is optional (Option<T>)
     pub is_optional: bool,
+    /// Element type of a `Vec<T>` or `Option<Vec<T>>` field
+    pub element_type: Option<String>,
     /// The serde rename if any
     pub serde_rename: Option<String>,
     /// Whether this field is flattened
     pub is_flattened: bool,
     /// Whether this field is skipped by serde
     pub is_skipped: bool,
+    /// Whether serde fills this field in when it's absent (#[serde(default)]
+    /// on the field or the struct)
+    pub has_default: bool,
+    /// Whether the field is annotated `#[drift(ignore)]` and excluded from comparison
+    pub drift_ignored: bool,
+}
+
+impl RustTypeInfo {
+    /// Fields that take part in schema comparison (everything not `#[drift(ignore)]`)
+    pub fn checked_fields(&self) -> impl Iterator<Item = (&String, &RustFieldInfo)> {
+        self.fields.iter().filter(|(_, field)| !field.drift_ignored)
+    }
 }
 
 /// Find and parse a Rust type from a directory of .rs files
@@ -89,10 +106,14 @@ pub fn parse_type_from_source(
     })?;
     
     for item in syntax.items {
-        if let Item::Struct(item_struct) = item {
-            if item_struct.ident == type_name {
+        match item {
+            Item::Struct(item_struct) if item_struct.ident == type_name => {
                 return Ok(Some(extract_struct_info(&item_struct)?));
             }
+            Item::Enum(item_enum) if item_enum.ident == type_name => {
+                return Ok(Some(extract_enum_info(&item_enum)));
+            }
+            _ => {}
         }
     }
     
@@ -104,11 +125,13 @@ fn extract_struct_info(item: &syn::ItemStruct) -> Result<RustTypeInfo, DriftErro
     let mut fields = HashMap::new();
     let mut has_flattened = false;
     let mut flattened_types = Vec::new();
+    let container_default = parse_serde_attrs(&item.attrs).has_default;
     
     if let Fields::Named(named_fields) = &item.fields {
         for field in &named_fields.named {
             if let Some(field_name) = &field.ident {
-                let field_info = extract_field_info(field)?;
+                let mut field_info = extract_field_info(field)?;
+                field_info.has_default |= container_default;
                 
                 if field_info.is_skipped {
                     continue;
@@ -131,21 +154,51 @@ fn extract_struct_info(item: &syn::ItemStruct) -> Result<RustTypeInfo, DriftErro
         fields,
         has_flattened,
         flattened_types,
+        variants: Vec::new(),
     })
 }
 
+/// Extract the serialized variant names of a syn enum
+fn extract_enum_info(item: &syn::ItemEnum) -> RustTypeInfo {
+    let rename_all = parse_rename_all(&item.attrs);
+
+    let variants = item.variants.iter()
+        .filter_map(|variant| {
+            let attrs = parse_serde_attrs(&variant.attrs);
+            if attrs.is_skipped {
+                return None;
+            }
+            Some(attrs.rename.unwrap_or_else(|| {
+                apply_rename_all(&variant.ident.to_string(), rename_all.as_deref())
+            }))
+        })
+        .collect();
+
+    RustTypeInfo {
+        name: item.ident.to_string(),
+        fields: HashMap::new(),
+        has_flattened: false,
+        flattened_types: Vec::new(),
+        variants,
+    }
+}
+
 /// Extract field information including serde attributes
 fn extract_field_info(field: &syn::Field) -> Result<RustFieldInfo, DriftError> {
     let type_string = type_to_string(&field.ty);
     let is_optional = is_option_type(&field.ty);
-    let (serde_rename, is_flattened, is_skipped) = parse_serde_attrs(&field.attrs);
+    let element_type = vec_element_type(&field.ty).map(type_to_string);
+    let attrs = parse_serde_attrs(&field.attrs);
     
     Ok(RustFieldInfo {
         type_string,
         is_optional,
-        serde_rename,
-        is_flattened,
-        is_skipped,
+        element_type,
+        serde_rename: attrs.rename,
+        is_flattened: attrs.is_flattened,
+        is_skipped: attrs.is_skipped,
+        has_default: attrs.has_default,
+        drift_ignored: parse_drift_ignore(&field.attrs),
     })
 }
 
@@ -198,11 +251,39 @@ fn is_option_type(ty: &Type) -> bool {
     false
 }
 
-/// Parse serde attributes from field attributes
-fn parse_serde_attrs(attrs: &[Attribute]) -> (Option<String>, bool, bool) {
-    let mut rename = None;
-    let mut is_flattened = false;
-    let mut is_skipped = false;
+/// Element type of a `Vec<T>` or `Option<Vec<T>>`, however the path is spelled
+fn vec_element_type(ty: &Type) -> Option<&Type> {
+    let ty = generic_argument(ty, "Option").unwrap_or(ty);
+    generic_argument(ty, "Vec")
+}
+
+/// The first type argument of `ty` if its last path segment is `wrapper`
+fn generic_argument<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last().filter(|seg| seg.ident == wrapper)?;
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|arg| match arg {
+        syn::GenericArgument::Type(t) => Some(t),
+        _ => None,
+    })
+}
+
+/// The serde attributes that affect drift checking
+#[derive(Default)]
+struct SerdeAttrs {
+    rename: Option<String>,
+    is_flattened: bool,
+    is_skipped: bool,
+    has_default: bool,
+}
+
+/// Parse serde attributes from field, variant or container attributes
+fn parse_serde_attrs(attrs: &[Attribute]) -> SerdeAttrs {
+    let mut parsed = SerdeAttrs::default();
     
     for attr in attrs {
         if attr.path().is_ident("serde") {
@@ -210,20 +291,111 @@ fn parse_serde_attrs(attrs: &[Attribute]) -> (Option<String>, bool, bool) {
                 if meta.path.is_ident("rename") {
                     if let Ok(value) = meta.value() {
                         if let Ok(lit) = value.parse::<syn::LitStr>() {
-                            rename = Some(lit.value());
+                            parsed.rename = Some(lit.value());
                         }
                     }
                 } else if meta.path.is_ident("flatten") {
-                    is_flattened = true;
+                    parsed.is_flattened = true;
                 } else if meta.path.is_ident("skip") {
-                    is_skipped = true;
+                    parsed.is_skipped = true;
+                } else if meta.path.is_ident("default") {
+                    parsed.has_default = true;
+                    // #[serde(default = "path")]
+                    if let Ok(value) = meta.value() {
+                        let _ = value.parse::<syn::LitStr>();
+                    }
                 }
                 Ok(())
             });
         }
     }
     
-    (rename, is_flattened, is_skipped)
+    parsed
+}
+
+/// Check for `#[drift(ignore)]`.
+///
+/// Rust-only fields (caches, computed values) carry this to stay out of the
+/// drift comparison. The attribute is registered by
+/// `familiar_core_macros::DriftCheck`. Unknown `drift` options are skipped.
+fn parse_drift_ignore(attrs: &[Attribute]) -> bool {
+    let mut ignore = false;
+    
+    for attr in attrs {
+        if attr.path().is_ident("drift") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("ignore") {
+                    ignore = true;
+                } else {
+                    skip_meta(&meta)?;
+                }
+                Ok(())
+            });
+        }
+    }
+    
+    ignore
+}
+
+/// Consume an unrecognized `key = value` or `key(...)` so parsing can continue
+fn skip_meta(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_meta(&nested))?;
+    }
+    Ok(())
+}
+
+/// Parse a container-level #[serde(rename_all = "...")]
+fn parse_rename_all(attrs: &[Attribute]) -> Option<String> {
+    let mut rename_all = None;
+
+    for attr in attrs {
+        if attr.path().is_ident("serde") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename_all") {
+                    if let Ok(value) = meta.value() {
+                        if let Ok(lit) = value.parse::<syn::LitStr>() {
+                            rename_all = Some(lit.value());
+                        }
+                    }
+                }
+                Ok(())
+            });
+        }
+    }
+
+    rename_all
+}
+
+/// Apply a serde rename_all rule to a PascalCase variant name
+fn apply_rename_all(name: &str, rule: Option<&str>) -> String {
+    let words = || {
+        let mut words: Vec<String> = Vec::new();
+        for c in name.chars() {
+            if c.is_uppercase() || words.is_empty() {
+                words.push(String::new());
+            }
+            words.last_mut().unwrap().push(c);
+        }
+        words
+    };
+    let join_lower = |sep: &str| words().iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join(sep);
+
+    match rule {
+        Some("lowercase") => name.to_lowercase(),
+        Some("UPPERCASE") => name.to_uppercase(),
+        Some("camelCase") => {
+            let mut chars = name.chars();
+            chars.next().map(|c| c.to_lowercase().chain(chars).collect()).unwrap_or_default()
+        }
+        Some("snake_case") => join_lower("_"),
+        Some("SCREAMING_SNAKE_CASE") => join_lower("_").to_uppercase(),
+        Some("kebab-case") => join_lower("-"),
+        Some("SCREAMING-KEBAB-CASE") => join_lower("-").to_uppercase(),
+        _ => name.to_string(),
+    }
 }
 
 #[cfg(test)]
@@ -276,5 +448,72 @@ mod tests {
         assert!(info.flattened_types.contains(&"Identity".to_string()));
         assert!(info.fields.contains_key("moment_type"));
     }
+    
+    #[test]
+    fn test_parse_vec_element_types() {
+        let source = r#"
+            pub struct Test {
+                pub tags: Vec<String>,
+                pub scores: Option<Vec<i64>>,
+                pub labels: std::vec::Vec<Option<String>>,
+                pub name: String,
+            }
+        "#;
+        
+        let info = parse_type_from_source(source, "Test").unwrap().unwrap();
+        assert_eq!(info.fields["tags"].element_type.as_deref(), Some("String"));
+        assert_eq!(info.fields["scores"].element_type.as_deref(), Some("i64"));
+        assert!(info.fields["scores"].is_optional);
+        assert_eq!(info.fields["labels"].element_type.as_deref(), Some("Option<String>"));
+        assert_eq!(info.fields["name"].element_type, None);
+    }
+    
+    #[test]
+    fn test_parse_drift_ignore() {
+        let source = r#"
+            #[derive(Serialize, Deserialize, DriftCheck)]
+            pub struct Test {
+                pub id: String,
+                #[drift(reason = "cache", ignore)]
+                pub cached: String,
+                #[drift(unknown(nested))]
+                pub annotated: String,
+            }
+        "#;
+        
+        let info = parse_type_from_source(source, "Test").unwrap().unwrap();
+        assert!(info.fields["cached"].drift_ignored);
+        assert!(!info.fields["annotated"].drift_ignored);
+        assert!(!info.fields["id"].drift_ignored);
+        
+        let checked: Vec<_> = info.checked_fields().map(|(name, _)| name.as_str()).collect();
+        assert!(!checked.contains(&"cached"));
+    }
+    
+    #[test]
+    fn test_parse_enum_variants() {
+        let source = r#"
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            pub enum InviteRole {
+                #[serde(rename = "admin")]
+                Admin,
+                #[serde(rename = "member")]
+                Member,
+                #[serde(skip)]
+                Internal,
+            }
+            
+            #[derive(Debug, Clone, Serialize, Deserialize)]
+            #[serde(rename_all = "snake_case")]
+            pub enum MomentType { TextEntry, Voice }
+        "#;
+        
+        let info = parse_type_from_source(source, "InviteRole").unwrap().unwrap();
+        assert_eq!(info.variants, vec!["admin", "member"]);
+        assert!(info.fields.is_empty());
+        
+        let info = parse_type_from_source(source, "MomentType").unwrap().unwrap();
+        assert_eq!(info.variants, vec!["text_entry", "voice"]);
+    }
 }
 
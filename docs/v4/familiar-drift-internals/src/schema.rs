@@ -18,6 +18,8 @@ pub struct JsonSchemaInfo {
     pub properties: HashMap<String, JsonPropertyInfo>,
     /// List of required field names
     pub required: Vec<String>,
+    /// Enum variant names from `enum` or `oneOf`/`anyOf` (empty for object schemas)
+    pub variants: Vec<String>,
 }
 
 /// Information about a single JSON Schema property
@@ -31,10 +33,12 @@ pub struct JsonPropertyInfo {
     pub ref_type: Option<String>,
     /// Description from schema
     pub description: Option<String>,
+    /// Element schema for arrays (`items`)
+    pub items: Option<Box<JsonPropertyInfo>>,
 }
 
 /// Represents JSON Schema types
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum JsonType {
     String,
     Integer,
@@ -63,6 +67,12 @@ struct RawSchema {
     definitions: Option<HashMap<String, serde_json::Value>>,
     #[serde(rename = "allOf")]
     all_of: Option<Vec<serde_json::Value>>,
+    #[serde(rename = "enum")]
+    enum_values: Option<Vec<serde_json::Value>>,
+    #[serde(rename = "oneOf")]
+    one_of: Option<Vec<serde_json::Value>>,
+    #[serde(rename = "anyOf")]
+    any_of: Option<Vec<serde_json::Value>>,
 }
 
 /// Find and parse a JSON Schema file for a given type name
@@ -103,6 +113,7 @@ pub fn parse_schema(content: &str, type_name: &str) -> Result<JsonSchemaInfo, Dr
     
     let name = raw.title.unwrap_or_else(|| type_name.to_string());
     let required = raw.required.unwrap_or_default();
+    let variants = parse_variants(&raw.enum_values, &raw.one_of, &raw.any_of);
     
     let mut properties = HashMap::new();
     
@@ -117,9 +128,40 @@ pub fn parse_schema(content: &str, type_name: &str) -> Result<JsonSchemaInfo, Dr
         name,
         properties,
         required,
+        variants,
     })
 }
 
+/// Collect enum variant names.
+///
+/// Unit enums are a string `enum`; `oneOf`/`anyOf` alternatives name their
+/// variant with `const`, a single-value `enum`, or (for externally tagged
+/// variants with data) an object with exactly one property.
+fn parse_variants(
+    enum_values: &Option<Vec<serde_json::Value>>,
+    one_of: &Option<Vec<serde_json::Value>>,
+    any_of: &Option<Vec<serde_json::Value>>,
+) -> Vec<String> {
+    let mut variants: Vec<String> = enum_values.iter()
+        .flatten()
+        .filter_map(|v| v.as_str().map(String::from))
+        .collect();
+
+    for alternative in one_of.iter().chain(any_of.iter()).flatten() {
+        if let Some(variant) = alternative.get("const").and_then(|v| v.as_str()) {
+            variants.push(variant.to_string());
+        } else if let Some(values) = alternative.get("enum").and_then(|v| v.as_array()) {
+            variants.extend(values.iter().filter_map(|v| v.as_str().map(String::from)));
+        } else if let Some(props) = alternative.get("properties").and_then(|v| v.as_object()) {
+            if props.len() == 1 {
+                variants.extend(props.keys().cloned());
+            }
+        }
+    }
+
+    variants
+}
+
 /// Parse a single property from its JSON value
 fn parse_property(value: &serde_json::Value) -> Result<JsonPropertyInfo, DriftError> {
     let obj = value.as_object().ok_or_else(|| {
@@ -134,6 +176,7 @@ fn parse_property(value: &serde_json::Value) -> Result<JsonPropertyInfo, DriftEr
             format: None,
             ref_type: Some(ref_str.to_string()),
             description: obj.get("description").and_then(|v| v.as_str()).map(String::from),
+            items: None,
         });
     }
     
@@ -149,6 +192,7 @@ fn parse_property(value: &serde_json::Value) -> Result<JsonPropertyInfo, DriftEr
                             format: None,
                             ref_type: Some(ref_str.to_string()),
                             description: obj.get("description").and_then(|v| v.as_str()).map(String::from),
+                            items: None,
                         });
                     }
                 }
@@ -159,12 +203,17 @@ fn parse_property(value: &serde_json::Value) -> Result<JsonPropertyInfo, DriftEr
     let json_type = parse_type_field(obj.get("type"));
     let format = obj.get("format").and_then(|v| v.as_str()).map(String::from);
     let description = obj.get("description").and_then(|v| v.as_str()).map(String::from);
+    let items = match obj.get("items") {
+        Some(items) if items.is_object() => Some(Box::new(parse_property(items)?)),
+        _ => None,
+    };
     
     Ok(JsonPropertyInfo {
         json_type,
         format,
         ref_type: None,
         description,
+        items,
     })
 }
 
@@ -254,5 +303,25 @@ mod tests {
         let value_prop = info.properties.get("value").unwrap();
         assert!(matches!(value_prop.json_type, JsonType::Union(_)));
     }
+    
+    #[test]
+    fn test_parse_enum_variants() {
+        let schema = r#"{ "title": "InviteRole", "type": "string", "enum": ["admin", "member", "guest"] }"#;
+        let info = parse_schema(schema, "InviteRole").unwrap();
+        assert_eq!(info.variants, vec!["admin", "member", "guest"]);
+        
+        let schema = r#"
+        {
+            "title": "MomentType",
+            "oneOf": [
+                { "const": "text_entry" },
+                { "type": "string", "enum": ["voice"] },
+                { "type": "object", "required": ["image"], "properties": { "image": { "type": "string" } } }
+            ]
+        }
+        "#;
+        let info = parse_schema(schema, "MomentType").unwrap();
+        assert_eq!(info.variants, vec!["text_entry", "voice", "image"]);
+    }
 }
 
@@ -34,6 +34,9 @@ impl AppState {
     pub async fn new(app_config: AppConfig) -> Result<Self, String> {
         // Initialize ContractEnforcer (compiles embedded schemas at startup)
         let enforcer = Arc::new(ContractEnforcer::new());
+        if let Err(e) = enforcer.warm_all() {
+            tracing::warn!("⚠️ ContractEnforcer warm-up failed: {}", e);
+        }
         tracing::info!("✅ ContractEnforcer initialized with {} schemas", enforcer.schema_count());
         
         // Try to connect to database from config
@@ -8,8 +8,10 @@
 //! Activities receive `serde_json::Value` (opaque envelope) and unpack
 //! to typed structs internally using ContractEnforcer + SIMD-JSON.
 
+use crate::drain::Draining;
 use crate::state::SharedState;
 use anyhow::{Context, Result};
+use familiar_core::ContractEnforcer;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -28,6 +30,9 @@ pub struct FatesInput {
     pub tenant_id: Option<String>,
 }
 
+/// Input to the Gate stage
+pub type GateInput = FatesInput;
+
 /// Output from Gate stage
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -38,6 +43,14 @@ pub struct GateOutput {
     pub confidence: Option<f64>,
 }
 
+/// One input's outcome in a Gate batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum GateBatchItem {
+    Ok { output: GateOutput },
+    Error { message: String },
+}
+
 /// Output from Morta stage
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -114,26 +127,85 @@ pub async fn fates_gate_activity(
     let mut bytes = serde_json::to_vec(&input).context("Failed to serialize input")?;
 
     // 2. Parse with SIMD-JSON (fast path, no validation for internal use)
-    let req: FatesInput = state
+    let req: GateInput = state
         .enforcer
         .parse_mut(&mut bytes)
         .context("Failed to parse FatesInput")?;
 
+    // 3. Execute gate logic
+    let result = gate(req);
+
+    tracing::info!(classification = %result.classification, "Gate complete");
+
+    // 4. Return as JSON
+    serde_json::to_value(result).context("Failed to serialize GateOutput")
+}
+
+/// Gate batch activity - Classification of many inputs at once
+///
+/// Takes an array of GateInput and returns one GateBatchItem per input, in
+/// order. An input that fails doesn't fail the rest of the batch.
+pub async fn fates_gate_batch_activity(
+    state: SharedState,
+    input: Value,
+) -> Result<Value> {
+    let inputs: Vec<Value> = serde_json::from_value(input)
+        .context("FatesGateBatch expects an array of GateInput")?;
+    let max_batch_size = state.config.systems.fates_gate.batch.max_size;
+
+    // TODO: Persist gate results once gate persistence exists
+    let results = gate_batch(&state.enforcer, inputs, max_batch_size).await;
+
+    let items: Vec<GateBatchItem> = results
+        .into_iter()
+        .map(|result| match result {
+            Ok(output) => GateBatchItem::Ok { output },
+            Err(e) => GateBatchItem::Error { message: format!("{:#}", e) },
+        })
+        .collect();
+
+    let failed = items.iter().filter(|item| matches!(item, GateBatchItem::Error { .. })).count();
+    tracing::info!(total = items.len(), failed, "Gate batch complete");
+
+    serde_json::to_value(items).context("Failed to serialize GateBatchItems")
+}
+
+/// Classify `inputs` in chunks of at most `max_batch_size`, one result per input
+pub async fn gate_batch(
+    enforcer: &ContractEnforcer,
+    inputs: Vec<Value>,
+    max_batch_size: usize,
+) -> Vec<Result<GateOutput>> {
+    let mut results = Vec::with_capacity(inputs.len());
+    for chunk in inputs.chunks(max_batch_size.max(1)) {
+        for input in chunk {
+            results.push(gate_one(enforcer, input));
+        }
+        // Let other activities on this worker run between chunks
+        tokio::task::yield_now().await;
+    }
+    results
+}
+
+fn gate_one(enforcer: &ContractEnforcer, input: &Value) -> Result<GateOutput> {
+    let mut bytes = serde_json::to_vec(input).context("Failed to serialize input")?;
+    let req: GateInput = enforcer
+        .parse_mut(&mut bytes)
+        .context("Failed to parse GateInput")?;
+    Ok(gate(req))
+}
+
+/// Gate logic shared by the single and batch activities
+fn gate(req: GateInput) -> GateOutput {
     tracing::debug!(course_id = %req.course_id, "Gate processing");
 
-    // 3. Execute gate logic
     // TODO: Wire up to actual familiar-core fates::gate module
-    let result = GateOutput {
+    GateOutput {
         classification: "default".to_string(),
         next_stage: "morta".to_string(),
         input: req,
         confidence: Some(0.95),
-    };
-
-    tracing::info!(classification = %result.classification, "Gate complete");
-
-    // 4. Return as JSON
-    serde_json::to_value(result).context("Failed to serialize GateOutput")
+    }
 }
 
 /// Morta activity - Content Segmentation
@@ -240,10 +312,13 @@ pub async fn fates_pipeline_activity(
 ) -> Result<Value> {
     tracing::info!("Starting full Fates pipeline");
 
-    // Run each stage in sequence
+    // Run each stage in sequence, stopping between stages once draining
     let gate_result = fates_gate_activity(state.clone(), input).await?;
+    ensure_not_draining(&state)?;
     let morta_result = fates_morta_activity(state.clone(), gate_result.clone()).await?;
+    ensure_not_draining(&state)?;
     let decima_result = fates_decima_activity(state.clone(), morta_result.clone()).await?;
+    ensure_not_draining(&state)?;
     let nona_result = fates_nona_activity(state.clone(), decima_result.clone()).await?;
 
     // Parse results for final output
@@ -267,3 +342,48 @@ pub async fn fates_pipeline_activity(
     serde_json::to_value(result).context("Failed to serialize PipelineOutput")
 }
 
+/// Fail with the retryable `Draining` error once shutdown has begun
+///
+/// The pipeline runs as one activity, so a retry restarts it from Gate on
+/// another worker.
+fn ensure_not_draining(state: &SharedState) -> Result<()> {
+    if state.drain.is_draining() {
+        tracing::info!("Fates pipeline stopped between stages for drain");
+        return Err(Draining.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(course_id: &str) -> Value {
+        serde_json::json!({
+            "course_id": course_id,
+            "shuttle_id": "shuttle-1",
+            "content": "Hello",
+            "sender_id": null,
+            "channel_id": null,
+            "tenant_id": null
+        })
+    }
+
+    #[tokio::test]
+    async fn test_gate_batch_isolates_item_failures() {
+        let enforcer = ContractEnforcer::new();
+        // The middle input is missing its content
+        let inputs = vec![
+            input("course-1"),
+            serde_json::json!({ "course_id": "course-2", "shuttle_id": "shuttle-1" }),
+            input("course-3"),
+        ];
+
+        let results = gate_batch(&enforcer, inputs, 2).await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().input.course_id, "course-1");
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap().input.course_id, "course-3");
+    }
+}
@@ -8,6 +8,9 @@
 //! Activities are registered with the worker in main.rs using closures
 //! that capture the SharedState (Arc<HotState>). This allows each
 //! activity to access the hot resources without re-initialization.
+//!
+//! Each registered activity holds an `ActivityGuard` while it runs, so a
+//! draining daemon rejects new tasks and waits for the running ones.
 
 mod fates;
 
@@ -23,6 +26,9 @@ use temporalio_sdk::{ActivityError, Worker};
 ///
 /// The SharedState is captured by cloning the Arc for each activity.
 /// This is cheap (just increments refcount) and allows concurrent execution.
+///
+/// Once draining, new activities fail with a retryable error (the default
+/// for errors converted into `ActivityError`).
 pub fn register_fates_activities(worker: &mut Worker, state: SharedState) {
     tracing::info!("Registering Fates activities...");
 
@@ -31,7 +37,20 @@ pub fn register_fates_activities(worker: &mut Worker, state: SharedState) {
     worker.register_activity("FatesGate", move |_ctx, input| {
         let state = s.clone();
         async move { 
-            fates_gate_activity(state, input)
+            let _guard = state.begin_activity().map_err(ActivityError::from)?;
+            fates_gate_activity(state.clone(), input)
+                .await
+                .map_err(ActivityError::from)
+        }
+    });
+
+    // Gate Batch - Classification of many inputs, results per item
+    let s = state.clone();
+    worker.register_activity("FatesGateBatch", move |_ctx, input| {
+        let state = s.clone();
+        async move { 
+            let _guard = state.begin_activity().map_err(ActivityError::from)?;
+            fates_gate_batch_activity(state.clone(), input)
                 .await
                 .map_err(ActivityError::from)
         }
@@ -42,7 +61,8 @@ pub fn register_fates_activities(worker: &mut Worker, state: SharedState) {
     worker.register_activity("FatesMorta", move |_ctx, input| {
         let state = s.clone();
         async move { 
-            fates_morta_activity(state, input)
+            let _guard = state.begin_activity().map_err(ActivityError::from)?;
+            fates_morta_activity(state.clone(), input)
                 .await
                 .map_err(ActivityError::from)
         }
@@ -53,7 +73,8 @@ pub fn register_fates_activities(worker: &mut Worker, state: SharedState) {
     worker.register_activity("FatesDecima", move |_ctx, input| {
         let state = s.clone();
         async move { 
-            fates_decima_activity(state, input)
+            let _guard = state.begin_activity().map_err(ActivityError::from)?;
+            fates_decima_activity(state.clone(), input)
                 .await
                 .map_err(ActivityError::from)
         }
@@ -64,7 +85,8 @@ pub fn register_fates_activities(worker: &mut Worker, state: SharedState) {
     worker.register_activity("FatesNona", move |_ctx, input| {
         let state = s.clone();
         async move { 
-            fates_nona_activity(state, input)
+            let _guard = state.begin_activity().map_err(ActivityError::from)?;
+            fates_nona_activity(state.clone(), input)
                 .await
                 .map_err(ActivityError::from)
         }
@@ -75,12 +97,13 @@ pub fn register_fates_activities(worker: &mut Worker, state: SharedState) {
     worker.register_activity("FatesPipeline", move |_ctx, input| {
         let state = s.clone();
         async move { 
-            fates_pipeline_activity(state, input)
+            let _guard = state.begin_activity().map_err(ActivityError::from)?;
+            fates_pipeline_activity(state.clone(), input)
                 .await
                 .map_err(ActivityError::from)
         }
     });
 
-    tracing::info!("Registered 5 Fates activities: Gate, Morta, Decima, Nona, Pipeline");
+    tracing::info!("Registered 6 Fates activities: Gate, GateBatch, Morta, Decima, Nona, Pipeline");
 }
 
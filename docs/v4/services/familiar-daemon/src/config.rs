@@ -3,6 +3,7 @@
 //! Configuration for the familiar-daemon Temporal worker.
 //! Loads from environment variables with sensible defaults.
 
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -12,6 +13,13 @@ pub enum ConfigError {
 
     #[error("Invalid URL format: {0}")]
     InvalidUrl(String),
+
+    #[error("Invalid value for {name}: {value:?} ({reason})")]
+    InvalidEnv {
+        name: String,
+        value: String,
+        reason: String,
+    },
 }
 
 /// Daemon configuration
@@ -31,6 +39,9 @@ pub struct DaemonConfig {
 
     /// Log level filter
     pub log_level: String,
+
+    /// How long shutdown waits for in-flight activities
+    pub drain_timeout: Duration,
 }
 
 impl DaemonConfig {
@@ -52,12 +63,28 @@ impl DaemonConfig {
         let log_level = std::env::var("RUST_LOG")
             .unwrap_or_else(|_| "familiar_daemon=info,familiar_core=info".to_string());
 
+        // Tracing isn't up yet, so a bad value fails startup rather than
+        // being logged and replaced by the default
+        let drain_timeout = match std::env::var("DRAIN_TIMEOUT_SECS") {
+            Ok(secs) => secs
+                .trim()
+                .parse::<u64>()
+                .map(Duration::from_secs)
+                .map_err(|e| ConfigError::InvalidEnv {
+                    name: "DRAIN_TIMEOUT_SECS".to_string(),
+                    value: secs.clone(),
+                    reason: e.to_string(),
+                })?,
+            Err(_) => Duration::from_secs(30),
+        };
+
         Ok(Self {
             database_url,
             temporal_url,
             temporal_namespace,
             task_queue,
             log_level,
+            drain_timeout,
         })
     }
 }
@@ -0,0 +1,133 @@
+//! Drain - Graceful shutdown for in-flight activities
+//!
+//! On SIGTERM the daemon stops admitting new activities but lets the ones
+//! already running finish, so Temporal doesn't retry their work from scratch.
+//!
+//! ```text
+//! begin_drain() ──▶ new activities rejected (retryable)
+//!                   in-flight activities run to completion
+//! await_idle()  ──▶ resolves once the last one finishes
+//! ```
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::Notify;
+
+/// Returned to activities that arrive after draining began
+///
+/// Activities surface this as a retryable error, so Temporal hands the task
+/// to another worker.
+#[derive(Error, Debug)]
+#[error("Daemon is draining for shutdown; retry on another worker")]
+pub struct Draining;
+
+/// Tracks in-flight activities and whether new ones are admitted
+#[derive(Debug, Default)]
+pub struct DrainState {
+    draining: AtomicBool,
+    in_flight: AtomicUsize,
+    idle: Notify,
+}
+
+impl DrainState {
+    /// Stop admitting new activities
+    pub fn begin_drain(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Number of activities currently running
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Admit an activity, which counts as in flight until the guard drops
+    pub fn begin_activity(&self) -> Result<ActivityGuard<'_>, Draining> {
+        // Count first, then check: a drain that starts in between still
+        // waits for this activity to back out
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let guard = ActivityGuard { state: self };
+        if self.is_draining() {
+            return Err(Draining);
+        }
+        Ok(guard)
+    }
+
+    /// Wait until no activities are in flight
+    ///
+    /// Returns `false` if some were still running when `timeout` elapsed.
+    pub async fn await_idle(&self, timeout: Duration) -> bool {
+        let idle = async {
+            loop {
+                let notified = self.idle.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+                if self.in_flight() == 0 {
+                    return;
+                }
+                notified.await;
+            }
+        };
+        tokio::time::timeout(timeout, idle).await.is_ok()
+    }
+}
+
+/// Marks an activity as in flight for as long as it lives
+#[derive(Debug)]
+pub struct ActivityGuard<'a> {
+    state: &'a DrainState,
+}
+
+impl Drop for ActivityGuard<'_> {
+    fn drop(&mut self) {
+        if self.state.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.state.idle.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_drain_waits_for_in_flight_activity() {
+        let state = Arc::new(DrainState::default());
+        let (started_tx, started_rx) = tokio::sync::oneshot::channel();
+
+        // One activity is mid-unit when the drain begins
+        let activity = {
+            let state = state.clone();
+            tokio::spawn(async move {
+                let _guard = state.begin_activity().unwrap();
+                started_tx.send(()).unwrap();
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                "unit finished"
+            })
+        };
+        started_rx.await.unwrap();
+
+        state.begin_drain();
+        assert!(state.begin_activity().is_err());
+        assert_eq!(state.in_flight(), 1);
+        assert!(!state.await_idle(Duration::from_millis(10)).await);
+
+        assert!(state.await_idle(Duration::from_secs(5)).await);
+        assert_eq!(state.in_flight(), 0);
+        assert_eq!(activity.await.unwrap(), "unit finished");
+    }
+
+    #[tokio::test]
+    async fn test_idle_without_activities() {
+        let state = DrainState::default();
+        drop(state.begin_activity().unwrap());
+
+        state.begin_drain();
+        assert!(state.await_idle(Duration::from_millis(10)).await);
+    }
+}
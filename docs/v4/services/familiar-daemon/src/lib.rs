@@ -20,6 +20,7 @@
 //! │  ┌──────────────────────────────────────────────────────────┐   │
 //! │  │  Activities                                               │   │
 //! │  │    ├── FatesGate (classification)                         │   │
+//! │  │    ├── FatesGateBatch (classification, many inputs)       │   │
 //! │  │    ├── FatesMorta (segmentation)                          │   │
 //! │  │    ├── FatesDecima (entity extraction)                    │   │
 //! │  │    ├── FatesNona (response generation)                    │   │
@@ -45,6 +46,7 @@
 
 pub mod activities;
 pub mod config;
+pub mod drain;
 pub mod state;
 
 pub use config::DaemonConfig;
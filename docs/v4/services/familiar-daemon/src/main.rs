@@ -19,6 +19,8 @@
 //! - `TEMPORAL_URL` - Temporal server URL (default: http://localhost:7233)
 //! - `TEMPORAL_NAMESPACE` - Temporal namespace (default: "default")
 //! - `TEMPORAL_TASK_QUEUE` - Task queue name (default: "fates-pipeline")
+//! - `DRAIN_TIMEOUT_SECS` - How long shutdown waits for in-flight activities
+//!   before exiting non-zero (default: 30; a non-integer value fails startup)
 //! - `RUST_LOG` - Log level filter
 
 use std::sync::Arc;
@@ -38,7 +40,7 @@ use temporalio_common::{
 };
 
 use anyhow::{Context, Result};
-use tracing::info;
+use tracing::{info, warn};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -125,10 +127,35 @@ async fn main() -> Result<()> {
     // Register Activities
     // ==========================================================================
     
-    register_fates_activities(&mut worker, hot_state);
+    register_fates_activities(&mut worker, hot_state.clone());
 
     // ==========================================================================
-    // Run Worker (polls indefinitely)
+    // Graceful Shutdown - drain in-flight activities on SIGTERM / Ctrl-C
+    // ==========================================================================
+
+    let drain_timeout = config.drain_timeout;
+    let stop_polling = worker.shutdown_handle();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        info!("Shutdown requested - draining {} in-flight activities...", hot_state.drain.in_flight());
+        hot_state.begin_drain();
+        // Stop taking activity tasks; worker.run() returns once the ones
+        // already polled have completed
+        stop_polling();
+        if hot_state.await_idle(drain_timeout).await {
+            info!("All activities drained");
+        } else {
+            warn!(
+                "Drain timed out after {:?} with {} activities in flight",
+                drain_timeout,
+                hot_state.drain.in_flight()
+            );
+            std::process::exit(1);
+        }
+    });
+
+    // ==========================================================================
+    // Run Worker (polls until shutdown)
     // ==========================================================================
     
     info!("🚀 familiar-daemon online - polling {} queue", config.task_queue);
@@ -144,3 +171,26 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Resolve on SIGTERM (container stop) or Ctrl-C
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c().await.ok();
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending::<()>().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
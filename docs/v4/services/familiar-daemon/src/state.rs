@@ -4,13 +4,18 @@
 //! All expensive initialization happens once at startup:
 //! - ContractEnforcer compiles JSON schemas to DFA (~100ms saved per request)
 //! - SeaORM opens connection pool with TLS (~50ms saved per request)
+//! - GlobalConfig is loaded once (batch sizes, timeouts)
 //!
 //! The state is wrapped in Arc for safe concurrent access across activities.
+//! It also tracks in-flight activities so shutdown can drain them.
 
+use crate::drain::{ActivityGuard, DrainState, Draining};
 use familiar_core::infrastructure::TigerDataStore;
-use familiar_core::ContractEnforcer;
+use familiar_config::GlobalConfig;
+use familiar_core::{ContractEnforcer, ContractError};
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Errors that can occur during state initialization
@@ -21,6 +26,9 @@ pub enum StateError {
 
     #[error("Configuration error: {0}")]
     Config(String),
+
+    #[error("Embedded schema failed self-check: {0}")]
+    Contract(#[from] ContractError),
 }
 
 /// Long-lived shared resources - initialized once at startup
@@ -39,8 +47,14 @@ pub struct HotState {
     pub store: TigerDataStore,
 
     /// Contract enforcer for JSON Schema validation
-    /// Schemas are compiled to DFA at startup for O(1) validation
+    /// Schemas are compiled to DFA at startup (`warm_all`) for O(1) validation
     pub enforcer: Arc<ContractEnforcer>,
+
+    /// In-flight activity tracking for graceful shutdown
+    pub drain: DrainState,
+
+    /// Platform configuration (familiar-config)
+    pub config: GlobalConfig,
 }
 
 impl HotState {
@@ -49,21 +63,74 @@ impl HotState {
     /// This is expensive (~150ms) but only done once at startup.
     /// All activities share this state via Arc.
     pub async fn new(database_url: &str) -> Result<Self, StateError> {
-        // 1. Compile all JSON schemas (expensive, ~100ms)
-        let enforcer = Arc::new(ContractEnforcer::new());
-        tracing::info!(
-            schema_count = enforcer.schema_count(),
-            "ContractEnforcer initialized (schemas compiled to DFA)"
-        );
+        let config = GlobalConfig::load().map_err(|e| StateError::Config(e.to_string()))?;
 
-        // 2. Open database connection pool (expensive, ~50ms for TLS handshake)
+        // 1. Open database connection pool (expensive, ~50ms for TLS handshake)
         let db = sea_orm::Database::connect(database_url).await?;
         tracing::info!("Database connection pool opened");
 
-        // 3. Create TigerDataStore from connection
+        // 2. Create TigerDataStore from connection
         let store = TigerDataStore::from_connection(db.clone());
 
-        Ok(Self { db, store, enforcer })
+        let state = Self {
+            db,
+            store,
+            enforcer: Arc::new(ContractEnforcer::new()),
+            drain: DrainState::default(),
+            config,
+        };
+
+        // 3. Compile all JSON schemas (expensive, ~100ms) before serving
+        state.warm_all()?;
+
+        Ok(state)
+    }
+
+    /// Compile every embedded schema so no activity pays for compilation
+    ///
+    /// Fails if any registered schema doesn't compile, so a malformed schema
+    /// stops startup instead of its first request. Every failing schema is
+    /// logged and the first, by message type, is returned. Embedded schemas
+    /// the enforcer skipped (invalid JSON or an unsupported `$schema`) are
+    /// logged as warnings. Returns the number of schemas compiled and logs
+    /// how long it took.
+    pub fn warm_all(&self) -> Result<usize, ContractError> {
+        let started = Instant::now();
+        let before = self.enforcer.compilations();
+        for (message_type, err) in self.enforcer.rejected() {
+            tracing::warn!(message_type = %message_type, error = %err, "Embedded schema skipped");
+        }
+        if let Err(failures) = self.enforcer.self_check() {
+            for (message_type, err) in &failures {
+                tracing::error!(message_type = %message_type, error = %err, "Embedded schema failed self-check");
+            }
+            if let Some((_, first)) = failures.into_iter().next() {
+                return Err(first);
+            }
+        }
+        let compiled = self.enforcer.compilations() - before;
+        tracing::info!(
+            schema_count = self.enforcer.schema_count(),
+            compiled,
+            elapsed_ms = started.elapsed().as_millis() as u64,
+            "ContractEnforcer warmed (schemas compiled to DFA)"
+        );
+        Ok(compiled)
+    }
+
+    /// Stop accepting activities; running ones finish their current unit
+    pub fn begin_drain(&self) {
+        self.drain.begin_drain();
+    }
+
+    /// Admit an activity, or reject it (retryably) once draining
+    pub fn begin_activity(&self) -> Result<ActivityGuard<'_>, Draining> {
+        self.drain.begin_activity()
+    }
+
+    /// Wait for in-flight activities to finish; `false` on timeout
+    pub async fn await_idle(&self, timeout: Duration) -> bool {
+        self.drain.await_idle(timeout).await
     }
 }
 
@@ -36,7 +36,25 @@
 //! Domain data is opaque JSON passed between steps.
 
 // Re-export from familiar-core for convenience
-pub use familiar_core::types::{EvaluationStep, EvaluationResult};
+pub use familiar_core::types::{
+    EvaluationStep, EvaluationResult, RejectionCode, RejectionData, RetryPolicy,
+};
+
+use familiar_config::{ConfigPath, GlobalConfig};
+
+/// Retry policy for a step whose retry count lives in a `systems.*.retries`
+/// slot, with the backoff taken from `observability.resilience`
+///
+/// The slot counts retries, so the first attempt comes on top of it.
+pub fn retry_policy(retries: ConfigPath<u32>, config: &GlobalConfig) -> RetryPolicy {
+    let resilience = &config.observability.resilience;
+    RetryPolicy {
+        max_attempts: retries.get(config).saturating_add(1),
+        backoff_ms: resilience.initial_delay.as_millis() as u64,
+        max_backoff_ms: resilience.max_delay.as_millis() as u64,
+        jitter: true,
+    }
+}
 
 /// Extension trait for worker-specific evaluation helpers
 pub trait EvaluationResultExt {
@@ -74,6 +92,27 @@ mod tests {
         assert_eq!(result.data["_shuttle_id"], "shuttle-456");
     }
 
+    #[test]
+    fn test_retry_policy_from_config() {
+        let mut config = GlobalConfig::default();
+        config.systems.fates_gate.retries.weave = 5;
+
+        let policy = retry_policy(familiar_config::config_path!(systems.fates_gate.retries.weave), &config);
+        assert_eq!(policy.max_attempts, 6);
+        assert_eq!(policy.backoff_ms, 100);
+        assert_eq!(policy.max_backoff_ms, 30_000);
+        assert!(policy.jitter);
+
+        // classify = 1 retry allows the first attempt plus one more
+        let classify = retry_policy(familiar_config::config_path!(systems.fates_gate.retries.classify), &config);
+        assert_eq!(config.systems.fates_gate.retries.classify, 1);
+        assert_eq!(classify.max_attempts, 2);
+
+        config.systems.fates_gate.retries.search = u32::MAX;
+        let search = retry_policy(familiar_config::config_path!(systems.fates_gate.retries.search), &config);
+        assert_eq!(search.max_attempts, u32::MAX);
+    }
+
     #[test]
     fn test_evaluation_result_serialization() {
         let result = EvaluationResult::with_data(
@@ -29,7 +29,7 @@ use tracing::{error, info};
 
 use familiar_worker::cli::{Cli, Domain};
 use familiar_worker::config::WorkerConfig;
-use familiar_worker::evaluator::{EvaluationResult, EvaluationStep};
+use familiar_worker::evaluator::{EvaluationResult, RejectionCode};
 use familiar_worker::runtime::StepRuntime;
 
 #[tokio::main]
@@ -61,8 +61,8 @@ async fn main() {
     let config = match WorkerConfig::load() {
         Ok(c) => c,
         Err(e) => {
-            let error = EvaluationResult::new(
-                EvaluationStep::Reject,
+            let error = EvaluationResult::reject(
+                RejectionCode::Internal,
                 format!("Configuration error: {}", e),
             );
             eprintln!("{}", serde_json::to_string(&error).unwrap());
@@ -74,8 +74,8 @@ async fn main() {
     let input = match get_input(&cli) {
         Ok(i) => i,
         Err(e) => {
-            let error = EvaluationResult::new(
-                EvaluationStep::Reject,
+            let error = EvaluationResult::reject(
+                RejectionCode::InvalidInput,
                 format!("Input error: {}", e),
             );
             eprintln!("{}", serde_json::to_string(&error).unwrap());
@@ -93,8 +93,8 @@ async fn main() {
             info!("Minerva completed successfully");
         }
         Err(e) => {
-            let error = EvaluationResult::new(
-                EvaluationStep::Reject,
+            let error = EvaluationResult::reject(
+                RejectionCode::Internal,
                 e.to_string(),
             );
             eprintln!("{}", serde_json::to_string(&error).unwrap());
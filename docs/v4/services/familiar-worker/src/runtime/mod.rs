@@ -71,7 +71,8 @@ pub struct SharedResources {
 impl SharedResources {
     /// Create shared resources from configuration
     pub async fn new(config: WorkerConfig) -> Result<Self, RuntimeError> {
-        // Initialize ContractEnforcer (compiles embedded schemas at startup)
+        // Initialize ContractEnforcer (schemas compile on first use, so a
+        // one-shot command only pays for the schemas it validates against)
         let enforcer = Arc::new(ContractEnforcer::new());
         tracing::info!(
             schema_count = enforcer.schema_count(),
@@ -0,0 +1,97 @@
+//! Facet Lint Fixes
+//!
+//! Rewrites schema files for `cargo xtask schemas lint-facets --fix`,
+//! removing `NOOP_FACET` findings. Schemas are re-emitted pretty-printed in
+//! their original key order, which xtask's `serde_json` keeps
+//! (`preserve_order`).
+
+use serde_json::Value;
+
+/// Whether an `x-familiar-*` facet only restates its default, so removing
+/// it changes nothing
+pub fn is_noop_facet(key: &str, val: &Value) -> bool {
+    match key {
+        "x-familiar-rust-derive-policy" => val.as_str() == Some("strict"),
+        "x-familiar-rust-default" => val.as_str() == Some("derived"),
+        "x-familiar-flatten" => val.as_bool() == Some(false),
+        "x-familiar-skip-none" => val.as_bool() == Some(false),
+        "x-familiar-newtype" => val.as_bool() == Some(false),
+        "x-familiar-rust-derive-add" => val.as_array().map(|a| a.is_empty()).unwrap_or(false),
+        "x-familiar-rust-derive-exclude" => val.as_array().map(|a| a.is_empty()).unwrap_or(false),
+        _ => false,
+    }
+}
+
+/// Remove every no-op facet from the schema in `content`.
+///
+/// Returns the rewritten schema, or `None` when there was nothing to remove.
+/// The result is checked to parse back as JSON before it's returned.
+pub fn strip_noop_facets(content: &str) -> anyhow::Result<Option<String>> {
+    let mut schema: Value = serde_json::from_str(content)?;
+    if strip(&mut schema) == 0 {
+        return Ok(None);
+    }
+
+    let mut fixed = serde_json::to_string_pretty(&schema)?;
+    fixed.push('\n');
+    serde_json::from_str::<Value>(&fixed)?;
+    Ok(Some(fixed))
+}
+
+/// Strip no-op facets recursively, returning how many were removed
+fn strip(value: &mut Value) -> usize {
+    match value {
+        Value::Object(members) => {
+            let before = members.len();
+            // `retain` keeps the remaining members in document order
+            members.retain(|key, val| !(key.starts_with("x-familiar-") && is_noop_facet(key, val)));
+            let removed = before - members.len();
+            removed + members.values_mut().map(strip).sum::<usize>()
+        }
+        Value::Array(items) => items.iter_mut().map(strip).sum(),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_noop_facets() {
+        let schema = r#"{
+  "title": "Moment",
+  "type": "object",
+  "x-familiar-flatten": false,
+  "x-familiar-casing": "snake_case",
+  "properties": {
+    "meta": { "$ref": "Meta.schema.json", "x-familiar-flatten": true },
+    "tags": { "type": "array", "x-familiar-rust-derive-add": [] }
+  }
+}"#;
+
+        let fixed = strip_noop_facets(schema).unwrap().unwrap();
+        assert_eq!(fixed, r#"{
+  "title": "Moment",
+  "type": "object",
+  "x-familiar-casing": "snake_case",
+  "properties": {
+    "meta": {
+      "$ref": "Meta.schema.json",
+      "x-familiar-flatten": true
+    },
+    "tags": {
+      "type": "array"
+    }
+  }
+}
+"#);
+        assert!(serde_json::from_str::<serde_json::Value>(&fixed).is_ok());
+    }
+
+    #[test]
+    fn test_clean_schema_is_left_alone() {
+        let schema = r#"{ "title": "Moment", "x-familiar-flatten": true }"#;
+        assert_eq!(strip_noop_facets(schema).unwrap(), None);
+    }
+}
@@ -0,0 +1,480 @@
+//! GraphQL SDL Generator
+//!
+//! Renders [`type_model`](crate::type_model) declarations as one GraphQL
+//! schema document (GraphQL has a single namespace, so a name declared in
+//! more than one directory is qualified by its directory after the first).
+//! Objects become `type`s, or `input`s when the schema is named `*Input`;
+//! string enums become `enum`s keeping their wire values, and `oneOf`s of
+//! objects become `union`s. Scalar primitives map to GraphQL scalars, with
+//! the `uuid` and `date-time` newtypes declared as custom `UUID` and
+//! `DateTime` scalars.
+//!
+//! A wire value that can't be a GraphQL enum value (`null`, `2f-office`) is
+//! renamed, and the rename carries the original in a `@wireValue` directive
+//! so resolvers can map it back.
+//!
+//! GraphQL has no aliases, and inputs can't hold output types or unions. So
+//! other aliases are inlined where they're used, an object referenced from
+//! the other side gets a twin (`FooInput` for an input copy of type `Foo`,
+//! `FooInputType` for an output copy of input `FooInput`), and unions on the
+//! input side, maps, and anything else without a GraphQL shape become the
+//! `JSON` scalar.
+
+use crate::type_model::{to_pascal_case, DeclBody, Field, TypeDecl, TypeExpr};
+use std::collections::{BTreeMap, BTreeSet};
+
+const HEADER: &str = "# Generated by `cargo xtask codegen graphql`. Do not edit.\n";
+
+/// Built-in scalars, and the custom scalars this generator may declare
+const RESERVED: &[&str] = &["String", "Int", "Float", "Boolean", "ID", "UUID", "DateTime", "JSON"];
+
+const CUSTOM_SCALARS: &[(&str, &str)] = &[
+    ("DateTime", "RFC 3339 date-time string"),
+    ("JSON", "Any JSON value"),
+    ("UUID", "UUID string"),
+];
+
+const WIRE_VALUE_DIRECTIVE: &str = "\"Wire value of an enum value renamed to be a valid GraphQL name\"
+directive @wireValue(value: String!) on ENUM_VALUE
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Mode {
+    Output,
+    Input,
+}
+
+impl Mode {
+    /// The side a declaration belongs to by name
+    fn of(decl: &TypeDecl) -> Self {
+        if decl.name.ends_with("Input") {
+            Mode::Input
+        } else {
+            Mode::Output
+        }
+    }
+}
+
+/// Render `decls` as a GraphQL SDL document.
+pub fn generate(decls: &[TypeDecl]) -> String {
+    let mut schema = Schema::new(decls);
+    for decl in decls {
+        schema.named(decl, Mode::of(decl));
+    }
+    schema.render()
+}
+
+type Key<'a> = (&'a str, &'a str);
+
+struct Schema<'a> {
+    decls: BTreeMap<Key<'a>, &'a TypeDecl>,
+    /// GraphQL name of each declaration by `(dir, name)`
+    names: BTreeMap<Key<'a>, String>,
+    /// Every type name in use, declared or hoisted
+    taken: BTreeSet<String>,
+    /// Type a declaration renders to on each side, once rendered
+    rendered: BTreeMap<(Key<'a>, Mode), String>,
+    /// Aliases being inlined, to cut reference cycles
+    inlining: BTreeSet<Key<'a>>,
+    scalars: BTreeSet<&'static str>,
+    /// Whether any enum value was renamed, so `@wireValue` is declared
+    wire_values: bool,
+    definitions: BTreeMap<String, String>,
+}
+
+impl<'a> Schema<'a> {
+    fn new(decls: &'a [TypeDecl]) -> Self {
+        let mut taken: BTreeSet<String> = RESERVED.iter().map(|s| s.to_string()).collect();
+        let mut by_key = BTreeMap::new();
+        let mut names = BTreeMap::new();
+        for decl in decls {
+            let key = (decl.dir.as_str(), decl.name.as_str());
+            if by_key.insert(key, decl).is_some() {
+                continue;
+            }
+            let name = if taken.insert(decl.name.clone()) {
+                decl.name.clone()
+            } else {
+                let qualified = format!("{}{}", to_pascal_case(&decl.dir.replace('/', "_")), decl.name);
+                unique(&mut taken, qualified)
+            };
+            names.insert(key, name);
+        }
+
+        Self {
+            decls: by_key,
+            names,
+            taken,
+            rendered: BTreeMap::new(),
+            inlining: BTreeSet::new(),
+            scalars: BTreeSet::new(),
+            wire_values: false,
+            definitions: BTreeMap::new(),
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut out = String::from(HEADER);
+        for (scalar, description) in CUSTOM_SCALARS {
+            if self.scalars.contains(scalar) {
+                out.push('\n');
+                out.push_str(&description_block(Some(description), ""));
+                out.push_str(&format!("scalar {}\n", scalar));
+            }
+        }
+        if self.wire_values {
+            out.push('\n');
+            out.push_str(WIRE_VALUE_DIRECTIVE);
+        }
+        for definition in self.definitions.values() {
+            out.push('\n');
+            out.push_str(definition);
+        }
+        out
+    }
+
+    /// GraphQL type for a reference to `decl` from `mode`'s side, rendering
+    /// its definition on first use
+    fn named(&mut self, decl: &'a TypeDecl, mode: Mode) -> String {
+        let key = (decl.dir.as_str(), decl.name.as_str());
+        // Enums are the same on both sides
+        let mode = if matches!(decl.body, DeclBody::Enum(_)) { Mode::Output } else { mode };
+        if let Some(name) = self.rendered.get(&(key, mode)) {
+            return name.clone();
+        }
+
+        let name = self.names[&key].clone();
+        let description = decl.description.as_deref();
+        match &decl.body {
+            DeclBody::Enum(values) => {
+                self.rendered.insert((key, mode), name.clone());
+                self.enum_definition(&name, description, values);
+                name
+            }
+            DeclBody::Object(fields) if !fields.is_empty() => {
+                let name = match (mode, Mode::of(decl)) {
+                    (Mode::Input, Mode::Output) => unique(&mut self.taken, format!("{}Input", name)),
+                    (Mode::Output, Mode::Input) => unique(&mut self.taken, format!("{}Type", name)),
+                    _ => name,
+                };
+                self.rendered.insert((key, mode), name.clone());
+                self.object(&name, description, fields, mode);
+                name
+            }
+            DeclBody::Object(_) => self.scalar("JSON"),
+            DeclBody::TaggedUnion { variants, .. } => {
+                let arms: Vec<(&TypeExpr, String)> = variants
+                    .iter()
+                    .map(|v| (&v.ty, format!("{}{}", name, v.name)))
+                    .collect();
+                if mode == Mode::Output && self.union_definition(&name, description, &arms) {
+                    self.rendered.insert((key, mode), name.clone());
+                    name
+                } else {
+                    self.scalar("JSON")
+                }
+            }
+            DeclBody::Alias(ty) => {
+                if let TypeExpr::Union(types) = ty {
+                    let arms: Vec<(&TypeExpr, String)> = types
+                        .iter()
+                        .filter(|t| **t != TypeExpr::Null)
+                        .enumerate()
+                        .map(|(i, t)| (t, format!("{}{}", name, i + 1)))
+                        .collect();
+                    if mode == Mode::Output && arms.len() > 1 && self.union_definition(&name, description, &arms) {
+                        self.rendered.insert((key, mode), name.clone());
+                        return name;
+                    }
+                }
+
+                if !self.inlining.insert(key) {
+                    return self.scalar("JSON");
+                }
+                let inlined = self.base_type(ty, mode, &name);
+                self.inlining.remove(&key);
+                inlined
+            }
+        }
+    }
+
+    fn object(&mut self, name: &str, description: Option<&str>, fields: &[Field], mode: Mode) {
+        let keyword = match mode {
+            Mode::Output => "type",
+            Mode::Input => "input",
+        };
+        let mut body = String::new();
+        let mut field_names = BTreeSet::new();
+        for field in fields {
+            let hint = format!("{}{}", name, to_pascal_case(&field.name));
+            let ty = self.field_type(&field.ty, field.required, mode, &hint);
+            body.push_str(&description_block(field.description.as_deref(), "  "));
+            body.push_str(&format!("  {}: {}\n", unique(&mut field_names, identifier(&field.name)), ty));
+        }
+
+        let definition = format!("{}{} {} {{\n{}}}\n", description_block(description, ""), keyword, name, body);
+        self.definitions.insert(name.to_string(), definition);
+    }
+
+    fn enum_definition(&mut self, name: &str, description: Option<&str>, values: &[String]) {
+        let mut body = String::new();
+        let mut members = BTreeSet::new();
+        for value in values {
+            let member = unique(&mut members, enum_value(value));
+            if member == *value {
+                body.push_str(&format!("  {}\n", member));
+            } else {
+                self.wire_values = true;
+                let quoted = serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value));
+                body.push_str(&format!("  {} @wireValue(value: {})\n", member, quoted));
+            }
+        }
+        let definition = format!("{}enum {} {{\n{}}}\n", description_block(description, ""), name, body);
+        self.definitions.insert(name.to_string(), definition);
+    }
+
+    /// Declare `union name` over `arms` (types with hints for hoisted inline
+    /// objects). Returns false, declaring nothing, unless every arm is an
+    /// object.
+    fn union_definition(&mut self, name: &str, description: Option<&str>, arms: &[(&TypeExpr, String)]) -> bool {
+        if !arms.iter().all(|(ty, _)| self.is_object(ty)) {
+            return false;
+        }
+        let members: Vec<String> = arms
+            .iter()
+            .map(|(ty, hint)| self.base_type(ty, Mode::Output, hint))
+            .collect();
+        let definition = format!(
+            "{}union {} = {}\n",
+            description_block(description, ""),
+            name,
+            members.join(" | ")
+        );
+        self.definitions.insert(name.to_string(), definition);
+        true
+    }
+
+    /// Whether `ty` renders as an object type, so it can be a union member
+    fn is_object(&self, ty: &TypeExpr) -> bool {
+        match ty {
+            TypeExpr::Object(fields) => !fields.is_empty(),
+            TypeExpr::Named { name, dir } => self
+                .decls
+                .get(&(dir.as_str(), name.as_str()))
+                .is_some_and(|decl| matches!(&decl.body, DeclBody::Object(fields) if !fields.is_empty())),
+            _ => false,
+        }
+    }
+
+    /// Whether `null` is accepted, looking through aliases
+    fn is_nullable(&self, ty: &TypeExpr) -> bool {
+        match ty {
+            TypeExpr::Any => true,
+            TypeExpr::Named { name, dir } => self
+                .decls
+                .get(&(dir.as_str(), name.as_str()))
+                .is_some_and(|decl| matches!(&decl.body, DeclBody::Alias(alias) if alias.is_nullable())),
+            _ => ty.is_nullable(),
+        }
+    }
+
+    /// Field (or list item) type: non-null when required and `null` isn't allowed
+    fn field_type(&mut self, ty: &TypeExpr, required: bool, mode: Mode, hint: &str) -> String {
+        let base = self.base_type(ty, mode, hint);
+        if required && !self.is_nullable(ty) {
+            format!("{}!", base)
+        } else {
+            base
+        }
+    }
+
+    /// Nullable GraphQL type for `ty`. Inline enums and objects are hoisted
+    /// into definitions named after `hint`.
+    fn base_type(&mut self, ty: &TypeExpr, mode: Mode, hint: &str) -> String {
+        match ty {
+            TypeExpr::String | TypeExpr::Literal(_) => "String".to_string(),
+            TypeExpr::Integer => "Int".to_string(),
+            TypeExpr::Number => "Float".to_string(),
+            TypeExpr::Boolean => "Boolean".to_string(),
+            TypeExpr::Null | TypeExpr::Any | TypeExpr::Map(_) => self.scalar("JSON"),
+            TypeExpr::Primitive { scalar, format, .. } => match format.as_deref() {
+                Some("uuid") => self.scalar("UUID"),
+                Some("date-time") => self.scalar("DateTime"),
+                _ => self.base_type(scalar, mode, hint),
+            },
+            TypeExpr::Enum(values) => {
+                let name = unique(&mut self.taken, hint.to_string());
+                self.enum_definition(&name, None, values);
+                name
+            }
+            TypeExpr::Named { name, dir } => match self.decls.get(&(dir.as_str(), name.as_str())).copied() {
+                Some(decl) => self.named(decl, mode),
+                None => self.scalar("JSON"),
+            },
+            TypeExpr::Array(inner) => {
+                format!("[{}]", self.field_type(inner, true, mode, &format!("{}Item", hint)))
+            }
+            TypeExpr::Object(fields) if fields.is_empty() => self.scalar("JSON"),
+            TypeExpr::Object(fields) => {
+                let name = unique(&mut self.taken, hint.to_string());
+                self.object(&name, None, fields, mode);
+                name
+            }
+            TypeExpr::Union(types) => {
+                let arms: Vec<&TypeExpr> = types.iter().filter(|t| **t != TypeExpr::Null).collect();
+                if let [only] = arms.as_slice() {
+                    return self.base_type(only, mode, hint);
+                }
+                if mode == Mode::Output && !arms.is_empty() && arms.iter().all(|t| self.is_object(t)) {
+                    let name = unique(&mut self.taken, hint.to_string());
+                    let arms: Vec<(&TypeExpr, String)> = arms
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, t)| (t, format!("{}{}", name, i + 1)))
+                        .collect();
+                    self.union_definition(&name, None, &arms);
+                    return name;
+                }
+                self.scalar("JSON")
+            }
+        }
+    }
+
+    fn scalar(&mut self, name: &'static str) -> String {
+        self.scalars.insert(name);
+        name.to_string()
+    }
+}
+
+/// GraphQL name for a wire name: characters names can't hold become `_`
+fn identifier(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+/// Enum value for a wire value; `true`, `false` and `null` can't be enum values
+fn enum_value(value: &str) -> String {
+    let value = identifier(value);
+    if ["true", "false", "null"].contains(&value.as_str()) {
+        value.to_uppercase()
+    } else {
+        value
+    }
+}
+
+/// `name`, or `name` with the first free numeric suffix
+fn unique(taken: &mut BTreeSet<String>, name: String) -> String {
+    let mut candidate = name.clone();
+    let mut n = 2;
+    while !taken.insert(candidate.clone()) {
+        candidate = format!("{}{}", name, n);
+        n += 1;
+    }
+    candidate
+}
+
+/// Description preceding a definition or field: a string on one line, a
+/// block string otherwise
+fn description_block(description: Option<&str>, indent: &str) -> String {
+    let Some(description) = description.map(str::trim).filter(|d| !d.is_empty()) else {
+        return String::new();
+    };
+    if !description.contains('\n') {
+        let quoted = serde_json::to_string(description).unwrap_or_else(|_| format!("\"{}\"", description));
+        return format!("{}{}\n", indent, quoted);
+    }
+
+    let mut out = format!("{}\"\"\"\n", indent);
+    for line in description.replace("\"\"\"", "\\\"\"\"").lines().map(str::trim_end) {
+        if line.is_empty() {
+            out.push('\n');
+        } else {
+            out.push_str(&format!("{}{}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{}\"\"\"\n", indent));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_model;
+    use std::path::Path;
+
+    fn fixture() -> Vec<TypeDecl> {
+        type_model::load_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/codegen")).unwrap()
+    }
+
+    #[test]
+    fn test_renamed_enum_values_carry_their_wire_value() {
+        let sdl = generate(&fixture());
+        assert_eq!(sdl.matches("directive @wireValue(value: String!) on ENUM_VALUE").count(), 1);
+        assert!(sdl.contains(r#"enum MomentStatus {
+  draft
+  woven
+  _2d @wireValue(value: "2d")
+  NULL @wireValue(value: "null")
+}
+"#));
+        assert!(sdl.contains(r#"  _2f_office @wireValue(value: "2f-office")
+"#));
+    }
+
+    #[test]
+    fn test_one_of_becomes_a_union() {
+        let sdl = generate(&fixture());
+        assert!(sdl.contains(r#""""
+Something that happened to a moment.
+
+Carried on the moments topic.
+"""
+union MomentEvent = MomentEventCaptured | MomentEventDeleted
+
+type MomentEventCaptured {
+  event_type: String!
+  moment: Moment!
+}
+
+type MomentEventDeleted {
+  event_type: String!
+  reason: String
+}
+"#));
+
+        // Inputs can't hold unions
+        assert!(sdl.contains("input CreateMomentInput {\n  tenant_id: UUID!\n  status: MomentStatus\n  place: TypesPlaceInput\n  event: JSON\n}\n"));
+    }
+
+    #[test]
+    fn test_components_are_hoisted() {
+        let sdl = generate(&fixture());
+
+        // Inline objects and enums get their own named types
+        assert!(sdl.contains("  position: MomentPosition\n"));
+        assert!(sdl.contains("type MomentPosition {\n  x: Int!\n  y: Int!\n}\n"));
+        assert!(sdl.contains("type Place {\n  room: PlaceRoom\n}\n"));
+        assert!(sdl.contains("enum PlaceRoom {\n"));
+
+        // A name declared twice is qualified by its directory after the first,
+        // and an output type used by an input gets an input twin
+        assert!(sdl.contains("  place: TypesPlace\n"));
+        assert!(sdl.contains("type TypesPlace {\n  lat: Float!\n  lng: Float!\n}\n"));
+        assert!(sdl.contains("input TypesPlaceInput {\n  lat: Float!\n  lng: Float!\n}\n"));
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(identifier("created_at"), "created_at");
+        assert_eq!(identifier("x-trace-id"), "x_trace_id");
+        assert_eq!(identifier("3d"), "_3d");
+        assert_eq!(enum_value("false"), "FALSE");
+    }
+}
@@ -6,7 +6,7 @@
 //!   cargo xtask schemas drift          Check for drift between Rust types and stored schemas
 //!   cargo xtask schemas sync           Sync generated types to Windmill workspace
 //!   cargo xtask schemas update         Update schema.lock to latest version with integrity hash
-//!   cargo xtask schemas graph          Generate dependency graph visualization
+//!   cargo xtask schemas graph          Generate dependency graph visualization (DOT, Mermaid or JSON)
 //!   cargo xtask schemas validate-graph Validate graph connectivity and extensions
 //!
 //! ## Schema-First Architecture
@@ -31,17 +31,28 @@
 //!
 //! ```
 
+mod facet_fix;
+mod graphql_codegen;
+mod openapi_codegen;
+mod py_codegen;
+mod rust_field_order;
+mod rust_profile;
+mod rust_split;
 mod sea_codegen;
+mod ts_codegen;
+mod type_model;
+mod validator_codegen;
 
 use clap::{Parser, Subcommand};
 #[allow(unused_imports)]
 use petgraph::dot::{Config as DotConfig, Dot};
 use petgraph::graph::DiGraph;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, ExitCode};
 use walkdir::WalkDir;
 
@@ -140,7 +151,7 @@ enum SchemaCommands {
         /// Layout direction: TB (top-bottom), LR (left-right), BT, RL
         #[arg(long, default_value = "LR")]
         layout: String,
-        /// Output format: svg, png, pdf
+        /// Output format: svg, png, pdf (DOT, rendered with --render), mermaid, json
         #[arg(long, default_value = "svg")]
         format: String,
         /// Filter by edge types (comma-separated): type_ref, local_ref, extends, variant_of, union_of, item_type, value_type, field_type, runs_on, uses_queue, requires, reads, writes, connects_to, input, output
@@ -176,6 +187,9 @@ enum SchemaCommands {
         /// Lint a specific schema by ID
         #[arg(long)]
         schema: Option<String>,
+        /// Remove no-op facets (NOOP_FACET) from the schema files
+        #[arg(long)]
+        fix: bool,
     },
 }
 
@@ -192,6 +206,12 @@ enum CodegenCommands {
         /// Output directory (default: familiar-contracts/src)
         #[arg(short, long)]
         output: Option<PathBuf>,
+        /// Split output into generated/ with one module per schema dir or x-familiar-kind (dir, kind)
+        #[arg(long)]
+        split_by: Option<String>,
+        /// Feature-gated derives to keep: minimal (serde only), full, database (serde + sqlx + sea-orm)
+        #[arg(long, default_value = "full")]
+        profile: String,
         /// Verbose output
         #[arg(long)]
         verbose: bool,
@@ -213,12 +233,36 @@ enum CodegenCommands {
         /// Output directory
         #[arg(short, long, default_value = "generated/typescript")]
         output: PathBuf,
+        /// Path to schema registry (default: ../familiar-schemas)
+        #[arg(long)]
+        registry: Option<PathBuf>,
+        /// Direct path to json-schema directory (bypasses registry/lock)
+        #[arg(long)]
+        schema_dir: Option<PathBuf>,
     },
     /// Generate Python/Pydantic models from schemas
     Python {
         /// Output directory
         #[arg(short, long, default_value = "generated/python")]
         output: PathBuf,
+        /// Path to schema registry (default: ../familiar-schemas)
+        #[arg(long)]
+        registry: Option<PathBuf>,
+        /// Direct path to json-schema directory (bypasses registry/lock)
+        #[arg(long)]
+        schema_dir: Option<PathBuf>,
+    },
+    /// Generate GraphQL SDL types from schemas
+    Graphql {
+        /// Output file
+        #[arg(short, long, default_value = "generated/graphql/schema.graphql")]
+        output: PathBuf,
+        /// Path to schema registry (default: ../familiar-schemas)
+        #[arg(long)]
+        registry: Option<PathBuf>,
+        /// Direct path to json-schema directory (bypasses registry/lock)
+        #[arg(long)]
+        schema_dir: Option<PathBuf>,
     },
     /// Generate SeaORM entity files from database schemas
     SeaEntities {
@@ -255,12 +299,24 @@ enum CodegenCommands {
         /// Output file
         #[arg(short, long, default_value = "openapi.yaml")]
         output: PathBuf,
+        /// Path to schema registry (default: ../familiar-schemas)
+        #[arg(long)]
+        registry: Option<PathBuf>,
+        /// Direct path to json-schema directory (bypasses registry/lock)
+        #[arg(long)]
+        schema_dir: Option<PathBuf>,
     },
     /// Generate JSON Schema validators as Rust code
     Validators {
         /// Output file
         #[arg(short, long, default_value = "src/validation/generated.rs")]
         output: PathBuf,
+        /// Path to schema registry (default: ../familiar-schemas)
+        #[arg(long)]
+        registry: Option<PathBuf>,
+        /// Direct path to json-schema directory (bypasses registry/lock)
+        #[arg(long)]
+        schema_dir: Option<PathBuf>,
     },
 }
 
@@ -304,22 +360,25 @@ fn main() -> ExitCode {
             SchemaCommands::ValidateGraph { version, registry } => {
                 schemas_validate_graph(version, registry)
             },
-            SchemaCommands::LintFacets { registry, strict, format, schema } => {
-                schemas_lint_facets(registry, strict, format, schema)
+            SchemaCommands::LintFacets { registry, strict, format, schema, fix } => {
+                schemas_lint_facets(registry, strict, format, schema, fix)
             },
         },
         Commands::Codegen { action } => match action {
-            CodegenCommands::Generate { registry, schema_dir, output, verbose, dry_run } => {
-                codegen_generate(registry, schema_dir, output, verbose, dry_run)
+            CodegenCommands::Generate { registry, schema_dir, output, split_by, profile, verbose, dry_run } => {
+                codegen_generate(registry, schema_dir, output, split_by, profile, verbose, dry_run)
             },
             CodegenCommands::Check { registry, contracts } => {
                 codegen_check(registry, contracts)
             },
-            CodegenCommands::Typescript { output } => {
-                codegen_typescript(output)
+            CodegenCommands::Typescript { output, registry, schema_dir } => {
+                codegen_typescript(output, registry, schema_dir)
             },
-            CodegenCommands::Python { output } => {
-                codegen_python(output)
+            CodegenCommands::Python { output, registry, schema_dir } => {
+                codegen_python(output, registry, schema_dir)
+            },
+            CodegenCommands::Graphql { output, registry, schema_dir } => {
+                codegen_graphql(output, registry, schema_dir)
             },
             CodegenCommands::SeaEntities { output, registry, validate, migrate } => {
                 codegen_sea_entities(output, registry, validate, migrate)
@@ -330,17 +389,18 @@ fn main() -> ExitCode {
             CodegenCommands::ApiHandlers { output } => {
                 codegen_api_handlers(output)
             },
-            CodegenCommands::Openapi { output } => {
-                codegen_openapi(output)
+            CodegenCommands::Openapi { output, registry, schema_dir } => {
+                codegen_openapi(output, registry, schema_dir)
             },
-            CodegenCommands::Validators { output } => {
-                codegen_validators(output)
+            CodegenCommands::Validators { output, registry, schema_dir } => {
+                codegen_validators(output, registry, schema_dir)
             },
         },
     };
     
     match result {
         Ok(()) => ExitCode::SUCCESS,
+        Err(e) if e.is::<AlreadyReported>() => ExitCode::FAILURE,
         Err(e) => {
             eprintln!("Error: {e}");
             ExitCode::FAILURE
@@ -348,6 +408,19 @@ fn main() -> ExitCode {
     }
 }
 
+/// A failure the command has already reported on stdout (e.g. as JSON), so
+/// `main` exits non-zero without printing it again
+#[derive(Debug)]
+struct AlreadyReported;
+
+impl std::fmt::Display for AlreadyReported {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("failure already reported")
+    }
+}
+
+impl std::error::Error for AlreadyReported {}
+
 /// Get the workspace root directory
 fn workspace_root() -> anyhow::Result<PathBuf> {
     let output = Command::new("cargo")
@@ -575,6 +648,10 @@ fn schemas_drift(
     strict: bool,
     verbose: bool,
 ) -> anyhow::Result<()> {
+    if format == "json" {
+        return schemas_drift_json(registry, version, strict);
+    }
+    
     let root = workspace_root()?;
     let registry_path = get_registry_path(registry)?;
     
@@ -620,6 +697,86 @@ fn schemas_drift(
     Ok(())
 }
 
+/// Check familiar-core's manual types against the registry schemas and print
+/// the outcome as a single JSON object, for CI annotations. `strict` also
+/// reports Rust fields the schema doesn't declare.
+///
+/// Nothing but that object reaches stdout, failures included; see
+/// [`write_drift_json`] for its shape.
+fn schemas_drift_json(
+    registry: Option<PathBuf>,
+    version: Option<String>,
+    strict: bool,
+) -> anyhow::Result<()> {
+    let paths = workspace_root().and_then(|root| Ok((root, get_registry_path(registry)?)));
+    let clean = write_drift_json(&mut std::io::stdout().lock(), paths, version, strict)?;
+    if clean {
+        Ok(())
+    } else {
+        Err(AlreadyReported.into())
+    }
+}
+
+/// Write the drift summary for the workspace at `paths` (root, registry) to `out`.
+///
+/// The summary is `{status, drift_count, drift, error}`: `status` is `clean`,
+/// `drift` or `error`, `drift` holds the report's `{type, field, kind, detail}`
+/// entries and `error` explains an `error` status. Returns whether the check
+/// came back clean.
+fn write_drift_json(
+    out: &mut impl std::io::Write,
+    paths: anyhow::Result<(PathBuf, PathBuf)>,
+    version: Option<String>,
+    strict: bool,
+) -> anyhow::Result<bool> {
+    let checked = paths.and_then(|(root, registry_path)| {
+        let version = match version {
+            Some(v) => v,
+            None => parse_lock_file(&root)?.version,
+        };
+        let schemas_dir = registry_path.join("versions").join(&version).join("json-schema");
+        
+        let core_src = root.join("familiar-core/src");
+        let search_dirs: Vec<PathBuf> = ["types", "entities", "components", "primitives"]
+            .iter()
+            .map(|dir| core_src.join(dir))
+            .filter(|dir| dir.exists())
+            .collect();
+        let search_dirs: Vec<&Path> = search_dirs.iter().map(|dir| dir.as_path()).collect();
+        
+        Ok(familiar_drift_internals::check_drift_multi(
+            &schemas_dir,
+            &search_dirs,
+            familiar_drift_internals::MANUAL_TYPES,
+            strict,
+        ))
+    });
+    
+    let summary = match &checked {
+        Ok(Ok(())) => serde_json::json!({
+            "status": "clean",
+            "drift_count": 0,
+            "drift": [],
+            "error": null,
+        }),
+        Ok(Err(report)) => serde_json::json!({
+            "status": "drift",
+            "drift_count": report.errors.len(),
+            "drift": report.to_json(),
+            "error": null,
+        }),
+        Err(e) => serde_json::json!({
+            "status": "error",
+            "drift_count": 0,
+            "drift": [],
+            "error": e.to_string(),
+        }),
+    };
+    writeln!(out, "{}", serde_json::to_string_pretty(&summary)?)?;
+    
+    Ok(matches!(checked, Ok(Ok(()))))
+}
+
 /// Update schema.lock to the latest version with integrity hash
 fn schemas_update(registry: Option<PathBuf>) -> anyhow::Result<()> {
     let root = workspace_root()?;
@@ -833,6 +990,11 @@ fn schemas_graph(
     depth: usize,
     include_defs: bool,
 ) -> anyhow::Result<()> {
+    // Only DOT output goes through graphviz
+    if render && matches!(format.as_str(), "mermaid" | "json") {
+        anyhow::bail!("--render only applies to DOT output (svg, png, pdf), not {}", format);
+    }
+    
     let root = workspace_root()?;
     let registry_path = get_registry_path(None)?;
     
@@ -879,122 +1041,7 @@ fn schemas_graph(
     println!("Layout: {}", layout);
     println!();
     
-    // Collect all schema files
-    let mut schema_files: Vec<PathBuf> = WalkDir::new(&schema_dir)
-        .follow_links(true)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().map_or(false, |ext| ext == "json"))
-        .map(|e| e.path().to_path_buf())
-        .collect();
-    
-    schema_files.sort();
-    
-    println!("Found {} schema files", schema_files.len());
-    
-    // Build data structures for the graph
-    // Map: relative_path -> (display_name, directory)
-    let mut schema_info: HashMap<String, (String, String)> = HashMap::new();
-    // Map: relative_path -> [(dependency, edge_type)]
-    let mut schema_deps: HashMap<String, Vec<(String, EdgeType)>> = HashMap::new();
-    
-    // First pass: collect all schema info
-    for path in &schema_files {
-        let relative = path.strip_prefix(&schema_dir)
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|_| path.file_name().unwrap().to_string_lossy().to_string());
-        
-        // Apply filter if specified
-        if let Some(ref f) = filter {
-            if !relative.starts_with(f) {
-                continue;
-            }
-        }
-        
-        let display_name = relative
-            .replace(".schema.json", "")
-            .replace(".component.json", "")
-            .replace(".node.json", "")
-            .replace(".system.json", "")
-            .replace(".resource.json", "")
-            .replace(".queue.json", "")
-            .split('/')
-            .last()
-            .unwrap_or(&relative)
-            .to_string();
-        
-        let directory = relative
-            .split('/')
-            .next()
-            .unwrap_or("root")
-            .to_string();
-        
-        schema_info.insert(relative.clone(), (display_name.clone(), directory.clone()));
-        
-        // Parse the schema file for typed dependencies
-        let content = fs::read_to_string(path)?;
-        let mut deps: Vec<(String, EdgeType)> = Vec::new();
-        
-        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-            // Extract typed refs from x-familiar-* extensions
-            let typed_refs = extract_typed_refs_for_graph(&json);
-            for (ref_path, edge_type) in typed_refs {
-                let normalized = normalize_ref(&relative, &ref_path);
-                if !normalized.is_empty() {
-                    deps.push((normalized, edge_type));
-                }
-            }
-            
-            // Extract ALL type refs (including composition: allOf, oneOf, anyOf, items, properties)
-            let composition_refs = extract_all_typed_refs_for_graph(&json, &relative, depth, 0);
-            for (target, edge_type) in composition_refs {
-                // Normalize external refs
-                let normalized = if target.contains('#') {
-                    // Local definition reference - keep as-is
-                    target
-                } else {
-                    normalize_ref(&relative, &target)
-                };
-                
-                if !normalized.is_empty() && !deps.iter().any(|(d, _)| d == &normalized) {
-                    deps.push((normalized, edge_type));
-                }
-            }
-            
-            // Add local definitions as separate nodes (if enabled)
-            if include_defs {
-                let local_defs = extract_definitions_for_graph(&json);
-                for def_name in local_defs {
-                    let def_id = format!("{}#{}", relative, def_name);
-                    let def_display = format!("{}#{}", display_name, def_name);
-                    schema_info.insert(def_id.clone(), (def_display, directory.clone()));
-                    
-                    // Extract refs from this definition
-                    let def_schema = json.get("definitions")
-                        .or_else(|| json.get("$defs"))
-                        .and_then(|defs| defs.get(&def_name));
-                    
-                    if let Some(def_schema) = def_schema {
-                        let def_refs = extract_all_typed_refs_for_graph(def_schema, &relative, depth, 0);
-                        let mut def_deps = Vec::new();
-                        for (target, edge_type) in def_refs {
-                            let normalized = if target.contains('#') {
-                                target
-                            } else {
-                                normalize_ref(&relative, &target)
-                            };
-                            if !normalized.is_empty() {
-                                def_deps.push((normalized, edge_type));
-                            }
-                        }
-                        schema_deps.insert(def_id, def_deps);
-                    }
-                }
-            }
-        }
-        
-        schema_deps.insert(relative, deps);
-    }
+    let (schema_info, mut schema_deps) = collect_schema_graph(&schema_dir, filter.as_deref(), depth, include_defs)?;
     
     // Apply edge type filter to deps
     if let Some(ref ef) = edge_filter {
@@ -1063,47 +1110,63 @@ fn schemas_graph(
         }
     }
     
-    // Generate custom DOT output with styling (now with typed edges)
-    let dot_output = generate_styled_dot_typed(
-        &schema_info,
-        &schema_deps,
-        &connected_schemas,
-        connected_only,
-        cluster,
-        &layout,
-    );
-    
-    fs::write(&output, &dot_output)?;
-    println!("✅ Wrote {}", output.display());
-    
-    // Optionally render to image
-    if render {
-        let ext = match format.as_str() {
-            "png" => "png",
-            "pdf" => "pdf",
-            _ => "svg",
-        };
-        let img_path = output.with_extension(ext);
-        println!("Rendering to {}...", img_path.display());
-        
-        let format_arg = format!("-T{}", ext);
-        let status = Command::new("dot")
-            .args([&format_arg, "-o"])
-            .arg(&img_path)
-            .arg(&output)
-            .status();
-        
-        match status {
-            Ok(s) if s.success() => {
-                println!("✅ Rendered {}", img_path.display());
-            }
-            Ok(_) => {
-                println!("⚠ graphviz `dot` command failed");
-            }
-            Err(_) => {
-                println!("⚠ graphviz not installed. Install with:");
-                println!("   brew install graphviz  # macOS");
-                println!("   apt install graphviz   # Ubuntu");
+    match format.as_str() {
+        "mermaid" => {
+            let output = output.with_extension("mmd");
+            let mermaid = generate_mermaid(&schema_info, &schema_deps, &connected_schemas, connected_only, cluster, &layout);
+            fs::write(&output, mermaid)?;
+            println!("✅ Wrote {}", output.display());
+        }
+        "json" => {
+            let output = output.with_extension("json");
+            let json = generate_graph_json(&schema_info, &schema_deps, &connected_schemas, connected_only);
+            fs::write(&output, serde_json::to_string_pretty(&json)?)?;
+            println!("✅ Wrote {}", output.display());
+        }
+        _ => {
+            // Generate custom DOT output with styling (now with typed edges)
+            let dot_output = generate_styled_dot_typed(
+                &schema_info,
+                &schema_deps,
+                &connected_schemas,
+                connected_only,
+                cluster,
+                &layout,
+            );
+
+            fs::write(&output, &dot_output)?;
+            println!("✅ Wrote {}", output.display());
+
+            // Optionally render to image
+            if render {
+                let ext = match format.as_str() {
+                    "png" => "png",
+                    "pdf" => "pdf",
+                    _ => "svg",
+                };
+                let img_path = output.with_extension(ext);
+                println!("Rendering to {}...", img_path.display());
+
+                let format_arg = format!("-T{}", ext);
+                let status = Command::new("dot")
+                    .args([&format_arg, "-o"])
+                    .arg(&img_path)
+                    .arg(&output)
+                    .status();
+
+                match status {
+                    Ok(s) if s.success() => {
+                        println!("✅ Rendered {}", img_path.display());
+                    }
+                    Ok(_) => {
+                        println!("⚠ graphviz `dot` command failed");
+                    }
+                    Err(_) => {
+                        println!("⚠ graphviz not installed. Install with:");
+                        println!("   brew install graphviz  # macOS");
+                        println!("   apt install graphviz   # Ubuntu");
+                    }
+                }
             }
         }
     }
@@ -1116,6 +1179,162 @@ fn schemas_graph(
     Ok(())
 }
 
+/// Schema display info by relative path: (display_name, directory)
+type SchemaInfo = HashMap<String, (String, String)>;
+/// Typed dependencies by relative path
+type SchemaDeps = HashMap<String, Vec<(String, EdgeType)>>;
+
+/// Walk `schema_dir` and collect every schema's display info and typed dependencies
+fn collect_schema_graph(
+    schema_dir: &Path,
+    filter: Option<&str>,
+    depth: usize,
+    include_defs: bool,
+) -> anyhow::Result<(SchemaInfo, SchemaDeps)> {
+    let schema_files = graph_schema_files(schema_dir);
+    println!("Found {} schema files", schema_files.len());
+    
+    // Files are read and parsed in parallel; each yields its own slice of
+    // the graph, merged as they complete
+    schema_files
+        .par_iter()
+        .map(|path| schema_graph_entry(schema_dir, path, filter, depth, include_defs))
+        .try_reduce(|| (HashMap::new(), HashMap::new()), merge_schema_graphs)
+}
+
+/// All schema files under `schema_dir`, sorted
+fn graph_schema_files(schema_dir: &Path) -> Vec<PathBuf> {
+    let mut schema_files: Vec<PathBuf> = WalkDir::new(schema_dir)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().map_or(false, |ext| ext == "json"))
+        .map(|e| e.path().to_path_buf())
+        .collect();
+    
+    schema_files.sort();
+    schema_files
+}
+
+/// Display info and typed dependencies for one schema file, plus its local
+/// definitions when `include_defs` is set. Empty if `filter` excludes it.
+fn schema_graph_entry(
+    schema_dir: &Path,
+    path: &Path,
+    filter: Option<&str>,
+    depth: usize,
+    include_defs: bool,
+) -> anyhow::Result<(SchemaInfo, SchemaDeps)> {
+    let relative = path.strip_prefix(schema_dir)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.file_name().unwrap().to_string_lossy().to_string());
+    
+    let mut schema_info: SchemaInfo = HashMap::new();
+    let mut schema_deps: SchemaDeps = HashMap::new();
+    
+    // Apply filter if specified
+    if let Some(f) = filter {
+        if !relative.starts_with(f) {
+            return Ok((schema_info, schema_deps));
+        }
+    }
+    
+    let display_name = relative
+        .replace(".schema.json", "")
+        .replace(".component.json", "")
+        .replace(".node.json", "")
+        .replace(".system.json", "")
+        .replace(".resource.json", "")
+        .replace(".queue.json", "")
+        .split('/')
+        .last()
+        .unwrap_or(&relative)
+        .to_string();
+    
+    let directory = relative
+        .split('/')
+        .next()
+        .unwrap_or("root")
+        .to_string();
+    
+    schema_info.insert(relative.clone(), (display_name.clone(), directory.clone()));
+    
+    // Parse the schema file for typed dependencies
+    let content = fs::read_to_string(path)?;
+    let mut deps: Vec<(String, EdgeType)> = Vec::new();
+    
+    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+        // Extract typed refs from x-familiar-* extensions
+        let typed_refs = extract_typed_refs_for_graph(&json);
+        for (ref_path, edge_type) in typed_refs {
+            let normalized = normalize_ref(&relative, &ref_path);
+            if !normalized.is_empty() {
+                deps.push((normalized, edge_type));
+            }
+        }
+        
+        // Extract ALL type refs (including composition: allOf, oneOf, anyOf, items, properties)
+        let composition_refs = extract_all_typed_refs_for_graph(&json, &relative, depth, 0);
+        for (target, edge_type) in composition_refs {
+            // Normalize external refs
+            let normalized = if target.contains('#') {
+                // Local definition reference - keep as-is
+                target
+            } else {
+                normalize_ref(&relative, &target)
+            };
+            
+            if !normalized.is_empty() && !deps.iter().any(|(d, _)| d == &normalized) {
+                deps.push((normalized, edge_type));
+            }
+        }
+        
+        // Add local definitions as separate nodes (if enabled)
+        if include_defs {
+            let local_defs = extract_definitions_for_graph(&json);
+            for def_name in local_defs {
+                let def_id = format!("{}#{}", relative, def_name);
+                let def_display = format!("{}#{}", display_name, def_name);
+                schema_info.insert(def_id.clone(), (def_display, directory.clone()));
+                
+                // Extract refs from this definition
+                let def_schema = json.get("definitions")
+                    .or_else(|| json.get("$defs"))
+                    .and_then(|defs| defs.get(&def_name));
+                
+                if let Some(def_schema) = def_schema {
+                    let def_refs = extract_all_typed_refs_for_graph(def_schema, &relative, depth, 0);
+                    let mut def_deps = Vec::new();
+                    for (target, edge_type) in def_refs {
+                        let normalized = if target.contains('#') {
+                            target
+                        } else {
+                            normalize_ref(&relative, &target)
+                        };
+                        if !normalized.is_empty() {
+                            def_deps.push((normalized, edge_type));
+                        }
+                    }
+                    schema_deps.insert(def_id, def_deps);
+                }
+            }
+        }
+    }
+    
+    schema_deps.insert(relative, deps);
+    Ok((schema_info, schema_deps))
+}
+
+/// Combine the graph slices of two disjoint sets of schema files
+fn merge_schema_graphs(
+    (mut schema_info, mut schema_deps): (SchemaInfo, SchemaDeps),
+    (more_info, more_deps): (SchemaInfo, SchemaDeps),
+) -> anyhow::Result<(SchemaInfo, SchemaDeps)> {
+    schema_info.extend(more_info);
+    schema_deps.extend(more_deps);
+    Ok((schema_info, schema_deps))
+}
+
 /// Print detailed graph statistics
 fn print_graph_statistics(
     graph: &DiGraph<String, ()>,
@@ -1592,7 +1811,7 @@ fn generate_styled_dot_typed(
     
     if cluster {
         // Group by directory
-        let mut by_dir: HashMap<&str, Vec<&String>> = HashMap::new();
+        let mut by_dir: std::collections::BTreeMap<&str, Vec<&String>> = std::collections::BTreeMap::new();
         for (relative, (_, dir)) in schema_info {
             if connected_only && !connected_schemas.contains(relative) {
                 continue;
@@ -1600,7 +1819,8 @@ fn generate_styled_dot_typed(
             by_dir.entry(dir.as_str()).or_default().push(relative);
         }
         
-        for (dir, schemas) in by_dir {
+        for (dir, mut schemas) in by_dir {
+            schemas.sort();
             let color = dir_colors.get(dir).unwrap_or(&"#9E9E9E");
             dot.push_str(&format!("  subgraph cluster_{} {{\n", dir.replace('-', "_")));
             dot.push_str(&format!("    label=\"{}\";\n", dir));
@@ -1620,7 +1840,9 @@ fn generate_styled_dot_typed(
         }
     } else {
         // Non-clustered nodes
-        for (relative, (display_name, dir)) in schema_info {
+        let mut nodes: Vec<_> = schema_info.iter().collect();
+        nodes.sort();
+        for (relative, (display_name, dir)) in nodes {
             if connected_only && !connected_schemas.contains(relative) {
                 continue;
             }
@@ -1633,28 +1855,16 @@ fn generate_styled_dot_typed(
     }
     
     // Edges with colors based on type
-    for (relative, deps) in schema_deps {
+    for (relative, dep, edge_type) in graph_edges(schema_info, schema_deps, connected_schemas, connected_only) {
         let from_id = relative.replace('/', "_").replace('.', "_").replace('-', "_");
+        let to_id = dep.replace('/', "_").replace('.', "_").replace('-', "_");
+        let color = edge_type.color();
+        let label = edge_type.label();
         
-        for (dep, edge_type) in deps {
-            if connected_only {
-                if !connected_schemas.contains(relative) || !connected_schemas.contains(dep) {
-                    continue;
-                }
-            }
-            if !schema_info.contains_key(dep) {
-                continue;
-            }
-            
-            let to_id = dep.replace('/', "_").replace('.', "_").replace('-', "_");
-            let color = edge_type.color();
-            let label = edge_type.label();
-            
-            if label.is_empty() {
-                dot.push_str(&format!("  \"{}\" -> \"{}\" [color=\"{}\"];\n", from_id, to_id, color));
-            } else {
-                dot.push_str(&format!("  \"{}\" -> \"{}\" [color=\"{}\", label=\"{}\"];\n", from_id, to_id, color, label));
-            }
+        if label.is_empty() {
+            dot.push_str(&format!("  \"{}\" -> \"{}\" [color=\"{}\"];\n", from_id, to_id, color));
+        } else {
+            dot.push_str(&format!("  \"{}\" -> \"{}\" [color=\"{}\", label=\"{}\"];\n", from_id, to_id, color, label));
         }
     }
     
@@ -1683,6 +1893,132 @@ fn generate_styled_dot_typed(
     dot
 }
 
+/// Mermaid/JSON node id: the relative path with non-identifier characters replaced
+fn graph_node_id(relative: &str) -> String {
+    relative.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect()
+}
+
+/// Edges to draw: (from, to, edge_type), sorted, skipping dangling and filtered-out targets
+fn graph_edges<'a>(
+    schema_info: &SchemaInfo,
+    schema_deps: &'a SchemaDeps,
+    connected_schemas: &std::collections::HashSet<String>,
+    connected_only: bool,
+) -> Vec<(&'a str, &'a str, EdgeType)> {
+    let mut edges: Vec<_> = schema_deps
+        .iter()
+        .flat_map(|(relative, deps)| deps.iter().map(move |(dep, edge_type)| (relative.as_str(), dep.as_str(), *edge_type)))
+        .filter(|(from, to, _)| schema_info.contains_key(*from) && schema_info.contains_key(*to))
+        .filter(|(from, to, _)| !connected_only || (connected_schemas.contains(*from) && connected_schemas.contains(*to)))
+        .collect();
+    edges.sort_by(|a, b| (a.0, a.1, a.2.label()).cmp(&(b.0, b.1, b.2.label())));
+    edges
+}
+
+/// Generate a Mermaid `graph` block with typed edges
+fn generate_mermaid(
+    schema_info: &SchemaInfo,
+    schema_deps: &SchemaDeps,
+    connected_schemas: &std::collections::HashSet<String>,
+    connected_only: bool,
+    cluster: bool,
+    layout: &str,
+) -> String {
+    let mut out = format!("graph {}\n", layout);
+    
+    let mut nodes: Vec<_> = schema_info
+        .iter()
+        .filter(|(relative, _)| !connected_only || connected_schemas.contains(*relative))
+        .collect();
+    nodes.sort();
+    
+    let node_line = |relative: &str, display_name: &str| {
+        format!("{}[\"{}\"]", graph_node_id(relative), display_name.replace('"', "#quot;"))
+    };
+    if cluster {
+        let mut by_dir: std::collections::BTreeMap<&str, Vec<_>> = std::collections::BTreeMap::new();
+        for (relative, (display_name, dir)) in &nodes {
+            by_dir.entry(dir.as_str()).or_default().push((relative.as_str(), display_name.as_str()));
+        }
+        for (dir, schemas) in by_dir {
+            out.push_str(&format!("  subgraph {}\n", dir));
+            for (relative, display_name) in schemas {
+                out.push_str(&format!("    {}\n", node_line(relative, display_name)));
+            }
+            out.push_str("  end\n");
+        }
+    } else {
+        for (relative, (display_name, _)) in &nodes {
+            out.push_str(&format!("  {}\n", node_line(relative, display_name)));
+        }
+    }
+    
+    // Mermaid styles links by their declaration order
+    let edges = graph_edges(schema_info, schema_deps, connected_schemas, connected_only);
+    for (from, to, edge_type) in &edges {
+        out.push_str(&format!("  {} -->|{}| {}\n", graph_node_id(from), edge_type.label(), graph_node_id(to)));
+    }
+    for (i, (_, _, edge_type)) in edges.iter().enumerate() {
+        out.push_str(&format!("  linkStyle {} stroke:{}\n", i, edge_type.color()));
+    }
+    
+    out
+}
+
+/// Schema graph as written by `--format json`, shaped for D3
+#[derive(Debug, Serialize, Deserialize)]
+struct GraphJson {
+    nodes: Vec<GraphJsonNode>,
+    edges: Vec<GraphJsonEdge>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GraphJsonNode {
+    /// Schema path relative to the schema directory
+    id: String,
+    label: String,
+    /// Top-level directory, for grouping
+    group: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GraphJsonEdge {
+    from: String,
+    to: String,
+    /// `EdgeType::label`
+    kind: String,
+}
+
+/// Generate the `{nodes, edges}` JSON graph
+fn generate_graph_json(
+    schema_info: &SchemaInfo,
+    schema_deps: &SchemaDeps,
+    connected_schemas: &std::collections::HashSet<String>,
+    connected_only: bool,
+) -> GraphJson {
+    let mut nodes: Vec<GraphJsonNode> = schema_info
+        .iter()
+        .filter(|(relative, _)| !connected_only || connected_schemas.contains(*relative))
+        .map(|(relative, (display_name, dir))| GraphJsonNode {
+            id: relative.clone(),
+            label: display_name.clone(),
+            group: dir.clone(),
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.id.cmp(&b.id));
+    
+    let edges = graph_edges(schema_info, schema_deps, connected_schemas, connected_only)
+        .into_iter()
+        .map(|(from, to, edge_type)| GraphJsonEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            kind: edge_type.label().to_string(),
+        })
+        .collect();
+    
+    GraphJson { nodes, edges }
+}
+
 /// Validate schema graph connectivity and x-familiar-* extensions
 fn schemas_validate_graph(
     version: Option<String>,
@@ -1747,8 +2083,8 @@ fn schemas_validate_graph(
         total_schemas += 1;
     }
     
-    // Second pass: validate and build edges
-    for entry in WalkDir::new(&schema_dir)
+    // Read and parse every schema in parallel, keeping walk order
+    let schema_files: Vec<PathBuf> = WalkDir::new(&schema_dir)
         .follow_links(true)
         .into_iter()
         .filter_map(|e| e.ok())
@@ -1756,15 +2092,22 @@ fn schemas_validate_graph(
             e.file_type().is_file() && 
             e.path().extension().map(|ext| ext == "json").unwrap_or(false)
         })
-    {
-        let path = entry.path();
-        let relative = path.strip_prefix(&schema_dir)
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|_| path.file_name().unwrap().to_string_lossy().to_string());
-        
-        let content = fs::read_to_string(path)?;
-        let schema: serde_json::Value = serde_json::from_str(&content)?;
-        
+        .map(|e| e.path().to_path_buf())
+        .collect();
+    let parsed = schema_files
+        .par_iter()
+        .map(|path| {
+            let relative = path.strip_prefix(&schema_dir)
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_else(|_| path.file_name().unwrap().to_string_lossy().to_string());
+            let content = fs::read_to_string(path)?;
+            let schema: serde_json::Value = serde_json::from_str(&content)?;
+            Ok((relative, schema))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    
+    // Second pass: validate and build edges
+    for (relative, schema) in parsed {
         // Check for x-familiar-kind
         if schema.get("x-familiar-kind").is_some() {
             schemas_with_kind += 1;
@@ -1921,6 +2264,7 @@ fn schemas_lint_facets(
     strict: bool,
     format: String,
     schema_id: Option<String>,
+    fix: bool,
 ) -> anyhow::Result<()> {
     use regex::Regex;
     use std::collections::HashSet;
@@ -2105,17 +2449,7 @@ fn schemas_lint_facets(
                         }
                         
                         // No-op defaults
-                        let is_noop = match key.as_str() {
-                            "x-familiar-rust-derive-policy" => val.as_str() == Some("strict"),
-                            "x-familiar-rust-default" => val.as_str() == Some("derived"),
-                            "x-familiar-flatten" => val.as_bool() == Some(false),
-                            "x-familiar-skip-none" => val.as_bool() == Some(false),
-                            "x-familiar-newtype" => val.as_bool() == Some(false),
-                            "x-familiar-rust-derive-add" => val.as_array().map(|a| a.is_empty()).unwrap_or(false),
-                            "x-familiar-rust-derive-exclude" => val.as_array().map(|a| a.is_empty()).unwrap_or(false),
-                            _ => false,
-                        };
-                        if is_noop {
+                        if facet_fix::is_noop_facet(key, val) {
                             warnings.push(LintWarning {
                                 schema_id: schema_id.to_string(),
                                 code: "NOOP_FACET",
@@ -2162,6 +2496,34 @@ fn schemas_lint_facets(
         
         total_schemas += 1;
         lint_value(&schema, "", &rel_path, &code_patterns, &orchestration_keywords, &validation_keywords, &allowed_extensions, &mut errors, &mut warnings);
+        
+        for (facet, message) in conflicting_facets(&schema, &rel_path, &schema_dir) {
+            errors.push(LintError {
+                schema_id: rel_path.clone(),
+                code: "CONFLICTING_FACET",
+                message,
+                path: facet,
+            });
+        }
+    }
+    
+    // Remove no-op facets with --fix; otherwise just say which files have them
+    let mut noop_files: Vec<String> = warnings.iter()
+        .filter(|w| w.code == "NOOP_FACET")
+        .map(|w| w.schema_id.clone())
+        .collect();
+    noop_files.sort();
+    noop_files.dedup();
+    let mut fixed_files = Vec::new();
+    if fix {
+        for rel_path in &noop_files {
+            let path = schema_dir.join(rel_path);
+            if let Some(fixed) = facet_fix::strip_noop_facets(&fs::read_to_string(&path)?)? {
+                fs::write(&path, fixed)?;
+                fixed_files.push(rel_path.clone());
+            }
+        }
+        warnings.retain(|w| !(w.code == "NOOP_FACET" && fixed_files.contains(&w.schema_id)));
     }
     
     // Output results
@@ -2169,6 +2531,7 @@ fn schemas_lint_facets(
         println!("{}", serde_json::to_string_pretty(&serde_json::json!({
             "doctrine": "Schemas describe data shape + portable intent; everything executable lives elsewhere.",
             "total_schemas": total_schemas,
+            "fixed_files": fixed_files,
             "total_errors": errors.len(),
             "total_warnings": warnings.len(),
             "errors": errors.iter().map(|e| serde_json::json!({
@@ -2225,6 +2588,17 @@ fn schemas_lint_facets(
             println!();
         }
         
+        if !fixed_files.is_empty() {
+            println!("🔧 Removed no-op facets from {} files:", fixed_files.len());
+            for f in &fixed_files {
+                println!("   {}", f);
+            }
+            println!();
+        } else if !noop_files.is_empty() {
+            println!("🔧 {} files have no-op facets; run with --fix to remove them", noop_files.len());
+            println!();
+        }
+        
         if errors.is_empty() && (!strict || warnings.is_empty()) {
             println!("✅ PASSED - Schema facets are clean");
         } else {
@@ -2241,6 +2615,76 @@ fn schemas_lint_facets(
     Ok(())
 }
 
+/// Top-level `x-familiar-*` facets along a schema's `allOf` chain, as
+/// (schema, facet, value). Inline `allOf` members count as part of the
+/// schema itself; referenced bases are loaded from `schema_dir` and
+/// followed recursively.
+fn inherited_facets(
+    schema: &serde_json::Value,
+    rel_path: &str,
+    schema_dir: &Path,
+    visited: &mut std::collections::HashSet<String>,
+    facets: &mut Vec<(String, String, serde_json::Value)>,
+) {
+    if !visited.insert(rel_path.to_string()) {
+        return;
+    }
+    
+    let Some(obj) = schema.as_object() else { return };
+    for (key, val) in obj {
+        if key.starts_with("x-familiar-") {
+            facets.push((rel_path.to_string(), key.clone(), val.clone()));
+        }
+    }
+    
+    for item in obj.get("allOf").and_then(|a| a.as_array()).into_iter().flatten() {
+        match item.get("$ref").and_then(|r| r.as_str()) {
+            Some(ref_path) => {
+                let base = normalize_ref(rel_path, ref_path);
+                if base.is_empty() {
+                    continue;
+                }
+                // Broken refs are validate-graph's concern
+                let Ok(content) = fs::read_to_string(schema_dir.join(&base)) else { continue };
+                let Ok(base_schema) = serde_json::from_str::<serde_json::Value>(&content) else { continue };
+                inherited_facets(&base_schema, &base, schema_dir, visited, facets);
+            }
+            None => {
+                for (key, val) in item.as_object().into_iter().flatten() {
+                    if key.starts_with("x-familiar-") {
+                        facets.push((rel_path.to_string(), key.clone(), val.clone()));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Facets set to different values at different levels of a schema's `allOf`
+/// chain, as (facet, message)
+fn conflicting_facets(schema: &serde_json::Value, rel_path: &str, schema_dir: &Path) -> Vec<(String, String)> {
+    let mut facets = Vec::new();
+    inherited_facets(schema, rel_path, schema_dir, &mut std::collections::HashSet::new(), &mut facets);
+    
+    let mut by_facet: std::collections::BTreeMap<&str, Vec<(&str, &serde_json::Value)>> = std::collections::BTreeMap::new();
+    for (schema_id, facet, value) in &facets {
+        by_facet.entry(facet.as_str()).or_default().push((schema_id.as_str(), value));
+    }
+    
+    by_facet
+        .into_iter()
+        .filter(|(_, levels)| levels.iter().any(|(_, value)| *value != levels[0].1))
+        .map(|(facet, levels)| {
+            let values: Vec<String> = levels
+                .iter()
+                .map(|(schema_id, value)| format!("{} = {}", schema_id, value))
+                .collect();
+            let message = format!("Facet '{}' conflicts across allOf: {}", facet, values.join(", "));
+            (facet.to_string(), message)
+        })
+        .collect()
+}
+
 // =============================================================================
 // Codegen Commands
 // =============================================================================
@@ -2250,9 +2694,13 @@ fn codegen_generate(
     registry: Option<PathBuf>,
     schema_dir_override: Option<PathBuf>,
     output: Option<PathBuf>,
+    split_by: Option<String>,
+    profile: String,
     verbose: bool,
     dry_run: bool,
 ) -> anyhow::Result<()> {
+    let split_by = split_by.as_deref().map(rust_split::SplitBy::parse).transpose()?;
+    let profile = rust_profile::RenderProfile::parse(&profile)?;
     let root = workspace_root()?;
     
     // If schema_dir is provided directly, use it (bypasses registry/lock)
@@ -2277,6 +2725,8 @@ fn codegen_generate(
     println!("   Schema version: {}", version_info);
     println!("   Schema dir:     {}", schema_dir.display());
     println!("   Output dir:     {}", output_dir.display());
+    if let Some(split_by) = split_by { println!("   Split by:       {:?}", split_by); }
+    println!("   Profile:        {:?}", profile);
     if verbose { println!("   Verbose:        true"); }
     if dry_run { println!("   Dry run:        true"); }
     println!();
@@ -2290,13 +2740,51 @@ fn codegen_generate(
     // Primitives are auto-detected from directory structure (anything in primitives/ directory)
     let result = familiar_schemas::codegen::generate_rust(&schema_dir)
         .map_err(|diags| anyhow::anyhow!("Codegen failed:\n{}", diags))?;
+    let decls = type_model::load_dir(&schema_dir)?;
+    let code = rust_field_order::apply(&profile.apply(&result.code), &rust_field_order::field_orders(&decls));
     
-    // Write output
-    let output_file = output_dir.join("generated.rs");
-    std::fs::write(&output_file, &result.code)?;
+    // Stamp the schema fingerprint so `codegen check` can tell it's current
+    let header = fingerprint_header(&schema_fingerprint(&schema_dir)?);
+    
+    // Write output, removing whichever layout the other mode left behind
+    let single_file = output_dir.join("generated.rs");
+    let module_dir = output_dir.join("generated");
+    let output_file = if let Some(split_by) = split_by {
+        let mut files = rust_split::split(&code, &rust_split::modules(&decls, split_by));
+        if let Some(mod_rs) = files.get_mut("mod.rs") {
+            mod_rs.insert_str(0, &header);
+        }
+        if module_dir.exists() {
+            std::fs::remove_dir_all(&module_dir)?;
+        }
+        std::fs::create_dir_all(&module_dir)?;
+        for (name, contents) in &files {
+            std::fs::write(module_dir.join(name), contents)?;
+        }
+        if single_file.exists() {
+            std::fs::remove_file(&single_file)?;
+        }
+        println!("   📁 Split into {} modules", files.len() - 1);
+        module_dir
+    } else {
+        std::fs::write(&single_file, format!("{}{}", header, code))?;
+        if module_dir.exists() {
+            std::fs::remove_dir_all(&module_dir)?;
+        }
+        single_file
+    };
     
     println!("   📊 Generated {} types", result.type_count);
     
+    // Same-named schemas in different directories share one Rust namespace
+    let collisions = type_model::name_collisions(&decls);
+    if !collisions.is_empty() {
+        println!("   ⚠️  {} type name collisions", collisions.len());
+        for collision in &collisions {
+            println!("      - {}", collision);
+        }
+    }
+    
     if !result.diagnostics.is_empty() {
         println!("   ⚠️  {} warnings", result.diagnostics.warning_count());
         if verbose {
@@ -2317,9 +2805,6 @@ fn codegen_check(
     registry: Option<PathBuf>,
     contracts: Option<PathBuf>,
 ) -> anyhow::Result<()> {
-    use std::collections::HashSet;
-    use sha2::{Sha256, Digest};
-    
     let root = workspace_root()?;
     let registry_path = get_registry_path(registry)?;
     let lock = parse_lock_file(&root)?;
@@ -2329,19 +2814,14 @@ fn codegen_check(
         .join(&lock.version)
         .join("json-schema");
     
-    let contracts_path = contracts.unwrap_or_else(|| root.join("familiar-contracts/src/generated.rs"));
+    // Split output (`--split-by`) carries the fingerprint in generated/mod.rs
+    let contracts_path = contracts.unwrap_or_else(|| {
+        let single_file = root.join("familiar-contracts/src/generated.rs");
+        let module_file = root.join("familiar-contracts/src/generated/mod.rs");
+        if !single_file.exists() && module_file.exists() { module_file } else { single_file }
+    });
     
-    // Compute hash of current schemas
-    let mut schema_hash = Sha256::new();
-    for entry in walkdir::WalkDir::new(&schema_dir)
-        .into_iter()
-        .filter_map(|e| e.ok())
-        .filter(|e| e.path().extension().map(|x| x == "json").unwrap_or(false))
-    {
-        let content = std::fs::read(entry.path())?;
-        schema_hash.update(&content);
-    }
-    let schema_fingerprint = format!("{:x}", schema_hash.finalize());
+    let schema_fingerprint = schema_fingerprint(&schema_dir)?;
     
     // Check if generated file exists and contains the same fingerprint
     if !contracts_path.exists() {
@@ -2350,46 +2830,102 @@ fn codegen_check(
     
     let contracts_content = std::fs::read_to_string(&contracts_path)?;
     
-    // Look for fingerprint comment in generated file
-    // Format: // Schema fingerprint: <hash>
-    if !contracts_content.contains(&format!("// Schema fingerprint: {}", &schema_fingerprint[..16])) {
-        println!("   Schema fingerprint: {}", &schema_fingerprint[..16]);
+    if !has_fingerprint(&contracts_content, &schema_fingerprint) {
+        println!("   Schema fingerprint: {}", schema_fingerprint);
         anyhow::bail!("Contracts are out of date. Run `cargo xtask codegen generate` to update.");
     }
     
-    println!("   ✅ Contracts are up-to-date (fingerprint: {}...)", &schema_fingerprint[..16]);
+    println!("   ✅ Contracts are up-to-date (fingerprint: {}...)", schema_fingerprint);
     Ok(())
 }
 
+/// First 16 hex chars of the SHA-256 over every `.json` file under
+/// `schema_dir`, read in path order so the result doesn't depend on the
+/// filesystem. Shared by `codegen generate` and `codegen check`.
+fn schema_fingerprint(schema_dir: &Path) -> anyhow::Result<String> {
+    use sha2::{Sha256, Digest};
+    
+    let mut schema_hash = Sha256::new();
+    for entry in walkdir::WalkDir::new(schema_dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().extension().map(|x| x == "json").unwrap_or(false))
+    {
+        let content = std::fs::read(entry.path())?;
+        schema_hash.update(&content);
+    }
+    Ok(format!("{:x}", schema_hash.finalize())[..16].to_string())
+}
+
+/// Header line `codegen generate` writes and `codegen check` looks for
+fn fingerprint_header(fingerprint: &str) -> String {
+    format!("// Schema fingerprint: {}\n", fingerprint)
+}
+
+fn has_fingerprint(contracts: &str, fingerprint: &str) -> bool {
+    let header = fingerprint_header(fingerprint);
+    contracts.lines().any(|line| line == header.trim_end())
+}
+
 // ============================================================================
 // NEW CODEGEN TARGETS (TODO: Implement)
 // ============================================================================
 
+/// Resolve the json-schema directory for a codegen target
+///
+/// An explicit `schema_dir` wins; otherwise the registry's version pinned in
+/// schema.lock is used.
+fn codegen_schema_dir(
+    registry: Option<PathBuf>,
+    schema_dir: Option<PathBuf>,
+) -> anyhow::Result<(PathBuf, String)> {
+    if let Some(dir) = schema_dir {
+        return Ok((dir, "direct".to_string()));
+    }
+    let root = workspace_root()?;
+    let registry_path = get_registry_path(registry)?;
+    let lock = parse_lock_file(&root)?;
+    let dir = registry_path
+        .join("versions")
+        .join(&lock.version)
+        .join("json-schema");
+    Ok((dir, lock.version))
+}
+
 /// Generate TypeScript types from schemas
 /// 
-/// Uses ts-rs or custom generator to create TypeScript interfaces
-/// that match the Rust types exactly.
-fn codegen_typescript(output: PathBuf) -> anyhow::Result<()> {
+/// Emits one `.ts` file per schema directory with `export interface` /
+/// `export type` declarations (see `ts_codegen`).
+fn codegen_typescript(
+    output: PathBuf,
+    registry: Option<PathBuf>,
+    schema_dir: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let (schema_dir, version_info) = codegen_schema_dir(registry, schema_dir)?;
+    
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!("📘 Generate TypeScript Types");
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!();
-    println!("   Output: {}", output.display());
+    println!("   Schema version: {}", version_info);
+    println!("   Schema dir:     {}", schema_dir.display());
+    println!("   Output:         {}", output.display());
     println!();
     
-    // TODO: Implement TypeScript generation
-    // Options:
-    // 1. Use ts-rs crate (already in dependencies)
-    // 2. Use typify with TypeScript target
-    // 3. Custom JSON Schema -> TypeScript generator
+    let decls = type_model::load_dir(&schema_dir)?;
+    let files = ts_codegen::generate(&decls);
     
-    println!("⚠️  Not yet implemented");
-    println!();
-    println!("   Planned approach:");
-    println!("   - Read schemas from registry");
-    println!("   - Generate TypeScript interfaces");
-    println!("   - Generate Zod validators");
-    println!("   - Generate fetch client");
+    for (path, contents) in &files {
+        let path = output.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, contents)?;
+    }
+    
+    println!("   📊 Generated {} types in {} files", decls.len(), files.len());
+    println!("   ✅ Wrote TypeScript types to {}", output.display());
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     
     Ok(())
@@ -2397,26 +2933,73 @@ fn codegen_typescript(output: PathBuf) -> anyhow::Result<()> {
 
 /// Generate Python/Pydantic models from schemas
 /// 
-/// Creates Pydantic v2 models that match the Rust types.
-fn codegen_python(output: PathBuf) -> anyhow::Result<()> {
+/// Creates Pydantic v2 models that match the Rust types: one module per
+/// schema directory plus a shared `_base.py` (see `py_codegen`).
+fn codegen_python(
+    output: PathBuf,
+    registry: Option<PathBuf>,
+    schema_dir: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let (schema_dir, version_info) = codegen_schema_dir(registry, schema_dir)?;
+    
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!("🐍 Generate Python/Pydantic Models");
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!();
-    println!("   Output: {}", output.display());
+    println!("   Schema version: {}", version_info);
+    println!("   Schema dir:     {}", schema_dir.display());
+    println!("   Output:         {}", output.display());
     println!();
     
-    // TODO: Implement Pydantic generation
-    // Options:
-    // 1. datamodel-codegen (pip install)
-    // 2. Custom JSON Schema -> Pydantic generator
+    let decls = type_model::load_dir(&schema_dir)?;
+    let files = py_codegen::generate(&decls);
     
-    println!("⚠️  Not yet implemented");
+    fs::create_dir_all(&output)?;
+    for (path, contents) in &files {
+        fs::write(output.join(path), contents)?;
+    }
+    
+    println!("   📊 Generated {} types in {} modules", decls.len(), files.len());
+    println!("   ✅ Wrote Pydantic models to {}", output.display());
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    
+    Ok(())
+}
+
+/// Generate GraphQL SDL types from schemas
+/// 
+/// Emits a single schema document with `type` / `input` / `enum` / `union`
+/// definitions and custom scalars (see `graphql_codegen`).
+fn codegen_graphql(
+    output: PathBuf,
+    registry: Option<PathBuf>,
+    schema_dir: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let (schema_dir, version_info) = codegen_schema_dir(registry, schema_dir)?;
+    
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    println!("🕸️  Generate GraphQL Schema");
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!();
-    println!("   Planned approach:");
-    println!("   - Read schemas from registry");
-    println!("   - Generate Pydantic v2 models");
-    println!("   - Generate httpx client");
+    println!("   Schema version: {}", version_info);
+    println!("   Schema dir:     {}", schema_dir.display());
+    println!("   Output:         {}", output.display());
+    println!();
+    
+    let decls = type_model::load_dir(&schema_dir)?;
+    let sdl = graphql_codegen::generate(&decls);
+    let definitions = sdl
+        .lines()
+        .filter(|line| ["type ", "input ", "enum ", "union ", "scalar "].iter().any(|k| line.starts_with(k)))
+        .count();
+    
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&output, sdl)?;
+    
+    println!("   📊 Generated {} definitions from {} types", definitions, decls.len());
+    println!("   ✅ Wrote GraphQL schema to {}", output.display());
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     
     Ok(())
@@ -2648,26 +3231,38 @@ fn codegen_api_handlers(output: PathBuf) -> anyhow::Result<()> {
 
 /// Generate OpenAPI specification from schemas
 /// 
-/// Creates an OpenAPI 3.1 spec that documents the API.
-fn codegen_openapi(output: PathBuf) -> anyhow::Result<()> {
+/// Creates an OpenAPI 3.1 spec that documents the API: the `action`,
+/// `entities_api` and `contract` schemas as components, and a `POST` path per
+/// `CreateXInput` / `XResponse` pair (see `openapi_codegen`).
+fn codegen_openapi(
+    output: PathBuf,
+    registry: Option<PathBuf>,
+    schema_dir: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let (schema_dir, version_info) = codegen_schema_dir(registry, schema_dir)?;
+    
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!("📖 Generate OpenAPI Specification");
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!();
-    println!("   Output: {}", output.display());
+    println!("   Schema version: {}", version_info);
+    println!("   Schema dir:     {}", schema_dir.display());
+    println!("   Output:         {}", output.display());
     println!();
     
-    // TODO: Implement OpenAPI generation
-    // - Use utoipa or custom generator
-    // - Include all API schemas
-    // - Generate paths from handler patterns
+    let schemas = type_model::read_schemas(&schema_dir, &[".schema.json", ".action.json"])?;
+    let yaml = openapi_codegen::generate(&schemas, &version_info)?;
+    let spec: serde_json::Value = serde_yaml::from_str(&yaml)?;
+    let paths = spec["paths"].as_object().map_or(0, |p| p.len());
+    let components = spec["components"]["schemas"].as_object().map_or(0, |s| s.len());
     
-    println!("⚠️  Not yet implemented");
-    println!();
-    println!("   Planned approach:");
-    println!("   - Collect API schemas");
-    println!("   - Generate OpenAPI 3.1 paths");
-    println!("   - Include $ref to schema components");
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&output, yaml)?;
+    
+    println!("   📊 Generated {} paths, {} component schemas", paths, components);
+    println!("   ✅ Wrote OpenAPI spec to {}", output.display());
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     
     Ok(())
@@ -2675,27 +3270,176 @@ fn codegen_openapi(output: PathBuf) -> anyhow::Result<()> {
 
 /// Generate JSON Schema validators as Rust code
 /// 
-/// Embeds schema validation as compiled Rust code for runtime validation.
-fn codegen_validators(output: PathBuf) -> anyhow::Result<()> {
+/// Embeds schema validation as compiled Rust code for runtime validation:
+/// one `validate_*` function per `action`, `entities_api` and `contract`
+/// schema (see `validator_codegen`).
+fn codegen_validators(
+    output: PathBuf,
+    registry: Option<PathBuf>,
+    schema_dir: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let (schema_dir, version_info) = codegen_schema_dir(registry, schema_dir)?;
+    
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!("✅ Generate Validators");
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     println!();
-    println!("   Output: {}", output.display());
+    println!("   Schema version: {}", version_info);
+    println!("   Schema dir:     {}", schema_dir.display());
+    println!("   Output:         {}", output.display());
     println!();
     
-    // TODO: Implement validator generation
-    // - Use jsonschema crate
-    // - Embed schema as static JSON
-    // - Generate validate_X() functions
+    let schemas = type_model::read_schemas(&schema_dir, &[".schema.json", ".action.json"])?;
+    let code = validator_codegen::generate(&schemas, "familiar_contracts")?;
+    let count = code.matches("\npub fn validate_").count();
     
-    println!("⚠️  Not yet implemented");
-    println!();
-    println!("   Planned approach:");
-    println!("   - For each API input schema:");
-    println!("   - Generate: pub fn validate_create_x(input: &CreateXInput) -> Result<(), ValidationError>");
-    println!("   - Use jsonschema crate for validation");
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&output, code)?;
+    
+    println!("   📊 Generated {} validators ({})", count, validator_codegen::KINDS.join(", "));
+    println!("   ✅ Wrote validators to {}", output.display());
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_graph() -> (SchemaInfo, SchemaDeps) {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/graph");
+        collect_schema_graph(&dir, None, 0, false).unwrap()
+    }
+
+    #[test]
+    fn test_graph_json_round_trips() {
+        let (schema_info, schema_deps) = fixture_graph();
+        let json = generate_graph_json(&schema_info, &schema_deps, &Default::default(), false);
+
+        let parsed: GraphJson = serde_json::from_str(&serde_json::to_string(&json).unwrap()).unwrap();
+        assert_eq!(parsed.nodes.len(), 4);
+        // The ref to the missing UserId schema is dropped
+        assert_eq!(parsed.edges.len(), 4);
+
+        let thread_edges: Vec<_> = parsed.edges.iter()
+            .filter(|e| e.from == "entities/Thread.schema.json")
+            .map(|e| (e.to.as_str(), e.kind.as_str()))
+            .collect();
+        assert_eq!(thread_edges, [
+            ("entities/Moment.schema.json", "extends"),
+            ("primitives/UUID.schema.json", "item"),
+        ]);
+    }
+
+    #[test]
+    fn test_generated_fingerprint_passes_check() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/graph");
+        let dir = std::env::temp_dir().join(format!("xtask-fingerprint-{}", std::process::id()));
+        for entry in walkdir::WalkDir::new(&fixtures).into_iter().filter_map(|e| e.ok()).filter(|e| e.file_type().is_file()) {
+            let target = dir.join(entry.path().strip_prefix(&fixtures).unwrap());
+            fs::create_dir_all(target.parent().unwrap()).unwrap();
+            fs::copy(entry.path(), target).unwrap();
+        }
+
+        // What `codegen generate` writes, as `codegen check` reads it
+        let fingerprint = schema_fingerprint(&dir).unwrap();
+        assert_eq!(fingerprint, schema_fingerprint(&fixtures).unwrap());
+        let generated = format!("{}pub struct Moment;\n", fingerprint_header(&fingerprint));
+        assert!(has_fingerprint(&generated, &schema_fingerprint(&dir).unwrap()));
+
+        fs::write(dir.join("entities/Moment.schema.json"), r#"{ "title": "Moment", "type": "object" }"#).unwrap();
+        let changed = schema_fingerprint(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_ne!(changed, fingerprint);
+        assert!(!has_fingerprint(&generated, &changed));
+    }
+
+    #[test]
+    fn test_parallel_graph_matches_serial() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/graph");
+        let (parallel_info, parallel_deps) = collect_schema_graph(&dir, None, 0, true).unwrap();
+
+        let (serial_info, serial_deps) = graph_schema_files(&dir)
+            .iter()
+            .map(|path| schema_graph_entry(&dir, path, None, 0, true))
+            .try_fold((HashMap::new(), HashMap::new()), |graph, entry| merge_schema_graphs(graph, entry?))
+            .unwrap();
+        assert_eq!(parallel_info, serial_info);
+        assert_eq!(parallel_deps, serial_deps);
+
+        let connected = Default::default();
+        assert_eq!(
+            generate_styled_dot_typed(&parallel_info, &parallel_deps, &connected, false, true, "LR"),
+            generate_styled_dot_typed(&serial_info, &serial_deps, &connected, false, true, "LR"),
+        );
+        assert_eq!(
+            serde_json::to_string(&generate_graph_json(&parallel_info, &parallel_deps, &connected, false)).unwrap(),
+            serde_json::to_string(&generate_graph_json(&serial_info, &serial_deps, &connected, false)).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_conflicting_facets_across_all_of() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/facets");
+        let load = |rel: &str| -> serde_json::Value {
+            serde_json::from_str(&fs::read_to_string(dir.join(rel)).unwrap()).unwrap()
+        };
+
+        let conflicts = conflicting_facets(&load("entities/CamelMoment.schema.json"), "entities/CamelMoment.schema.json", &dir);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].0, "x-familiar-casing");
+        assert_eq!(
+            conflicts[0].1,
+            "Facet 'x-familiar-casing' conflicts across allOf: \
+             entities/CamelMoment.schema.json = \"camelCase\", \
+             entities/Moment.schema.json = \"snake_case\", \
+             base/Entity.schema.json = \"snake_case\""
+        );
+
+        // Restating the inherited value is fine
+        let agreeing = conflicting_facets(&load("entities/Moment.schema.json"), "entities/Moment.schema.json", &dir);
+        assert!(agreeing.is_empty(), "{:?}", agreeing);
+    }
+
+    #[test]
+    fn test_drift_json_is_a_single_object() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/graph");
+        let paths = Ok((root.clone(), root));
+
+        let mut out = Vec::new();
+        let clean = write_drift_json(&mut out, paths, Some("v0.0.0".to_string()), false).unwrap();
+        assert!(!clean);
+
+        // No schemas or Rust types here, so every manual type drifts
+        let summary: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(summary["status"], "drift");
+        assert_eq!(summary["drift_count"], familiar_drift_internals::MANUAL_TYPES.len());
+        assert_eq!(summary["drift"].as_array().unwrap().len(), familiar_drift_internals::MANUAL_TYPES.len());
+    }
+
+    #[test]
+    fn test_drift_json_reports_errors_as_json() {
+        let mut out = Vec::new();
+        let paths = Err(anyhow::anyhow!("no workspace"));
+        assert!(!write_drift_json(&mut out, paths, None, false).unwrap());
+
+        let summary: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(summary["status"], "error");
+        assert_eq!(summary["error"], "no workspace");
+    }
+
+    #[test]
+    fn test_mermaid_output() {
+        let (schema_info, schema_deps) = fixture_graph();
+        let mermaid = generate_mermaid(&schema_info, &schema_deps, &Default::default(), false, false, "LR");
+
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("  entities_Moment_schema_json[\"Moment\"]\n"));
+        assert!(mermaid.contains("  entities_Thread_schema_json -->|extends| entities_Moment_schema_json\n"));
+        assert_eq!(mermaid.matches("-->").count(), 4);
+        assert!(mermaid.contains(&format!("linkStyle 0 stroke:{}", EdgeType::FieldType.color())));
+    }
+}
@@ -0,0 +1,472 @@
+//! OpenAPI Generator
+//!
+//! Builds an OpenAPI 3.1 document from the API-facing schemas
+//! (`x-familiar-kind` of `action`, `entities_api` or `contract`). Each one is
+//! a `components.schemas` entry. Schemas they `$ref` are added alongside
+//! them, and the refs are pointed at those components rather than inlined.
+//! Referenced `definitions` are hoisted into components of their own, since
+//! a component can't be pointed into. An action contributes its
+//! `signature.inputs` object as `<Action>Input`.
+//!
+//! Paths follow the handler convention: each `CreateXInput` with a matching
+//! `XResponse` is served as `POST /api/<xs>`.
+
+use crate::type_model::{apply_casing, declared_name, to_pascal_case};
+use crate::validator_codegen::{
+    action_input, file_stem, resolve, sibling_definition, strip_annotations, DATA_KEYWORDS, KINDS,
+    SCHEMA_MAPS,
+};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Render the OpenAPI document (YAML) for `(relative path, schema)` pairs
+/// from schema registry version `version`.
+pub fn generate(schemas: &[(String, Value)], version: &str) -> anyhow::Result<String> {
+    Ok(serde_yaml::to_string(&document(schemas, version)?)?)
+}
+
+fn document(schemas: &[(String, Value)], version: &str) -> anyhow::Result<Value> {
+    let by_path: BTreeMap<&str, &Value> = schemas.iter().map(|(p, json)| (p.as_str(), json)).collect();
+    let pairs = create_pairs(schemas);
+
+    let mut roots: Vec<&str> = schemas
+        .iter()
+        .filter(|(_, json)| {
+            let kind = json.get("x-familiar-kind").and_then(Value::as_str);
+            kind.is_some_and(|kind| KINDS.contains(&kind))
+        })
+        .map(|(path, _)| path.as_str())
+        .collect();
+    roots.extend(pairs.iter().flat_map(|(_, input, response)| [*input, *response]));
+
+    let mut components = Components {
+        schemas: &by_path,
+        names: BTreeMap::new(),
+        pending: roots.iter().map(|path| path.to_string()).collect(),
+        found: BTreeSet::new(),
+        missing: BTreeSet::new(),
+    };
+
+    // Everything the API schemas reference, transitively
+    while let Some(key) = components.pending.pop() {
+        if components.found.insert(key.clone()) {
+            let mut schema = source_schema(&key, &by_path);
+            components.rewrite(&mut schema, split_key(&key).0);
+        }
+    }
+    if !components.missing.is_empty() {
+        let missing: Vec<String> = std::mem::take(&mut components.missing).into_iter().collect();
+        anyhow::bail!("unresolved $ref to {}", missing.join(", "));
+    }
+
+    // API schemas claim their names first, then shallower paths, then
+    // definitions, so a name declared in several places goes to the most
+    // general schema
+    let mut order: Vec<&str> = components.found.iter().map(String::as_str).collect();
+    order.sort_by_key(|key| {
+        let (path, definition) = split_key(key);
+        (!roots.contains(key), path.matches('/').count(), definition.is_some(), *key)
+    });
+    let mut taken = BTreeSet::new();
+    let mut names = BTreeMap::new();
+    for key in order {
+        // A name already taken falls back to the dot-separated path
+        // ("domains.agentic.WeaveBlock", "entities_api.MomentResponse.Status")
+        let mut name = component_name(key, &by_path);
+        if !taken.insert(name.clone()) {
+            name = qualified_name(key);
+            taken.insert(name.clone());
+        }
+        names.insert(key.to_string(), name);
+    }
+    components.names = names;
+
+    let mut component_schemas = Map::new();
+    for key in components.found.clone() {
+        let mut schema = source_schema(&key, &by_path);
+        components.rewrite(&mut schema, split_key(&key).0);
+        component_schemas.insert(components.names[&key].clone(), schema);
+    }
+
+    let mut paths = Map::new();
+    for (resource, input, response) in pairs {
+        let operation = json!({
+            "operationId": format!("create_{}", apply_casing(&resource, "snake_case")),
+            "summary": format!("Create {}", resource),
+            "requestBody": {
+                "required": true,
+                "content": { "application/json": { "schema": { "$ref": components.ref_to(input) } } },
+            },
+            "responses": {
+                "200": {
+                    "description": format!("Created {}", resource),
+                    "content": { "application/json": { "schema": { "$ref": components.ref_to(response) } } },
+                },
+            },
+        });
+        paths.insert(route(&resource), json!({ "post": operation }));
+    }
+
+    Ok(json!({
+        "openapi": "3.1.0",
+        "info": { "title": "Familiar API", "version": version },
+        "paths": paths,
+        "components": { "schemas": component_schemas },
+    }))
+}
+
+/// Schema a component is built from: a definition, an action's input
+/// object, or the file itself. Components share the document's base URI and
+/// dialect, so `$id` and `$schema` are dropped, and definitions are
+/// components of their own.
+fn source_schema(key: &str, schemas: &BTreeMap<&str, &Value>) -> Value {
+    let (path, definition) = split_key(key);
+    let json = schemas[path];
+    let mut schema = match definition {
+        Some(pointer) => json.pointer(pointer).cloned().unwrap_or_default(),
+        None if is_action(json) => action_input(json),
+        None => json.clone(),
+    };
+    if let Some(object) = schema.as_object_mut() {
+        for keyword in ["$id", "$schema", "definitions", "$defs"] {
+            object.remove(keyword);
+        }
+    }
+    schema
+}
+
+/// A component key is a schema path, or a path and the pointer to one of its
+/// definitions ("a/B.schema.json#/definitions/C")
+fn split_key(key: &str) -> (&str, Option<&str>) {
+    match key.split_once('#') {
+        Some((path, definition)) => (path, Some(definition)),
+        None => (key, None),
+    }
+}
+
+/// Split `fragment` into the (possibly nested) definition it points into and
+/// the pointer within that definition
+fn split_definition(fragment: &str) -> (&str, &str) {
+    let mut end = 0;
+    while let Some(after) = fragment[end..]
+        .strip_prefix("/definitions/")
+        .or_else(|| fragment[end..].strip_prefix("/$defs/"))
+    {
+        end = fragment.len() - after.len() + after.find('/').unwrap_or(after.len());
+    }
+    fragment.split_at(end)
+}
+
+/// Components are collected in one pass over the API schemas and their
+/// references, then named, then rewritten in a second pass.
+struct Components<'a> {
+    schemas: &'a BTreeMap<&'a str, &'a Value>,
+    /// Component name of each component key; empty during collection
+    names: BTreeMap<String, String>,
+    /// Referenced component keys still to visit
+    pending: Vec<String>,
+    /// Every component key (see `split_key`) that becomes a component
+    found: BTreeSet<String>,
+    /// `$ref`s that match no schema
+    missing: BTreeSet<String>,
+}
+
+impl Components<'_> {
+    fn ref_to(&self, key: &str) -> String {
+        format!("#/components/schemas/{}", self.names.get(key).map_or(key, String::as_str))
+    }
+
+    /// Point the `$ref`s of a subschema of `file` at components
+    fn rewrite(&mut self, schema: &mut Value, file: &str) {
+        let Value::Object(map) = schema else {
+            return;
+        };
+        strip_annotations(map);
+
+        // OpenAPI 3.1 schemas are 2020-12, which spells draft-07 tuples
+        // `prefixItems`
+        if let Some(Value::Array(_)) = map.get("items") {
+            let tuple = map.remove("items").unwrap_or_default();
+            map.insert("prefixItems".to_string(), tuple);
+            if let Some(rest) = map.remove("additionalItems") {
+                map.insert("items".to_string(), rest);
+            }
+        }
+
+        if let Some(Value::String(reference)) = map.get_mut("$ref") {
+            if let Some(rewritten) = self.rewrite_ref(reference, file) {
+                *reference = rewritten;
+            }
+        }
+
+        for (key, child) in map.iter_mut() {
+            if DATA_KEYWORDS.contains(&key.as_str()) {
+                continue;
+            }
+            match child {
+                Value::Object(named) if SCHEMA_MAPS.contains(&key.as_str()) => {
+                    for subschema in named.values_mut() {
+                        self.rewrite(subschema, file);
+                    }
+                }
+                Value::Object(_) => self.rewrite(child, file),
+                Value::Array(items) => {
+                    for item in items {
+                        self.rewrite(item, file);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn rewrite_ref(&mut self, reference: &str, file: &str) -> Option<String> {
+        let (path, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+        let target = if path.is_empty() {
+            file.to_string()
+        } else {
+            match resolve(file, path, self.schemas) {
+                Some(target) => target,
+                None => {
+                    self.missing.insert(format!("{} (from {})", reference, file));
+                    return None;
+                }
+            }
+        };
+
+        let (target, fragment) = match self.schemas.get(target.as_str()) {
+            Some(schema) if schema.pointer(fragment).is_none() => {
+                match sibling_definition(&target, fragment).filter(|s| self.schemas.contains_key(s.as_str())) {
+                    Some(sibling) => (sibling, ""),
+                    None => {
+                        self.missing.insert(format!("{} (from {})", reference, file));
+                        return None;
+                    }
+                }
+            }
+            _ => (target, fragment),
+        };
+
+        let (definition, pointer) = split_definition(fragment);
+        let key = if definition.is_empty() { target } else { format!("{}#{}", target, definition) };
+        let rewritten = format!("{}{}", self.ref_to(&key), pointer);
+        if !self.found.contains(&key) {
+            self.pending.push(key);
+        }
+        Some(rewritten)
+    }
+}
+
+fn is_action(json: &Value) -> bool {
+    json.get("x-familiar-kind").and_then(Value::as_str) == Some("action")
+}
+
+/// `MomentWeaveInput` for action `moment.weave`, the definition's own name
+/// for a definition, the declared name otherwise
+fn component_name(key: &str, schemas: &BTreeMap<&str, &Value>) -> String {
+    let (path, definition) = split_key(key);
+    if let Some(definition) = definition {
+        return definition.rsplit('/').next().unwrap_or(definition).to_string();
+    }
+    let json = schemas[path];
+    if !is_action(json) {
+        return declared_name(path, json);
+    }
+    let id = json
+        .get("id")
+        .and_then(Value::as_str)
+        .unwrap_or_else(|| file_stem(path).rsplit('/').next().unwrap_or(path));
+    format!("{}Input", to_pascal_case(&id.replace('.', "_")))
+}
+
+/// Name of a component that lost its own name to another: the schema path
+/// and definition names, dot-separated
+fn qualified_name(key: &str) -> String {
+    let (path, definition) = split_key(key);
+    let mut name = file_stem(path).replace('/', ".");
+    let mut segments = definition.unwrap_or("").split('/').skip(1);
+    // Every other segment is `definitions` or `$defs`
+    while let (Some(_), Some(definition)) = (segments.next(), segments.next()) {
+        name.push('.');
+        name.push_str(definition);
+    }
+    name
+}
+
+/// `(X, CreateXInput path, XResponse path)` for every resource with both.
+/// Where a name is declared more than once the first path wins.
+fn create_pairs(schemas: &[(String, Value)]) -> Vec<(String, &str, &str)> {
+    let mut by_name: BTreeMap<String, &str> = BTreeMap::new();
+    for (path, json) in schemas {
+        by_name.entry(declared_name(path, json)).or_insert(path);
+    }
+
+    by_name
+        .iter()
+        .filter_map(|(name, input)| {
+            let resource = name.strip_prefix("Create")?.strip_suffix("Input")?;
+            let response = by_name.get(&format!("{}Response", resource))?;
+            Some((resource.to_string(), *input, *response))
+        })
+        .collect()
+}
+
+/// Collection route for a resource ("MagicLink" -> "/api/magic-links")
+fn route(resource: &str) -> String {
+    let name = apply_casing(resource, "kebab-case");
+    let plural = if let Some(stem) = name.strip_suffix('y').filter(|s| !s.ends_with(['a', 'e', 'i', 'o', 'u'])) {
+        format!("{}ies", stem)
+    } else if ["s", "x", "z", "ch", "sh"].iter().any(|suffix| name.ends_with(suffix)) {
+        format!("{}es", name)
+    } else {
+        format!("{}s", name)
+    };
+    format!("/api/{}", plural)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Vec<(String, Value)> {
+        vec![
+            ("primitives/UUID.schema.json".to_string(), json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "$id": "primitives/UUID.schema.json",
+                "title": "UUID",
+                "type": "string",
+                "format": "uuid"
+            })),
+            ("primitives/UserId.schema.json".to_string(), json!({ "title": "UserId", "type": "string" })),
+            ("entities_api/CreateMomentInput.schema.json".to_string(), json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "title": "CreateMomentInput",
+                "x-familiar-kind": "entities_api",
+                "x-familiar-service": { "$ref": "../infrastructure/nodes/api.node.json" },
+                "type": "object",
+                "required": ["tenant_id"],
+                "properties": {
+                    "tenant_id": { "$ref": "../primitives/UUID.schema.json" },
+                    "status": { "$ref": "#/definitions/Status" },
+                    "author_id": { "$ref": "../primitives/UUID.schema.json#/definitions/UserId" }
+                },
+                "definitions": { "Status": { "type": "string", "enum": ["draft", "woven"] } }
+            })),
+            ("entities_api/MomentResponse.schema.json".to_string(), json!({
+                "title": "MomentResponse",
+                "x-familiar-kind": "entities_api",
+                "type": "object",
+                "properties": {
+                    "id": { "$ref": "../primitives/UUID.schema.json" },
+                    "position": { "type": "array", "items": [{ "type": "number" }, { "type": "number" }] },
+                    "status": { "$ref": "#/definitions/Status" },
+                    "weight": { "$ref": "#/definitions/Scores/definitions/Weight/properties/value" }
+                },
+                "definitions": {
+                    "Status": { "type": "string", "enum": ["active", "archived"] },
+                    "Scores": {
+                        "definitions": {
+                            "Weight": { "type": "object", "properties": { "value": { "type": "number" } } }
+                        }
+                    }
+                }
+            })),
+            ("domains/agentic/UUID.schema.json".to_string(), json!({ "title": "UUID", "type": "string" })),
+            ("actions/moment/weave.action.json".to_string(), json!({
+                "id": "moment.weave",
+                "x-familiar-kind": "action",
+                "signature": {
+                    "inputs": {
+                        "moment": { "schema": { "$ref": "../../entities_api/CreateMomentInput.schema.json" } },
+                        "agent": { "schema": { "$ref": "../../domains/agentic/UUID.schema.json" } }
+                    }
+                }
+            })),
+        ]
+    }
+
+    #[test]
+    fn test_openapi_document() {
+        let yaml = generate(&fixture(), "v1.2.0").unwrap();
+        let doc: Value = serde_yaml::from_str(&yaml).unwrap();
+
+        assert_eq!(doc["openapi"], "3.1.0");
+        assert_eq!(doc["info"]["version"], "v1.2.0");
+
+        let schemas = &doc["components"]["schemas"];
+        let input = &schemas["CreateMomentInput"];
+        assert_eq!(input["properties"]["tenant_id"]["$ref"], "#/components/schemas/UUID");
+        assert_eq!(input["properties"]["status"]["$ref"], "#/components/schemas/Status");
+        assert_eq!(schemas["Status"], json!({ "type": "string", "enum": ["draft", "woven"] }));
+        assert!(input.get("definitions").is_none());
+        assert_eq!(input["properties"]["author_id"]["$ref"], "#/components/schemas/UserId");
+        assert!(input.get("$schema").is_none());
+        assert!(input.get("x-familiar-service").is_none());
+        assert!(schemas["UUID"].get("$id").is_none());
+        let position = &schemas["MomentResponse"]["properties"]["position"];
+        assert_eq!(position["prefixItems"], json!([{ "type": "number" }, { "type": "number" }]));
+        assert!(position.get("items").is_none());
+
+        // Hoisted definitions: a second `Status` under its path, and a ref
+        // into a nested definition pointing into its component
+        let response = &schemas["MomentResponse"];
+        assert_eq!(response["properties"]["status"]["$ref"], "#/components/schemas/entities_api.MomentResponse.Status");
+        assert_eq!(schemas["entities_api.MomentResponse.Status"]["enum"], json!(["active", "archived"]));
+        assert_eq!(response["properties"]["weight"]["$ref"], "#/components/schemas/Weight/properties/value");
+        assert_eq!(schemas["Weight"]["properties"]["value"], json!({ "type": "number" }));
+        assert!(schemas.get("Scores").is_none());
+        assert!(response.get("definitions").is_none());
+
+        // The action's input object, with the second UUID under its path
+        let action = &schemas["MomentWeaveInput"];
+        assert_eq!(action["properties"]["moment"]["$ref"], "#/components/schemas/CreateMomentInput");
+        assert_eq!(action["properties"]["agent"]["$ref"], "#/components/schemas/domains.agentic.UUID");
+        assert_eq!(action["required"], json!(["moment", "agent"]));
+
+        let post = &doc["paths"]["/api/moments"]["post"];
+        assert_eq!(post["operationId"], "create_moment");
+        assert_eq!(
+            post["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/CreateMomentInput"
+        );
+        assert_eq!(
+            post["responses"]["200"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/MomentResponse"
+        );
+    }
+
+    #[test]
+    fn test_unresolved_ref() {
+        let schemas = vec![("contracts/Envelope.schema.json".to_string(), json!({
+            "title": "Envelope",
+            "x-familiar-kind": "contract",
+            "properties": { "trace": { "$ref": "../primitives/Trace.schema.json" } }
+        }))];
+
+        let err = generate(&schemas, "direct").unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "unresolved $ref to ../primitives/Trace.schema.json (from contracts/Envelope.schema.json)"
+        );
+    }
+
+    #[test]
+    fn test_component_keys() {
+        assert_eq!(split_definition("/definitions/A"), ("/definitions/A", ""));
+        assert_eq!(split_definition("/$defs/A/definitions/B/properties/c"), ("/$defs/A/definitions/B", "/properties/c"));
+        assert_eq!(split_definition("/properties/c"), ("", "/properties/c"));
+        assert_eq!(split_definition(""), ("", ""));
+        assert_eq!(qualified_name("a/B.schema.json#/definitions/C/$defs/D"), "a.B.C.D");
+        assert_eq!(qualified_name("a/B.schema.json"), "a.B");
+    }
+
+    #[test]
+    fn test_route() {
+        assert_eq!(route("Moment"), "/api/moments");
+        assert_eq!(route("Entity"), "/api/entities");
+        assert_eq!(route("MagicLink"), "/api/magic-links");
+        assert_eq!(route("JoinRequest"), "/api/join-requests");
+        assert_eq!(route("Gateway"), "/api/gateways");
+        assert_eq!(route("Address"), "/api/addresses");
+    }
+}
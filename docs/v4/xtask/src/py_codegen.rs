@@ -0,0 +1,561 @@
+//! Python/Pydantic Code Generator
+//!
+//! Renders [`type_model`](crate::type_model) declarations as Pydantic v2
+//! models, one module per schema directory (`domains/agentic` becomes
+//! `domains_agentic.py`). Objects become `FamiliarModel` subclasses, string
+//! enums `(str, Enum)` classes, and tagged unions `RootModel`s over one class
+//! per variant, discriminated with `Field(discriminator=...)`.
+//!
+//! Schema directories can reference each other in cycles (`types` and
+//! `components` do), so every reference lives in an annotation under
+//! `from __future__ import annotations`, and sibling modules are imported at
+//! the bottom as `from . import x as _x`. Cross-module names are undefined
+//! while the classes are built, so Pydantic defers them to first use.
+
+use crate::type_model::{apply_casing, to_pascal_case, DeclBody, Field, TypeDecl, TypeExpr};
+use std::collections::{BTreeMap, BTreeSet};
+
+const HEADER: &str = "# Generated by `cargo xtask codegen python`. Do not edit.\n";
+
+/// Shared base model and constrained primitives, written as `_base.py`
+const BASE_MODULE: &str = r#"
+from typing import Annotated
+
+from pydantic import BaseModel, ConfigDict, Field
+
+NormalizedFloat = Annotated[float, Field(ge=0.0, le=1.0)]
+"""A float in the closed range 0..1"""
+
+
+class FamiliarModel(BaseModel):
+    """Base for generated models
+
+    Fields are Python attribute names aliased to their `x-familiar-casing`
+    wire names. Either is accepted on input; output uses the wire names.
+    """
+
+    model_config = ConfigDict(
+        validate_by_name=True,
+        validate_by_alias=True,
+        serialize_by_alias=True,
+        use_attribute_docstrings=True,
+    )
+"#;
+
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+    "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+    "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+    "try", "while", "with", "yield",
+];
+
+/// `BaseModel` attributes a field must not shadow
+const RESERVED_ATTRS: &[&str] = &["construct", "copy", "dict", "json", "model_config", "schema", "validate"];
+
+/// Render `decls` into `(file path, contents)` pairs: `__init__.py`,
+/// `_base.py`, and one module per directory.
+pub fn generate(decls: &[TypeDecl]) -> BTreeMap<String, String> {
+    let mut by_dir: BTreeMap<&str, Vec<&TypeDecl>> = BTreeMap::new();
+    for decl in decls {
+        by_dir.entry(decl.dir.as_str()).or_default().push(decl);
+    }
+
+    let mut files = BTreeMap::new();
+    let mut init = format!("{}\n", HEADER);
+    for (dir, decls) in by_dir {
+        init.push_str(&format!("from . import {}\n", module_name(dir)));
+        files.insert(format!("{}.py", module_name(dir)), Module::new(dir, &decls).render(&decls));
+    }
+    files.insert("__init__.py".to_string(), init);
+    files.insert("_base.py".to_string(), format!("{}{}", HEADER, BASE_MODULE));
+    files
+}
+
+/// Rendering state for one module: its classes and the imports they need
+struct Module<'a> {
+    dir: &'a str,
+    /// Class names taken in this module, so hoisted classes don't collide
+    names: BTreeSet<String>,
+    classes: Vec<String>,
+    typing: BTreeSet<&'static str>,
+    pydantic: BTreeSet<&'static str>,
+    base: BTreeSet<&'static str>,
+    siblings: BTreeSet<String>,
+    uses_enum: bool,
+}
+
+impl<'a> Module<'a> {
+    fn new(dir: &'a str, decls: &[&TypeDecl]) -> Self {
+        Self {
+            dir,
+            names: decls.iter().map(|d| d.name.clone()).collect(),
+            classes: Vec::new(),
+            typing: BTreeSet::new(),
+            pydantic: BTreeSet::new(),
+            base: BTreeSet::new(),
+            siblings: BTreeSet::new(),
+            uses_enum: false,
+        }
+    }
+
+    fn render(mut self, decls: &[&TypeDecl]) -> String {
+        for decl in decls {
+            self.decl(decl);
+        }
+
+        let mut out = format!("{}\nfrom __future__ import annotations\n", HEADER);
+
+        let mut stdlib = Vec::new();
+        if self.uses_enum {
+            stdlib.push("from enum import Enum".to_string());
+        }
+        if !self.typing.is_empty() {
+            stdlib.push(import_line("typing", &self.typing));
+        }
+        let third_party: Vec<String> = (!self.pydantic.is_empty())
+            .then(|| import_line("pydantic", &self.pydantic))
+            .into_iter()
+            .collect();
+        let local: Vec<String> = (!self.base.is_empty())
+            .then(|| import_line("._base", &self.base))
+            .into_iter()
+            .collect();
+
+        for group in [stdlib, third_party, local] {
+            if !group.is_empty() {
+                out.push('\n');
+                out.push_str(&group.join("\n"));
+                out.push('\n');
+            }
+        }
+        for class in &self.classes {
+            out.push_str("\n\n");
+            out.push_str(class);
+        }
+
+        if !self.siblings.is_empty() {
+            out.push_str("\n\n# Imported last so import cycles between modules resolve\n");
+            for module in &self.siblings {
+                out.push_str(&format!("from . import {} as _{}  # noqa: E402\n", module, module));
+            }
+        }
+        out
+    }
+
+    fn decl(&mut self, decl: &TypeDecl) {
+        let description = decl.description.as_deref();
+        match &decl.body {
+            DeclBody::Object(fields) => self.model(&decl.name, description, fields),
+            DeclBody::Enum(values) => {
+                self.uses_enum = true;
+                let mut class = format!("class {}(str, Enum):\n", decl.name);
+                push_docstring(&mut class, description);
+                let mut members = BTreeSet::new();
+                for value in values {
+                    let member = unique(&mut members, member_name(value));
+                    class.push_str(&format!("    {} = {}\n", member, quote(value)));
+                }
+                self.classes.push(class);
+            }
+            DeclBody::TaggedUnion { discriminator, variants } => {
+                // Discriminated only when every arm is an inline object pinning the tag
+                let discriminated = variants.iter().all(|v| match &v.ty {
+                    TypeExpr::Object(fields) => v.tag.is_some()
+                        && fields.iter().any(|f| &f.name == discriminator && f.required),
+                    _ => false,
+                });
+
+                let members: Vec<String> = variants
+                    .iter()
+                    .map(|v| self.py_type(&v.ty, &format!("{}{}", decl.name, v.name)))
+                    .collect();
+                let mut root = self.union(members);
+                if discriminated {
+                    self.typing.insert("Annotated");
+                    self.pydantic.insert("Field");
+                    root = format!(
+                        "Annotated[{}, Field(discriminator={})]",
+                        root,
+                        quote(&attr_name(discriminator))
+                    );
+                }
+                self.root_model(&decl.name, description, root);
+            }
+            DeclBody::Alias(ty) => {
+                let root = self.py_type(ty, &decl.name);
+                self.root_model(&decl.name, description, root);
+            }
+        }
+    }
+
+    fn model(&mut self, name: &str, description: Option<&str>, fields: &[Field]) {
+        let mut body = String::new();
+        let mut attrs = BTreeSet::new();
+        for field in fields {
+            let hint = format!("{}{}", name, to_pascal_case(&field.name));
+            let mut ty = self.py_type(&field.ty, &hint);
+            if !field.required && !field.ty.is_nullable() {
+                self.typing.insert("Optional");
+                ty = format!("Optional[{}]", ty);
+            }
+
+            let attr = unique(&mut attrs, attr_name(&field.name));
+            let default = match (attr == field.name, field.required) {
+                (true, true) => String::new(),
+                (true, false) => " = None".to_string(),
+                (false, required) => {
+                    self.pydantic.insert("Field");
+                    let default = if required { "" } else { "default=None, " };
+                    format!(" = Field({}alias={})", default, quote(&field.name))
+                }
+            };
+            body.push_str(&format!("    {}: {}{}\n", attr, ty, default));
+            if let Some(description) = field.description.as_deref().filter(|d| !d.trim().is_empty()) {
+                body.push_str(&docstring(description, "    "));
+            }
+        }
+
+        self.base.insert("FamiliarModel");
+        let mut class = format!("class {}(FamiliarModel):\n", name);
+        match description.filter(|d| !d.trim().is_empty()) {
+            Some(description) if body.is_empty() => class.push_str(&docstring(description, "    ")),
+            Some(_) => push_docstring(&mut class, description),
+            None if body.is_empty() => class.push_str("    pass\n"),
+            None => {}
+        }
+        class.push_str(&body);
+        self.classes.push(class);
+    }
+
+    fn root_model(&mut self, name: &str, description: Option<&str>, root: String) {
+        self.pydantic.insert("RootModel");
+        let mut class = format!("class {}(RootModel):\n", name);
+        push_docstring(&mut class, description);
+        class.push_str(&format!("    root: {}\n", root));
+        self.classes.push(class);
+    }
+
+    /// Python annotation for `ty`. Inline objects are hoisted into classes
+    /// named after `hint`.
+    fn py_type(&mut self, ty: &TypeExpr, hint: &str) -> String {
+        match ty {
+            TypeExpr::String => "str".to_string(),
+            TypeExpr::Integer => "int".to_string(),
+            TypeExpr::Number => "float".to_string(),
+            TypeExpr::Boolean => "bool".to_string(),
+            TypeExpr::Null => "None".to_string(),
+            TypeExpr::Any => {
+                self.typing.insert("Any");
+                "Any".to_string()
+            }
+            TypeExpr::Literal(value) => {
+                self.typing.insert("Literal");
+                format!("Literal[{}]", quote(value))
+            }
+            TypeExpr::Enum(values) => {
+                self.typing.insert("Literal");
+                let values: Vec<String> = values.iter().map(|v| quote(v)).collect();
+                format!("Literal[{}]", values.join(", "))
+            }
+            TypeExpr::Primitive { name, scalar, .. } => match name.as_str() {
+                "NormalizedFloat" => {
+                    self.base.insert("NormalizedFloat");
+                    "NormalizedFloat".to_string()
+                }
+                "QuantizedCoord" => "int".to_string(),
+                _ => self.py_type(scalar, hint),
+            },
+            TypeExpr::Named { name, dir } if dir == self.dir => name.clone(),
+            TypeExpr::Named { name, dir } => {
+                let module = module_name(dir);
+                let reference = format!("_{}.{}", module, name);
+                self.siblings.insert(module);
+                reference
+            }
+            TypeExpr::Array(inner) => format!("list[{}]", self.py_type(inner, &format!("{}Item", hint))),
+            TypeExpr::Map(values) => format!("dict[str, {}]", self.py_type(values, &format!("{}Value", hint))),
+            TypeExpr::Union(types) => {
+                let members: Vec<String> = types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, ty)| self.py_type(ty, &format!("{}{}", hint, i + 1)))
+                    .collect();
+                self.union(members)
+            }
+            TypeExpr::Object(fields) if fields.is_empty() => {
+                self.typing.insert("Any");
+                "dict[str, Any]".to_string()
+            }
+            TypeExpr::Object(fields) => {
+                let name = unique(&mut self.names, hint.to_string());
+                self.model(&name, None, fields);
+                name
+            }
+        }
+    }
+
+    /// `Union[...]` of rendered members, with `None` folded into `Optional[...]`
+    fn union(&mut self, members: Vec<String>) -> String {
+        let nullable = members.iter().any(|m| m == "None");
+        let members: Vec<String> = members.into_iter().filter(|m| m != "None").collect();
+        let inner = match members.as_slice() {
+            [] => return "None".to_string(),
+            [only] => only.clone(),
+            _ => {
+                self.typing.insert("Union");
+                format!("Union[{}]", members.join(", "))
+            }
+        };
+        if nullable {
+            self.typing.insert("Optional");
+            format!("Optional[{}]", inner)
+        } else {
+            inner
+        }
+    }
+}
+
+fn import_line(module: &str, names: &BTreeSet<&str>) -> String {
+    let names: Vec<&str> = names.iter().copied().collect();
+    format!("from {} import {}", module, names.join(", "))
+}
+
+/// Module name for a schema directory
+fn module_name(dir: &str) -> String {
+    if dir.is_empty() {
+        return "root".to_string();
+    }
+    identifier(&dir.replace('/', "_"), "module")
+}
+
+/// Python attribute name for a wire field name
+fn attr_name(wire: &str) -> String {
+    let name = identifier(&apply_casing(wire, "snake_case"), "field");
+    if RESERVED_ATTRS.contains(&name.as_str()) {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+/// Enum member name for a wire value
+fn member_name(value: &str) -> String {
+    identifier(&apply_casing(value, "SCREAMING_SNAKE_CASE"), "VALUE")
+}
+
+/// Replace characters Python identifiers can't hold, and step around
+/// keywords and leading underscores (private to Pydantic and `Enum`)
+fn identifier(name: &str, fallback: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let name = name.trim_start_matches('_');
+    if name.is_empty() {
+        fallback.to_string()
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("{}_{}", fallback, name)
+    } else if KEYWORDS.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// `name`, or `name` with the first free numeric suffix
+fn unique(taken: &mut BTreeSet<String>, name: String) -> String {
+    let mut candidate = name.clone();
+    let mut n = 2;
+    while !taken.insert(candidate.clone()) {
+        candidate = format!("{}{}", name, n);
+        n += 1;
+    }
+    candidate
+}
+
+fn push_docstring(class: &mut String, description: Option<&str>) {
+    if let Some(description) = description.filter(|d| !d.trim().is_empty()) {
+        class.push_str(&docstring(description, "    "));
+        class.push('\n');
+    }
+}
+
+fn docstring(description: &str, indent: &str) -> String {
+    let escaped = description.trim().replace('\\', "\\\\").replace("\"\"\"", "\\\"\\\"\\\"");
+    let escaped = match escaped.strip_suffix('"') {
+        Some(rest) => format!("{}\\\"", rest),
+        None => escaped,
+    };
+    let lines: Vec<&str> = escaped.lines().map(str::trim_end).collect();
+    if let [line] = lines.as_slice() {
+        return format!("{}\"\"\"{}\"\"\"\n", indent, line);
+    }
+
+    let mut out = format!("{}\"\"\"{}\n", indent, lines[0]);
+    for line in &lines[1..] {
+        if line.is_empty() {
+            out.push('\n');
+        } else {
+            out.push_str(&format!("{}{}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{}\"\"\"\n", indent));
+    out
+}
+
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_model;
+    use std::path::Path;
+
+    fn fixture() -> Vec<TypeDecl> {
+        type_model::load_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/codegen")).unwrap()
+    }
+
+    #[test]
+    fn test_python_snapshot() {
+        let files = generate(&fixture());
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec!["__init__.py", "_base.py", "domains_agentic.py", "entities.py", "entities_api.py", "types.py"]
+        );
+
+        assert_eq!(files["__init__.py"], r#"# Generated by `cargo xtask codegen python`. Do not edit.
+
+from . import domains_agentic
+from . import entities
+from . import entities_api
+from . import types
+"#);
+
+        assert_eq!(files["domains_agentic.py"], r#"# Generated by `cargo xtask codegen python`. Do not edit.
+
+from __future__ import annotations
+
+from typing import Literal, Optional
+
+from ._base import FamiliarModel
+
+
+class Place(FamiliarModel):
+    room: Optional[Literal["kitchen", "2f-office"]] = None
+"#);
+
+        assert_eq!(files["entities.py"], r#"# Generated by `cargo xtask codegen python`. Do not edit.
+
+from __future__ import annotations
+
+from typing import Optional
+
+from pydantic import Field
+
+from ._base import FamiliarModel, NormalizedFloat
+
+
+class MomentPosition(FamiliarModel):
+    x: int
+    y: int
+
+
+class Moment(FamiliarModel):
+    """A captured moment"""
+
+    id: str
+    author: Optional[str] = None
+    created_at: str = Field(alias="createdAt")
+    """When it was captured"""
+    weight: Optional[NormalizedFloat] = None
+    status: _types.MomentStatus
+    place: Optional[_types.Place] = None
+    position: Optional[MomentPosition] = None
+    tags: Optional[list[str]] = None
+    metadata: Optional[dict[str, str]] = None
+
+
+# Imported last so import cycles between modules resolve
+from . import types as _types  # noqa: E402
+"#);
+
+        assert_eq!(files["entities_api.py"], r#"# Generated by `cargo xtask codegen python`. Do not edit.
+
+from __future__ import annotations
+
+from typing import Optional
+
+from ._base import FamiliarModel
+
+
+class CreateMomentInput(FamiliarModel):
+    tenant_id: str
+    status: Optional[_types.MomentStatus] = None
+    place: Optional[_types.Place] = None
+    event: Optional[_types.MomentEvent] = None
+
+
+# Imported last so import cycles between modules resolve
+from . import types as _types  # noqa: E402
+"#);
+
+        assert_eq!(files["types.py"], r#"# Generated by `cargo xtask codegen python`. Do not edit.
+
+from __future__ import annotations
+
+from enum import Enum
+from typing import Annotated, Literal, Optional, Union
+
+from pydantic import Field, RootModel
+
+from ._base import FamiliarModel
+
+
+class MomentEventCaptured(FamiliarModel):
+    event_type: Literal["created"]
+    moment: _entities.Moment
+
+
+class MomentEventDeleted(FamiliarModel):
+    event_type: Literal["deleted"]
+    reason: Optional[str] = None
+
+
+class MomentEvent(RootModel):
+    """Something that happened to a moment.
+
+    Carried on the moments topic.
+    """
+
+    root: Annotated[Union[MomentEventCaptured, MomentEventDeleted], Field(discriminator="event_type")]
+
+
+class MomentStatus(str, Enum):
+    DRAFT = "draft"
+    WOVEN = "woven"
+    VALUE_2D = "2d"
+    NULL = "null"
+
+
+class Place(FamiliarModel):
+    lat: float
+    lng: float
+
+
+# Imported last so import cycles between modules resolve
+from . import entities as _entities  # noqa: E402
+"#);
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(attr_name("createdAt"), "created_at");
+        assert_eq!(attr_name("from"), "from_");
+        assert_eq!(attr_name("$schema"), "schema_");
+        assert_eq!(attr_name("json"), "json_");
+        assert_eq!(member_name("text/plain"), "TEXT_PLAIN");
+        assert_eq!(module_name("domains/agentic"), "domains_agentic");
+    }
+}
@@ -0,0 +1,183 @@
+//! Field Order for Rust Contracts
+//!
+//! `generate_rust` comes from familiar-schemas and lays struct fields out in
+//! its own order. This pass moves the fields of every generated struct into
+//! the order the type model gives them (`x-familiar-field-order` first, then
+//! schema declaration order), matching the other codegen targets.
+
+use crate::rust_split::Scanner;
+use crate::type_model::{apply_casing, DeclBody, TypeDecl};
+use std::collections::HashMap;
+
+/// Wire field names of each declared object type, in type-model order. When
+/// two schemas declare the same name, the first one wins.
+pub fn field_orders(decls: &[TypeDecl]) -> HashMap<String, Vec<String>> {
+    let mut orders = HashMap::new();
+    for decl in decls {
+        if let DeclBody::Object(fields) = &decl.body {
+            orders
+                .entry(decl.name.clone())
+                .or_insert_with(|| fields.iter().map(|f| f.name.clone()).collect());
+        }
+    }
+    orders
+}
+
+/// `code` with the fields of each `pub struct` listed in `orders` reordered.
+/// Fields the order doesn't name keep their relative order after the rest;
+/// everything outside struct bodies is untouched.
+pub fn apply(code: &str, orders: &HashMap<String, Vec<String>>) -> String {
+    let mut out = String::with_capacity(code.len());
+    let mut lines = code.lines();
+    while let Some(line) = lines.next() {
+        out.push_str(line);
+        out.push('\n');
+
+        let Some(order) = struct_name(line).and_then(|name| orders.get(name)) else {
+            continue;
+        };
+        let mut fields: Vec<String> = Vec::new();
+        let mut pending = String::new();
+        let mut depth = 0;
+        let mut scanner = Scanner::default();
+        for line in lines.by_ref() {
+            let trimmed = line.trim();
+            if depth == 0 && pending.is_empty() && trimmed == "}" {
+                for field in sorted(fields, order) {
+                    out.push_str(&field);
+                }
+                out.push_str(line);
+                out.push('\n');
+                break;
+            }
+            pending.push_str(line);
+            pending.push('\n');
+            depth += scanner.nesting(line);
+            let is_attr = trimmed.starts_with("#[") || trimmed.starts_with("//");
+            if depth == 0 && !is_attr && trimmed.ends_with(',') {
+                fields.push(std::mem::take(&mut pending));
+            }
+        }
+    }
+    out
+}
+
+/// Name of the struct a `pub struct Name {` line opens
+fn struct_name(line: &str) -> Option<&str> {
+    let rest = line.trim().strip_prefix("pub struct ")?;
+    if !rest.ends_with('{') {
+        return None;
+    }
+    let end = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
+/// Fields in `order`, matched on their serde name or snake_cased identifier
+fn sorted(fields: Vec<String>, order: &[String]) -> Vec<String> {
+    let mut keyed: Vec<(usize, String)> = fields
+        .into_iter()
+        .map(|field| {
+            let position = order.iter().position(|name| names_field(name, &field));
+            (position.unwrap_or(order.len()), field)
+        })
+        .collect();
+    // Stable, so unmatched fields keep their generated order
+    keyed.sort_by_key(|(position, _)| *position);
+    keyed.into_iter().map(|(_, field)| field).collect()
+}
+
+/// Whether the wire name `name` refers to the generated `field`
+fn names_field(name: &str, field: &str) -> bool {
+    let renamed = field.lines().find_map(|line| {
+        let rest = &line[line.find("rename = \"")? + 10..];
+        Some(&rest[..rest.find('"')?])
+    });
+    if let Some(renamed) = renamed {
+        return renamed == name;
+    }
+    field_ident(field).is_some_and(|ident| ident == name || ident == apply_casing(name, "snake_case"))
+}
+
+/// Identifier a field declaration (`pub r#type: String,`) binds
+fn field_ident(field: &str) -> Option<&str> {
+    let line = field.lines().map(str::trim).find(|l| !l.starts_with("#[") && !l.starts_with("//"))?;
+    let line = line.strip_prefix("pub ").unwrap_or(line);
+    let ident = line[..line.find(':')?].trim();
+    Some(ident.strip_prefix("r#").unwrap_or(ident))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_model;
+    use serde_json::json;
+
+    /// Shaped like `generate_rust` output: alphabetical fields, serde renames
+    const GENERATED: &str = r#"#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Moment {
+    pub body: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    /// Unique id
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+}
+
+impl Moment {
+    pub fn builder() -> builder::Moment {
+        Default::default()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Unlisted {
+    pub b: String,
+    pub a: String,
+}
+"#;
+
+    fn decls() -> Vec<TypeDecl> {
+        type_model::from_schemas(&[("entities/Moment.schema.json".to_string(), json!({
+            "title": "Moment",
+            "type": "object",
+            "x-familiar-field-order": ["id", "createdAt"],
+            "properties": {
+                "weight": { "type": "number" },
+                "createdAt": { "type": "string" },
+                "body": { "type": "string" },
+                "id": { "type": "string" }
+            }
+        }))])
+    }
+
+    #[test]
+    fn test_generated_struct_follows_field_order() {
+        let ordered = apply(GENERATED, &field_orders(&decls()));
+        assert!(ordered.contains(r#"pub struct Moment {
+    /// Unique id
+    pub id: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    pub body: String,
+}
+"#));
+
+        // Structs without a declared order and non-struct items are untouched
+        assert!(ordered.contains("pub struct Unlisted {\n    pub b: String,\n    pub a: String,\n}\n"));
+        assert!(ordered.contains("impl Moment {\n    pub fn builder() -> builder::Moment {\n        Default::default()\n    }\n}\n"));
+        assert_eq!(ordered.len(), GENERATED.len());
+    }
+
+    #[test]
+    fn test_field_names() {
+        assert!(names_field("createdAt", "    #[serde(rename = \"createdAt\")]\n    pub created_at: String,\n"));
+        assert!(!names_field("created_at", "    #[serde(rename = \"createdAt\")]\n    pub created_at: String,\n"));
+        assert!(names_field("type", "    pub r#type: String,\n"));
+        assert!(names_field("tenantId", "    pub tenant_id: String,\n"));
+        assert_eq!(struct_name("pub struct Moment {"), Some("Moment"));
+        assert_eq!(struct_name("pub struct Uuid(pub String);"), None);
+    }
+}
@@ -0,0 +1,106 @@
+//! Render Profiles for Rust Contracts
+//!
+//! Controls which feature-gated derives the generated contracts keep. Every
+//! `#[cfg_attr(feature = "...", ...)]` line whose feature is outside the
+//! profile is dropped, so a wasm frontend can build the contracts with serde
+//! alone while services keep the database derives.
+
+/// Preset selected by `codegen generate --profile`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderProfile {
+    /// serde only: no feature-gated derives
+    Minimal,
+    /// Every feature-gated derive
+    Full,
+    /// serde plus the sqlx and sea-orm derives
+    Database,
+}
+
+impl RenderProfile {
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "minimal" => Ok(Self::Minimal),
+            "full" => Ok(Self::Full),
+            "database" => Ok(Self::Database),
+            other => anyhow::bail!("Unknown --profile '{}' (expected minimal, full or database)", other),
+        }
+    }
+
+    /// Whether `cfg_attr` lines gated on `feature` are kept
+    pub fn keeps(&self, feature: &str) -> bool {
+        match self {
+            Self::Minimal => false,
+            Self::Full => true,
+            Self::Database => matches!(feature, "sqlx" | "sea-orm"),
+        }
+    }
+
+    /// `code` without the `#[cfg_attr(feature = "...", ...)]` lines this
+    /// profile leaves out. Other attributes are untouched.
+    pub fn apply(&self, code: &str) -> String {
+        if *self == Self::Full {
+            return code.to_string();
+        }
+        let mut out = String::with_capacity(code.len());
+        for line in code.lines() {
+            if gated_feature(line).is_some_and(|feature| !self.keeps(feature)) {
+                continue;
+            }
+            out.push_str(line);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Feature a single-line `#[cfg_attr(feature = "x", ...)]` is gated on
+fn gated_feature(line: &str) -> Option<&str> {
+    let rest = line.trim().strip_prefix("#[cfg_attr(")?.trim_start();
+    let rest = rest.strip_prefix("feature")?.trim_start().strip_prefix('=')?.trim_start();
+    let rest = rest.strip_prefix('"')?;
+    Some(&rest[..rest.find('"')?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODE: &str = r#"use serde::{Deserialize, Serialize};
+
+/// A moment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS))]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "sea-orm", derive(sea_orm::FromQueryResult))]
+#[serde(rename_all = "camelCase")]
+pub struct Moment {
+    #[cfg_attr(feature = "sqlx", sqlx(rename = "moment_id"))]
+    pub id: String,
+}
+"#;
+
+    #[test]
+    fn test_minimal_drops_feature_gated_derives() {
+        let minimal = RenderProfile::Minimal.apply(CODE);
+        assert!(!minimal.contains("sqlx"));
+        assert!(!minimal.contains("cfg_attr"));
+        assert!(minimal.contains("#[derive(Debug, Clone, Serialize, Deserialize)]\n#[serde(rename_all = \"camelCase\")]"));
+        assert!(minimal.contains("pub id: String,"));
+
+        let full = RenderProfile::Full.apply(CODE);
+        assert_eq!(full, CODE);
+        assert!(full.contains("#[cfg_attr(feature = \"sqlx\", derive(sqlx::FromRow))]"));
+    }
+
+    #[test]
+    fn test_database_keeps_sqlx_and_sea_orm() {
+        let database = RenderProfile::Database.apply(CODE);
+        assert!(database.contains("derive(sqlx::FromRow)"));
+        assert!(database.contains("sqlx(rename = \"moment_id\")"));
+        assert!(database.contains("derive(sea_orm::FromQueryResult)"));
+        assert!(!database.contains("ts_rs"));
+
+        assert_eq!(RenderProfile::parse("database").unwrap(), RenderProfile::Database);
+        assert!(RenderProfile::parse("wasm").is_err());
+    }
+}
@@ -0,0 +1,508 @@
+//! Split Rust Contracts by Module
+//!
+//! Splits the single `generated.rs` produced by `codegen generate` into one
+//! module file per schema directory or per `x-familiar-kind`, plus a `mod.rs`
+//! that declares and glob re-exports them. Written as `generated/`, the split
+//! output is a drop-in replacement for `generated.rs` behind `mod generated;`.
+//!
+//! Every module glob-imports its parent, so a type can name one from any other
+//! module regardless of file order, just as it could within the single file.
+//! Items are assigned by name: a type goes where its schema declared it, an
+//! `impl` follows its self type, and anything else lands in `common`.
+
+use crate::type_model::TypeDecl;
+use std::collections::{BTreeMap, HashMap};
+
+/// `mod.rs` opener; also marks the output as generated
+const HEADER: &str = "// Generated by `cargo xtask codegen generate`. Do not edit.\n";
+
+/// Module for items no schema declares (helpers, shared impls)
+const COMMON: &str = "common";
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+    "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
+    "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try",
+    "type", "union", "unsafe", "use", "where", "while", "yield",
+];
+
+/// How `codegen generate --split-by` groups types into modules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitBy {
+    /// One module per schema directory (`domains/agentic` -> `domains_agentic`)
+    Dir,
+    /// One module per `x-familiar-kind`
+    Kind,
+}
+
+impl SplitBy {
+    pub fn parse(value: &str) -> anyhow::Result<Self> {
+        match value {
+            "dir" => Ok(Self::Dir),
+            "kind" => Ok(Self::Kind),
+            other => anyhow::bail!("Unknown --split-by '{}' (expected dir or kind)", other),
+        }
+    }
+}
+
+/// Module for each declared type name. When two schemas declare the same
+/// name, the first one wins, as in the single file.
+pub fn modules(decls: &[TypeDecl], split_by: SplitBy) -> HashMap<String, String> {
+    let mut modules = HashMap::new();
+    for decl in decls {
+        let module = match split_by {
+            SplitBy::Dir if decl.dir.is_empty() => "root".to_string(),
+            SplitBy::Dir => module_name(&decl.dir),
+            SplitBy::Kind => decl.kind.as_deref().map(module_name).unwrap_or_else(|| COMMON.to_string()),
+        };
+        modules.entry(decl.name.clone()).or_insert(module);
+    }
+    modules
+}
+
+/// Split single-file contracts into `(file name, contents)` pairs, one per
+/// module plus `mod.rs`. `modules` maps type names to module names.
+///
+/// The file's leading comments, inner attributes and `use` declarations are
+/// repeated at the top of every module so each compiles on its own terms.
+pub fn split(code: &str, modules: &HashMap<String, String>) -> BTreeMap<String, String> {
+    let (prelude, items) = parse_items(code);
+
+    let mut by_module: BTreeMap<String, Vec<&Item>> = BTreeMap::new();
+    for item in &items {
+        let module = item.name.as_ref()
+            .and_then(|name| modules.get(name))
+            .map(String::as_str)
+            .unwrap_or(COMMON);
+        by_module.entry(module.to_string()).or_default().push(item);
+    }
+
+    let mut files = BTreeMap::new();
+    let mut mod_rs = String::from(HEADER);
+    mod_rs.push('\n');
+    for module in by_module.keys() {
+        mod_rs.push_str(&format!("pub mod {};\n", module));
+    }
+    mod_rs.push('\n');
+    for module in by_module.keys() {
+        mod_rs.push_str(&format!("pub use {}::*;\n", module));
+    }
+
+    for (module, items) in by_module {
+        let mut out = String::from("#![allow(unused_imports)]\n\n");
+        out.push_str(&prelude);
+        out.push_str("use super::*;\n");
+        for item in items {
+            out.push('\n');
+            out.push_str(&item.text);
+        }
+        files.insert(format!("{}.rs", module), out);
+    }
+    files.insert("mod.rs".to_string(), mod_rs);
+    files
+}
+
+/// A top-level item with its doc comments and attributes
+#[derive(Debug)]
+struct Item {
+    /// Declared name, or the self type of an `impl`
+    name: Option<String>,
+    text: String,
+}
+
+/// Separate the prelude (leading comments, inner attributes and every `use`)
+/// from the top-level items, tracking nesting so that braces, brackets and
+/// parentheses inside an item don't end it early.
+fn parse_items(code: &str) -> (String, Vec<Item>) {
+    let mut prelude = String::new();
+    let mut items = Vec::new();
+    let mut pending = String::new();
+    let mut head: Option<String> = None;
+    let mut depth = 0i32;
+    let mut scanner = Scanner::default();
+
+    for line in code.lines() {
+        let trimmed = line.trim();
+
+        if depth == 0 && head.is_none() {
+            let is_prelude = trimmed.starts_with("#![")
+                || trimmed.starts_with("//!")
+                || trimmed.starts_with("use ")
+                || trimmed.starts_with("pub use ");
+            let is_leading = items.is_empty()
+                && pending.is_empty()
+                && (trimmed.is_empty() || (is_comment(trimmed) && !trimmed.starts_with("///")));
+            if is_prelude || is_leading {
+                let extra_blank = trimmed.is_empty() && (prelude.is_empty() || prelude.ends_with("\n\n"));
+                if !extra_blank {
+                    prelude.push_str(line);
+                    prelude.push('\n');
+                }
+                depth += scanner.nesting(line);
+                continue;
+            }
+            if trimmed.is_empty() {
+                continue;
+            }
+        }
+
+        pending.push_str(line);
+        pending.push('\n');
+        let opens_item = depth == 0 && head.is_none() && !trimmed.starts_with("#[") && !is_comment(trimmed);
+        depth += scanner.nesting(line);
+        if opens_item {
+            head = Some(trimmed.to_string());
+        }
+
+        if depth == 0 && head.is_some() && (trimmed.ends_with('}') || trimmed.ends_with(';')) {
+            let name = head.take().and_then(|h| item_name(&h));
+            items.push(Item { name, text: std::mem::take(&mut pending) });
+        }
+    }
+
+    if !pending.trim().is_empty() {
+        let name = head.and_then(|h| item_name(&h));
+        items.push(Item { name, text: pending });
+    }
+    if !prelude.is_empty() && !prelude.ends_with("\n\n") {
+        prelude.push('\n');
+    }
+    (prelude, items)
+}
+
+fn is_comment(line: &str) -> bool {
+    line.starts_with("//") && !line.starts_with("//!")
+}
+
+/// Tracks string and block-comment state across lines
+#[derive(Default)]
+pub struct Scanner {
+    /// Inside a string; `Some(n)` for a raw string closed by `"` and `n` hashes
+    string: Option<Option<usize>>,
+    block_comment: usize,
+}
+
+impl Scanner {
+    /// Net change in `{[(` nesting over `line`, ignoring strings and comments
+    pub fn nesting(&mut self, line: &str) -> i32 {
+        let chars: Vec<char> = line.chars().collect();
+        let mut delta = 0;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).copied();
+
+            if self.block_comment > 0 {
+                if c == '*' && next == Some('/') {
+                    self.block_comment -= 1;
+                    i += 1;
+                } else if c == '/' && next == Some('*') {
+                    self.block_comment += 1;
+                    i += 1;
+                }
+            } else if let Some(raw) = self.string {
+                match raw {
+                    None if c == '\\' => i += 1,
+                    None if c == '"' => self.string = None,
+                    Some(hashes) if c == '"' && chars[i + 1..].iter().take_while(|h| **h == '#').count() >= hashes => {
+                        self.string = None;
+                        i += hashes;
+                    }
+                    _ => {}
+                }
+            } else {
+                match c {
+                    '/' if next == Some('/') => break,
+                    '/' if next == Some('*') => {
+                        self.block_comment += 1;
+                        i += 1;
+                    }
+                    '"' => self.string = Some(None),
+                    'r' if !chars[..i].last().is_some_and(|p| p.is_alphanumeric() || *p == '_') => {
+                        let hashes = chars[i + 1..].iter().take_while(|h| **h == '#').count();
+                        if chars.get(i + 1 + hashes) == Some(&'"') {
+                            self.string = Some(Some(hashes));
+                            i += 1 + hashes;
+                        }
+                    }
+                    // A char literal, not a lifetime
+                    '\'' if next == Some('\\') => {
+                        i += chars[i + 2..].iter().position(|q| *q == '\'').map_or(0, |p| p + 2);
+                    }
+                    '\'' if chars.get(i + 2) == Some(&'\'') => i += 2,
+                    '{' | '[' | '(' => delta += 1,
+                    '}' | ']' | ')' => delta -= 1,
+                    _ => {}
+                }
+            }
+            i += 1;
+        }
+        delta
+    }
+}
+
+/// Name an item declares, or the self type of an `impl`
+fn item_name(head: &str) -> Option<String> {
+    let mut rest = head;
+    if let Some(after) = rest.strip_prefix("pub") {
+        rest = after.trim_start();
+        if rest.starts_with('(') {
+            rest = &rest[rest.find(')')? + 1..];
+        }
+    }
+    let mut words = rest.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        match word {
+            "unsafe" | "async" | "extern" | "\"C\"" | "default" => continue,
+            "struct" | "enum" | "type" | "trait" | "union" | "mod" | "fn" | "const" | "static" => {
+                let name = words.next()?;
+                return Some(leading_ident(name.trim_start_matches("mut ")).to_string())
+                    .filter(|name| !name.is_empty());
+            }
+            w if w == "impl" || w.starts_with("impl<") => return impl_self_type(rest),
+            _ => return None,
+        }
+    }
+    None
+}
+
+/// `impl<T> Trait<T> for path::Foo<T> {` -> `Foo`
+fn impl_self_type(head: &str) -> Option<String> {
+    let rest = head[head.find("impl")? + 4..].trim_start();
+    let rest = if rest.starts_with('<') { skip_generics(rest)? } else { rest };
+    let rest = rest.trim_start().trim_end_matches('{').trim_end();
+    let rest = rest.split(" where ").next().unwrap_or(rest);
+    let self_type = match rest.rfind(" for ") {
+        Some(at) => &rest[at + 5..],
+        None => rest,
+    };
+    let path = self_type.trim().trim_start_matches('&').split('<').next()?;
+    let name = path.rsplit("::").next()?.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// The text after a balanced `<...>`
+fn skip_generics(s: &str) -> Option<&str> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&s[i + 1..]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn leading_ident(s: &str) -> &str {
+    let end = s.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(s.len());
+    &s[..end]
+}
+
+/// Rust module name for a directory or kind
+fn module_name(group: &str) -> String {
+    let name: String = group
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    let name = name.trim_matches('_');
+    if name.is_empty() {
+        COMMON.to_string()
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("m_{}", name)
+    } else if KEYWORDS.contains(&name) || name == "mod" {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_model;
+    use serde_json::json;
+    use std::collections::BTreeSet;
+    use std::process::Command;
+
+    const SINGLE_FILE: &str = r#"//! Generated contracts
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+/// A moment, with a `{` in its docs
+#[derive(Debug, Clone, PartialEq)]
+pub struct Moment {
+    pub id: Uuid,
+    pub thread: Option<Thread>,
+    pub tags: HashMap<String, String>,
+}
+
+impl Moment {
+    pub fn label(&self) -> &'static str {
+        "moment {"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thread {
+    pub moments: Vec<Moment>,
+    pub status: ThreadStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThreadStatus {
+    Open,
+    Woven,
+}
+
+impl Default for ThreadStatus {
+    fn default() -> Self {
+        Self::Open
+    }
+}
+
+pub type Uuid = String;
+
+pub const SCHEMA_VERSION: &str = "v1";
+
+impl<T> From<T> for crate::generated::Thread
+where
+    T: Into<Vec<Moment>>,
+{
+    fn from(moments: T) -> Self {
+        Thread { moments: moments.into(), status: ThreadStatus::default() }
+    }
+}
+"#;
+
+    fn decls() -> Vec<TypeDecl> {
+        type_model::from_schemas(&[
+            ("entities/Moment.schema.json".to_string(), json!({
+                "title": "Moment",
+                "x-familiar-kind": "entity",
+                "type": "object",
+                "properties": {}
+            })),
+            ("domains/weave/Thread.schema.json".to_string(), json!({
+                "title": "Thread",
+                "x-familiar-kind": "entity",
+                "type": "object",
+                "properties": {},
+                "definitions": { "ThreadStatus": { "type": "string", "enum": ["open", "woven"] } }
+            })),
+            ("types/Uuid.schema.json".to_string(), json!({ "title": "Uuid", "type": "object", "properties": {} })),
+        ])
+    }
+
+    /// Compile `files` as the `generated` module of a library crate
+    fn compiles(files: &BTreeMap<String, String>) -> Result<(), String> {
+        let dir = std::env::temp_dir().join(format!("xtask-rust-split-{}-{}", std::process::id(), files.len()));
+        let module_dir = dir.join("generated");
+        std::fs::create_dir_all(&module_dir).unwrap();
+        for (name, contents) in files {
+            std::fs::write(module_dir.join(name), contents).unwrap();
+        }
+        std::fs::write(dir.join("lib.rs"), "pub mod generated;\npub use generated::*;\n").unwrap();
+
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+        let output = Command::new(rustc)
+            .args(["--edition", "2021", "--crate-type", "lib", "--emit", "metadata", "-D", "warnings", "--out-dir"])
+            .arg(&dir)
+            .arg(dir.join("lib.rs"))
+            .output()
+            .unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+        if output.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&output.stderr).into_owned())
+        }
+    }
+
+    fn declared_types(code: &str) -> BTreeSet<String> {
+        parse_items(code).1.into_iter()
+            .filter(|item| !item.text.lines().any(|l| l.trim_start().starts_with("impl")))
+            .filter_map(|item| item.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_items() {
+        let (prelude, items) = parse_items(SINGLE_FILE);
+        assert_eq!(prelude, "//! Generated contracts\n#![allow(dead_code)]\n\nuse std::collections::HashMap;\n\n");
+
+        let names: Vec<_> = items.iter().map(|item| item.name.as_deref().unwrap()).collect();
+        assert_eq!(names, [
+            "Moment", "Moment", "Thread", "ThreadStatus", "ThreadStatus", "Uuid", "SCHEMA_VERSION", "Thread",
+        ]);
+        assert!(items[0].text.starts_with("/// A moment"));
+        assert!(items[1].text.contains("\"moment {\""));
+    }
+
+    #[test]
+    fn test_split_by_dir() {
+        let files = split(SINGLE_FILE, &modules(&decls(), SplitBy::Dir));
+        let names: Vec<_> = files.keys().map(String::as_str).collect();
+        assert_eq!(names, ["common.rs", "domains_weave.rs", "entities.rs", "mod.rs", "types.rs"]);
+
+        assert!(files["mod.rs"].starts_with(HEADER));
+        assert!(files["mod.rs"].contains("pub mod domains_weave;\n"));
+        assert!(files["mod.rs"].contains("pub use domains_weave::*;\n"));
+
+        // Impls follow their type; the local definition stays with its file
+        let weave = &files["domains_weave.rs"];
+        assert!(weave.contains("pub enum ThreadStatus"));
+        assert!(weave.contains("impl Default for ThreadStatus"));
+        assert!(weave.contains("impl<T> From<T> for crate::generated::Thread"));
+        assert!(files["entities.rs"].contains("impl Moment {"));
+        assert!(files["common.rs"].contains("pub const SCHEMA_VERSION"));
+        assert!(weave.contains("use std::collections::HashMap;\n\nuse super::*;\n"));
+    }
+
+    #[test]
+    fn test_split_by_kind() {
+        let files = split(SINGLE_FILE, &modules(&decls(), SplitBy::Kind));
+        let names: Vec<_> = files.keys().map(String::as_str).collect();
+        assert_eq!(names, ["common.rs", "entity.rs", "mod.rs"]);
+        assert!(files["entity.rs"].contains("pub struct Moment"));
+        assert!(files["entity.rs"].contains("pub enum ThreadStatus"));
+        assert!(files["common.rs"].contains("pub type Uuid"));
+    }
+
+    #[test]
+    fn test_split_output_compiles_and_reexports_every_type() {
+        let single: BTreeMap<String, String> = [("mod.rs".to_string(), SINGLE_FILE.to_string())].into();
+        compiles(&single).unwrap();
+
+        for split_by in [SplitBy::Dir, SplitBy::Kind] {
+            let files = split(SINGLE_FILE, &modules(&decls(), split_by));
+            compiles(&files).unwrap_or_else(|e| panic!("{:?} split doesn't compile:\n{}", split_by, e));
+
+            let reexported: BTreeSet<String> = files.iter()
+                .filter(|(name, _)| *name != "mod.rs")
+                .flat_map(|(_, code)| declared_types(code))
+                .collect();
+            assert_eq!(reexported, declared_types(SINGLE_FILE));
+            for module in files.keys().filter(|name| *name != "mod.rs") {
+                let module = module.trim_end_matches(".rs");
+                assert!(files["mod.rs"].contains(&format!("pub use {}::*;", module)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_module_names() {
+        assert_eq!(module_name("domains/agentic"), "domains_agentic");
+        assert_eq!(module_name("entities_api"), "entities_api");
+        assert_eq!(module_name("type"), "type_");
+        assert_eq!(module_name("2d"), "m_2d");
+        assert!(SplitBy::parse("dir").is_ok());
+        assert!(SplitBy::parse("file").is_err());
+    }
+}
@@ -0,0 +1,255 @@
+//! TypeScript Code Generator
+//!
+//! Renders [`type_model`](crate::type_model) declarations as one `.ts` file per
+//! schema directory. Objects become `export interface`, everything else an
+//! `export type`. Tagged unions are unions of object types whose
+//! discriminator property is a string literal, so TypeScript narrows on it.
+
+use crate::type_model::{DeclBody, Field, TypeDecl, TypeExpr};
+use std::collections::{BTreeMap, BTreeSet};
+
+const HEADER: &str = "// Generated by `cargo xtask codegen typescript`. Do not edit.\n";
+
+/// Render `decls` into `(file path, contents)` pairs, one file per directory.
+pub fn generate(decls: &[TypeDecl]) -> BTreeMap<String, String> {
+    let mut by_dir: BTreeMap<&str, Vec<&TypeDecl>> = BTreeMap::new();
+    for decl in decls {
+        by_dir.entry(decl.dir.as_str()).or_default().push(decl);
+    }
+
+    by_dir
+        .into_iter()
+        .map(|(dir, decls)| (format!("{}.ts", module_path(dir)), render_module(dir, &decls)))
+        .collect()
+}
+
+fn render_module(dir: &str, decls: &[&TypeDecl]) -> String {
+    let mut out = String::from(HEADER);
+
+    // Imports from sibling modules, grouped by module. A name this module
+    // declares itself (e.g. a local definition copy) shadows the import.
+    let local: BTreeSet<&str> = decls.iter().map(|d| d.name.as_str()).collect();
+    let mut imports: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for decl in decls {
+        decl.for_each_named(&mut |name, target_dir| {
+            if target_dir != dir && !local.contains(name) {
+                imports
+                    .entry(import_spec(dir, target_dir))
+                    .or_default()
+                    .insert(name.to_string());
+            }
+        });
+    }
+    if !imports.is_empty() {
+        out.push('\n');
+    }
+    for (spec, names) in &imports {
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        out.push_str(&format!("import type {{ {} }} from \"{}\";\n", names.join(", "), spec));
+    }
+
+    for decl in decls {
+        out.push('\n');
+        out.push_str(&doc_comment(decl.description.as_deref(), ""));
+        out.push_str(&render_decl(decl));
+    }
+    out
+}
+
+fn render_decl(decl: &TypeDecl) -> String {
+    match &decl.body {
+        DeclBody::Object(fields) => {
+            let mut out = format!("export interface {} {{\n", decl.name);
+            for field in fields {
+                out.push_str(&doc_comment(field.description.as_deref(), "  "));
+                out.push_str(&format!("  {};\n", field_signature(field)));
+            }
+            out.push_str("}\n");
+            out
+        }
+        DeclBody::Enum(values) => {
+            format!("export type {} = {};\n", decl.name, ts_type(&TypeExpr::Enum(values.clone())))
+        }
+        DeclBody::TaggedUnion { variants, .. } => {
+            let mut out = format!("export type {} =\n", decl.name);
+            for variant in variants {
+                out.push_str(&format!("  | {}\n", ts_type(&variant.ty)));
+            }
+            out.pop();
+            out.push_str(";\n");
+            out
+        }
+        DeclBody::Alias(ty) => format!("export type {} = {};\n", decl.name, ts_type(ty)),
+    }
+}
+
+fn field_signature(field: &Field) -> String {
+    let optional = if field.required { "" } else { "?" };
+    format!("{}{}: {}", property_name(&field.name), optional, ts_type(&field.ty))
+}
+
+fn ts_type(ty: &TypeExpr) -> String {
+    match ty {
+        TypeExpr::String => "string".to_string(),
+        TypeExpr::Integer | TypeExpr::Number => "number".to_string(),
+        TypeExpr::Boolean => "boolean".to_string(),
+        TypeExpr::Null => "null".to_string(),
+        TypeExpr::Any => "unknown".to_string(),
+        TypeExpr::Literal(value) => quote(value),
+        TypeExpr::Enum(values) => values.iter().map(|v| quote(v)).collect::<Vec<_>>().join(" | "),
+        TypeExpr::Primitive { scalar, .. } => ts_type(scalar),
+        TypeExpr::Named { name, .. } => name.clone(),
+        TypeExpr::Array(inner) => match inner.as_ref() {
+            TypeExpr::Union(_) | TypeExpr::Enum(_) => format!("({})[]", ts_type(inner)),
+            _ => format!("{}[]", ts_type(inner)),
+        },
+        TypeExpr::Map(values) => format!("Record<string, {}>", ts_type(values)),
+        TypeExpr::Union(types) => types.iter().map(ts_type).collect::<Vec<_>>().join(" | "),
+        TypeExpr::Object(fields) if fields.is_empty() => "{}".to_string(),
+        TypeExpr::Object(fields) => {
+            let fields: Vec<String> = fields.iter().map(field_signature).collect();
+            format!("{{ {} }}", fields.join("; "))
+        }
+    }
+}
+
+fn doc_comment(description: Option<&str>, indent: &str) -> String {
+    let Some(description) = description.map(str::trim).filter(|d| !d.is_empty()) else {
+        return String::new();
+    };
+    let lines: Vec<&str> = description.lines().map(str::trim_end).collect();
+    if let [line] = lines.as_slice() {
+        return format!("{}/** {} */\n", indent, line);
+    }
+
+    let mut out = format!("{}/**\n", indent);
+    for line in lines {
+        if line.is_empty() {
+            out.push_str(&format!("{} *\n", indent));
+        } else {
+            out.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{} */\n", indent));
+    out
+}
+
+/// Property names that aren't plain identifiers need quoting
+fn property_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
+}
+
+/// Module path (without extension) for a schema directory
+fn module_path(dir: &str) -> &str {
+    if dir.is_empty() {
+        "index"
+    } else {
+        dir
+    }
+}
+
+/// Import specifier for `target_dir`'s module from `from_dir`'s module
+fn import_spec(from_dir: &str, target_dir: &str) -> String {
+    let depth = module_path(from_dir).matches('/').count();
+    let prefix = if depth == 0 { "./".to_string() } else { "../".repeat(depth) };
+    format!("{}{}", prefix, module_path(target_dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_model;
+    use std::path::Path;
+
+    fn fixture() -> Vec<TypeDecl> {
+        type_model::load_dir(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/codegen")).unwrap()
+    }
+
+    #[test]
+    fn test_typescript_snapshot() {
+        let files = generate(&fixture());
+        assert_eq!(
+            files.keys().collect::<Vec<_>>(),
+            vec!["domains/agentic.ts", "entities.ts", "entities_api.ts", "types.ts"]
+        );
+
+        assert_eq!(files["domains/agentic.ts"], r#"// Generated by `cargo xtask codegen typescript`. Do not edit.
+
+export interface Place {
+  room?: "kitchen" | "2f-office";
+}
+"#);
+
+        assert_eq!(files["entities.ts"], r#"// Generated by `cargo xtask codegen typescript`. Do not edit.
+
+import type { MomentStatus, Place } from "./types";
+
+/** A captured moment */
+export interface Moment {
+  id: string;
+  author?: string;
+  /** When it was captured */
+  createdAt: string;
+  weight?: number | null;
+  status: MomentStatus;
+  place?: Place;
+  position?: { x: number; y: number };
+  tags?: string[];
+  metadata?: Record<string, string>;
+}
+"#);
+
+        assert_eq!(files["entities_api.ts"], r#"// Generated by `cargo xtask codegen typescript`. Do not edit.
+
+import type { MomentEvent, MomentStatus, Place } from "./types";
+
+export interface CreateMomentInput {
+  tenant_id: string;
+  status?: MomentStatus;
+  place?: Place;
+  event?: MomentEvent;
+}
+"#);
+
+        assert_eq!(files["types.ts"], r#"// Generated by `cargo xtask codegen typescript`. Do not edit.
+
+import type { Moment } from "./entities";
+
+/**
+ * Something that happened to a moment.
+ *
+ * Carried on the moments topic.
+ */
+export type MomentEvent =
+  | { event_type: "created"; moment: Moment }
+  | { event_type: "deleted"; reason?: string | null };
+
+export type MomentStatus = "draft" | "woven" | "2d" | "null";
+
+export interface Place {
+  lat: number;
+  lng: number;
+}
+"#);
+    }
+
+    #[test]
+    fn test_import_spec() {
+        assert_eq!(import_spec("types", "entities"), "./entities");
+        assert_eq!(import_spec("domains/agentic", "types"), "../types");
+        assert_eq!(import_spec("types", ""), "./index");
+    }
+}
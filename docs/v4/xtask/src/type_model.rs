@@ -0,0 +1,761 @@
+//! Language-neutral type model for the TypeScript and Python generators
+//!
+//! Reads the `*.schema.json` files of a schema directory and reduces every
+//! schema (and every local definition) to a [`TypeDecl`]: an object, a string
+//! enum, a tagged union, or an alias. Scalar primitives (`primitives/UUID`,
+//! `primitives/NormalizedFloat`, ...) are not declared; references to them are
+//! inlined as [`TypeExpr::Primitive`] so each target can map them directly.
+
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// A named type declared by a schema file or one of its local definitions
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDecl {
+    pub name: String,
+    /// Directory relative to the schema root (e.g. "types", "domains/agentic")
+    pub dir: String,
+    /// `x-familiar-kind` of the declaring schema (definitions inherit their file's)
+    pub kind: Option<String>,
+    pub description: Option<String>,
+    pub body: DeclBody,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeclBody {
+    /// Object with named properties
+    Object(Vec<Field>),
+    /// String enum, as wire values
+    Enum(Vec<String>),
+    /// `oneOf` tagged by the `x-familiar-discriminator` property
+    TaggedUnion {
+        discriminator: String,
+        variants: Vec<Variant>,
+    },
+    /// Anything else
+    Alias(TypeExpr),
+}
+
+/// One arm of a [`DeclBody::TaggedUnion`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variant {
+    /// Discriminator value, when the arm pins one
+    pub tag: Option<String>,
+    /// Variant name from `x-familiar-variants`, falling back to the PascalCased tag
+    pub name: String,
+    pub ty: TypeExpr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    /// Name on the wire, after applying `x-familiar-casing`
+    pub name: String,
+    pub ty: TypeExpr,
+    pub required: bool,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeExpr {
+    String,
+    Integer,
+    Number,
+    Boolean,
+    Null,
+    Any,
+    /// A single allowed string value
+    Literal(String),
+    /// Inline string enum
+    Enum(Vec<String>),
+    /// Scalar schema from `primitives/`, inlined (e.g. `UUID` -> `String`),
+    /// with its `format` (e.g. "uuid", "date-time")
+    Primitive {
+        name: String,
+        scalar: Box<TypeExpr>,
+        format: Option<String>,
+    },
+    /// Reference to a declared type
+    Named { name: String, dir: String },
+    Array(Box<TypeExpr>),
+    Map(Box<TypeExpr>),
+    Union(Vec<TypeExpr>),
+    Object(Vec<Field>),
+}
+
+impl TypeExpr {
+    /// Whether `null` is one of the accepted values
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            TypeExpr::Null => true,
+            TypeExpr::Union(types) => types.iter().any(TypeExpr::is_nullable),
+            _ => false,
+        }
+    }
+
+    /// Visit every named reference in this expression
+    pub fn for_each_named(&self, f: &mut impl FnMut(&str, &str)) {
+        match self {
+            TypeExpr::Named { name, dir } => f(name, dir),
+            TypeExpr::Array(inner) | TypeExpr::Map(inner) => inner.for_each_named(f),
+            TypeExpr::Union(types) => types.iter().for_each(|t| t.for_each_named(f)),
+            TypeExpr::Object(fields) => fields.iter().for_each(|field| field.ty.for_each_named(f)),
+            _ => {}
+        }
+    }
+}
+
+impl TypeDecl {
+    /// Visit every named reference in this declaration
+    pub fn for_each_named(&self, f: &mut impl FnMut(&str, &str)) {
+        match &self.body {
+            DeclBody::Object(fields) => fields.iter().for_each(|field| field.ty.for_each_named(f)),
+            DeclBody::Enum(_) => {}
+            DeclBody::TaggedUnion { variants, .. } => {
+                variants.iter().for_each(|v| v.ty.for_each_named(f))
+            }
+            DeclBody::Alias(ty) => ty.for_each_named(f),
+        }
+    }
+}
+
+/// Load every type schema under `schema_dir`, in path order.
+pub fn load_dir(schema_dir: &Path) -> anyhow::Result<Vec<TypeDecl>> {
+    Ok(from_schemas(&read_schemas(schema_dir, &[".schema.json"])?))
+}
+
+/// Read `(relative path, schema)` pairs for every file under `schema_dir`
+/// whose name ends with one of `suffixes`, in path order.
+pub fn read_schemas(schema_dir: &Path, suffixes: &[&str]) -> anyhow::Result<Vec<(String, Value)>> {
+    let mut schemas = Vec::new();
+    for entry in WalkDir::new(schema_dir).sort_by_file_name() {
+        let entry = entry?;
+        let path = entry.path();
+        let file_name = path.to_string_lossy();
+        if !suffixes.iter().any(|suffix| file_name.ends_with(suffix)) {
+            continue;
+        }
+        let relative = path
+            .strip_prefix(schema_dir)?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let json: Value = serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| anyhow::anyhow!("Failed to parse {}: {}", path.display(), e))?;
+        schemas.push((relative, json));
+    }
+    Ok(schemas)
+}
+
+/// Build declarations from `(relative path, schema)` pairs.
+///
+/// Declarations come out in input order; when two schemas in one directory
+/// declare the same name, the first one wins.
+pub fn from_schemas(schemas: &[(String, Value)]) -> Vec<TypeDecl> {
+    let schemas: Vec<&(String, Value)> = schemas
+        .iter()
+        .filter(|(_, json)| json.get("x-familiar-kind").and_then(Value::as_str) != Some("meta"))
+        .collect();
+
+    let index = Index::build(&schemas);
+    let mut decls = Vec::new();
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+
+    for (path, json) in &schemas {
+        let file = SchemaFile::new(path);
+        let ctx = Ctx {
+            index: &index,
+            file: &file,
+            casing: json.get("x-familiar-casing").and_then(Value::as_str),
+            kind: json.get("x-familiar-kind").and_then(Value::as_str),
+        };
+
+        // Files that only hold definitions don't declare a type of their own
+        let is_container = json.get("definitions").is_some() && describes_nothing(json);
+        if !index.primitives.contains_key(path.as_str()) && !is_container {
+            decls.push(ctx.decl(file.name(json), json));
+        }
+
+        for (def_name, def) in definitions(json) {
+            let ctx = Ctx {
+                casing: def.get("x-familiar-casing").and_then(Value::as_str).or(ctx.casing),
+                kind: def.get("x-familiar-kind").and_then(Value::as_str).or(ctx.kind),
+                ..ctx
+            };
+            decls.push(ctx.decl(type_name(def_name), def));
+        }
+    }
+
+    decls.retain(|d| seen.insert((d.dir.clone(), d.name.clone())));
+    decls
+}
+
+/// Properties in `x-familiar-field-order` order, then the rest in schema
+/// declaration order. Names in the order that aren't properties are ignored.
+fn ordered_properties<'v>(schema: &Value, properties: &'v serde_json::Map<String, Value>) -> Vec<(&'v String, &'v Value)> {
+    let order: Vec<&str> = schema
+        .get("x-familiar-field-order")
+        .and_then(Value::as_array)
+        .map(|names| names.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+
+    let mut ordered: Vec<_> = properties.iter().collect();
+    // Stable, so unlisted properties keep declaration order
+    ordered.sort_by_key(|(name, _)| order.iter().position(|o| o == name).unwrap_or(order.len()));
+    ordered
+}
+
+/// Name a schema file declares: its title, or its file stem when the title
+/// is missing or prose.
+pub fn declared_name(path: &str, json: &Value) -> String {
+    SchemaFile::new(path).name(json)
+}
+
+/// Two or more schema directories declaring the same type name. Targets
+/// with one namespace (the Rust contracts, GraphQL) can keep only one of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NameCollision {
+    pub name: String,
+    /// Declaring directories, in path order; the first wins
+    pub dirs: Vec<String>,
+}
+
+impl NameCollision {
+    /// Directory-prefixed names that would keep every declaration
+    /// (`domains/agentic` + `Config` -> `DomainsAgenticConfig`)
+    pub fn qualified_names(&self) -> Vec<String> {
+        self.dirs.iter().map(|dir| qualified_name(dir, &self.name)).collect()
+    }
+}
+
+impl std::fmt::Display for NameCollision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} is declared in {} (disambiguate as {})",
+            self.name, self.dirs.join(", "), self.qualified_names().join(", "))
+    }
+}
+
+/// Names declared in more than one directory, sorted by name
+pub fn name_collisions(decls: &[TypeDecl]) -> Vec<NameCollision> {
+    let mut dirs: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for decl in decls {
+        let entry = dirs.entry(decl.name.as_str()).or_default();
+        if !entry.contains(&decl.dir) {
+            entry.push(decl.dir.clone());
+        }
+    }
+    dirs.into_iter()
+        .filter(|(_, dirs)| dirs.len() > 1)
+        .map(|(name, dirs)| NameCollision { name: name.to_string(), dirs })
+        .collect()
+}
+
+/// `name` prefixed by its PascalCased directory
+pub fn qualified_name(dir: &str, name: &str) -> String {
+    format!("{}{}", to_pascal_case(&dir.replace('/', "_")), name)
+}
+
+/// Convert a name to the casing named by `x-familiar-casing`.
+pub fn apply_casing(name: &str, casing: &str) -> String {
+    let words = split_words(name);
+    match casing {
+        "snake_case" => words.join("_"),
+        "SCREAMING_SNAKE_CASE" => words.join("_").to_uppercase(),
+        "kebab-case" => words.join("-"),
+        "camelCase" => {
+            let pascal = to_pascal_case(name);
+            let mut chars = pascal.chars();
+            chars
+                .next()
+                .map(|c| c.to_lowercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+        "PascalCase" => to_pascal_case(name),
+        _ => name.to_string(),
+    }
+}
+
+/// Convert `snake_case`, `kebab-case` or `camelCase` to `PascalCase`.
+pub fn to_pascal_case(name: &str) -> String {
+    split_words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Split an identifier into lowercase words.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+
+    for c in name.chars() {
+        if c == '_' || c == '-' || c == ' ' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// `name` as a type identifier ("Action.meta" -> "ActionMeta")
+fn type_name(name: &str) -> String {
+    if is_identifier(name) {
+        name.to_string()
+    } else {
+        to_pascal_case(&name.replace(|c: char| !c.is_ascii_alphanumeric(), "_"))
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn definitions(json: &Value) -> impl Iterator<Item = (&String, &Value)> {
+    ["definitions", "$defs"]
+        .into_iter()
+        .filter_map(|key| json.get(key).and_then(Value::as_object))
+        .flatten()
+}
+
+/// True when a schema has no shape of its own (only metadata and definitions)
+fn describes_nothing(json: &Value) -> bool {
+    ["type", "properties", "oneOf", "anyOf", "allOf", "enum", "const", "$ref", "items"]
+        .iter()
+        .all(|key| json.get(*key).is_none())
+}
+
+/// A schema file's location, split into directory and file name
+struct SchemaFile<'a> {
+    path: &'a str,
+    dir: &'a str,
+}
+
+impl<'a> SchemaFile<'a> {
+    fn new(path: &'a str) -> Self {
+        let dir = path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+        Self { path, dir }
+    }
+
+    /// Declared name: the schema title, or the file stem when the title is
+    /// missing or prose ("Database Tooling")
+    fn name(&self, json: &Value) -> String {
+        json.get("title")
+            .and_then(Value::as_str)
+            .filter(|title| is_identifier(title))
+            .map(str::to_string)
+            .unwrap_or_else(|| {
+                let file_name = self.path.rsplit('/').next().unwrap_or(self.path);
+                type_name(file_name.trim_end_matches(".schema.json"))
+            })
+    }
+}
+
+/// Names and scalar primitives by schema path, for resolving `$ref`s
+struct Index {
+    names: BTreeMap<String, (String, String)>,
+    primitives: BTreeMap<String, (String, TypeExpr, Option<String>)>,
+}
+
+impl Index {
+    fn build(schemas: &[&(String, Value)]) -> Self {
+        let mut names = BTreeMap::new();
+        let mut primitives = BTreeMap::new();
+
+        for (path, json) in schemas {
+            let file = SchemaFile::new(path);
+            let name = file.name(json);
+            if file.dir == "primitives" || file.dir.starts_with("primitives/") {
+                if let Some(scalar) = scalar_type(json) {
+                    let format = json.get("format").and_then(Value::as_str).map(str::to_string);
+                    primitives.insert(path.clone(), (name.clone(), scalar, format));
+                }
+            }
+            names.insert(path.clone(), (name, file.dir.to_string()));
+        }
+
+        Self { names, primitives }
+    }
+}
+
+/// The scalar type of a schema that is nothing but a scalar
+fn scalar_type(json: &Value) -> Option<TypeExpr> {
+    if ["enum", "const", "properties", "oneOf", "anyOf", "allOf"]
+        .iter()
+        .any(|key| json.get(*key).is_some())
+    {
+        return None;
+    }
+    match json.get("type").and_then(Value::as_str)? {
+        "string" => Some(TypeExpr::String),
+        "integer" => Some(TypeExpr::Integer),
+        "number" => Some(TypeExpr::Number),
+        "boolean" => Some(TypeExpr::Boolean),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Ctx<'a> {
+    index: &'a Index,
+    file: &'a SchemaFile<'a>,
+    casing: Option<&'a str>,
+    kind: Option<&'a str>,
+}
+
+impl Ctx<'_> {
+    fn decl(&self, name: String, schema: &Value) -> TypeDecl {
+        let description = schema
+            .get("description")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let body = if let (Some(discriminator), Some(arms)) = (
+            schema.get("x-familiar-discriminator").and_then(Value::as_str),
+            schema.get("oneOf").and_then(Value::as_array),
+        ) {
+            DeclBody::TaggedUnion {
+                discriminator: discriminator.to_string(),
+                variants: arms
+                    .iter()
+                    .map(|arm| self.variant(arm, discriminator, schema))
+                    .collect(),
+            }
+        } else {
+            match self.expr(schema) {
+                TypeExpr::Object(fields) => DeclBody::Object(fields),
+                TypeExpr::Enum(values) => DeclBody::Enum(values),
+                TypeExpr::Literal(value) => DeclBody::Enum(vec![value]),
+                other => DeclBody::Alias(other),
+            }
+        };
+
+        TypeDecl {
+            name,
+            dir: self.file.dir.to_string(),
+            kind: self.kind.map(str::to_string),
+            description,
+            body,
+        }
+    }
+
+    fn variant(&self, arm: &Value, discriminator: &str, union: &Value) -> Variant {
+        let ty = self.expr(arm);
+        let tag = match &ty {
+            TypeExpr::Object(fields) => fields
+                .iter()
+                .find(|f| f.name == discriminator)
+                .and_then(|f| match &f.ty {
+                    TypeExpr::Literal(tag) => Some(tag.clone()),
+                    _ => None,
+                }),
+            _ => None,
+        };
+        let name = tag
+            .as_ref()
+            .and_then(|tag| {
+                union
+                    .get("x-familiar-variants")
+                    .and_then(|v| v.get(tag))
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            })
+            .or_else(|| tag.as_deref().map(to_pascal_case))
+            .or_else(|| match &ty {
+                TypeExpr::Named { name, .. } => Some(name.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| "Variant".to_string());
+
+        Variant { tag, name, ty }
+    }
+
+    fn expr(&self, schema: &Value) -> TypeExpr {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return self.resolve(reference);
+        }
+        if let Some(value) = schema.get("const").and_then(Value::as_str) {
+            return TypeExpr::Literal(value.to_string());
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            let strings: Option<Vec<String>> = values
+                .iter()
+                .map(|v| v.as_str().map(str::to_string))
+                .collect();
+            return match strings {
+                Some(mut s) if s.len() == 1 => TypeExpr::Literal(s.remove(0)),
+                Some(s) => TypeExpr::Enum(s),
+                None => TypeExpr::Any,
+            };
+        }
+        for key in ["oneOf", "anyOf"] {
+            if let Some(arms) = schema.get(key).and_then(Value::as_array) {
+                return union(arms.iter().map(|arm| self.expr(arm)).collect());
+            }
+        }
+        if let Some(parts) = schema.get("allOf").and_then(Value::as_array) {
+            if let [only] = parts.as_slice() {
+                return self.expr(only);
+            }
+            let fields: Vec<Field> = parts
+                .iter()
+                .filter_map(|part| match self.expr(part) {
+                    TypeExpr::Object(fields) => Some(fields),
+                    _ => None,
+                })
+                .flatten()
+                .collect();
+            return if fields.is_empty() { TypeExpr::Any } else { TypeExpr::Object(fields) };
+        }
+
+        match schema.get("type") {
+            Some(Value::String(ty)) => self.typed(ty, schema),
+            Some(Value::Array(types)) => union(
+                types
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|ty| self.typed(ty, schema))
+                    .collect(),
+            ),
+            _ if schema.get("properties").is_some() => self.object(schema),
+            _ => TypeExpr::Any,
+        }
+    }
+
+    fn typed(&self, ty: &str, schema: &Value) -> TypeExpr {
+        match ty {
+            "string" => TypeExpr::String,
+            "integer" => TypeExpr::Integer,
+            "number" => TypeExpr::Number,
+            "boolean" => TypeExpr::Boolean,
+            "null" => TypeExpr::Null,
+            "array" => TypeExpr::Array(Box::new(
+                schema.get("items").map(|items| self.expr(items)).unwrap_or(TypeExpr::Any),
+            )),
+            "object" => self.object(schema),
+            _ => TypeExpr::Any,
+        }
+    }
+
+    fn object(&self, schema: &Value) -> TypeExpr {
+        let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+            let values = match schema.get("additionalProperties") {
+                Some(Value::Object(_)) => self.expr(&schema["additionalProperties"]),
+                _ => TypeExpr::Any,
+            };
+            return TypeExpr::Map(Box::new(values));
+        };
+
+        let required: HashSet<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        TypeExpr::Object(
+            ordered_properties(schema, properties)
+                .into_iter()
+                .map(|(name, prop)| Field {
+                    name: self.casing.map_or_else(|| name.clone(), |c| apply_casing(name, c)),
+                    ty: self.expr(prop),
+                    required: required.contains(name.as_str()),
+                    description: prop
+                        .get("description")
+                        .and_then(Value::as_str)
+                        .map(str::to_string),
+                })
+                .collect(),
+        )
+    }
+
+    fn resolve(&self, reference: &str) -> TypeExpr {
+        let (path, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+        let target = if path.is_empty() {
+            self.file.path.to_string()
+        } else {
+            normalize_path(self.file.dir, path)
+        };
+
+        let definition = fragment
+            .strip_prefix("/definitions/")
+            .or_else(|| fragment.strip_prefix("/$defs/"));
+        if let Some(definition) = definition {
+            return match self.index.names.get(&target) {
+                Some((_, dir)) => TypeExpr::Named {
+                    name: type_name(definition),
+                    dir: dir.clone(),
+                },
+                None => TypeExpr::Any,
+            };
+        }
+
+        if let Some((name, scalar, format)) = self.index.primitives.get(&target) {
+            return TypeExpr::Primitive {
+                name: name.clone(),
+                scalar: Box::new(scalar.clone()),
+                format: format.clone(),
+            };
+        }
+        match self.index.names.get(&target) {
+            Some((name, dir)) => TypeExpr::Named {
+                name: name.clone(),
+                dir: dir.clone(),
+            },
+            None => TypeExpr::Any,
+        }
+    }
+}
+
+/// Collapse a list of alternatives, flattening nested unions
+fn union(types: Vec<TypeExpr>) -> TypeExpr {
+    let mut flat = Vec::new();
+    for ty in types {
+        match ty {
+            TypeExpr::Union(inner) => flat.extend(inner),
+            other if !flat.contains(&other) => flat.push(other),
+            _ => {}
+        }
+    }
+    if flat.len() == 1 {
+        flat.remove(0)
+    } else {
+        TypeExpr::Union(flat)
+    }
+}
+
+/// Resolve a `$ref` path against the referencing file's directory
+pub fn normalize_path(dir: &str, reference: &str) -> String {
+    let mut parts: Vec<&str> = if reference.starts_with("./") || reference.starts_with("../") {
+        dir.split('/').filter(|p| !p.is_empty()).collect()
+    } else {
+        Vec::new()
+    };
+    for part in reference.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            other => parts.push(other),
+        }
+    }
+    parts.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_apply_casing() {
+        assert_eq!(apply_casing("createdAt", "snake_case"), "created_at");
+        assert_eq!(apply_casing("created_at", "camelCase"), "createdAt");
+        assert_eq!(apply_casing("created_at", "SCREAMING_SNAKE_CASE"), "CREATED_AT");
+        assert_eq!(apply_casing("created_at", "kebab-case"), "created-at");
+        assert_eq!(apply_casing("created_at", "unknown"), "created_at");
+    }
+
+    #[test]
+    fn test_name_collisions() {
+        let decls = from_schemas(&[
+            ("domains/agentic/Config.schema.json".to_string(), json!({ "title": "Config", "type": "object", "properties": {} })),
+            ("domains/weave/Config.schema.json".to_string(), json!({ "title": "Config", "type": "object", "properties": {} })),
+            ("entities/Moment.schema.json".to_string(), json!({ "title": "Moment", "type": "object", "properties": {} })),
+        ]);
+        assert_eq!(decls.len(), 3);
+
+        let collisions = name_collisions(&decls);
+        assert_eq!(collisions, [NameCollision {
+            name: "Config".to_string(),
+            dirs: vec!["domains/agentic".to_string(), "domains/weave".to_string()],
+        }]);
+        assert_eq!(collisions[0].qualified_names(), ["DomainsAgenticConfig", "DomainsWeaveConfig"]);
+    }
+
+    #[test]
+    fn test_field_order() {
+        let schema = json!({
+            "title": "Moment",
+            "type": "object",
+            "x-familiar-field-order": ["id", "createdAt", "missing"],
+            "properties": {
+                "weight": { "type": "number" },
+                "createdAt": { "type": "string" },
+                "body": { "type": "string" },
+                "id": { "type": "string" }
+            }
+        });
+        let decls = from_schemas(&[("entities/Moment.schema.json".to_string(), schema.clone())]);
+        let DeclBody::Object(fields) = &decls[0].body else { panic!("expected an object") };
+        let names: Vec<_> = fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["id", "createdAt", "weight", "body"]);
+
+        // Without an explicit order, fields follow the schema as written
+        let mut unordered = schema;
+        unordered.as_object_mut().unwrap().remove("x-familiar-field-order");
+        let decls = from_schemas(&[("entities/Moment.schema.json".to_string(), unordered)]);
+        let DeclBody::Object(fields) = &decls[0].body else { panic!("expected an object") };
+        let names: Vec<_> = fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["weight", "createdAt", "body", "id"]);
+    }
+
+    #[test]
+    fn test_type_names() {
+        assert_eq!(type_name("Moment"), "Moment");
+        assert_eq!(type_name("Action.meta"), "ActionMeta");
+        assert_eq!(SchemaFile::new("tooling/DatabaseTooling.schema.json").name(&json!({ "title": "Database Tooling" })), "DatabaseTooling");
+    }
+
+    #[test]
+    fn test_refs_and_primitives() {
+        let decls = from_schemas(&[
+            ("primitives/UUID.schema.json".to_string(), json!({ "title": "UUID", "type": "string", "format": "uuid" })),
+            ("entities/Moment.schema.json".to_string(), json!({
+                "title": "Moment",
+                "type": "object",
+                "required": ["id"],
+                "properties": {
+                    "id": { "$ref": "../primitives/UUID.schema.json" },
+                    "tags": { "type": ["array", "null"], "items": { "$ref": "#/definitions/Tag" } }
+                },
+                "definitions": { "Tag": { "type": "string", "enum": ["a", "b"] } }
+            })),
+        ]);
+
+        // The scalar primitive is inlined, not declared
+        assert_eq!(decls.len(), 2);
+        let DeclBody::Object(fields) = &decls[0].body else { panic!("expected object") };
+        assert_eq!(fields[0].ty, TypeExpr::Primitive {
+            name: "UUID".into(),
+            scalar: Box::new(TypeExpr::String),
+            format: Some("uuid".into()),
+        });
+        assert!(fields[0].required);
+        assert_eq!(fields[1].ty, TypeExpr::Union(vec![
+            TypeExpr::Array(Box::new(TypeExpr::Named { name: "Tag".into(), dir: "entities".into() })),
+            TypeExpr::Null,
+        ]));
+        assert_eq!(decls[1].body, DeclBody::Enum(vec!["a".into(), "b".into()]));
+    }
+}
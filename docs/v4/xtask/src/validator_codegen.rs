@@ -0,0 +1,481 @@
+//! Validator Code Generator
+//!
+//! Emits one `validate_<type>` function per API-facing schema
+//! (`x-familiar-kind` of `action`, `entities_api` or `contract`). Each embeds
+//! its schema as a `static`, with every schema it `$ref`s bundled under
+//! `definitions`, and compiles a `jsonschema` validator on first call. The
+//! support code it shares lives in `validator_runtime.rs`.
+//!
+//! Actions have no Rust type: their validator takes the action's
+//! `signature.inputs` as a JSON object.
+
+use crate::type_model::{apply_casing, declared_name, normalize_path};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// `x-familiar-kind`s that get a validator
+pub const KINDS: &[&str] = &["action", "entities_api", "contract"];
+
+const HEADER: &str = "\
+//! Generated by `cargo xtask codegen validators`. Do not edit.
+//!
+//! One `validate_*` function per `action`, `entities_api` and `contract`
+//! schema. Each embeds its schema (with everything it references) and
+//! compiles a `jsonschema` validator on first use.
+";
+
+const RUNTIME: &str = include_str!("validator_runtime.rs");
+
+/// One validator to emit
+#[derive(Debug)]
+struct Spec {
+    fn_name: String,
+    /// Name reported in `ValidationError::type_name`
+    type_name: String,
+    /// Rust input type; `None` for action inputs, which are `serde_json::Value`
+    rust_type: Option<String>,
+    /// Schema file the validator was generated from
+    source: String,
+    /// `jsonschema::Draft` variant
+    draft: &'static str,
+    /// Bundled schema
+    schema: Value,
+}
+
+/// Render the validators module for the API-facing schemas among
+/// `(relative path, schema)` pairs. Input types are imported from `types_crate`.
+pub fn generate(schemas: &[(String, Value)], types_crate: &str) -> anyhow::Result<String> {
+    Ok(render(&specs(schemas)?, types_crate))
+}
+
+fn specs(schemas: &[(String, Value)]) -> anyhow::Result<Vec<Spec>> {
+    let by_path: BTreeMap<&str, &Value> = schemas.iter().map(|(p, json)| (p.as_str(), json)).collect();
+    let mut fn_names = BTreeSet::new();
+    let mut specs = Vec::new();
+
+    for (path, json) in schemas {
+        let kind = json.get("x-familiar-kind").and_then(Value::as_str);
+        if !kind.is_some_and(|kind| KINDS.contains(&kind)) {
+            continue;
+        }
+
+        let (type_name, rust_type, root) = if kind == Some("action") {
+            let id = json
+                .get("id")
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .unwrap_or_else(|| file_stem(path).to_string());
+            (id, None, action_input(json))
+        } else {
+            let name = declared_name(path, json);
+            (name.clone(), Some(name), json.clone())
+        };
+
+        let draft = declared_draft(&root)
+            .ok_or_else(|| anyhow::anyhow!("{}: unsupported $schema {}", path, root["$schema"]))?;
+        let base = match &rust_type {
+            Some(name) => apply_casing(name, "snake_case"),
+            None => format!("{}_input", apply_casing(&type_name.replace('.', "_"), "snake_case")),
+        };
+        let mut fn_name = format!("validate_{}", base);
+        let mut n = 2;
+        while !fn_names.insert(fn_name.clone()) {
+            fn_name = format!("validate_{}_{}", base, n);
+            n += 1;
+        }
+
+        specs.push(Spec {
+            fn_name,
+            type_name,
+            rust_type,
+            source: path.clone(),
+            draft,
+            schema: bundle(path, root, &by_path)?,
+        });
+    }
+    Ok(specs)
+}
+
+fn render(specs: &[Spec], types_crate: &str) -> String {
+    let mut out = String::from(HEADER);
+    out.push('\n');
+
+    let types: BTreeSet<&str> = specs.iter().filter_map(|s| s.rust_type.as_deref()).collect();
+    if !types.is_empty() {
+        out.push_str(&format!("use {}::{{\n", types_crate));
+        for ty in &types {
+            out.push_str(&format!("    {},\n", ty));
+        }
+        out.push_str("};\n");
+    }
+    out.push_str("use std::sync::OnceLock;\n");
+
+    // The runtime's own header comment describes the template, not the output
+    out.push('\n');
+    for line in RUNTIME.lines().skip_while(|l| l.starts_with("//")).skip_while(|l| l.is_empty()) {
+        out.push_str(line);
+        out.push('\n');
+    }
+
+    for spec in specs {
+        let (doc, input) = match &spec.rust_type {
+            Some(ty) => (format!("Validate a [`{}`] against `{}`", ty, spec.source), ty.clone()),
+            None => (
+                format!("Validate the inputs of action `{}` (`{}`)", spec.type_name, spec.source),
+                "serde_json::Value".to_string(),
+            ),
+        };
+        let schema = serde_json::to_string(&spec.schema).unwrap_or_default();
+
+        out.push('\n');
+        out.push_str(&format!("/// {}\n", doc));
+        out.push_str(&format!(
+            "pub fn {}(input: &{}) -> Result<(), ValidationError> {{\n",
+            spec.fn_name, input
+        ));
+        out.push_str(&format!("    static SCHEMA: &str = {};\n", raw_string(&schema)));
+        out.push_str("    static VALIDATOR: OnceLock<Validator> = OnceLock::new();\n");
+        out.push_str(&format!(
+            "    let validator = VALIDATOR.get_or_init(|| compile({:?}, SCHEMA, Draft::{}));\n",
+            spec.type_name, spec.draft
+        ));
+        out.push_str(&format!("    check(validator, {:?}, input)\n", spec.type_name));
+        out.push_str("}\n");
+    }
+    out
+}
+
+/// Object schema for an action's `signature.inputs`; inputs not marked
+/// `optional` are required.
+pub fn action_input(action: &Value) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    let inputs = action.pointer("/signature/inputs").and_then(Value::as_object);
+    for (name, input) in inputs.into_iter().flatten() {
+        properties.insert(name.clone(), input.get("schema").cloned().unwrap_or_else(|| json!({})));
+        if !input.get("optional").and_then(Value::as_bool).unwrap_or(false) {
+            required.push(name.clone());
+        }
+    }
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+/// Keywords whose value maps names to subschemas
+pub const SCHEMA_MAPS: &[&str] = &["properties", "patternProperties", "definitions", "$defs", "dependencies"];
+
+/// Keywords whose value is instance data, not schemas
+pub const DATA_KEYWORDS: &[&str] = &["enum", "const", "default", "examples"];
+
+/// Inline every schema `root` references, transitively, under `definitions`
+/// and point its `$ref`s there.
+fn bundle(root_path: &str, mut root: Value, schemas: &BTreeMap<&str, &Value>) -> anyhow::Result<Value> {
+    let mut bundler = Bundler {
+        root_path,
+        schemas,
+        pending: Vec::new(),
+        missing: BTreeSet::new(),
+    };
+    bundler.rewrite(&mut root, root_path);
+
+    let mut bundled: BTreeMap<String, Value> = BTreeMap::new();
+    while let Some(path) = bundler.pending.pop() {
+        let key = definition_key(&path);
+        if bundled.contains_key(&key) {
+            continue;
+        }
+        let mut schema = schemas[path.as_str()].clone();
+        if let Some(object) = schema.as_object_mut() {
+            // A nested $id would change the base URI the rewritten refs resolve against
+            object.remove("$id");
+            object.remove("$schema");
+        }
+        bundler.rewrite(&mut schema, &path);
+        bundled.insert(key, schema);
+    }
+
+    if !bundler.missing.is_empty() {
+        let missing: Vec<String> = bundler.missing.into_iter().collect();
+        anyhow::bail!("{}: unresolved $ref to {}", root_path, missing.join(", "));
+    }
+
+    let object = root
+        .as_object_mut()
+        .ok_or_else(|| anyhow::anyhow!("{}: schema is not an object", root_path))?;
+    object.remove("$id");
+    if !bundled.is_empty() {
+        let definitions = object.entry("definitions").or_insert_with(|| json!({}));
+        let definitions = definitions
+            .as_object_mut()
+            .ok_or_else(|| anyhow::anyhow!("{}: definitions is not an object", root_path))?;
+        definitions.extend(bundled);
+    }
+    Ok(root)
+}
+
+struct Bundler<'a> {
+    root_path: &'a str,
+    schemas: &'a BTreeMap<&'a str, &'a Value>,
+    /// Referenced schema paths still to inline
+    pending: Vec<String>,
+    /// `$ref`s that match no schema
+    missing: BTreeSet<String>,
+}
+
+impl Bundler<'_> {
+    /// Rewrite the `$ref`s of a subschema of `file`, queueing their targets
+    fn rewrite(&mut self, schema: &mut Value, file: &str) {
+        let Value::Object(map) = schema else {
+            return;
+        };
+        strip_annotations(map);
+
+        if let Some(Value::String(reference)) = map.get_mut("$ref") {
+            if let Some(rewritten) = self.rewrite_ref(reference, file) {
+                *reference = rewritten;
+            }
+        }
+
+        for (key, child) in map.iter_mut() {
+            if DATA_KEYWORDS.contains(&key.as_str()) {
+                continue;
+            }
+            match child {
+                Value::Object(named) if SCHEMA_MAPS.contains(&key.as_str()) => {
+                    for subschema in named.values_mut() {
+                        self.rewrite(subschema, file);
+                    }
+                }
+                Value::Object(_) => self.rewrite(child, file),
+                Value::Array(items) => {
+                    for item in items {
+                        self.rewrite(item, file);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn rewrite_ref(&mut self, reference: &str, file: &str) -> Option<String> {
+        let (path, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+        let target = if path.is_empty() {
+            file.to_string()
+        } else {
+            match resolve(file, path, self.schemas) {
+                Some(target) => target,
+                None => {
+                    self.missing.insert(reference.to_string());
+                    return None;
+                }
+            }
+        };
+
+        // Some generated schemas point at definitions they don't carry; those
+        // name the sibling schema, as the type model reads them
+        let (target, fragment) = match self.schemas.get(target.as_str()) {
+            Some(schema) if schema.pointer(fragment).is_none() => {
+                match sibling_definition(&target, fragment).filter(|s| self.schemas.contains_key(s.as_str())) {
+                    Some(sibling) => (sibling, ""),
+                    None => {
+                        self.missing.insert(reference.to_string());
+                        return None;
+                    }
+                }
+            }
+            _ => (target, fragment),
+        };
+
+        if target == self.root_path {
+            return Some(format!("#{}", fragment));
+        }
+        let rewritten = format!("#/definitions/{}{}", definition_key(&target), fragment);
+        self.pending.push(target);
+        Some(rewritten)
+    }
+}
+
+/// Drop keys that don't describe instances: `x-familiar-*` annotations
+/// (which `$ref` files that aren't schemas, like nodes and meta-schemas) and
+/// constraints set from `config://`, which are only known at deploy time.
+pub fn strip_annotations(map: &mut Map<String, Value>) {
+    map.retain(|key, value| {
+        !key.starts_with("x-") && !value.as_str().is_some_and(|v| v.starts_with("config://"))
+    });
+}
+
+/// Schema path a `$ref` from `file` points at. Bare paths are tried against
+/// the registry root first (as the type model reads them), then `file`'s
+/// directory.
+pub fn resolve(file: &str, path: &str, schemas: &BTreeMap<&str, &Value>) -> Option<String> {
+    let dir = file.rsplit_once('/').map_or("", |(dir, _)| dir);
+    let sibling = format!("./{}", path);
+    [normalize_path(dir, path), normalize_path(dir, &sibling)]
+        .into_iter()
+        .find(|candidate| schemas.contains_key(candidate.as_str()))
+}
+
+/// Schema file next to `file` named like the definition `fragment` points at
+pub fn sibling_definition(file: &str, fragment: &str) -> Option<String> {
+    let name = fragment
+        .strip_prefix("/definitions/")
+        .or_else(|| fragment.strip_prefix("/$defs/"))
+        .filter(|name| !name.contains('/'))?;
+    let dir = file.rsplit_once('/').map_or("", |(dir, _)| dir);
+    Some(normalize_path(dir, &format!("./{}.schema.json", name)))
+}
+
+/// `definitions` key for a bundled schema ("primitives/UUID.schema.json" ->
+/// "primitives.UUID")
+fn definition_key(path: &str) -> String {
+    file_stem(path).replace('/', ".")
+}
+
+pub fn file_stem(path: &str) -> &str {
+    [".schema.json", ".action.json", ".json"]
+        .iter()
+        .find_map(|suffix| path.strip_suffix(suffix))
+        .unwrap_or(path)
+}
+
+/// `jsonschema::Draft` variant for a schema's `$schema`, read the way
+/// `ContractEnforcer::declared_draft` reads it (absent means draft-07)
+fn declared_draft(schema: &Value) -> Option<&'static str> {
+    let Some(declared) = schema.get("$schema") else {
+        return Some("Draft7");
+    };
+    let normalized = declared.as_str()?.trim_end_matches('#').replacen("https://", "http://", 1);
+    match normalized.as_str() {
+        "http://json-schema.org/draft-04/schema" => Some("Draft4"),
+        "http://json-schema.org/draft-06/schema" => Some("Draft6"),
+        "http://json-schema.org/draft-07/schema" => Some("Draft7"),
+        "http://json-schema.org/draft/2019-09/schema" => Some("Draft201909"),
+        "http://json-schema.org/draft/2020-12/schema" => Some("Draft202012"),
+        _ => None,
+    }
+}
+
+/// Rust raw string literal with enough `#`s for `s`
+fn raw_string(s: &str) -> String {
+    let mut hashes = String::from("#");
+    while s.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+    format!("r{}\"{}\"{}", hashes, s, hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    include!("validator_runtime.rs");
+
+    fn fixture() -> Vec<(String, Value)> {
+        vec![
+            ("primitives/UUID.schema.json".to_string(), json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "$id": "primitives/UUID.schema.json",
+                "title": "UUID",
+                "type": "string",
+                "pattern": "^[0-9a-f-]{36}$"
+            })),
+            ("primitives/UserId.schema.json".to_string(), json!({ "title": "UserId", "type": "string" })),
+            ("entities_api/CreateMomentInput.schema.json".to_string(), json!({
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "title": "CreateMomentInput",
+                "x-familiar-kind": "entities_api",
+                "type": "object",
+                "required": ["tenant_id", "status"],
+                "maxProperties": "config://moments.max_fields",
+                "properties": {
+                    "tenant_id": { "$ref": "../primitives/UUID.schema.json" },
+                    "status": { "$ref": "#/definitions/Status" },
+                    "author_id": { "$ref": "../primitives/UUID.schema.json#/definitions/UserId" }
+                },
+                "definitions": { "Status": { "type": "string", "enum": ["draft", "woven"] } }
+            })),
+            ("actions/moment/weave.action.json".to_string(), json!({
+                "id": "moment.weave",
+                "x-familiar-kind": "action",
+                "signature": {
+                    "inputs": {
+                        "moment": { "schema": { "$ref": "../../entities_api/CreateMomentInput.schema.json" } },
+                        "note": { "schema": { "type": "string" }, "optional": true }
+                    }
+                }
+            })),
+        ]
+    }
+
+    #[test]
+    fn test_generated_functions() {
+        let code = generate(&fixture(), "familiar_contracts").unwrap();
+
+        assert!(code.starts_with(HEADER));
+        assert!(code.contains("use familiar_contracts::{\n    CreateMomentInput,\n};\n"));
+        assert!(code.contains("pub struct ValidationError {"));
+        assert!(!code.contains("Not a module of xtask"));
+        assert!(code.contains(
+            "pub fn validate_create_moment_input(input: &CreateMomentInput) -> Result<(), ValidationError> {"
+        ));
+        assert!(code.contains(
+            "pub fn validate_moment_weave_input(input: &serde_json::Value) -> Result<(), ValidationError> {"
+        ));
+        assert!(code.contains("compile(\"CreateMomentInput\", SCHEMA, Draft::Draft7)"));
+    }
+
+    #[test]
+    fn test_bundled_schemas_validate() {
+        let specs = specs(&fixture()).unwrap();
+        assert_eq!(specs.len(), 2);
+
+        let input = &specs[0];
+        assert_eq!(input.schema["properties"]["tenant_id"]["$ref"], "#/definitions/primitives.UUID");
+        assert_eq!(input.schema["properties"]["status"]["$ref"], "#/definitions/Status");
+        assert!(input.schema.get("maxProperties").is_none());
+        // UUID carries no UserId definition; the sibling schema stands in
+        assert_eq!(input.schema["properties"]["author_id"]["$ref"], "#/definitions/primitives.UserId");
+        let input = compile("CreateMomentInput", &input.schema.to_string(), Draft::Draft7);
+
+        let good = json!({ "tenant_id": "0b6a3c1e-8f6d-4c1a-9d4e-2f1b7a9c5e3d", "status": "woven" });
+        assert_eq!(check(&input, "CreateMomentInput", &good), Ok(()));
+
+        let bad = json!({ "tenant_id": "not-a-uuid", "status": "archived" });
+        let err = check(&input, "CreateMomentInput", &bad).unwrap_err();
+        assert_eq!(err.type_name, "CreateMomentInput");
+        assert_eq!(err.errors.len(), 2);
+
+        // The action input pulls in the entities_api schema and its UUID ref
+        let action = &specs[1];
+        assert_eq!(action.type_name, "moment.weave");
+        let action = compile("moment.weave", &action.schema.to_string(), Draft::Draft7);
+        assert_eq!(check(&action, "moment.weave", &json!({ "moment": good })), Ok(()));
+        assert!(check(&action, "moment.weave", &json!({ "note": "missing moment" })).is_err());
+        assert!(check(&action, "moment.weave", &json!({ "moment": bad })).is_err());
+    }
+
+    #[test]
+    fn test_unresolved_ref() {
+        let schemas = vec![("contracts/Envelope.schema.json".to_string(), json!({
+            "title": "Envelope",
+            "x-familiar-kind": "contract",
+            "x-familiar-service": { "$ref": "../infrastructure/nodes/api.node.json" },
+            "properties": { "trace": { "$ref": "#/definitions/Trace" } }
+        }))];
+
+        // Annotations are dropped, so only the dangling definition is reported
+        let err = generate(&schemas, "familiar_contracts").unwrap_err().to_string();
+        assert_eq!(err, "contracts/Envelope.schema.json: unresolved $ref to #/definitions/Trace");
+    }
+
+    #[test]
+    fn test_raw_string() {
+        assert_eq!(raw_string("{}"), "r#\"{}\"#");
+        assert_eq!(raw_string("{\"#/a\":1}"), "r##\"{\"#/a\":1}\"##");
+    }
+}
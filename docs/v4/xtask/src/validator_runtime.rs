@@ -0,0 +1,57 @@
+// Support code copied verbatim into the generated validators module.
+//
+// Not a module of xtask: `validator_codegen` embeds it with `include_str!`,
+// and its tests `include!` it to run schemas through exactly what it emits.
+
+use jsonschema::{Draft, Validator};
+use serde::Serialize;
+use std::fmt;
+
+/// A value rejected by its generated validator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// Type (or action input) that was validated
+    pub type_name: &'static str,
+    /// One message per violated constraint
+    pub errors: Vec<String>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed validation: {}", self.type_name, self.errors.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Parse and compile an embedded schema
+///
+/// Panics if the schema is malformed: that is a codegen bug, not bad input.
+fn compile(type_name: &'static str, schema: &str, draft: Draft) -> Validator {
+    let mut bytes = schema.as_bytes().to_vec();
+    let schema: serde_json::Value = simd_json::from_slice(&mut bytes)
+        .unwrap_or_else(|e| panic!("embedded schema for {} is not JSON: {}", type_name, e));
+    jsonschema::options()
+        .with_draft(draft)
+        .build(&schema)
+        .unwrap_or_else(|e| panic!("embedded schema for {} does not compile: {}", type_name, e))
+}
+
+/// Validate `input`'s serialized form against a compiled schema
+fn check<T: Serialize + ?Sized>(
+    validator: &Validator,
+    type_name: &'static str,
+    input: &T,
+) -> Result<(), ValidationError> {
+    let value = serde_json::to_value(input).map_err(|e| ValidationError {
+        type_name,
+        errors: vec![e.to_string()],
+    })?;
+
+    let errors: Vec<String> = validator.iter_errors(&value).map(|e| e.to_string()).collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError { type_name, errors })
+    }
+}
@@ -0,0 +1,255 @@
+use crate::config::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Capacity a node advertises in its schema (`x-familiar-memory`, `x-familiar-cpu`, `x-familiar-gpu`).
+/// Config allocations for that node must fit inside these limits.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct NodeCapacity {
+    /// Declared memory capacity in bytes
+    pub memory_bytes: Option<u64>,
+
+    /// Declared CPU capacity in milli-cores
+    pub cpu_millicores: Option<u64>,
+
+    /// Declared GPU count
+    pub gpu: Option<u32>,
+}
+
+impl NodeCapacity {
+    /// Read the declared capacity facets from a node schema.
+    /// Facets that are absent are left as `None` and not checked.
+    pub fn from_schema(schema: &serde_json::Value) -> Result<Self, String> {
+        let memory_bytes = match schema.get("x-familiar-memory") {
+            Some(v) => {
+                let raw = v.as_str().ok_or("x-familiar-memory must be a string")?;
+                Some(parse_memory_bytes(raw).ok_or_else(|| format!("invalid x-familiar-memory '{}'", raw))?)
+            }
+            None => None,
+        };
+
+        let cpu_millicores = match schema.get("x-familiar-cpu") {
+            Some(serde_json::Value::String(raw)) => {
+                Some(parse_cpu_millicores(raw).ok_or_else(|| format!("invalid x-familiar-cpu '{}'", raw))?)
+            }
+            Some(serde_json::Value::Number(n)) => {
+                let cores = n.as_f64().ok_or("x-familiar-cpu must be a number or string")?;
+                Some((cores * 1000.0).round() as u64)
+            }
+            Some(_) => return Err("x-familiar-cpu must be a number or string".to_string()),
+            None => None,
+        };
+
+        let gpu = match schema.get("x-familiar-gpu") {
+            Some(v) => Some(v.as_u64().ok_or("x-familiar-gpu must be an integer")? as u32),
+            None => None,
+        };
+
+        Ok(Self { memory_bytes, cpu_millicores, gpu })
+    }
+}
+
+/// A node whose configured allocation is inconsistent with its declared capacity
+#[derive(Debug, Clone, PartialEq)]
+pub enum CapacityViolation {
+    /// The config allocates more of a resource than the node schema declares
+    OverAllocated {
+        node: String,
+        resource: String,
+        allocated: String,
+        capacity: String,
+    },
+    /// The configured allocation could not be parsed, so it can't be checked
+    Unparseable {
+        node: String,
+        resource: String,
+        value: String,
+    },
+}
+
+impl std::fmt::Display for CapacityViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CapacityViolation::OverAllocated { node, resource, allocated, capacity } => write!(
+                f,
+                "node '{}' allocates {} {} but only declares {}",
+                node, allocated, resource, capacity
+            ),
+            CapacityViolation::Unparseable { node, resource, value } => write!(
+                f,
+                "node '{}' has an unparseable {} allocation '{}'",
+                node, resource, value
+            ),
+        }
+    }
+}
+
+/// Parse a Kubernetes-style memory quantity ("8Gi", "512Mi", "2G", "1048576") into bytes
+pub fn parse_memory_bytes(raw: &str) -> Option<u64> {
+    let raw = raw.trim();
+    let split = raw.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(raw.len());
+    let (number, unit) = raw.split_at(split);
+    let number: f64 = number.parse().ok()?;
+
+    let multiplier: u64 = match unit {
+        "" => 1,
+        "Ki" => 1 << 10,
+        "Mi" => 1 << 20,
+        "Gi" => 1 << 30,
+        "Ti" => 1 << 40,
+        "K" | "k" => 1_000,
+        "M" => 1_000_000,
+        "G" => 1_000_000_000,
+        "T" => 1_000_000_000_000,
+        _ => return None,
+    };
+
+    Some((number * multiplier as f64).round() as u64)
+}
+
+/// Parse a Kubernetes-style CPU quantity ("2000m", "2", "0.5") into milli-cores
+pub fn parse_cpu_millicores(raw: &str) -> Option<u64> {
+    let raw = raw.trim();
+    if let Some(millis) = raw.strip_suffix('m') {
+        return millis.parse().ok();
+    }
+    let cores: f64 = raw.parse().ok()?;
+    Some((cores * 1000.0).round() as u64)
+}
+
+impl NodeResources {
+    /// Look up a node's config by name. Schema ids use hyphens
+    /// ("familiar-daemon") while config keys use underscores.
+    pub fn get(&self, node: &str) -> Option<&NodeResourceConfig> {
+        match node.replace('-', "_").as_str() {
+            "familiar_daemon" => Some(&self.familiar_daemon),
+            "familiar_worker" => Some(&self.familiar_worker),
+            "classifier" => Some(&self.classifier),
+            _ => None,
+        }
+    }
+}
+
+impl GlobalConfig {
+    /// Check every node's configured allocation against the capacity its schema declares.
+    ///
+    /// Nodes without a config entry are skipped, as are resources the schema doesn't declare.
+    pub fn validate_node_capacities(
+        &self,
+        capacities: &HashMap<String, NodeCapacity>,
+    ) -> Result<(), Vec<CapacityViolation>> {
+        let mut violations = Vec::new();
+
+        let mut nodes: Vec<_> = capacities.iter().collect();
+        nodes.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (node, capacity) in nodes {
+            let Some(node_config) = self.nodes.get(node) else {
+                continue;
+            };
+            let resources = &node_config.resources;
+
+            if let Some(declared) = capacity.memory_bytes {
+                match parse_memory_bytes(&resources.memory) {
+                    Some(allocated) if allocated > declared => violations.push(CapacityViolation::OverAllocated {
+                        node: node.clone(),
+                        resource: "memory".to_string(),
+                        allocated: resources.memory.clone(),
+                        capacity: format!("{} bytes", declared),
+                    }),
+                    Some(_) => {}
+                    None => violations.push(CapacityViolation::Unparseable {
+                        node: node.clone(),
+                        resource: "memory".to_string(),
+                        value: resources.memory.clone(),
+                    }),
+                }
+            }
+
+            if let Some(declared) = capacity.cpu_millicores {
+                match parse_cpu_millicores(&resources.cpu) {
+                    Some(allocated) if allocated > declared => violations.push(CapacityViolation::OverAllocated {
+                        node: node.clone(),
+                        resource: "cpu".to_string(),
+                        allocated: resources.cpu.clone(),
+                        capacity: format!("{}m", declared),
+                    }),
+                    Some(_) => {}
+                    None => violations.push(CapacityViolation::Unparseable {
+                        node: node.clone(),
+                        resource: "cpu".to_string(),
+                        value: resources.cpu.clone(),
+                    }),
+                }
+            }
+
+            if let (Some(declared), Some(allocated)) = (capacity.gpu, resources.gpu) {
+                if allocated > declared {
+                    violations.push(CapacityViolation::OverAllocated {
+                        node: node.clone(),
+                        resource: "gpu".to_string(),
+                        allocated: allocated.to_string(),
+                        capacity: declared.to_string(),
+                    });
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_quantities() {
+        assert_eq!(parse_memory_bytes("8Gi"), Some(8 * 1024 * 1024 * 1024));
+        assert_eq!(parse_memory_bytes("256Mi"), Some(256 * 1024 * 1024));
+        assert_eq!(parse_memory_bytes("2G"), Some(2_000_000_000));
+        assert_eq!(parse_memory_bytes("lots"), None);
+        assert_eq!(parse_cpu_millicores("2000m"), Some(2000));
+        assert_eq!(parse_cpu_millicores("0.5"), Some(500));
+    }
+
+    #[test]
+    fn test_consistent_config_passes() {
+        let config = GlobalConfig::default();
+        let schema = serde_json::json!({
+            "x-familiar-kind": "node",
+            "x-familiar-memory": "2Gi",
+            "x-familiar-cpu": "1000m"
+        });
+
+        let mut capacities = HashMap::new();
+        capacities.insert("familiar-daemon".to_string(), NodeCapacity::from_schema(&schema).unwrap());
+
+        assert!(config.validate_node_capacities(&capacities).is_ok());
+    }
+
+    #[test]
+    fn test_memory_over_allocation_is_flagged() {
+        let mut config = GlobalConfig::default();
+        config.nodes.familiar_daemon.resources.memory = "8Gi".to_string();
+
+        let schema = serde_json::json!({
+            "x-familiar-kind": "node",
+            "x-familiar-memory": "256Mi"
+        });
+
+        let mut capacities = HashMap::new();
+        capacities.insert("familiar-daemon".to_string(), NodeCapacity::from_schema(&schema).unwrap());
+
+        let violations = config.validate_node_capacities(&capacities).unwrap_err();
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(
+            &violations[0],
+            CapacityViolation::OverAllocated { node, resource, .. } if node == "familiar-daemon" && resource == "memory"
+        ));
+    }
+}
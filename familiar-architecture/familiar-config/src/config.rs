@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::time::Duration;
 
+/// Prefix of environment variables that override config slots
+const ENV_PREFIX: &str = "FAMILIAR__";
+
 /// Global configuration structure for the Familiar platform.
 /// This serves as the central authority for all operational parameters.
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -17,8 +21,14 @@ pub struct GlobalConfig {
     /// Infrastructure-level settings and defaults
     pub infra: InfraConfig,
 
+    /// Core router decision limits
+    pub routing: RoutingConfig,
+
     /// Profile-Guided Optimization settings
     pub pgo: PgoConfig,
+
+    /// Model tuning, such as sampling temperatures
+    pub models: ModelConfig,
 }
 
 impl Default for GlobalConfig {
@@ -28,15 +38,47 @@ impl Default for GlobalConfig {
             systems: SystemConfig::default(),
             observability: ObservabilityConfig::default(),
             infra: InfraConfig::default(),
+            routing: RoutingConfig::default(),
             pgo: PgoConfig::default(),
+            models: ModelConfig::default(),
         }
     }
 }
 
 impl GlobalConfig {
     /// Load configuration from multiple sources with layered overrides
+    ///
+    /// Layers, lowest first: the built-in defaults, the config files, then
+    /// `FAMILIAR__` environment variables (see [`Self::load_with_env`]).
     pub fn load() -> anyhow::Result<Self> {
-        let mut builder = config::Config::builder();
+        Self::load_with_env(std::env::vars())
+    }
+
+    /// [`Self::load`] with the environment layer read from `env` instead of
+    /// the process environment.
+    ///
+    /// Each `FAMILIAR__`-prefixed variable overrides one slot, with `__`
+    /// between path segments: `FAMILIAR__NODES__FAMILIAR_DAEMON__RESOURCES__CPU=2.0`
+    /// sets `nodes.familiar_daemon.resources.cpu`. Values are parsed into the
+    /// slot's field type. A value that doesn't parse, or a variable that names
+    /// no slot, is an error.
+    pub fn load_with_env(env: impl IntoIterator<Item = (String, String)>) -> anyhow::Result<Self> {
+        Self::load_layers(None, env)
+    }
+
+    /// The layered load, with `file` (if any) applied over the standard config
+    /// files and under the environment.
+    pub(crate) fn load_layers(
+        file: Option<&std::path::Path>,
+        env: impl IntoIterator<Item = (String, String)>,
+    ) -> anyhow::Result<Self> {
+        let env: config::Map<String, String> = env
+            .into_iter()
+            .filter(|(key, _)| key.to_uppercase().starts_with(ENV_PREFIX))
+            .collect();
+
+        let mut builder = config::Config::builder()
+            .add_source(config::Config::try_from(&GlobalConfig::default())?);
         
         // Try to load from crate root config directory first
         let crate_config_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("config");
@@ -54,15 +96,92 @@ impl GlobalConfig {
             .add_source(config::File::with_name("config/production").required(false))
             .add_source(config::File::with_name("config/staging").required(false))
             .add_source(config::File::with_name("config/development").required(false));
+
+        if let Some(file) = file {
+            builder = builder.add_source(config::File::from(file));
+        }
         
-        // Load environment variables with FAMILIAR_ prefix
-        builder = builder.add_source(config::Environment::with_prefix("FAMILIAR").separator("_"));
+        // Environment overrides go last so they win over every file
+        builder = builder.add_source(
+            config::Environment::with_prefix("FAMILIAR")
+                .prefix_separator("__")
+                .separator("__")
+                .source(Some(env.clone())),
+        );
 
         let config = builder.build()?;
         let global_config: GlobalConfig = config.try_deserialize()?;
+
+        // Serde ignores keys it doesn't know, so a misspelled override would
+        // otherwise be dropped without a word
+        for key in env.keys() {
+            let slot = key[ENV_PREFIX.len()..].to_lowercase().replace("__", ".");
+            if global_config.resolve_slot(&slot).is_none() {
+                anyhow::bail!("{} does not name a config slot ({})", key, slot);
+            }
+        }
+
+        if let Err(problems) = global_config.validate() {
+            anyhow::bail!("invalid config: {}", problems.join("; "));
+        }
         
         Ok(global_config)
     }
+
+    /// Check values that deserialize fine but make no sense: node
+    /// allocations that don't parse, fractions outside 0..=1, and sizes or
+    /// tolerances that must be positive.
+    ///
+    /// Every load runs this, so a config that fails it is never returned.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+
+        for (node, config) in [
+            ("familiar_daemon", &self.nodes.familiar_daemon),
+            ("familiar_worker", &self.nodes.familiar_worker),
+            ("classifier", &self.nodes.classifier),
+        ] {
+            let resources = &config.resources;
+            if crate::capacity::parse_memory_bytes(&resources.memory).is_none() {
+                problems.push(format!("nodes.{}.resources.memory: unparseable quantity {:?}", node, resources.memory));
+            }
+            if crate::capacity::parse_cpu_millicores(&resources.cpu).is_none() {
+                problems.push(format!("nodes.{}.resources.cpu: unparseable quantity {:?}", node, resources.cpu));
+            }
+        }
+
+        let heddle = &self.systems.heddle;
+        let mut fractions = vec![
+            ("systems.heddle.default_collapse_threshold".to_string(), heddle.default_collapse_threshold),
+            ("observability.tracing.sampling_ratio".to_string(), self.observability.tracing.sampling_ratio),
+            ("observability.load_shedding.shedding_rate".to_string(), self.observability.load_shedding.shedding_rate),
+        ];
+        fractions.extend(heddle.collapse_thresholds.iter().map(|(name, threshold)| {
+            (format!("systems.heddle.collapse_thresholds.{}", name), *threshold)
+        }));
+        for (slot, value) in fractions {
+            if !(0.0..=1.0).contains(&value) {
+                problems.push(format!("{}: {} is outside 0..=1", slot, value));
+            }
+        }
+
+        if self.systems.fates_gate.batch.max_size == 0 {
+            problems.push("systems.fates_gate.batch.max_size: must be at least 1".to_string());
+        }
+        if self.systems.physics.max_iterations == 0 {
+            problems.push("systems.physics.max_iterations: must be at least 1".to_string());
+        }
+        let tolerance = self.systems.physics.tolerance;
+        if tolerance.is_nan() || tolerance <= 0.0 {
+            problems.push(format!("systems.physics.tolerance: {} must be positive", tolerance));
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
     
     /// Get a thread-local reference to the loaded configuration
     pub fn get() -> &'static GlobalConfig {
@@ -75,6 +194,38 @@ impl GlobalConfig {
     }
 }
 
+/// Durations are written as humantime strings in the config files ("30s",
+/// "5m", "100ms") but serialize as `{ secs, nanos }`; accept both.
+mod duration {
+    use serde::de::{self, Deserializer, MapAccess, Visitor};
+    use serde::Deserialize;
+    use std::fmt;
+    use std::time::Duration;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        deserializer.deserialize_any(DurationVisitor)
+    }
+
+    struct DurationVisitor;
+
+    impl<'de> Visitor<'de> for DurationVisitor {
+        type Value = Duration;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a duration such as \"30s\" or \"100ms\"")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Duration, E> {
+            humantime::parse_duration(value)
+                .map_err(|e| E::custom(format!("invalid duration {:?}: {}", value, e)))
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Duration, A::Error> {
+            Duration::deserialize(de::value::MapAccessDeserializer::new(map))
+        }
+    }
+}
+
 // =============================================================================
 // Node Resources Configuration
 // =============================================================================
@@ -176,7 +327,9 @@ impl Default for NodeConstraints {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// Limits a config layer leaves unset are 0
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
 pub struct NodeJobLimits {
     pub llm_jobs: usize,
     pub ml_jobs: usize,
@@ -191,6 +344,10 @@ pub struct NodeJobLimits {
 pub struct SystemConfig {
     pub fates_gate: FatesGateConfig,
     pub classifier_system: ClassifierSystemConfig,
+    #[serde(default)]
+    pub heddle: HeddleConfig,
+    #[serde(default)]
+    pub physics: PhysicsConfig,
 }
 
 impl Default for SystemConfig {
@@ -198,6 +355,45 @@ impl Default for SystemConfig {
         Self {
             fates_gate: FatesGateConfig::default(),
             classifier_system: ClassifierSystemConfig::default(),
+            heddle: HeddleConfig::default(),
+            physics: PhysicsConfig::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct HeddleConfig {
+    /// Classification weight an entity type needs to collapse when it has
+    /// no entry in `collapse_thresholds`
+    pub default_collapse_threshold: f64,
+    /// Classification weight each entity type needs to collapse, keyed by
+    /// entity type name (e.g. `Thread`). Unlisted types use the default.
+    pub collapse_thresholds: BTreeMap<String, f64>,
+}
+
+impl Default for HeddleConfig {
+    fn default() -> Self {
+        Self {
+            default_collapse_threshold: 0.7,
+            collapse_thresholds: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PhysicsConfig {
+    /// Most steps the action minimizer takes before giving up
+    pub max_iterations: u32,
+    /// Field gradient magnitude at which the minimizer counts as converged
+    pub tolerance: f64,
+}
+
+impl Default for PhysicsConfig {
+    fn default() -> Self {
+        Self {
+            max_iterations: 1000,
+            tolerance: 1e-6,
         }
     }
 }
@@ -212,6 +408,10 @@ pub struct FatesGateConfig {
     
     /// Routing policy parameters
     pub routing: FatesGateRouting,
+
+    /// Batch classification limits
+    #[serde(default)]
+    pub batch: FatesGateBatch,
 }
 
 impl Default for FatesGateConfig {
@@ -220,14 +420,18 @@ impl Default for FatesGateConfig {
             timeouts: FatesGateTimeouts::default(),
             retries: FatesGateRetries::default(),
             routing: FatesGateRouting::default(),
+            batch: FatesGateBatch::default(),
         }
     }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct FatesGateTimeouts {
+    #[serde(deserialize_with = "duration::deserialize")]
     pub weave: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub search: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub classify: Duration,
 }
 
@@ -273,6 +477,18 @@ impl Default for FatesGateRouting {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FatesGateBatch {
+    /// Most inputs classified per chunk of a batch
+    pub max_size: usize,
+}
+
+impl Default for FatesGateBatch {
+    fn default() -> Self {
+        Self { max_size: 32 }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ClassifierSystemConfig {
     pub timeouts: ClassifierTimeouts,
@@ -292,8 +508,11 @@ impl Default for ClassifierSystemConfig {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ClassifierTimeouts {
+    #[serde(deserialize_with = "duration::deserialize")]
     pub classification: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub entity_segment: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub purpose_classification: Duration,
 }
 
@@ -376,9 +595,13 @@ impl Default for ObservabilityConfig {
 pub struct CircuitBreakerConfig {
     pub failure_threshold: u32,
     pub success_threshold: u32,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub recovery_timeout: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub health_check_interval: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub failure_detection_window: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub slow_call_duration_threshold: Duration,
     pub slow_call_rate_threshold: f64,
 }
@@ -405,6 +628,7 @@ pub struct LoadSheddingConfig {
     pub request_rate_threshold: usize,
     pub shedding_strategy: String,
     pub shedding_rate: f64,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub recovery_cooldown: Duration,
 }
 
@@ -424,10 +648,13 @@ impl Default for LoadSheddingConfig {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MetricsConfig {
+    #[serde(deserialize_with = "duration::deserialize")]
     pub collection_interval: Duration,
     pub max_batch_size: usize,
     pub max_queue_size: usize,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub export_timeout: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub schedule_delay: Duration,
 }
 
@@ -445,6 +672,7 @@ impl Default for MetricsConfig {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct AlertingConfig {
+    #[serde(deserialize_with = "duration::deserialize")]
     pub for_duration: Duration,
 }
 
@@ -464,6 +692,8 @@ pub struct TracingConfig {
     pub max_links_per_span: usize,
     pub attribute_value_length_limit: usize,
     pub attribute_count_limit: usize,
+    /// Routing decisions the router keeps for post-mortems (0 disables)
+    pub decision_history: usize,
 }
 
 impl Default for TracingConfig {
@@ -475,19 +705,28 @@ impl Default for TracingConfig {
             max_links_per_span: 128,
             attribute_value_length_limit: 4096,
             attribute_count_limit: 128,
+            decision_history: 100,
         }
     }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct HealthCheckConfig {
+    #[serde(deserialize_with = "duration::deserialize")]
     pub liveness_initial_delay: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub liveness_period: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub liveness_timeout: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub readiness_initial_delay: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub readiness_period: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub readiness_timeout: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub checks_timeout: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub checks_interval: Duration,
     pub failure_threshold: u32,
     pub success_threshold: u32,
@@ -513,14 +752,20 @@ impl Default for HealthCheckConfig {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ResilienceConfig {
     pub max_attempts: u32,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub initial_delay: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub max_delay: Duration,
     pub backoff_multiplier: f64,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub request_timeout: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub connection_timeout: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub idle_timeout: Duration,
     pub max_concurrent_calls: usize,
     pub max_wait_queue_size: usize,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub wait_duration: Duration,
 }
 
@@ -543,9 +788,11 @@ impl Default for ResilienceConfig {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ResourceMonitoringConfig {
+    #[serde(deserialize_with = "duration::deserialize")]
     pub collection_interval: Duration,
     pub aggregation: String,
     pub connection_pool_exhaustion: f64,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub response_time_degradation: Duration,
     pub error_rate_threshold: f64,
     pub capacity_warning: f64,
@@ -567,10 +814,14 @@ impl Default for ResourceMonitoringConfig {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct QueueMonitoringConfig {
     pub max_queue_depth: usize,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub max_processing_latency: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub max_consumer_lag: Duration,
     pub rebalance_alert_threshold: f64,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub balance_check_interval: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub evaluation_period: Duration,
 }
 
@@ -630,7 +881,9 @@ impl Default for QueueInfraConfig {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ScalingDefaults {
+    #[serde(deserialize_with = "duration::deserialize")]
     pub cooldown_period: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub default_timeout: Duration,
 }
 
@@ -645,7 +898,9 @@ impl Default for ScalingDefaults {
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct InfraTimeouts {
+    #[serde(deserialize_with = "duration::deserialize")]
     pub default_activity_timeout: Duration,
+    #[serde(deserialize_with = "duration::deserialize")]
     pub default_workflow_timeout: Duration,
 }
 
@@ -658,6 +913,28 @@ impl Default for InfraTimeouts {
     }
 }
 
+// =============================================================================
+// Routing Configuration
+// =============================================================================
+
+/// Limits on the core router's decisions (`CoreRouter` dispatch constraints)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RoutingConfig {
+    /// Maximum time allowed for a routing decision, in milliseconds
+    pub decision_timeout_ms: u64,
+    /// Maximum number of routing retries on failure
+    pub max_routing_retries: u32,
+}
+
+impl Default for RoutingConfig {
+    fn default() -> Self {
+        Self {
+            decision_timeout_ms: 5000,
+            max_routing_retries: 3,
+        }
+    }
+}
+
 /// Profile-Guided Optimization configuration
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PgoConfig {
@@ -693,3 +970,108 @@ impl Default for PgoConfig {
         }
     }
 }
+
+// =============================================================================
+// Model Configuration
+// =============================================================================
+
+/// Model tuning parameters
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ModelConfig {
+    /// Temperature preset overrides
+    pub temperatures: TemperatureConfig,
+}
+
+/// Overrides for the `Temperature` presets. Unset presets keep the values
+/// built into `familiar-primitives`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct TemperatureConfig {
+    pub classification: Option<f64>,
+    pub creative: Option<f64>,
+    pub deterministic: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_load_defaults() {
+        let config = GlobalConfig::load_with_env(env(&[])).unwrap();
+
+        // From config/defaults.toml
+        assert_eq!(config.nodes.classifier.resources.memory, "32Gi");
+        assert_eq!(config.observability.resilience.initial_delay, Duration::from_millis(100));
+        // Not in the files, so the built-in default
+        assert_eq!(config.pgo.default_sample_count, 100);
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let config = GlobalConfig::load_with_env(env(&[
+            ("FAMILIAR__NODES__FAMILIAR_DAEMON__RESOURCES__CPU", "2.0"),
+            ("FAMILIAR__SYSTEMS__FATES_GATE__TIMEOUTS__WEAVE", "45s"),
+            ("FAMILIAR__NODES__CLASSIFIER__CONSTRAINTS__MEMORY_THRESHOLD", "8000000000"),
+            ("FAMILIAR__PGO__ENABLE_BY_DEFAULT", "true"),
+            ("FAMILIAR_LOG", "debug"),
+            ("PATH", "/usr/bin"),
+        ]))
+        .unwrap();
+
+        assert_eq!(config.nodes.familiar_daemon.resources.cpu, "2.0");
+        assert_eq!(config.systems.fates_gate.timeouts.weave, Duration::from_secs(45));
+        assert_eq!(config.nodes.classifier.constraints.memory_threshold, 8_000_000_000);
+        assert!(config.pgo.enable_by_default);
+        // Siblings keep their file values
+        assert_eq!(config.nodes.familiar_daemon.resources.memory, "8Gi");
+        assert_eq!(config.systems.fates_gate.timeouts.search, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_env_type_errors_fail() {
+        let err = GlobalConfig::load_with_env(env(&[
+            ("FAMILIAR__NODES__CLASSIFIER__CONSTRAINTS__MEMORY_THRESHOLD", "lots"),
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("nodes.classifier.constraints.memory_threshold"), "{}", err);
+
+        let err = GlobalConfig::load_with_env(env(&[
+            ("FAMILIAR__SYSTEMS__FATES_GATE__TIMEOUTS__WEAVE", "soon"),
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid duration \"soon\""), "{}", err);
+    }
+
+    #[test]
+    fn test_semantic_errors_fail() {
+        assert!(GlobalConfig::default().validate().is_ok());
+
+        let err = GlobalConfig::load_with_env(env(&[
+            ("FAMILIAR__OBSERVABILITY__TRACING__SAMPLING_RATIO", "1.5"),
+            ("FAMILIAR__NODES__CLASSIFIER__RESOURCES__MEMORY", "lots"),
+        ]))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid config: nodes.classifier.resources.memory: unparseable quantity \"lots\"; \
+             observability.tracing.sampling_ratio: 1.5 is outside 0..=1"
+        );
+    }
+
+    #[test]
+    fn test_env_unknown_slot_fails() {
+        let err = GlobalConfig::load_with_env(env(&[
+            ("FAMILIAR__NODES__FAMILIAR_DAEMON__RESOURCES__CPUS", "2.0"),
+        ]))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "FAMILIAR__NODES__FAMILIAR_DAEMON__RESOURCES__CPUS does not name a config slot \
+             (nodes.familiar_daemon.resources.cpus)"
+        );
+    }
+}
@@ -25,10 +25,28 @@
 //!
 //! // Access system timeouts
 //! let gate_timeout = config.systems.fates_gate.timeouts.weave;
+//!
+//! // Resolve a `config:` slot referenced from schema CEL
+//! let weave = config.resolve_slot("systems.fates_gate.timeouts.weave");
+//!
+//! // Or name it with a path checked against the config fields at compile time
+//! let weave = familiar_config::config_path!(systems.fates_gate.timeouts.weave).get(&config);
+//!
+//! // Reload on edits to a config file, checking allocations against the
+//! // node capacities declared in the schemas; keep the handle alive to keep watching
+//! let _watch = GlobalConfig::watch("config/development.toml", capacities, |config| {
+//!     // swap `config` into the running service
+//! })?;
 //! ```
 
+pub mod capacity;
 pub mod config;
 pub mod manifest;
+pub mod slots;
+pub mod watch;
 
+pub use capacity::*;
 pub use config::*;
 pub use manifest::*;
+pub use slots::*;
+pub use watch::*;
@@ -10,7 +10,7 @@ pub struct PolicyManifest {
     pub config_keys: HashMap<String, ConfigKeyInfo>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct ConfigKeyInfo {
     pub path: String,
     pub value_type: String,
@@ -29,6 +29,7 @@ impl GlobalConfig {
         Self::add_observability_keys(&mut config_keys);
         Self::add_infra_keys(&mut config_keys);
         Self::add_pgo_keys(&mut config_keys);
+        Self::add_model_keys(&mut config_keys);
         
         PolicyManifest {
             version: env!("CARGO_PKG_VERSION").to_string(),
@@ -116,6 +117,25 @@ impl GlobalConfig {
         Self::add_key(keys, "systems.fates_gate.retries.classify", "integer",
                      serde_json::to_value(&defaults.fates_gate.retries.classify).unwrap(),
                      "Retry count for FatesGate classify operations");
+        Self::add_key(keys, "systems.fates_gate.batch.max_size", "integer",
+                     serde_json::to_value(defaults.fates_gate.batch.max_size).unwrap(),
+                     "Maximum inputs per chunk of a FatesGateBatch");
+
+        // Heddle
+        Self::add_key(keys, "systems.heddle.default_collapse_threshold", "number",
+                     serde_json::to_value(defaults.heddle.default_collapse_threshold).unwrap(),
+                     "Collapse threshold for entity types without their own entry");
+        Self::add_key(keys, "systems.heddle.collapse_thresholds", "map",
+                     serde_json::to_value(&defaults.heddle.collapse_thresholds).unwrap(),
+                     "Collapse threshold per entity type name; unlisted types use the default");
+
+        // Physics
+        Self::add_key(keys, "systems.physics.max_iterations", "integer",
+                     serde_json::to_value(defaults.physics.max_iterations).unwrap(),
+                     "Maximum steps of the action minimizer");
+        Self::add_key(keys, "systems.physics.tolerance", "number",
+                     serde_json::to_value(defaults.physics.tolerance).unwrap(),
+                     "Field gradient magnitude at which the action minimizer has converged");
         
         // Classifier System
         Self::add_key(keys, "systems.classifier_system.timeouts.classification", "duration",
@@ -165,6 +185,11 @@ impl GlobalConfig {
                      serde_json::to_value(&defaults.metrics.collection_interval).unwrap(),
                      "How often to collect metrics");
         
+        // Tracing
+        Self::add_key(keys, "observability.tracing.decision_history", "integer",
+                     serde_json::to_value(&defaults.tracing.decision_history).unwrap(),
+                     "Recent routing decisions the router keeps for post-mortems (0 disables)");
+        
         // Health Checks
         Self::add_key(keys, "observability.health_check.liveness_period", "duration",
                      serde_json::to_value(&defaults.health_check.liveness_period).unwrap(),
@@ -220,6 +245,20 @@ impl GlobalConfig {
         });
     }
 
+    fn add_model_keys(keys: &mut HashMap<String, ConfigKeyInfo>) {
+        let defaults = ModelConfig::default();
+
+        Self::add_key(keys, "models.temperatures.classification", "number",
+                     serde_json::to_value(defaults.temperatures.classification).unwrap(),
+                     "Temperature for classification prompts (unset uses the built-in preset)");
+        Self::add_key(keys, "models.temperatures.creative", "number",
+                     serde_json::to_value(defaults.temperatures.creative).unwrap(),
+                     "Temperature for creative prompts (unset uses the built-in preset)");
+        Self::add_key(keys, "models.temperatures.deterministic", "number",
+                     serde_json::to_value(defaults.temperatures.deterministic).unwrap(),
+                     "Temperature for deterministic prompts (unset uses the built-in preset)");
+    }
+
     fn add_pgo_keys(keys: &mut HashMap<String, ConfigKeyInfo>) {
         let defaults = PgoConfig::default();
 
@@ -257,11 +296,12 @@ impl GlobalConfig {
                      "Delay before compressing old data chunks (e.g., '7d', '30d')");
 
         // Add routing configuration keys
+        let routing = RoutingConfig::default();
         Self::add_key(keys, "routing.decision_timeout_ms", "integer",
-                     serde_json::to_value(5000).unwrap(),
+                     serde_json::to_value(routing.decision_timeout_ms).unwrap(),
                      "Maximum time allowed for routing decision (milliseconds)");
         Self::add_key(keys, "routing.max_routing_retries", "integer",
-                     serde_json::to_value(3).unwrap(),
+                     serde_json::to_value(routing.max_routing_retries).unwrap(),
                      "Maximum number of routing retries on failure");
 
         // Add node-specific routing configuration
@@ -326,6 +366,70 @@ impl PolicyManifest {
         let manifest = serde_json::from_str(&json)?;
         Ok(manifest)
     }
+
+    /// Compare the config keys of this manifest against `other`.
+    /// Keys only in `other` are added, keys only in `self` are removed.
+    pub fn diff(&self, other: &PolicyManifest) -> ManifestDiff {
+        let mut diff = ManifestDiff::default();
+
+        for (key, info) in &other.config_keys {
+            match self.config_keys.get(key) {
+                None => diff.added.push(key.clone()),
+                Some(existing) if existing != info => diff.changed.push(key.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = self
+            .config_keys
+            .keys()
+            .filter(|key| !other.config_keys.contains_key(*key))
+            .cloned()
+            .collect();
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.changed.sort();
+        diff
+    }
+}
+
+/// Config keys that differ between two manifests, sorted by key
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ManifestDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// Keys whose type, default or description changed
+    pub changed: Vec<String>,
+}
+
+impl ManifestDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl std::fmt::Display for ManifestDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for key in &self.added {
+            writeln!(f, "  + {}", key)?;
+        }
+        for key in &self.removed {
+            writeln!(f, "  - {}", key)?;
+        }
+        for key in &self.changed {
+            writeln!(f, "  ~ {}", key)?;
+        }
+        Ok(())
+    }
+}
+
+impl GlobalConfig {
+    /// Check whether the manifest stored at `path` matches the one generated
+    /// from the current config code
+    pub fn manifest_is_current(path: &std::path::Path) -> anyhow::Result<bool> {
+        let stored = PolicyManifest::load_from_file(path)?;
+        Ok(stored.diff(&Self::generate_manifest()).is_empty())
+    }
 }
 
 #[cfg(test)]
@@ -363,4 +467,32 @@ mod tests {
         
         std::fs::remove_file(&temp_path).unwrap();
     }
+
+    #[test]
+    fn test_manifest_diff() {
+        let original = GlobalConfig::generate_manifest();
+        let mut updated = original.clone();
+        updated
+            .config_keys
+            .get_mut("systems.fates_gate.timeouts.weave")
+            .unwrap()
+            .default_value = serde_json::json!({ "secs": 45, "nanos": 0 });
+
+        assert!(original.diff(&original.clone()).is_empty());
+        assert_eq!(
+            original.diff(&updated),
+            ManifestDiff {
+                changed: vec!["systems.fates_gate.timeouts.weave".to_string()],
+                ..Default::default()
+            }
+        );
+
+        let current = tempfile::NamedTempFile::new().unwrap();
+        original.save_to_file(current.path()).unwrap();
+        assert!(GlobalConfig::manifest_is_current(current.path()).unwrap());
+
+        let stale = tempfile::NamedTempFile::new().unwrap();
+        updated.save_to_file(stale.path()).unwrap();
+        assert!(!GlobalConfig::manifest_is_current(stale.path()).unwrap());
+    }
 }
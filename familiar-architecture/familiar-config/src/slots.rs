@@ -0,0 +1,210 @@
+use crate::config::*;
+use std::time::Duration;
+
+/// A config value resolved from a slot path, keeping its type.
+/// Slots are the dotted paths schema CEL refers to as `config:<path>`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    Bool(bool),
+    Int(i64),
+    /// Unsigned value too large for `Int`
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Duration(Duration),
+}
+
+impl ConfigValue {
+    /// Convert a leaf of the serialized config. Sections and unset optional
+    /// values aren't slots.
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        use serde_json::Value;
+
+        match value {
+            Value::Bool(b) => Some(Self::Bool(*b)),
+            Value::Number(n) => n
+                .as_i64()
+                .map(Self::Int)
+                .or_else(|| n.as_u64().map(Self::UInt))
+                .or_else(|| n.as_f64().map(Self::Float)),
+            Value::String(s) => Some(Self::String(s.clone())),
+            // `Duration` serializes as `{ secs, nanos }`
+            Value::Object(map) if map.len() == 2 => {
+                let secs = map.get("secs")?.as_u64()?;
+                let nanos = u32::try_from(map.get("nanos")?.as_u64()?).ok()?;
+                Some(Self::Duration(Duration::new(secs, nanos)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl GlobalConfig {
+    /// Resolve a dotted slot path (e.g. `systems.fates_gate.timeouts.weave`)
+    /// to the value it holds in this config.
+    /// Returns `None` when the path doesn't name a set leaf value.
+    pub fn resolve_slot(&self, dotted_path: &str) -> Option<ConfigValue> {
+        let tree = serde_json::to_value(self).ok()?;
+        let leaf = dotted_path
+            .split('.')
+            .try_fold(&tree, |node, key| node.get(key))?;
+        ConfigValue::from_json(leaf)
+    }
+}
+
+/// Field types that hold a slot value, as opposed to config sections
+pub trait ConfigLeaf {}
+
+impl ConfigLeaf for bool {}
+impl ConfigLeaf for u32 {}
+impl ConfigLeaf for u64 {}
+impl ConfigLeaf for usize {}
+impl ConfigLeaf for f64 {}
+impl ConfigLeaf for String {}
+impl ConfigLeaf for Duration {}
+impl<T: ConfigLeaf> ConfigLeaf for Option<T> {}
+
+/// A slot path checked against the `GlobalConfig` fields at compile time.
+/// Build one with [`config_path!`](crate::config_path).
+pub struct ConfigPath<T: 'static> {
+    path: &'static str,
+    get: fn(&GlobalConfig) -> &T,
+}
+
+impl<T: ConfigLeaf> ConfigPath<T> {
+    #[doc(hidden)]
+    pub const fn __new(path: &'static str, get: fn(&GlobalConfig) -> &T) -> Self {
+        Self { path, get }
+    }
+
+    /// The dotted slot path, as written in schema `config:` references
+    pub const fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// The field this path names in `config`
+    pub fn get<'a>(&self, config: &'a GlobalConfig) -> &'a T {
+        (self.get)(config)
+    }
+
+    /// The slot value, as [`GlobalConfig::resolve_slot`] would return it
+    pub fn resolve(&self, config: &GlobalConfig) -> Option<ConfigValue> {
+        config.resolve_slot(self.path)
+    }
+}
+
+impl<T> Clone for ConfigPath<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ConfigPath<T> {}
+
+impl<T> std::fmt::Debug for ConfigPath<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ConfigPath").field(&self.path).finish()
+    }
+}
+
+impl<T> std::fmt::Display for ConfigPath<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.path)
+    }
+}
+
+/// A [`ConfigPath`] for a dotted slot path, checked against the
+/// `GlobalConfig` fields so a typo or a path that stops at a section fails
+/// to build.
+///
+/// ```
+/// use familiar_config::{config_path, ConfigPath, GlobalConfig};
+/// use std::time::Duration;
+///
+/// const WEAVE: ConfigPath<Duration> = config_path!(systems.fates_gate.timeouts.weave);
+///
+/// let config = GlobalConfig::default();
+/// assert_eq!(WEAVE.path(), "systems.fates_gate.timeouts.weave");
+/// assert_eq!(*WEAVE.get(&config), config.systems.fates_gate.timeouts.weave);
+/// ```
+///
+/// ```compile_fail
+/// let cpu = familiar_config::config_path!(nodes.familiar_deamon.resources.cpu);
+/// ```
+///
+/// ```compile_fail
+/// let resources = familiar_config::config_path!(nodes.familiar_daemon.resources);
+/// ```
+#[macro_export]
+macro_rules! config_path {
+    ($first:ident $(. $rest:ident)*) => {
+        $crate::ConfigPath::__new(
+            concat!(stringify!($first) $(, ".", stringify!($rest))*),
+            |config| &config.$first $(.$rest)*,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_real_slots() {
+        let mut config = GlobalConfig::default();
+        config.systems.fates_gate.timeouts.weave = Duration::from_millis(45_500);
+        config.nodes.classifier.constraints.memory_threshold = 16_000_000_000;
+
+        assert_eq!(
+            config.resolve_slot("systems.fates_gate.timeouts.weave"),
+            Some(ConfigValue::Duration(Duration::from_millis(45_500)))
+        );
+        assert_eq!(
+            config.resolve_slot("nodes.classifier.constraints.memory_threshold"),
+            Some(ConfigValue::Int(16_000_000_000))
+        );
+        assert_eq!(
+            config.resolve_slot("nodes.familiar_daemon.constraints.cpu_threshold"),
+            Some(ConfigValue::Float(0.8))
+        );
+        assert_eq!(
+            config.resolve_slot("systems.fates_gate.routing.memory_threshold"),
+            Some(ConfigValue::Float(10000.0))
+        );
+        assert_eq!(
+            config.resolve_slot("systems.classifier_system.routing.urgency_threshold"),
+            Some(ConfigValue::String("high".to_string()))
+        );
+        assert_eq!(config.resolve_slot("pgo.enable_by_default"), Some(ConfigValue::Bool(false)));
+        assert_eq!(config.resolve_slot("routing.decision_timeout_ms"), Some(ConfigValue::Int(5000)));
+        assert_eq!(config.resolve_slot("routing.max_routing_retries"), Some(ConfigValue::Int(3)));
+    }
+
+    #[test]
+    fn test_config_path() {
+        let mut config = GlobalConfig::default();
+        config.nodes.familiar_daemon.resources.cpu = "3000m".to_string();
+
+        let cpu = config_path!(nodes.familiar_daemon.resources.cpu);
+        assert_eq!(cpu.path(), "nodes.familiar_daemon.resources.cpu");
+        assert_eq!(cpu.get(&config), "3000m");
+        assert_eq!(cpu.resolve(&config), Some(ConfigValue::String("3000m".to_string())));
+
+        // Unset options are valid paths without a slot value
+        let gpu = config_path!(nodes.classifier.resources.gpu);
+        assert_eq!(*gpu.get(&config), None);
+        assert_eq!(gpu.resolve(&config), None);
+    }
+
+    #[test]
+    fn test_missing_slots_do_not_resolve() {
+        let config = GlobalConfig::default();
+
+        assert_eq!(config.resolve_slot("routing.decision_budget_ms"), None);
+        assert_eq!(config.resolve_slot("nodes.familiar_router.constraints.cpu_threshold"), None);
+        // Sections and unset options aren't values
+        assert_eq!(config.resolve_slot("systems.fates_gate.timeouts"), None);
+        assert_eq!(config.resolve_slot("nodes.classifier.resources.gpu"), None);
+        assert_eq!(config.resolve_slot(""), None);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::capacity::NodeCapacity;
+use crate::config::*;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+
+/// Quiet period after the last write before a change is reloaded, so an
+/// editor saving in several steps triggers a single reload
+pub const WATCH_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Keeps a config watch alive. Dropping it stops watching.
+pub struct WatchHandle {
+    _watcher: RecommendedWatcher,
+}
+
+impl GlobalConfig {
+    /// Watch the config file at `path` and call `callback` with the freshly
+    /// loaded config after each change.
+    ///
+    /// Reloads re-run the layered load (see [`Self::load`]) with `path` on top
+    /// of the config files, which includes the semantic checks of
+    /// [`Self::validate`], then check node allocations against `capacities`
+    /// (see [`Self::validate_node_capacities`]). A reload that fails either is
+    /// logged and skipped, so a bad edit never reaches the callback.
+    pub fn watch(
+        path: impl AsRef<Path>,
+        capacities: HashMap<String, NodeCapacity>,
+        callback: impl Fn(GlobalConfig) + Send + 'static,
+    ) -> anyhow::Result<WatchHandle> {
+        let shown = path.as_ref().display().to_string();
+        watch_reloads(path.as_ref(), capacities, move |reloaded| match reloaded {
+            Ok(config) => {
+                tracing::info!("reloaded config from {}", shown);
+                callback(config);
+            }
+            Err(e) => tracing::warn!("ignoring invalid config in {}: {:#}", shown, e),
+        })
+    }
+}
+
+/// Call `on_reload` with the outcome of every debounced reload of `path`
+fn watch_reloads(
+    path: &Path,
+    capacities: HashMap<String, NodeCapacity>,
+    on_reload: impl Fn(anyhow::Result<GlobalConfig>) + Send + 'static,
+) -> anyhow::Result<WatchHandle> {
+    // Watch the directory rather than the file itself: editors that save
+    // by renaming a temp file over the original would end a file watch
+    let path = path.canonicalize()?;
+    let dir = path
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("{} has no parent directory", path.display()))?
+        .to_path_buf();
+
+    let (tx, rx) = mpsc::channel();
+    let watched = path.clone();
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<notify::Event>| {
+        match result {
+            // Reads (including our own reloads) show up as access events
+            Ok(event)
+                if !matches!(event.kind, EventKind::Access(_))
+                    && event.paths.contains(&watched) =>
+            {
+                let _ = tx.send(());
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("config watch error: {}", e),
+        }
+    })?;
+    watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+
+    // The watcher owns the sender, so this thread ends once the handle is dropped
+    std::thread::spawn(move || {
+        while rx.recv().is_ok() {
+            loop {
+                match rx.recv_timeout(WATCH_DEBOUNCE) {
+                    Ok(()) => continue,
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+            on_reload(reload(&path, &capacities));
+        }
+    });
+
+    Ok(WatchHandle { _watcher: watcher })
+}
+
+/// Load `path` over the config files and run the checks a config must pass
+/// before it replaces the running one
+fn reload(path: &Path, capacities: &HashMap<String, NodeCapacity>) -> anyhow::Result<GlobalConfig> {
+    let config = GlobalConfig::load_layers(Some(path), std::env::vars())?;
+    if let Err(violations) = config.validate_node_capacities(capacities) {
+        let violations: Vec<String> = violations.iter().map(ToString::to_string).collect();
+        anyhow::bail!("capacity check failed: {}", violations.join("; "));
+    }
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Upper bound on how long a test waits for a reload it expects; only
+    /// reached if the watch is broken
+    const RELOAD_DEADLINE: Duration = Duration::from_secs(10);
+
+    fn watched_file(contents: &str) -> (tempfile::TempDir, std::path::PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("familiar.toml");
+        std::fs::write(&path, contents).unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_watch_reloads_once_per_burst() {
+        let (_dir, path) = watched_file("[pgo]\nenable_by_default = false\n");
+        let (tx, rx) = mpsc::channel();
+        let _handle = watch_reloads(&path, HashMap::new(), move |reloaded| tx.send(reloaded).unwrap()).unwrap();
+
+        for _ in 0..3 {
+            std::fs::write(&path, "[pgo]\nenable_by_default = true\n").unwrap();
+        }
+        let config = rx.recv_timeout(RELOAD_DEADLINE).unwrap().unwrap();
+        assert!(config.pgo.enable_by_default);
+
+        // Had the burst reloaded twice, the next reload would still see `true`
+        std::fs::write(&path, "[pgo]\nenable_by_default = false\n").unwrap();
+        let config = rx.recv_timeout(RELOAD_DEADLINE).unwrap().unwrap();
+        assert!(!config.pgo.enable_by_default);
+    }
+
+    #[test]
+    fn test_watch_rejects_what_startup_rejects() {
+        let (_dir, path) = watched_file("[pgo]\nenable_by_default = false\n");
+        let schema = serde_json::json!({ "x-familiar-memory": "16Gi" });
+        let capacities = HashMap::from([
+            ("familiar-daemon".to_string(), NodeCapacity::from_schema(&schema).unwrap()),
+        ]);
+        let (tx, rx) = mpsc::channel();
+        let _handle = watch_reloads(&path, capacities, move |reloaded| tx.send(reloaded).unwrap()).unwrap();
+        let next_reload = |contents: &str| {
+            std::fs::write(&path, contents).unwrap();
+            rx.recv_timeout(RELOAD_DEADLINE).unwrap()
+        };
+
+        // Fails to deserialize
+        let err = next_reload("[pgo]\nenable_by_default = \"sometimes\"\n").unwrap_err();
+        assert!(err.to_string().contains("enable_by_default"), "{:#}", err);
+
+        // Fails the semantic checks
+        let err = next_reload("[observability.tracing]\nsampling_ratio = 3.0\n").unwrap_err();
+        assert!(err.to_string().contains("sampling_ratio: 3 is outside 0..=1"), "{:#}", err);
+
+        // Allocates more than the node declares
+        let err = next_reload("[nodes.familiar_daemon.resources]\nmemory = \"32Gi\"\n").unwrap_err();
+        assert!(err.to_string().contains("node 'familiar-daemon' allocates 32Gi memory"), "{:#}", err);
+
+        let config = next_reload("[nodes.familiar_daemon.resources]\nmemory = \"12Gi\"\n").unwrap();
+        assert_eq!(config.nodes.familiar_daemon.resources.memory, "12Gi");
+    }
+}
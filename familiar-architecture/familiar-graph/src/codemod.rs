@@ -0,0 +1,205 @@
+//! Schema codemods
+//!
+//! Every schema refactor follows the same shape: load each schema in the
+//! registry, transform its JSON, and write back whatever changed.
+//! [`transform_registry`] does the loading and write-back so a codemod
+//! only has to supply the transform.
+
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Options controlling how [`transform_registry`] writes results back
+#[derive(Debug, Clone, Default)]
+pub struct TransformOptions {
+    /// Report the files that would change without writing anything
+    pub dry_run: bool,
+    /// Write every changed file into a staging directory first and only
+    /// swap them into place once all of them have been written
+    pub atomic: bool,
+}
+
+/// Outcome of a [`transform_registry`] run
+#[derive(Debug, Clone, Default)]
+pub struct TransformReport {
+    /// Number of schema files visited
+    pub scanned: usize,
+    /// Files that were rewritten (or would be, in dry-run mode), in path order
+    pub changed: Vec<PathBuf>,
+}
+
+#[derive(Debug, Error)]
+pub enum CodemodError {
+    #[error("I/O error at {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+fn io_err(path: &Path) -> impl FnOnce(std::io::Error) -> CodemodError + '_ {
+    move |source| CodemodError::Io { path: path.to_path_buf(), source }
+}
+
+/// Apply `f` to every `*.json` schema under `dir`.
+///
+/// `f` returns `true` when it modified the schema. Only schemas that report
+/// a change *and* actually differ from what was on disk are written back,
+/// always as pretty-printed JSON with a trailing newline.
+pub fn transform_registry(
+    dir: impl AsRef<Path>,
+    mut f: impl FnMut(&mut Value) -> bool,
+    opts: &TransformOptions,
+) -> Result<TransformReport, CodemodError> {
+    let dir = dir.as_ref();
+    let mut files = Vec::new();
+    collect_schema_files(dir, &mut files)?;
+    files.sort();
+
+    let mut report = TransformReport::default();
+    let mut pending = Vec::new();
+
+    for path in files {
+        report.scanned += 1;
+
+        let content = fs::read_to_string(&path).map_err(io_err(&path))?;
+        let original: Value = serde_json::from_str(&content)
+            .map_err(|source| CodemodError::Parse { path: path.clone(), source })?;
+
+        let mut schema = original.clone();
+        if !f(&mut schema) || schema == original {
+            continue;
+        }
+
+        let mut rendered = serde_json::to_string_pretty(&schema)
+            .map_err(|source| CodemodError::Parse { path: path.clone(), source })?;
+        rendered.push('\n');
+
+        report.changed.push(path.clone());
+        pending.push((path, rendered));
+    }
+
+    if opts.dry_run || pending.is_empty() {
+        return Ok(report);
+    }
+
+    if opts.atomic {
+        write_staged(dir, &pending)?;
+    } else {
+        for (path, rendered) in &pending {
+            fs::write(path, rendered).map_err(io_err(path))?;
+        }
+    }
+
+    Ok(report)
+}
+
+/// Write all files into a sibling staging directory, then rename them into place.
+/// A failure while staging leaves the registry untouched.
+fn write_staged(dir: &Path, pending: &[(PathBuf, String)]) -> Result<(), CodemodError> {
+    let dir_name = dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let staging = dir.with_file_name(format!(".{}.codemod-staging", dir_name));
+
+    if staging.exists() {
+        fs::remove_dir_all(&staging).map_err(io_err(&staging))?;
+    }
+
+    let staged: Result<Vec<_>, CodemodError> = pending
+        .iter()
+        .enumerate()
+        .map(|(i, (path, rendered))| {
+            let staged_path = staging.join(format!("{}.json", i));
+            fs::create_dir_all(&staging).map_err(io_err(&staging))?;
+            fs::write(&staged_path, rendered).map_err(io_err(&staged_path))?;
+            Ok((staged_path, path))
+        })
+        .collect();
+
+    let staged = match staged {
+        Ok(staged) => staged,
+        Err(e) => {
+            let _ = fs::remove_dir_all(&staging);
+            return Err(e);
+        }
+    };
+
+    for (staged_path, path) in staged {
+        fs::rename(&staged_path, path).map_err(io_err(path))?;
+    }
+
+    fs::remove_dir_all(&staging).map_err(io_err(&staging))
+}
+
+fn collect_schema_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), CodemodError> {
+    for entry in fs::read_dir(dir).map_err(io_err(dir))? {
+        let path = entry.map_err(io_err(dir))?.path();
+        if path.is_dir() {
+            collect_schema_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn write_registry(dir: &Path) {
+        fs::create_dir_all(dir.join("nodes")).unwrap();
+        fs::write(dir.join("a.json"), r#"{"title":"A","type":"object"}"#).unwrap();
+        fs::write(dir.join("nodes/b.json"), r#"{"title":"B","x-familiar-kind":"node"}"#).unwrap();
+    }
+
+    #[test]
+    fn test_noop_rewrites_nothing() {
+        let tmp = tempfile::tempdir().unwrap();
+        let registry = tmp.path().join("registry");
+        write_registry(&registry);
+        let before = fs::read_to_string(registry.join("a.json")).unwrap();
+
+        let report = transform_registry(&registry, |_| false, &TransformOptions::default()).unwrap();
+
+        assert_eq!(report.scanned, 2);
+        assert!(report.changed.is_empty());
+        assert_eq!(fs::read_to_string(registry.join("a.json")).unwrap(), before);
+    }
+
+    #[test]
+    fn test_field_adding_transform_only_touches_matching_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let registry = tmp.path().join("registry");
+        write_registry(&registry);
+        let untouched = fs::read_to_string(registry.join("a.json")).unwrap();
+
+        let add_memory = |schema: &mut Value| {
+            if schema.get("x-familiar-kind") != Some(&json!("node")) {
+                return false;
+            }
+            schema["x-familiar-memory"] = json!("256Mi");
+            true
+        };
+
+        let dry = transform_registry(&registry, add_memory, &TransformOptions { dry_run: true, atomic: false }).unwrap();
+        assert_eq!(dry.changed, vec![registry.join("nodes/b.json")]);
+        assert!(!fs::read_to_string(registry.join("nodes/b.json")).unwrap().contains("x-familiar-memory"));
+
+        let report = transform_registry(&registry, add_memory, &TransformOptions { dry_run: false, atomic: true }).unwrap();
+        assert_eq!(report.changed, vec![registry.join("nodes/b.json")]);
+
+        let rewritten: Value = serde_json::from_str(&fs::read_to_string(registry.join("nodes/b.json")).unwrap()).unwrap();
+        assert_eq!(rewritten["x-familiar-memory"], json!("256Mi"));
+        assert_eq!(fs::read_to_string(registry.join("a.json")).unwrap(), untouched);
+        assert!(!tmp.path().join(".registry.codemod-staging").exists());
+    }
+}
@@ -8,6 +8,7 @@ pub mod analysis;
 pub mod patterns;
 pub mod classify;
 pub mod diagnostics;
+pub mod codemod;
 
 // Re-export key types from submodules
 pub use analysis::{
@@ -25,6 +26,9 @@ pub use classify::{
 pub use diagnostics::{
     Diagnostics, DiagnosticCode, DiagnosticItem, Severity,
 };
+pub use codemod::{
+    CodemodError, TransformOptions, TransformReport, transform_registry,
+};
 pub use loader::{
     SchemaGraph, SchemaId, SchemaNode, EdgeKind, NodeId,
     ClosureNode, SearchResult, LintWarning,
@@ -0,0 +1,394 @@
+//! Serializable compiled CEL expressions
+//!
+//! `cel_interpreter::Program` can't be serialized, so the routing table can't
+//! ship programs compiled at codegen time. Instead, xtask parses each expression
+//! with the CEL parser and lowers the AST into [`CelExpr`], a minimal
+//! serializable representation covering what routing schemas use: literals,
+//! variables, member/index access, operators, lists and the ternary.
+//!
+//! Loading a [`CompiledExpression`] is plain deserialization; the CEL parser is
+//! only ever invoked by [`CompiledExpression::compile`].
+
+use crate::{Result, RouterError};
+use cel_parser::{ArithmeticOp, Atom, Expression, Member, RelationOp, UnaryOp};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+#[cfg(test)]
+thread_local! {
+    /// Number of times the CEL parser was invoked on this thread
+    pub(crate) static COMPILE_CALLS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// A CEL expression lowered from the parser AST at codegen time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompiledExpression {
+    /// Original CEL source, kept for traces and error messages
+    pub source: String,
+
+    /// Lowered expression tree
+    pub expr: CelExpr,
+
+    /// Config slots substituted into `source` at codegen time, by slot path
+    pub config_slots: BTreeMap<String, String>,
+}
+
+/// Minimal serializable CEL AST
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CelExpr {
+    Literal(Literal),
+    Ident(String),
+    Member(Box<CelExpr>, String),
+    Index(Box<CelExpr>, Box<CelExpr>),
+    List(Vec<CelExpr>),
+    Not(Box<CelExpr>),
+    Neg(Box<CelExpr>),
+    And(Box<CelExpr>, Box<CelExpr>),
+    Or(Box<CelExpr>, Box<CelExpr>),
+    Binary(BinaryOp, Box<CelExpr>, Box<CelExpr>),
+    Ternary(Box<CelExpr>, Box<CelExpr>, Box<CelExpr>),
+}
+
+/// Literal values. Kept separate from `serde_json::Value`, which bincode can't decode.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Literal {
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Bool(bool),
+    Null,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    In,
+}
+
+impl CompiledExpression {
+    /// Parse and lower a CEL expression
+    pub fn compile(source: &str) -> Result<Self> {
+        #[cfg(test)]
+        COMPILE_CALLS.with(|calls| calls.set(calls.get() + 1));
+
+        let ast = cel_parser::parse(source)
+            .map_err(|e| RouterError::CelCompilation(format!("'{}': {}", source, e)))?;
+        let expr = lower(&ast)
+            .map_err(|e| RouterError::CelCompilation(format!("'{}': {}", source, e)))?;
+
+        Ok(Self { source: source.to_string(), expr, config_slots: BTreeMap::new() })
+    }
+
+    /// Record the config slot values that were resolved into the source
+    pub fn with_config_slots(mut self, config_slots: BTreeMap<String, String>) -> Self {
+        self.config_slots = config_slots;
+        self
+    }
+
+    /// Values of the variables and fields the expression reads, keyed by
+    /// dotted path (e.g. `node.capacity.memory_percent`). Paths that don't
+    /// resolve against `vars` are left out.
+    pub fn bindings(&self, vars: &HashMap<String, Value>) -> HashMap<String, Value> {
+        let mut bindings = HashMap::new();
+        collect_bindings(&self.expr, vars, &mut bindings);
+        bindings
+    }
+
+    /// Evaluate the expression against the given variables
+    pub fn execute(&self, vars: &HashMap<String, Value>) -> Result<Value> {
+        eval(&self.expr, vars)
+            .map_err(|e| RouterError::CelExecution(format!("'{}': {}", self.source, e)))
+    }
+}
+
+fn lower(expr: &Expression) -> std::result::Result<CelExpr, String> {
+    let boxed = |e: &Expression| lower(e).map(Box::new);
+
+    Ok(match expr {
+        Expression::Atom(atom) => CelExpr::Literal(match atom {
+            Atom::Int(i) => Literal::Int(i64::from(*i)),
+            Atom::UInt(u) => Literal::UInt(u64::from(*u)),
+            Atom::Float(f) => Literal::Float(*f),
+            Atom::String(s) => Literal::String(s.to_string()),
+            Atom::Bool(b) => Literal::Bool(*b),
+            Atom::Null => Literal::Null,
+            Atom::Bytes(_) => return Err("bytes literals are not supported".to_string()),
+        }),
+        Expression::Ident(name) => CelExpr::Ident(name.to_string()),
+        Expression::Member(target, member) => match member.as_ref() {
+            Member::Attribute(field) => CelExpr::Member(boxed(target)?, field.to_string()),
+            Member::Index(index) => CelExpr::Index(boxed(target)?, boxed(index)?),
+            Member::Fields(_) => return Err("message construction is not supported".to_string()),
+            Member::FunctionCall(_) => return Err("function calls are not supported".to_string()),
+        },
+        Expression::List(items) => CelExpr::List(items.iter().map(lower).collect::<std::result::Result<_, _>>()?),
+        Expression::Unary(op, operand) => match op {
+            UnaryOp::Not => CelExpr::Not(boxed(operand)?),
+            UnaryOp::DoubleNot => lower(operand)?,
+            UnaryOp::Minus => CelExpr::Neg(boxed(operand)?),
+            UnaryOp::DoubleMinus => lower(operand)?,
+        },
+        Expression::And(lhs, rhs) => CelExpr::And(boxed(lhs)?, boxed(rhs)?),
+        Expression::Or(lhs, rhs) => CelExpr::Or(boxed(lhs)?, boxed(rhs)?),
+        Expression::Arithmetic(lhs, op, rhs) => {
+            let op = match op {
+                ArithmeticOp::Add => BinaryOp::Add,
+                ArithmeticOp::Subtract => BinaryOp::Sub,
+                ArithmeticOp::Multiply => BinaryOp::Mul,
+                ArithmeticOp::Divide => BinaryOp::Div,
+                ArithmeticOp::Modulus => BinaryOp::Rem,
+            };
+            CelExpr::Binary(op, boxed(lhs)?, boxed(rhs)?)
+        }
+        Expression::Relation(lhs, op, rhs) => {
+            let op = match op {
+                RelationOp::Equals => BinaryOp::Eq,
+                RelationOp::NotEquals => BinaryOp::Ne,
+                RelationOp::LessThan => BinaryOp::Lt,
+                RelationOp::LessThanEq => BinaryOp::Le,
+                RelationOp::GreaterThan => BinaryOp::Gt,
+                RelationOp::GreaterThanEq => BinaryOp::Ge,
+                RelationOp::In => BinaryOp::In,
+            };
+            CelExpr::Binary(op, boxed(lhs)?, boxed(rhs)?)
+        }
+        Expression::Ternary(cond, then, otherwise) => {
+            CelExpr::Ternary(boxed(cond)?, boxed(then)?, boxed(otherwise)?)
+        }
+        _ => return Err("function calls and map literals are not supported".to_string()),
+    })
+}
+
+fn collect_bindings(expr: &CelExpr, vars: &HashMap<String, Value>, bindings: &mut HashMap<String, Value>) {
+    if let Some(path) = path(expr) {
+        if let Ok(value) = eval(expr, vars) {
+            bindings.insert(path, value);
+        }
+        return;
+    }
+
+    match expr {
+        CelExpr::Literal(_) | CelExpr::Ident(_) => {}
+        CelExpr::Member(target, _) | CelExpr::Not(target) | CelExpr::Neg(target) => {
+            collect_bindings(target, vars, bindings)
+        }
+        CelExpr::Index(lhs, rhs)
+        | CelExpr::And(lhs, rhs)
+        | CelExpr::Or(lhs, rhs)
+        | CelExpr::Binary(_, lhs, rhs) => {
+            collect_bindings(lhs, vars, bindings);
+            collect_bindings(rhs, vars, bindings);
+        }
+        CelExpr::List(items) => items.iter().for_each(|item| collect_bindings(item, vars, bindings)),
+        CelExpr::Ternary(cond, then, otherwise) => {
+            collect_bindings(cond, vars, bindings);
+            collect_bindings(then, vars, bindings);
+            collect_bindings(otherwise, vars, bindings);
+        }
+    }
+}
+
+/// Dotted path for an identifier or a chain of field accesses on one
+fn path(expr: &CelExpr) -> Option<String> {
+    match expr {
+        CelExpr::Ident(name) => Some(name.clone()),
+        CelExpr::Member(target, field) => path(target).map(|p| format!("{}.{}", p, field)),
+        _ => None,
+    }
+}
+
+fn eval(expr: &CelExpr, vars: &HashMap<String, Value>) -> std::result::Result<Value, String> {
+    match expr {
+        CelExpr::Literal(literal) => Ok(match literal {
+            Literal::Int(i) => Value::from(*i),
+            Literal::UInt(u) => Value::from(*u),
+            Literal::Float(f) => Value::from(*f),
+            Literal::String(s) => Value::from(s.as_str()),
+            Literal::Bool(b) => Value::from(*b),
+            Literal::Null => Value::Null,
+        }),
+        CelExpr::Ident(name) => vars
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("undeclared reference to '{}'", name)),
+        CelExpr::Member(target, field) => match eval(target, vars)? {
+            Value::Object(mut map) => map
+                .remove(field)
+                .ok_or_else(|| format!("no such key: {}", field)),
+            other => Err(format!("cannot access '{}' on {}", field, other)),
+        },
+        CelExpr::Index(target, index) => match (eval(target, vars)?, eval(index, vars)?) {
+            (Value::Array(items), Value::Number(n)) => n
+                .as_u64()
+                .and_then(|i| items.get(i as usize).cloned())
+                .ok_or_else(|| format!("index {} out of range", n)),
+            (Value::Object(mut map), Value::String(key)) => map
+                .remove(&key)
+                .ok_or_else(|| format!("no such key: {}", key)),
+            (target, index) => Err(format!("cannot index {} with {}", target, index)),
+        },
+        CelExpr::List(items) => items
+            .iter()
+            .map(|item| eval(item, vars))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map(Value::Array),
+        CelExpr::Not(operand) => Ok(Value::Bool(!as_bool(&eval(operand, vars)?)?)),
+        CelExpr::Neg(operand) => match eval(operand, vars)? {
+            Value::Number(n) if n.is_i64() => Ok(Value::from(-n.as_i64().unwrap())),
+            Value::Number(n) => Ok(Value::from(-n.as_f64().unwrap_or_default())),
+            other => Err(format!("cannot negate {}", other)),
+        },
+        CelExpr::And(lhs, rhs) => {
+            if !as_bool(&eval(lhs, vars)?)? {
+                return Ok(Value::Bool(false));
+            }
+            Ok(Value::Bool(as_bool(&eval(rhs, vars)?)?))
+        }
+        CelExpr::Or(lhs, rhs) => {
+            if as_bool(&eval(lhs, vars)?)? {
+                return Ok(Value::Bool(true));
+            }
+            Ok(Value::Bool(as_bool(&eval(rhs, vars)?)?))
+        }
+        CelExpr::Ternary(cond, then, otherwise) => {
+            if as_bool(&eval(cond, vars)?)? {
+                eval(then, vars)
+            } else {
+                eval(otherwise, vars)
+            }
+        }
+        CelExpr::Binary(op, lhs, rhs) => binary(*op, eval(lhs, vars)?, eval(rhs, vars)?),
+    }
+}
+
+fn as_bool(value: &Value) -> std::result::Result<bool, String> {
+    value.as_bool().ok_or_else(|| format!("expected bool, got {}", value))
+}
+
+fn binary(op: BinaryOp, lhs: Value, rhs: Value) -> std::result::Result<Value, String> {
+    use std::cmp::Ordering;
+
+    let ordering = |lhs: &Value, rhs: &Value| match (lhs, rhs) {
+        (Value::Number(a), Value::Number(b)) => a.as_f64().zip(b.as_f64()).and_then(|(a, b)| a.partial_cmp(&b)),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        _ => None,
+    };
+
+    match op {
+        BinaryOp::Eq => Ok(Value::Bool(ordering(&lhs, &rhs).map_or(lhs == rhs, |o| o == Ordering::Equal))),
+        BinaryOp::Ne => Ok(Value::Bool(ordering(&lhs, &rhs).map_or(lhs != rhs, |o| o != Ordering::Equal))),
+        BinaryOp::Lt | BinaryOp::Le | BinaryOp::Gt | BinaryOp::Ge => {
+            let o = ordering(&lhs, &rhs).ok_or_else(|| format!("cannot compare {} and {}", lhs, rhs))?;
+            Ok(Value::Bool(match op {
+                BinaryOp::Lt => o == Ordering::Less,
+                BinaryOp::Le => o != Ordering::Greater,
+                BinaryOp::Gt => o == Ordering::Greater,
+                _ => o != Ordering::Less,
+            }))
+        }
+        BinaryOp::In => match rhs {
+            Value::Array(items) => Ok(Value::Bool(
+                items.iter().any(|item| ordering(&lhs, item).map_or(&lhs == item, |o| o == Ordering::Equal)),
+            )),
+            Value::Object(map) => match lhs {
+                Value::String(key) => Ok(Value::Bool(map.contains_key(&key))),
+                other => Err(format!("map keys must be strings, got {}", other)),
+            },
+            other => Err(format!("'in' requires a list or map, got {}", other)),
+        },
+        BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Rem => {
+            match (lhs, rhs) {
+                (Value::String(a), Value::String(b)) if op == BinaryOp::Add => Ok(Value::String(a + &b)),
+                (Value::Array(mut a), Value::Array(b)) if op == BinaryOp::Add => {
+                    a.extend(b);
+                    Ok(Value::Array(a))
+                }
+                (Value::Number(a), Value::Number(b)) => arithmetic(op, &a, &b),
+                (a, b) => Err(format!("unsupported operands {} and {}", a, b)),
+            }
+        }
+    }
+}
+
+fn arithmetic(op: BinaryOp, a: &serde_json::Number, b: &serde_json::Number) -> std::result::Result<Value, String> {
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        let result = match op {
+            BinaryOp::Add => a.checked_add(b),
+            BinaryOp::Sub => a.checked_sub(b),
+            BinaryOp::Mul => a.checked_mul(b),
+            BinaryOp::Div => a.checked_div(b),
+            _ => a.checked_rem(b),
+        };
+        return result
+            .map(Value::from)
+            .ok_or_else(|| "integer overflow or division by zero".to_string());
+    }
+
+    let (a, b) = (a.as_f64().unwrap_or_default(), b.as_f64().unwrap_or_default());
+    Ok(Value::from(match op {
+        BinaryOp::Add => a + b,
+        BinaryOp::Sub => a - b,
+        BinaryOp::Mul => a * b,
+        BinaryOp::Div => a / b,
+        _ => a % b,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compiled_expression_roundtrips_through_bincode() {
+        let compiled = CompiledExpression::compile("input.urgency == 'high' ? 'gpu-pool' : 'cpu-pool'").unwrap();
+        let bytes = bincode::serialize(&compiled).unwrap();
+        let decoded: CompiledExpression = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(decoded, compiled);
+
+        let mut vars = HashMap::new();
+        vars.insert("input".to_string(), json!({ "urgency": "high" }));
+        assert_eq!(decoded.execute(&vars).unwrap(), json!("gpu-pool"));
+
+        vars.insert("input".to_string(), json!({ "urgency": "low" }));
+        assert_eq!(decoded.execute(&vars).unwrap(), json!("cpu-pool"));
+    }
+
+    #[test]
+    fn test_numeric_and_logical_operators() {
+        let compiled = CompiledExpression::compile("input.complexity > 0.8 && input.retries + 1 <= 3").unwrap();
+
+        let mut vars = HashMap::new();
+        vars.insert("input".to_string(), json!({ "complexity": 0.9, "retries": 2 }));
+        assert_eq!(compiled.execute(&vars).unwrap(), json!(true));
+
+        vars.insert("input".to_string(), json!({ "complexity": 0.9, "retries": 3 }));
+        assert_eq!(compiled.execute(&vars).unwrap(), json!(false));
+
+        assert!(compiled.execute(&HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_bindings() {
+        let compiled = CompiledExpression::compile("input.complexity > 0.8 && input.missing == 1").unwrap();
+        let mut vars = HashMap::new();
+        vars.insert("input".to_string(), json!({ "complexity": 0.9 }));
+
+        let bindings = compiled.bindings(&vars);
+        assert_eq!(bindings.len(), 1);
+        assert_eq!(bindings["input.complexity"], json!(0.9));
+    }
+}
@@ -33,6 +33,11 @@ pub struct RoutingRequest {
     /// Request type (weave, search, classify, etc.)
     pub r#type: String,
 
+    /// System the request is dispatched to (a system schema title such as
+    /// `CoreRouter`); selects that system's node constraints in the routing table
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_system: Option<String>,
+
     /// Execution priority level
     pub priority: Priority,
 
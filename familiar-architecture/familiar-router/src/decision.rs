@@ -1,7 +1,9 @@
 //! Routing decision definitions
 
 use crate::context::{Priority, NodeLocation};
+use crate::trace::NodeEvaluation;
 use serde::{Deserialize, Serialize};
+use std::fmt::Write;
 
 /// The output of the routing engine specifying where and how to execute a request
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -113,6 +115,46 @@ pub struct RoutingMetadata {
 
     /// List of constraints that were violated but overridden
     pub constraints_violated: Vec<String>,
+
+    /// Per-node constraint results, when the router collects them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub node_evaluations: Vec<NodeEvaluation>,
+}
+
+impl RoutingDecision {
+    /// Render a human-readable account of why this node was chosen.
+    ///
+    /// Per-node detail is only present when the router was built with
+    /// `with_constraint_trace(true)`.
+    pub fn explain(&self) -> String {
+        let mut out = format!(
+            "request {} routed to {} (confidence {:.2})",
+            self.request_id, self.target_node.id, self.confidence_score
+        );
+
+        let evaluations = self
+            .routing_metadata
+            .as_ref()
+            .map(|metadata| metadata.node_evaluations.as_slice())
+            .unwrap_or_default();
+        if evaluations.is_empty() {
+            out.push_str("\n  (no constraint trace collected)");
+            return out;
+        }
+
+        for evaluation in evaluations {
+            if evaluation.accepted {
+                let _ = write!(out, "\n  node {} accepted", evaluation.node_id);
+                for constraint in &evaluation.constraints {
+                    let _ = write!(out, "\n    {}", constraint);
+                }
+            } else if let Some(rejecting) = evaluation.constraints.last() {
+                let _ = write!(out, "\n  node {} rejected: {}", evaluation.node_id, rejecting);
+            }
+        }
+
+        out
+    }
 }
 
 impl Default for ExecutionParameters {
@@ -133,6 +175,7 @@ impl Default for RoutingMetadata {
             evaluation_time_ms: 0.0,
             constraints_satisfied: vec![],
             constraints_violated: vec![],
+            node_evaluations: vec![],
         }
     }
 }
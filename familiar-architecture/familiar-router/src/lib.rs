@@ -15,13 +15,15 @@
 //!
 //! Current status: Solid foundation with proper config integration.
 
+pub mod compiled;
 pub mod context;
 pub mod decision;
 pub mod trace;
 pub mod router;
 pub mod telemetry;
+pub mod routing_table;
 
-// Re-export the generated routing table
+// Serialized routing table generated by xtask
 include!("generated_routing_table.rs");
 
 pub use router::Router;
@@ -29,6 +31,8 @@ pub use context::RoutingContext;
 pub use decision::RoutingDecision;
 pub use trace::RoutingTrace;
 pub use telemetry::TelemetryProvider;
+pub use routing_table::{RoutingTable, RoutingTableSource};
+pub use compiled::CompiledExpression;
 
 /// Result type for routing operations
 pub type Result<T> = std::result::Result<T, RouterError>;
@@ -42,6 +46,12 @@ pub enum RouterError {
     #[error("CEL parsing failed: {0}")]
     CelParsing(#[from] cel_interpreter::ParseError),
 
+    #[error("CEL compilation failed: {0}")]
+    CelCompilation(String),
+
+    #[error("CEL execution failed: {0}")]
+    CelExecution(String),
+
     #[error("Schema validation failed: {0}")]
     SchemaValidation(String),
 
@@ -54,6 +64,9 @@ pub enum RouterError {
     #[error("Routing table not loaded")]
     RoutingTableNotLoaded,
 
+    #[error("Routing table could not be decoded: {0}")]
+    RoutingTableDecode(String),
+
     #[error("No suitable nodes found for request")]
     NoSuitableNodes,
 
@@ -9,10 +9,14 @@
 //! - Dynamic routing policy compilation
 //! - Advanced load balancing algorithms
 
-use crate::{RoutingContext, RoutingDecision, RoutingTrace, RouterError, Result};
+use crate::{RoutingContext, RoutingDecision, RoutingTable, RoutingTrace, RouterError, Result};
+use crate::context::{NodeState, NodeStatus};
 use crate::telemetry::TelemetryProvider;
+use crate::trace::{CelExpressionResult, NodeEvaluation};
 use cel_interpreter::Context as CelContext;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, PoisonError, RwLock};
 use std::time::{Duration, Instant};
 use tracing::info;
 
@@ -26,6 +30,9 @@ pub struct Router {
 
     /// Performance tuning parameters
     config: RouterConfig,
+
+    /// Compiled routing table; swapped wholesale by [`Router::reload_table`]
+    table: RwLock<Arc<RoutingTable>>,
 }
 
 #[derive(Debug, Clone)]
@@ -33,6 +40,8 @@ pub struct RouterConfig {
     pub max_evaluation_time: Duration,
     pub enable_fallback_routing: bool,
     pub default_confidence_threshold: f64,
+    /// Record every node's constraint results on the decision (for `RoutingDecision::explain`)
+    pub collect_constraint_trace: bool,
 }
 
 impl Default for RouterConfig {
@@ -41,12 +50,14 @@ impl Default for RouterConfig {
             max_evaluation_time: Duration::from_millis(100),
             enable_fallback_routing: true,
             default_confidence_threshold: 0.7,
+            collect_constraint_trace: false,
         }
     }
 }
 
 impl Router {
-    /// Create a new router with the given telemetry provider
+    /// Create a new router with the given telemetry provider, using the
+    /// routing table embedded at build time
     pub fn new(telemetry: Arc<dyn TelemetryProvider>) -> Result<Self> {
         let context_schema = Self::load_context_schema()?;
 
@@ -54,9 +65,40 @@ impl Router {
             telemetry,
             context_schema,
             config: RouterConfig::default(),
+            table: RwLock::new(Arc::new(RoutingTable::load()?)),
         })
     }
 
+    /// Enable or disable per-node constraint tracing
+    pub fn with_constraint_trace(mut self, enabled: bool) -> Self {
+        self.config.collect_constraint_trace = enabled;
+        self
+    }
+
+    /// Create a router whose routing table is compiled from a table source file
+    pub fn from_table_file(
+        path: impl AsRef<Path>,
+        telemetry: Arc<dyn TelemetryProvider>,
+    ) -> Result<Self> {
+        let router = Self::new(telemetry)?;
+        router.reload_table(RoutingTable::from_file(path)?);
+        Ok(router)
+    }
+
+    /// Atomically replace the routing table.
+    ///
+    /// Requests already being routed finish against the table they started
+    /// with; requests that start afterwards see only the new one.
+    pub fn reload_table(&self, new_table: RoutingTable) {
+        *self.table.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(new_table);
+        info!("Routing table reloaded");
+    }
+
+    /// The routing table new requests are evaluated against
+    pub fn table(&self) -> Arc<RoutingTable> {
+        self.table.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
     /// Route a request based on the current system state
     pub async fn route_request(
         &self,
@@ -65,6 +107,10 @@ impl Router {
         let start_time = Instant::now();
         let mut trace = RoutingTrace::new(context);
 
+        // Pin the table for the whole evaluation so a concurrent reload can't
+        // mix constraints from two tables
+        let table = self.table();
+
         // Validate input schema
         self.validate_context(context)?;
 
@@ -75,11 +121,12 @@ impl Router {
         let cel_context = self.build_cel_context(context, &telemetry_snapshot)?;
 
         // Evaluate routing policies
-        let decision = self.evaluate_policies(context, &cel_context, &mut trace).await?;
+        let decision = self.evaluate_policies(&table, context, &cel_context, &mut trace)?;
 
         // Record performance metrics
         let evaluation_time = start_time.elapsed();
         trace.performance_metrics.total_evaluation_time_ms = evaluation_time.as_millis() as f64;
+        trace.decision = decision.clone();
 
         info!(
             request_id = %context.request.id,
@@ -117,25 +164,37 @@ impl Router {
     }
 
     /// Evaluate all routing policies and constraints to make a decision
-    async fn evaluate_policies<'a>(
+    fn evaluate_policies<'a>(
         &self,
+        table: &RoutingTable,
         context: &RoutingContext,
         cel_context: &CelContext<'a>,
         trace: &mut RoutingTrace,
     ) -> Result<RoutingDecision> {
         let mut candidates = Vec::new();
-
-        // Evaluate node constraints for each available node
-        for (node_id, _node_info) in &context.nodes {
-            let node_score = self.evaluate_node_constraints(node_id, cel_context, trace)?;
-            if node_score > 0.0 {
-                candidates.push((node_id.clone(), node_score));
+        let mut evaluations = Vec::new();
+        let request = serde_json::to_value(&context.request)?;
+        let system_id = context.request.target_system.as_deref();
+
+        // Evaluate the target system's node constraints against each available node.
+        // Schema expressions name the request `input`; `request` is kept as an alias.
+        for (node_id, node_info) in &context.nodes {
+            let vars = HashMap::from([
+                ("input".to_string(), request.clone()),
+                ("request".to_string(), request.clone()),
+                ("node".to_string(), serde_json::to_value(node_info)?),
+            ]);
+            if self.evaluate_node_constraints(table, system_id, node_id, &vars, cel_context, &mut evaluations)? {
+                candidates.push((node_id.clone(), node_score(node_info)));
             }
         }
 
+        trace.performance_metrics.nodes_evaluated = context.nodes.len();
+
         if candidates.is_empty() {
             return Err(RouterError::NoSuitableNodes);
         }
+        evaluations.sort_by(|a, b| a.node_id.cmp(&b.node_id));
 
         // Sort by score (highest first)
         candidates.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
@@ -170,33 +229,52 @@ impl Router {
                 evaluation_time_ms: 0.0, // Will be set by caller
                 constraints_satisfied: vec!["node_capacity".to_string()],
                 constraints_violated: vec![],
+                node_evaluations: evaluations,
             }),
         };
 
         Ok(decision)
     }
 
-    /// Evaluate constraints for a specific node
+    /// Evaluate the target system's constraints against a specific node
+    ///
+    /// The table keys node constraints by system id, as `cargo xtask
+    /// generate-routing-table` builds it. A node is rejected unless every
+    /// constraint of `system_id` evaluates to `true`; with no target system,
+    /// or one without constraints, every node passes. With constraint tracing
+    /// on, the results are appended to `evaluations`.
     fn evaluate_node_constraints<'a>(
         &self,
-        _node_id: &str,
+        table: &RoutingTable,
+        system_id: Option<&str>,
+        node_id: &str,
+        vars: &HashMap<String, serde_json::Value>,
         _cel_context: &CelContext<'a>,
-        _trace: &mut RoutingTrace,
-    ) -> Result<f64> {
-        // For now, return a simple score based on availability
-        // In a full implementation, this would evaluate all CEL constraints
-        // from the routing table for this node
-
-        // TODO: Use the generated routing table to evaluate node constraints
-        // let constraints = self.routing_table.node_constraints.get(node_id);
-        // if let Some(constraints) = constraints {
-        //     for program in constraints {
-        //         let result = program.execute(cel_context)?;
-        //         // Evaluate result and update score
-        //     }
-        // }
-
-        Ok(0.8) // Placeholder score
+        evaluations: &mut Vec<NodeEvaluation>,
+    ) -> Result<bool> {
+        let mut evaluation = NodeEvaluation {
+            node_id: node_id.to_string(),
+            accepted: true,
+            constraints: Vec::new(),
+        };
+
+        let constraints = system_id.and_then(|id| table.node_constraints.get(id));
+        for constraint in constraints.into_iter().flatten() {
+            let result = constraint.execute(vars);
+            if self.config.collect_constraint_trace {
+                evaluation.constraints.push(CelExpressionResult::from_evaluation(constraint, vars, &result));
+            }
+            if result? != serde_json::Value::Bool(true) {
+                evaluation.accepted = false;
+                break;
+            }
+        }
+
+        let accepted = evaluation.accepted;
+        if self.config.collect_constraint_trace {
+            evaluations.push(evaluation);
+        }
+        Ok(accepted)
     }
 
     /// Select the most appropriate system for the given node
@@ -205,10 +283,14 @@ impl Router {
         _node_id: &str,
         context: &RoutingContext,
     ) -> crate::decision::TargetSystem {
-        // For now, select the first available system
-        // In a full implementation, this would evaluate system-specific constraints
-
-        if let Some((system_id, _system_info)) = context.systems.iter().next() {
+        // The request names its system; otherwise select the first available one
+        if let Some(system_id) = &context.request.target_system {
+            crate::decision::TargetSystem {
+                id: system_id.clone(),
+                r#type: "generic".to_string(), // Placeholder
+                capabilities: vec!["routing".to_string()], // Placeholder
+            }
+        } else if let Some((system_id, _system_info)) = context.systems.iter().next() {
             crate::decision::TargetSystem {
                 id: system_id.clone(),
                 r#type: "generic".to_string(), // Placeholder
@@ -254,3 +336,253 @@ impl Router {
             .map_err(|e| RouterError::Configuration(format!("Schema compilation failed: {}", e)))
     }
 }
+
+/// Rank a node that passed its constraints, in (0, 1]; higher is better
+///
+/// Scales the headroom of the node's busiest resource (CPU, memory or GPU
+/// memory) down by its queue backlog and health. Every eligible node keeps a
+/// positive score, so an unhealthy one still serves when nothing else can.
+fn node_score(node: &NodeState) -> f64 {
+    let capacity = &node.capacity;
+    let utilization = [Some(capacity.cpu_percent), Some(capacity.memory_percent), capacity.gpu_memory_percent]
+        .into_iter()
+        .flatten()
+        .fold(0.0, f64::max)
+        .clamp(0.0, 100.0)
+        / 100.0;
+    let backlog = 1.0 / (1.0 + capacity.queue_depth as f64);
+    let health = match node.status {
+        NodeStatus::Healthy => 1.0,
+        NodeStatus::Degraded => 0.5,
+        NodeStatus::Unhealthy | NodeStatus::Offline => 0.1,
+    };
+    health * (0.1 + 0.9 * (1.0 - utilization) * backlog)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{
+        GlobalConfigSnapshot, NodeCapacity, NodeState, NodeStatus, Priority, RoutingRequest,
+    };
+    use crate::telemetry::MockTelemetryProvider;
+    use crate::{CompiledExpression, RoutingTableSource};
+
+    /// System the test requests are dispatched to
+    const SYSTEM: &str = "CoreRouter";
+
+    /// Nodes at increasing CPU load: the first at 40%, the next at 60%, ...
+    fn context(node_ids: &[&str]) -> RoutingContext {
+        let node = |cpu_percent| NodeState {
+            status: NodeStatus::Healthy,
+            capacity: NodeCapacity {
+                cpu_percent,
+                memory_percent: 50.0,
+                gpu_memory_percent: None,
+                active_tasks: 1,
+                queue_depth: 0,
+            },
+            specializations: vec![],
+            location: None,
+        };
+
+        RoutingContext {
+            timestamp: chrono::Utc::now(),
+            request: RoutingRequest {
+                id: "req-1".to_string(),
+                tenant_id: "tenant-1".to_string(),
+                r#type: "weave".to_string(),
+                target_system: Some(SYSTEM.to_string()),
+                priority: Priority::Normal,
+                payload_size_bytes: None,
+                estimated_complexity: None,
+                user_context: None,
+            },
+            nodes: node_ids
+                .iter()
+                .enumerate()
+                .map(|(i, id)| (id.to_string(), node(40.0 + 20.0 * i as f64)))
+                .collect(),
+            systems: HashMap::new(),
+            global_config: GlobalConfigSnapshot {
+                routing: None,
+                load_balancing: None,
+            },
+        }
+    }
+
+    /// Table holding `constraints` for [`SYSTEM`], keyed by system id the
+    /// way `cargo xtask generate-routing-table` builds it
+    fn system_table(constraints: &[&str]) -> RoutingTable {
+        let mut source = RoutingTableSource::default();
+        source.node_constraints.insert(
+            SYSTEM.to_string(),
+            constraints.iter().map(|c| c.to_string()).collect(),
+        );
+        source.compile().unwrap()
+    }
+
+    /// Table that only lets work through to `allowed` (node-a at 40% CPU, node-b at 60%)
+    fn table_allowing(allowed: &str) -> RoutingTable {
+        match allowed {
+            "node-a" => system_table(&["node.capacity.cpu_percent < 50.0"]),
+            _ => system_table(&["node.capacity.cpu_percent > 50.0"]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_constraints_keyed_by_target_system() {
+        let router = Router::new(Arc::new(MockTelemetryProvider)).unwrap();
+        router.reload_table(table_allowing("node-b"));
+
+        let mut ctx = context(&["node-a", "node-b"]);
+        let (decision, _) = router.route_request(&ctx).await.unwrap();
+        assert_eq!(decision.target_node.id, "node-b");
+        assert_eq!(decision.target_system.id, SYSTEM);
+
+        // Another system's constraints don't apply
+        ctx.request.target_system = Some("FatesGate".to_string());
+        ctx.nodes.remove("node-b");
+        let (decision, _) = router.route_request(&ctx).await.unwrap();
+        assert_eq!(decision.target_node.id, "node-a");
+    }
+
+    #[tokio::test]
+    async fn test_constraints_see_request_as_input() {
+        let router = Router::new(Arc::new(MockTelemetryProvider)).unwrap();
+        let mut ctx = context(&["node-a"]);
+
+        router.reload_table(system_table(&["input.priority == 'normal'"]));
+        assert!(router.route_request(&ctx).await.is_ok());
+
+        ctx.request.priority = Priority::Low;
+        assert!(matches!(router.route_request(&ctx).await, Err(RouterError::NoSuitableNodes)));
+    }
+
+    #[tokio::test]
+    async fn test_reload_changes_decision() {
+        let router = Router::new(Arc::new(MockTelemetryProvider)).unwrap();
+        let ctx = context(&["node-a", "node-b"]);
+
+        router.reload_table(table_allowing("node-a"));
+        let in_flight = router.table();
+        let (decision, _) = router.route_request(&ctx).await.unwrap();
+        assert_eq!(decision.target_node.id, "node-a");
+
+        router.reload_table(table_allowing("node-b"));
+        let (decision, _) = router.route_request(&ctx).await.unwrap();
+        assert_eq!(decision.target_node.id, "node-b");
+
+        // A table pinned before the reload is unaffected by it
+        assert_eq!(*in_flight, table_allowing("node-a"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_reload_is_atomic() {
+        let router = Arc::new(Router::new(Arc::new(MockTelemetryProvider)).unwrap());
+        let tables = [table_allowing("node-a"), table_allowing("node-b")];
+        router.reload_table(tables[0].clone());
+
+        let reloader = {
+            let router = router.clone();
+            tokio::spawn(async move {
+                for i in 0..200 {
+                    router.reload_table(tables[i % 2].clone());
+                    tokio::task::yield_now().await;
+                }
+            })
+        };
+
+        // A request mixing the two tables could see both nodes rejected
+        let routes: Vec<_> = (0..200)
+            .map(|_| {
+                let router = router.clone();
+                // RouterError can hold a CEL error, which isn't Send
+                tokio::spawn(async move {
+                    router
+                        .route_request(&context(&["node-a", "node-b"]))
+                        .await
+                        .map(|(decision, _)| decision.target_node.id)
+                        .map_err(|e| e.to_string())
+                })
+            })
+            .collect();
+
+        for route in routes {
+            let target = route.await.unwrap().unwrap();
+            assert!(["node-a", "node-b"].contains(&target.as_str()));
+        }
+        reloader.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_explain_names_rejecting_constraint() {
+        let mut table = RoutingTable::default();
+        table.node_constraints.insert(SYSTEM.to_string(), vec![
+            CompiledExpression::compile("node.capacity.memory_percent < 80")
+                .unwrap()
+                .with_config_slots([("nodes.node_b.constraints.memory_threshold".to_string(), "80".to_string())].into()),
+        ]);
+        let mut ctx = context(&["node-a", "node-b"]);
+        ctx.nodes.get_mut("node-b").unwrap().capacity.memory_percent = 91.0;
+
+        let router = Router::new(Arc::new(MockTelemetryProvider)).unwrap().with_constraint_trace(true);
+        router.reload_table(table.clone());
+        let (decision, trace) = router.route_request(&ctx).await.unwrap();
+
+        let explanation = decision.explain();
+        assert!(explanation.contains("routed to node-a"), "{}", explanation);
+        assert!(explanation.contains("node node-a accepted"), "{}", explanation);
+        assert!(
+            explanation.contains(
+                "node node-b rejected: node.capacity.memory_percent < 80 => false \
+                 (config:nodes.node_b.constraints.memory_threshold = 80, node.capacity.memory_percent = 91.0)"
+            ),
+            "{}",
+            explanation
+        );
+        assert_eq!(trace.decision.explain(), explanation);
+
+        // Tracing off: same decision, no per-node detail
+        let router = Router::new(Arc::new(MockTelemetryProvider)).unwrap();
+        router.reload_table(table);
+        let (decision, _) = router.route_request(&ctx).await.unwrap();
+        assert_eq!(decision.target_node.id, "node-a");
+        assert!(decision.explain().contains("no constraint trace collected"));
+    }
+
+    #[tokio::test]
+    async fn test_eligible_nodes_ranked_by_load_and_health() {
+        let router = Router::new(Arc::new(MockTelemetryProvider)).unwrap();
+        router.reload_table(system_table(&[]));
+        let mut ctx = context(&["node-a", "node-b"]);
+
+        // Both pass; node-a at 40% CPU outranks node-b at 60%
+        let (decision, _) = router.route_request(&ctx).await.unwrap();
+        assert_eq!(decision.target_node.id, "node-a");
+        let scores = [node_score(&ctx.nodes["node-a"]), node_score(&ctx.nodes["node-b"])];
+        assert!(scores[0] > scores[1], "{:?}", scores);
+        assert_eq!(decision.confidence_score, scores[0]);
+
+        // A backlog or degraded health costs node-a the lead
+        ctx.nodes.get_mut("node-a").unwrap().capacity.queue_depth = 4;
+        let (decision, _) = router.route_request(&ctx).await.unwrap();
+        assert_eq!(decision.target_node.id, "node-b");
+
+        ctx.nodes.get_mut("node-a").unwrap().capacity.queue_depth = 0;
+        ctx.nodes.get_mut("node-a").unwrap().status = NodeStatus::Degraded;
+        let (decision, _) = router.route_request(&ctx).await.unwrap();
+        assert_eq!(decision.target_node.id, "node-b");
+    }
+
+    #[tokio::test]
+    async fn test_from_table_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("routing.json");
+        std::fs::write(&path, r#"{ "node_constraints": { "CoreRouter": ["node.capacity.cpu_percent > 50.0"] } }"#).unwrap();
+
+        let router = Router::from_table_file(&path, Arc::new(MockTelemetryProvider)).unwrap();
+        let (decision, _) = router.route_request(&context(&["node-a", "node-b"])).await.unwrap();
+        assert_eq!(decision.target_node.id, "node-b");
+    }
+}
@@ -0,0 +1,146 @@
+//! Routing table of CEL expressions extracted from system schemas
+//!
+//! The table is built and serialized by `cargo xtask generate-routing-table`
+//! and embedded as [`ROUTING_TABLE_BYTES`](crate::ROUTING_TABLE_BYTES), so
+//! loading it at startup is a bincode decode with no CEL parsing.
+//!
+//! Operators can also keep a [`RoutingTableSource`] on disk and compile it at
+//! runtime with [`RoutingTable::from_file`], e.g. to hot-reload a running router.
+
+use crate::compiled::CompiledExpression;
+use crate::{Result, RouterError};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Compiled routing expressions keyed by system id.
+///
+/// `BTreeMap`s keep the serialized table byte-for-byte reproducible.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoutingTable {
+    pub node_constraints: BTreeMap<String, Vec<CompiledExpression>>,
+    pub routing_policies: BTreeMap<String, Vec<CompiledExpression>>,
+    pub timeout_constraints: BTreeMap<String, CompiledExpression>,
+}
+
+impl RoutingTable {
+    /// Load the routing table generated at build time
+    pub fn load() -> Result<Self> {
+        Self::from_bytes(crate::ROUTING_TABLE_BYTES)
+    }
+
+    /// Decode a table serialized with [`RoutingTable::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode::deserialize(bytes).map_err(|e| RouterError::RoutingTableDecode(e.to_string()))
+    }
+
+    /// Serialize the table for embedding
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        bincode::serialize(self).map_err(|e| RouterError::RoutingTableDecode(e.to_string()))
+    }
+
+    /// Read a JSON [`RoutingTableSource`] and compile it
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            RouterError::Configuration(format!("Failed to read routing table {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str::<RoutingTableSource>(&json)?.compile()
+    }
+}
+
+/// Uncompiled routing table: CEL source keyed the same way as [`RoutingTable`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoutingTableSource {
+    #[serde(default)]
+    pub node_constraints: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub routing_policies: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub timeout_constraints: BTreeMap<String, String>,
+}
+
+impl RoutingTableSource {
+    /// Compile every expression, failing on the first invalid one
+    pub fn compile(&self) -> Result<RoutingTable> {
+        let compile_all = |exprs: &BTreeMap<String, Vec<String>>| {
+            exprs
+                .iter()
+                .map(|(id, sources)| {
+                    let compiled = sources
+                        .iter()
+                        .map(|source| CompiledExpression::compile(source))
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok((id.clone(), compiled))
+                })
+                .collect::<Result<BTreeMap<_, _>>>()
+        };
+
+        Ok(RoutingTable {
+            node_constraints: compile_all(&self.node_constraints)?,
+            routing_policies: compile_all(&self.routing_policies)?,
+            timeout_constraints: self
+                .timeout_constraints
+                .iter()
+                .map(|(id, source)| Ok((id.clone(), CompiledExpression::compile(source)?)))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiled::COMPILE_CALLS;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_load_does_not_invoke_cel_parser() {
+        let before = COMPILE_CALLS.with(|calls| calls.get());
+        let table = RoutingTable::load().unwrap();
+        assert_eq!(COMPILE_CALLS.with(|calls| calls.get()), before);
+
+        let policy = &table.routing_policies["Fates Gate"][0];
+        let mut vars = HashMap::new();
+        vars.insert("input".to_string(), json!({ "content_length": 20000 }));
+        assert_eq!(policy.execute(&vars).unwrap(), json!("high-memory-pool"));
+
+        // config:routing.decision_timeout_ms, resolved from the GlobalConfig default
+        let timeout = &table.timeout_constraints["CoreRouter"];
+        assert_eq!(timeout.execute(&HashMap::new()).unwrap(), json!(5000));
+        assert_eq!(timeout.config_slots["routing.decision_timeout_ms"], "5000");
+
+        // Node constraints are keyed by the system whose default_node declares them
+        let fates_gate: Vec<_> = table.node_constraints["Fates Gate"].iter().map(|c| c.source.as_str()).collect();
+        assert!(fates_gate.contains(&"node.cpu_usage < 0.8"), "{:?}", fates_gate);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut table = RoutingTable::default();
+        table
+            .routing_policies
+            .entry("Test System".to_string())
+            .or_default()
+            .push(CompiledExpression::compile("'cpu-pool'").unwrap());
+
+        let decoded = RoutingTable::from_bytes(&table.to_bytes().unwrap()).unwrap();
+        assert_eq!(decoded, table);
+        assert!(RoutingTable::from_bytes(&[0xff]).is_err());
+    }
+
+    #[test]
+    fn test_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("routing.json");
+        std::fs::write(&path, r#"{ "node_constraints": { "CoreRouter": ["node.capacity.cpu_percent < 90.0"] } }"#).unwrap();
+
+        let table = RoutingTable::from_file(&path).unwrap();
+        assert_eq!(table.node_constraints["CoreRouter"][0].source, "node.capacity.cpu_percent < 90.0");
+        assert!(table.routing_policies.is_empty());
+
+        std::fs::write(&path, r#"{ "node_constraints": { "CoreRouter": ["node.capacity <"] } }"#).unwrap();
+        assert!(matches!(RoutingTable::from_file(&path), Err(RouterError::CelCompilation(_))));
+    }
+}
@@ -1,8 +1,9 @@
 //! Routing trace definitions for audit logging
 
-use crate::{RoutingContext, RoutingDecision};
+use crate::{CompiledExpression, Result, RoutingContext, RoutingDecision};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 
 /// Complete audit trail of a routing decision for debugging and analytics
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +106,60 @@ pub struct CelExpressionResult {
 
     /// Error message if evaluation failed
     pub error_message: Option<String>,
+
+    /// Config slot values resolved into the expression at codegen time
+    #[serde(default)]
+    pub config_slots: BTreeMap<String, String>,
+}
+
+impl CelExpressionResult {
+    /// Capture the outcome of evaluating a compiled expression
+    pub fn from_evaluation(
+        expression: &CompiledExpression,
+        vars: &HashMap<String, serde_json::Value>,
+        result: &Result<serde_json::Value>,
+    ) -> Self {
+        Self {
+            expression: expression.source.clone(),
+            context_values: expression.bindings(vars),
+            result: result.as_ref().cloned().unwrap_or(serde_json::Value::Null),
+            success: result.is_ok(),
+            error_message: result.as_ref().err().map(|e| e.to_string()),
+            config_slots: expression.config_slots.clone(),
+        }
+    }
+}
+
+/// Renders as e.g. `node.capacity.memory_percent < 80.0 => false (node.capacity.memory_percent = 91.0)`
+impl fmt::Display for CelExpressionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.error_message {
+            Some(error) => write!(f, "{} failed: {}", self.expression, error)?,
+            None => write!(f, "{} => {}", self.expression, self.result)?,
+        }
+
+        let mut values: Vec<_> = self.context_values.iter().map(|(path, value)| format!("{} = {}", path, value)).collect();
+        values.sort();
+        let slots = self.config_slots.iter().map(|(slot, value)| format!("config:{} = {}", slot, value));
+        let bindings: Vec<_> = slots.chain(values).collect();
+        if !bindings.is_empty() {
+            write!(f, " ({})", bindings.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Constraint results for one candidate node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeEvaluation {
+    /// Node the constraints were evaluated for
+    pub node_id: String,
+
+    /// Whether the node passed every constraint
+    pub accepted: bool,
+
+    /// Constraints in evaluation order; a rejected node's last entry is the one that rejected it
+    pub constraints: Vec<CelExpressionResult>,
 }
 
 /// Performance characteristics of the routing evaluation
@@ -128,8 +128,17 @@ fn validate_cel_expression(
 
 /// Resolve config slot references in CEL expressions using the real config system
 fn resolve_config_slots_in_expression(expression: &str, config: &familiar_config::GlobalConfig) -> Result<String, String> {
+    resolve_config_slots(expression, config).map(|(resolved, _)| resolved)
+}
+
+/// Resolve config slots, also returning the value substituted for each slot
+fn resolve_config_slots(
+    expression: &str,
+    config: &familiar_config::GlobalConfig,
+) -> Result<(String, std::collections::BTreeMap<String, String>), String> {
     let config_slot_regex = Regex::new(r#"config:([a-zA-Z_][a-zA-Z0-9_.]*)"#).unwrap();
     let mut result = expression.to_string();
+    let mut slots = std::collections::BTreeMap::new();
 
     // Find all config slots and replace with actual values
     for capture in config_slot_regex.captures_iter(expression) {
@@ -143,43 +152,31 @@ fn resolve_config_slots_in_expression(expression: &str, config: &familiar_config
             // Replace the config reference with the resolved value
             let full_match = capture.get(0).unwrap().as_str();
             result = result.replace(full_match, &resolved_value);
+            slots.insert(slot, resolved_value);
         }
     }
 
-    Ok(result)
-}
-
-/// Resolve a config slot path to its actual value
-fn resolve_config_value(slot: &str, _config: &familiar_config::GlobalConfig) -> Option<String> {
-    // This would need to be implemented based on the actual config structure
-    // For now, return dummy values that match the expected types
-    match slot {
-        "nodes.familiar_daemon.constraints.memory_threshold" => Some("8000000000".to_string()), // 8GB
-        "nodes.familiar_daemon.constraints.cpu_threshold" => Some("0.8".to_string()),
-        "nodes.familiar_daemon.constraints.queue_depth_threshold" => Some("100".to_string()),
-        "nodes.familiar_daemon.constraints.error_rate_threshold" => Some("0.01".to_string()),
-        "nodes.familiar_worker.constraints.memory_threshold" => Some("4000000000".to_string()), // 4GB
-        "nodes.familiar_worker.constraints.cpu_threshold" => Some("0.8".to_string()),
-        "nodes.familiar_worker.constraints.active_db_connections_limit" => Some("50".to_string()),
-        "nodes.classifier.constraints.memory_threshold" => Some("16000000000".to_string()), // 16GB
-        "nodes.classifier.constraints.gpu_memory_threshold" => Some("8000000000".to_string()), // 8GB
-        "nodes.classifier.constraints.active_ml_jobs_limit" => Some("5".to_string()),
-        "nodes.familiar_router.constraints.memory_threshold" => Some("2000000000".to_string()), // 2GB
-        "nodes.familiar_router.constraints.cpu_threshold" => Some("0.7".to_string()),
-        "nodes.familiar_router.constraints.network_latency_threshold" => Some("50".to_string()),
-        "systems.fates_gate.timeouts.weave" => Some("30000".to_string()), // 30s
-        "systems.fates_gate.timeouts.search" => Some("45000".to_string()), // 45s
-        "systems.fates_gate.timeouts.classify" => Some("60000".to_string()), // 60s
-        "systems.classifier_system.timeouts.classification" => Some("120000".to_string()), // 2min
-        "systems.classifier_system.timeouts.entity_segment" => Some("30000".to_string()),
-        "systems.classifier_system.timeouts.purpose_classification" => Some("45000".to_string()),
-        "systems.classifier_system.timeouts.batch_processing" => Some("300000".to_string()), // 5min
-        "routing.decision_timeout_ms" => Some("100".to_string()),
-        // Add more as needed...
-        _ => {
-            eprintln!("Warning: Unknown config slot '{}' - using dummy value", slot);
-            Some("123".to_string()) // Fallback dummy value
-        }
+    Ok((result, slots))
+}
+
+/// Resolve a config slot path to its actual value, as a CEL literal
+fn resolve_config_value(slot: &str, config: &familiar_config::GlobalConfig) -> Option<String> {
+    config.resolve_slot(slot).map(|value| cel_literal(&value))
+}
+
+/// Render a config value as a CEL literal. Durations become milliseconds,
+/// like the `*_ms` fields they are compared against.
+fn cel_literal(value: &familiar_config::ConfigValue) -> String {
+    use familiar_config::ConfigValue;
+
+    match value {
+        ConfigValue::Bool(b) => b.to_string(),
+        ConfigValue::Int(n) => n.to_string(),
+        ConfigValue::UInt(n) => n.to_string(),
+        // Debug keeps the fractional part ("10000.0"), so CEL reads a double
+        ConfigValue::Float(f) => format!("{:?}", f),
+        ConfigValue::String(s) => serde_json::to_string(s).unwrap_or_else(|_| format!("\"{}\"", s)),
+        ConfigValue::Duration(d) => d.as_millis().to_string(),
     }
 }
 
@@ -330,6 +327,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     std::process::exit(1);
                 }
             }
+
+            println!("🔍 Validating node allocations against declared capacity...");
+            match validate_node_capacities(&schema_dir) {
+                Ok(_) => println!("✅ All node allocations fit their declared capacity!"),
+                Err(errors) => {
+                    eprintln!("❌ Found {} capacity violations:", errors.len());
+                    for error in errors {
+                        eprintln!("  {}: {}", error.schema_path, error.message);
+                    }
+                    std::process::exit(1);
+                }
+            }
         }
 
         Commands::GenerateManifest => {
@@ -357,11 +366,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Commands::SyncAll => {
             println!("🔄 Running full config sync...");
 
-            // Generate manifest
-            println!("  📄 Generating config manifest...");
-            if let Err(e) = generate_config_manifest() {
-                eprintln!("❌ Failed to generate manifest: {}", e);
-                std::process::exit(1);
+            // Check the committed manifest against the config code
+            println!("  📄 Checking config manifest...");
+            match check_config_manifest() {
+                Ok(diff) if diff.is_empty() => {}
+                Ok(diff) => {
+                    eprintln!("❌ Config manifest drift detected:");
+                    eprint!("{}", diff);
+                    eprintln!("   Run `cargo xtask generate-manifest` to update it.");
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to check manifest: {}", e);
+                    std::process::exit(1);
+                }
             }
 
             // Validate config slots
@@ -374,6 +392,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 std::process::exit(1);
             }
 
+            // Validate node allocations against schema-declared capacity
+            println!("  📦 Validating node capacities...");
+            if let Err(errors) = validate_node_capacities("versions/latest") {
+                eprintln!("❌ Node capacity validation failed:");
+                for error in errors {
+                    eprintln!("  {}: {}", error.schema_path, error.message);
+                }
+                std::process::exit(1);
+            }
+
             // Validate CEL variables in system schemas
             println!("  🎯 Validating CEL expressions...");
             if let Err(errors) = validate_cel_variables("versions/latest") {
@@ -409,13 +437,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 fn validate_config_slots(schema_dir: &str) -> Result<(), Vec<ConfigValidationError>> {
-    use std::fs;
-    use walkdir::WalkDir;
-    use regex::Regex;
-
-    let mut errors = Vec::new();
-    let config_slot_pattern = Regex::new(r#""config:([a-zA-Z_][a-zA-Z0-9_.]*)"|config:([a-zA-Z_][a-zA-Z0-9_.]*)"#).unwrap();
-
     // Load the manifest to check against
     let manifest = match load_config_manifest() {
         Ok(m) => m,
@@ -427,29 +448,238 @@ fn validate_config_slots(schema_dir: &str) -> Result<(), Vec<ConfigValidationErr
         }
     };
 
+    let errors = check_schema_dir_slots(schema_dir, &manifest);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Check the config slots of every schema under `schema_dir`
+fn check_schema_dir_slots(
+    schema_dir: &str,
+    manifest: &familiar_config::PolicyManifest,
+) -> Vec<ConfigValidationError> {
+    use std::fs;
+    use walkdir::WalkDir;
+
+    let mut errors = Vec::new();
     for entry in WalkDir::new(schema_dir).into_iter().filter_map(|e| e.ok()) {
         if entry.file_type().is_file() && entry.path().extension() == Some(std::ffi::OsStr::new("json")) {
-            let content = match fs::read_to_string(entry.path()) {
-                Ok(c) => c,
-                Err(_) => continue, // Skip files we can't read
-            };
-
             let schema_path = entry.path().strip_prefix(schema_dir).unwrap_or(entry.path())
                 .to_string_lossy().to_string();
 
-            // Find all config slots in the file
-            for capture in config_slot_pattern.captures_iter(&content) {
-                let slot = capture.get(1).or_else(|| capture.get(2)).unwrap().as_str();
+            // A file that can't be read can't be checked; report it rather
+            // than passing over its slots
+            let parsed = fs::read_to_string(entry.path())
+                .map_err(|e| format!("Failed to read schema: {}", e))
+                .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).map_err(|e| format!("Invalid JSON: {}", e)));
+            let mut schema = match parsed {
+                Ok(schema) => schema,
+                Err(message) => {
+                    errors.push(ConfigValidationError { schema_path, message });
+                    continue;
+                }
+            };
+
+            // Slots on inherited properties count as this schema's own, so an
+            // unresolved parent leaves slots unchecked
+            if let Err(inheritance_errors) = resolve_schema_inheritance(&mut schema, entry.path()) {
+                errors.extend(inheritance_errors.into_iter().map(|message| ConfigValidationError {
+                    schema_path: schema_path.clone(),
+                    message: format!("Failed to resolve inheritance: {}", message),
+                }));
+            }
+
+            errors.extend(check_config_slots(&schema, &schema_path, manifest));
+        }
+    }
+    errors
+}
+
+/// Check every config slot in `schema` against the manifest: the slot must
+/// exist, and its declared type must fit where the schema uses it.
+fn check_config_slots(
+    schema: &serde_json::Value,
+    schema_path: &str,
+    manifest: &familiar_config::PolicyManifest,
+) -> Vec<ConfigValidationError> {
+    let config_slot_pattern = Regex::new(r#"config:([a-zA-Z_][a-zA-Z0-9_.]*)"#).unwrap();
+    let mut errors = Vec::new();
+    collect_config_slot_errors(schema, None, schema_path, manifest, &config_slot_pattern, &mut errors);
+    errors
+}
 
-                // Check if this slot exists in the manifest
-                if !manifest.config_keys.contains_key(slot) {
+fn collect_config_slot_errors(
+    value: &serde_json::Value,
+    key: Option<&str>,
+    schema_path: &str,
+    manifest: &familiar_config::PolicyManifest,
+    config_slot_pattern: &Regex,
+    errors: &mut Vec<ConfigValidationError>,
+) {
+    match value {
+        serde_json::Value::String(text) => {
+            for capture in config_slot_pattern.captures_iter(text) {
+                let slot = capture.get(1).unwrap().as_str();
+
+                let Some(info) = manifest.config_keys.get(slot) else {
                     errors.push(ConfigValidationError {
-                        schema_path: schema_path.clone(),
+                        schema_path: schema_path.to_string(),
                         message: format!("Config slot '{}' not found in manifest", slot),
                     });
+                    continue;
+                };
+
+                if let Some(kind) = expected_slot_kind(key, text, capture.get(0).unwrap().range()) {
+                    if !kind.accepts(&info.value_type) {
+                        errors.push(ConfigValidationError {
+                            schema_path: schema_path.to_string(),
+                            message: format!(
+                                "Config slot '{}' has the wrong type: the manifest declares '{}', but it is used where {} is expected",
+                                slot, info.value_type, kind.describe()
+                            ),
+                        });
+                    }
                 }
             }
         }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_config_slot_errors(item, key, schema_path, manifest, config_slot_pattern, errors);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for (k, v) in map {
+                collect_config_slot_errors(v, Some(k), schema_path, manifest, config_slot_pattern, errors);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The kind of value a config slot has to hold where a schema uses it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SlotKind {
+    Integer,
+    Number,
+    Duration,
+    /// An operand of a CEL ordering comparison
+    Ordered,
+}
+
+impl SlotKind {
+    /// Whether a slot of the manifest's `value_type` fits this use
+    fn accepts(self, value_type: &str) -> bool {
+        match self {
+            SlotKind::Integer => value_type == "integer",
+            SlotKind::Number => matches!(value_type, "integer" | "number"),
+            SlotKind::Duration => value_type == "duration",
+            SlotKind::Ordered => matches!(value_type, "integer" | "number" | "duration"),
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            SlotKind::Integer => "an integer",
+            SlotKind::Number => "a number",
+            SlotKind::Duration => "a duration",
+            SlotKind::Ordered => "a number or duration",
+        }
+    }
+}
+
+/// Work out what kind of value the slot at `slot` in `text` must hold.
+///
+/// A slot that makes up the whole value takes its kind from the key it's
+/// under (`maxItems`, `timeout`, ...), with a `timeout` slot named `*_ms`
+/// holding plain milliseconds; one inside a CEL expression must be
+/// numeric when it's compared with `<`, `>`, `<=` or `>=`. Returns `None`
+/// when the use says nothing about the type.
+fn expected_slot_kind(key: Option<&str>, text: &str, slot: std::ops::Range<usize>) -> Option<SlotKind> {
+    if text.trim() == &text[slot.clone()] {
+        return match key? {
+            "maxItems" | "minItems" | "maxLength" | "minLength" | "maxProperties" | "minProperties" => {
+                Some(SlotKind::Integer)
+            }
+            "minimum" | "maximum" | "exclusiveMinimum" | "exclusiveMaximum" | "multipleOf" => Some(SlotKind::Number),
+            "retries" => Some(SlotKind::Integer),
+            "timeout" if text[slot.clone()].ends_with("_ms") => Some(SlotKind::Integer),
+            "timeout" => Some(SlotKind::Duration),
+            _ => None,
+        };
+    }
+
+    let before = text[..slot.start].trim_end();
+    let after = text[slot.end..].trim_start();
+    let compared = before.ends_with('<') || before.ends_with('>')
+        || before.ends_with("<=") || before.ends_with(">=")
+        || after.starts_with('<') || after.starts_with('>');
+    compared.then_some(SlotKind::Ordered)
+}
+
+/// Check that the config never allocates a node more than its schema declares
+/// (`x-familiar-memory`, `x-familiar-cpu`, `x-familiar-gpu`).
+fn validate_node_capacities(schema_dir: &str) -> Result<(), Vec<ConfigValidationError>> {
+    use std::collections::HashMap;
+    use std::fs;
+    use walkdir::WalkDir;
+
+    let mut errors = Vec::new();
+    let mut capacities = HashMap::new();
+    let mut schema_paths = HashMap::new();
+
+    for entry in WalkDir::new(schema_dir).into_iter().filter_map(|e| e.ok()) {
+        let file_name = entry.file_name().to_string_lossy();
+        if !entry.file_type().is_file() || !file_name.ends_with(".node.json") {
+            continue;
+        }
+
+        let schema_path = entry.path().strip_prefix(schema_dir).unwrap_or(entry.path())
+            .to_string_lossy().to_string();
+
+        let schema: serde_json::Value = match fs::read_to_string(entry.path())
+            .ok()
+            .and_then(|c| serde_json::from_str(&c).ok())
+        {
+            Some(s) => s,
+            None => continue, // Skip files we can't read or parse
+        };
+
+        // Node ids come from the file stem, e.g. familiar-daemon.node.json
+        let node_id = file_name.trim_end_matches(".node.json").to_string();
+
+        match familiar_config::NodeCapacity::from_schema(&schema) {
+            Ok(capacity) => {
+                schema_paths.insert(node_id.clone(), schema_path);
+                capacities.insert(node_id, capacity);
+            }
+            Err(message) => errors.push(ConfigValidationError { schema_path, message }),
+        }
+    }
+
+    let config = match familiar_config::GlobalConfig::load() {
+        Ok(c) => c,
+        Err(e) => {
+            errors.push(ConfigValidationError {
+                schema_path: "config".to_string(),
+                message: format!("Failed to load config: {}", e),
+            });
+            return Err(errors);
+        }
+    };
+    if let Err(violations) = config.validate_node_capacities(&capacities) {
+        for violation in violations {
+            let node = match &violation {
+                familiar_config::CapacityViolation::OverAllocated { node, .. }
+                | familiar_config::CapacityViolation::Unparseable { node, .. } => node,
+            };
+            errors.push(ConfigValidationError {
+                schema_path: schema_paths.get(node).cloned().unwrap_or_else(|| node.clone()),
+                message: violation.to_string(),
+            });
+        }
     }
 
     if errors.is_empty() {
@@ -459,14 +689,16 @@ fn validate_config_slots(schema_dir: &str) -> Result<(), Vec<ConfigValidationErr
     }
 }
 
-fn generate_config_manifest() -> Result<String, Box<dyn std::error::Error>> {
-    use std::path::Path;
-
-    let manifest = familiar_config::GlobalConfig::generate_manifest();
-    let output_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+fn config_manifest_path() -> std::path::PathBuf {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
         .parent()
         .unwrap()
-        .join("familiar-config/policy_manifest.json");
+        .join("familiar-config/policy_manifest.json")
+}
+
+fn generate_config_manifest() -> Result<String, Box<dyn std::error::Error>> {
+    let manifest = familiar_config::GlobalConfig::generate_manifest();
+    let output_path = config_manifest_path();
 
     // Ensure the directory exists
     if let Some(parent) = output_path.parent() {
@@ -477,6 +709,13 @@ fn generate_config_manifest() -> Result<String, Box<dyn std::error::Error>> {
     Ok(output_path.to_string_lossy().to_string())
 }
 
+/// Diff the committed manifest against the one the config code generates.
+/// An empty diff means the manifest is current.
+fn check_config_manifest() -> Result<familiar_config::ManifestDiff, Box<dyn std::error::Error>> {
+    let stored = familiar_config::PolicyManifest::load_from_file(&config_manifest_path())?;
+    Ok(stored.diff(&familiar_config::GlobalConfig::generate_manifest()))
+}
+
 /// ⚠️ **LIMITED CODEGEN**: This function only extracts individual CEL expressions
 /// from schemas and pre-compiles them. It does NOT generate complete routing
 /// algorithms, state machines, or decision logic from schema hierarchies.
@@ -515,7 +754,8 @@ fn generate_routing_table() -> Result<String, Box<dyn std::error::Error>> {
 
                 // Extract constraints from node schemas referenced by this system
                 if let Some(default_node) = schema.get("default_node").and_then(|n| n.get("$ref")).and_then(|r| r.as_str()) {
-                    let node_constraints = extract_node_constraints(schema_dir, default_node)?;
+                    let system_dir = entry.path().parent().unwrap_or(std::path::Path::new(schema_dir));
+                    let node_constraints = extract_node_constraints(system_dir, default_node)?;
                     for constraint in node_constraints {
                         routing_entries.push(RoutingEntry {
                             system_id: system_id.to_string(),
@@ -552,9 +792,11 @@ fn generate_routing_table() -> Result<String, Box<dyn std::error::Error>> {
         }
     }
 
-    // Generate Rust code for the routing table
-    let rust_code = generate_routing_table_code(&routing_entries);
-    fs::write(&output_path, rust_code)?;
+    // Compile the routing table and embed it as bincode
+    let config = familiar_config::GlobalConfig::load()?;
+    let table = build_routing_table(&routing_entries, &config);
+    fs::write(output_path.with_extension("bin"), table.to_bytes()?)?;
+    fs::write(&output_path, generate_routing_table_code())?;
 
     Ok(output_path.display().to_string())
 }
@@ -566,10 +808,12 @@ struct RoutingEntry {
     expression: String,
 }
 
-fn extract_node_constraints(schema_dir: &str, node_ref: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+/// Constraints of the node schema `node_ref`, which is relative to the
+/// referencing system's directory
+fn extract_node_constraints(system_dir: &std::path::Path, node_ref: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
     use std::fs;
 
-    let node_path = resolve_schema_path(std::path::Path::new(schema_dir), node_ref);
+    let node_path = resolve_schema_path(system_dir, node_ref);
     if !node_path.exists() {
         return Ok(Vec::new());
     }
@@ -589,94 +833,62 @@ fn extract_node_constraints(schema_dir: &str, node_ref: &str) -> Result<Vec<Stri
     Ok(constraints)
 }
 
-fn generate_routing_table_code(entries: &[RoutingEntry]) -> String {
-    // CRITICAL FIX: Pre-compile CEL expressions at CODEGEN time, not runtime
-    // This eliminates the startup bottleneck by moving compilation to build time
-
-    let mut compiled_entries = Vec::new();
+fn build_routing_table(
+    entries: &[RoutingEntry],
+    config: &familiar_config::GlobalConfig,
+) -> familiar_router::RoutingTable {
+    // Parse every CEL expression at codegen time and keep the lowered AST, so
+    // RoutingTable::load() only has to deserialize it
+    let mut table = familiar_router::RoutingTable::default();
 
     for entry in entries {
-        // Pre-compile each CEL expression and serialize it
-        match cel_interpreter::Program::compile(&entry.expression) {
-            Ok(_program) => {
-                // In a real implementation, you'd serialize the AST here
-                // For now, we'll store the compiled expression as a placeholder
-                // This is a placeholder - you'd want to use a proper binary serialization
-                compiled_entries.push((entry, "PRECOMPILED_CEL_PROGRAM_PLACEHOLDER".to_string()));
-            }
+        let compiled = resolve_config_slots(&entry.expression, config)
+            .and_then(|(resolved, slots)| {
+                familiar_router::CompiledExpression::compile(&resolved)
+                    .map(|compiled| compiled.with_config_slots(slots))
+                    .map_err(|e| e.to_string())
+            });
+
+        let compiled = match compiled {
+            Ok(compiled) => compiled,
             Err(e) => {
-                eprintln!("⚠️  Failed to pre-compile CEL expression '{}' for {}: {}",
+                eprintln!("⚠️  Skipping CEL expression '{}' for {}: {}",
                          entry.expression, entry.system_id, e);
-                // Continue with compilation - runtime will handle the error
-                compiled_entries.push((entry, "INVALID_CEL_PROGRAM".to_string()));
+                continue;
             }
-        }
-    }
-
-    let mut code = String::from(r#"// Auto-generated routing table with PRE-COMPILED CEL expressions
-// This eliminates runtime compilation bottlenecks for high-performance routing
-//
-// Generated by xtask from schema CEL expressions
-// ⚠️  CEL expressions are pre-compiled at build time for optimal performance
-
-use std::collections::HashMap;
-use cel_interpreter::Program;
-
-#[derive(Debug)]
-pub struct RoutingTable {
-    pub node_constraints: HashMap<String, Vec<Program>>,
-    pub routing_policies: HashMap<String, Vec<Program>>,
-    pub timeout_constraints: HashMap<String, Program>,
-}
-
-impl RoutingTable {
-    /// Load pre-compiled routing table
-    /// ⚡ FAST: No runtime CEL compilation - expressions pre-compiled at build time
-    pub fn load() -> Result<Self> {
-        let mut node_constraints = HashMap::new();
-        let mut routing_policies = HashMap::new();
-        let mut timeout_constraints = HashMap::new();
-
-"#);
+        };
 
-    for (entry, _compiled_expr) in &compiled_entries {
         match entry.expression_type.as_str() {
             "node_constraint" => {
-                code.push_str(&format!(r#"        // {} - {} (PRE-COMPILED)
-        node_constraints.entry("{}".to_string())
-            .or_insert_with(Vec::new)
-            .push(Program::compile("{}").expect("Pre-compiled CEL failed"));
-
-"#, entry.system_id, entry.expression_type, entry.system_id, entry.expression.replace("\"", "\\\"")));
+                table.node_constraints.entry(entry.system_id.clone()).or_default().push(compiled);
             }
             "routing_policy" => {
-                code.push_str(&format!(r#"        // {} - {} (PRE-COMPILED)
-        routing_policies.entry("{}".to_string())
-            .or_insert_with(Vec::new)
-            .push(Program::compile("{}").expect("Pre-compiled CEL failed"));
-
-"#, entry.system_id, entry.expression_type, entry.system_id, entry.expression.replace("\"", "\\\"")));
+                table.routing_policies.entry(entry.system_id.clone()).or_default().push(compiled);
             }
             "timeout_constraint" => {
-                code.push_str(&format!(r#"        // {} - {} (PRE-COMPILED)
-        timeout_constraints.insert("{}".to_string(), Program::compile("{}").expect("Pre-compiled CEL failed"));
-
-"#, entry.system_id, entry.expression_type, entry.system_id, entry.expression.replace("\"", "\\\"")));
+                table.timeout_constraints.insert(entry.system_id.clone(), compiled);
             }
             _ => {}
         }
     }
 
-    code.push_str(r#"        Ok(RoutingTable {
-            node_constraints,
-            routing_policies,
-            timeout_constraints,
-        })
-    }
+    table
 }
-"#);
 
-    code
+fn generate_routing_table_code() -> String {
+    String::from(r#"// ⚠️ **PARTIALLY GENERATED** routing table
+// This extracts SOME CEL expressions from schemas but does NOT generate
+// actual routing algorithms. True schema-driven routing would generate
+// complete decision logic, not just pre-compiled expressions.
+//
+// What this does: Extract, parse and serialize individual CEL strings
+// What it should do: Generate complete routing state machines from schema hierarchies
+//
+// Generated by xtask from schema CEL expressions
+
+/// bincode-encoded [`RoutingTable`], compiled from schema CEL at codegen time
+pub(crate) static ROUTING_TABLE_BYTES: &[u8] = include_bytes!("generated_routing_table.bin");
+"#)
 }
 
 fn validate_cel_variables(schema_dir: &str) -> Result<(), Vec<CelValidationError>> {
@@ -848,23 +1060,30 @@ fn pgo_action(schema_dir: &str, action: PgoAction) -> Result<(), Box<dyn std::er
                 run_system_workload_samples(&system)?;
             }
 
+            println!("🔀 Merging profile data...");
+            let llvm_profdata = find_llvm_profdata()?;
+            let merged = merge_pgo_profiles(&llvm_profdata, Path::new("/tmp/familiar-pgo-data"), |program, args| {
+                println!("💡 Running: {} {}", program.display(), args.join(" "));
+                Ok(std::process::Command::new(program).args(args).status()?.success())
+            })?;
+
             println!("✅ PGO sampling completed!");
-            println!("💡 Profile data written to /tmp/familiar-pgo-data/");
+            println!("💡 Profile data merged into {}", merged.display());
             println!("💡 Use 'cargo xtask pgo optimize' to build the optimized binary");
         }
 
         PgoAction::Optimize => {
             println!("🚀 Building PGO-optimized binary...");
 
-            // Check if profile data exists
+            // Check if merged profile data exists
             let profile_dir = "/tmp/familiar-pgo-data";
-            if !std::path::Path::new(profile_dir).exists() {
-                eprintln!("❌ Profile data not found at {}", profile_dir);
+            if !Path::new(profile_dir).join("merged.profdata").exists() {
+                eprintln!("❌ Merged profile data not found at {}/merged.profdata", profile_dir);
                 eprintln!("💡 Run 'cargo xtask pgo instrument' then 'cargo xtask pgo sample' first");
                 std::process::exit(1);
             }
 
-            // Merge profiles and build optimized binary
+            // Build optimized binary from the merged profile
             run_command(&[
                 "cargo", "build", "--release",
                 "--config", &format!("target.'cfg(all())'.rustflags = [\"-Cprofile-use={}/merged.profdata\"]", profile_dir)
@@ -878,6 +1097,60 @@ fn pgo_action(schema_dir: &str, action: PgoAction) -> Result<(), Box<dyn std::er
     Ok(())
 }
 
+/// Locate `llvm-profdata`, preferring the one the `llvm-tools` rustup
+/// component installs for the active toolchain over one on `PATH`
+fn find_llvm_profdata() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let tool = format!("llvm-profdata{}", std::env::consts::EXE_SUFFIX);
+
+    // llvm-tools lives beside the host target's libraries: lib/rustlib/<host>/bin
+    if let Ok(output) = std::process::Command::new("rustc").args(["--print", "target-libdir"]).output() {
+        let libdir = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+        if let Some(candidate) = libdir.parent().map(|dir| dir.join("bin").join(&tool)) {
+            if candidate.is_file() {
+                return Ok(candidate);
+            }
+        }
+    }
+
+    std::env::var_os("PATH")
+        .and_then(|paths| std::env::split_paths(&paths).map(|dir| dir.join(&tool)).find(|p| p.is_file()))
+        .ok_or_else(|| {
+            "llvm-profdata not found. Install it with 'rustup component add llvm-tools-preview'".into()
+        })
+}
+
+/// Merge the `.profraw` files in `profile_dir` into the `merged.profdata`
+/// that `pgo optimize` builds with, returning its path.
+///
+/// `run` executes `llvm_profdata` with the given arguments and reports
+/// whether it succeeded.
+fn merge_pgo_profiles(
+    llvm_profdata: &Path,
+    profile_dir: &Path,
+    run: impl FnOnce(&Path, &[String]) -> std::io::Result<bool>,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let mut profiles: Vec<PathBuf> = std::fs::read_dir(profile_dir)
+        .map_err(|e| format!("Cannot read profile data at {}: {}", profile_dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension() == Some(std::ffi::OsStr::new("profraw")))
+        .collect();
+    profiles.sort();
+
+    if profiles.is_empty() {
+        return Err(format!("No .profraw files found in {}", profile_dir.display()).into());
+    }
+
+    let merged = profile_dir.join("merged.profdata");
+    let mut args = vec!["merge".to_string(), "-o".to_string(), merged.display().to_string()];
+    args.extend(profiles.iter().map(|path| path.display().to_string()));
+
+    if !run(llvm_profdata, &args)? {
+        return Err(format!("{} merge failed", llvm_profdata.display()).into());
+    }
+
+    Ok(merged)
+}
+
 #[derive(Debug)]
 struct PgoSystem {
     name: String,
@@ -2085,3 +2358,165 @@ fn run_command(args: &[&str]) {
 
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn manifest(keys: &[(&str, &str)]) -> familiar_config::PolicyManifest {
+        familiar_config::PolicyManifest {
+            version: "test".to_string(),
+            config_keys: keys
+                .iter()
+                .map(|(path, value_type)| {
+                    let info = familiar_config::ConfigKeyInfo {
+                        path: path.to_string(),
+                        value_type: value_type.to_string(),
+                        default_value: serde_json::Value::Null,
+                        description: String::new(),
+                    };
+                    (path.to_string(), info)
+                })
+                .collect(),
+        }
+    }
+
+    fn load_inherited(name: &str) -> serde_json::Value {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config_slots").join(name);
+        let mut schema = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        resolve_schema_inheritance(&mut schema, &path).unwrap();
+        schema
+    }
+
+    #[test]
+    fn test_inherited_slot_is_checked() {
+        // RoutingQueue only gets its slot through allOf
+        let schema = load_inherited("RoutingQueue.schema.json");
+
+        let errors = check_config_slots(&schema, "RoutingQueue.schema.json", &manifest(&[]));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].schema_path, "RoutingQueue.schema.json");
+        assert_eq!(errors[0].message, "Config slot 'queues.routing_queue.capacity' not found in manifest");
+
+        let manifest = manifest(&[("queues.routing_queue.capacity", "integer")]);
+        assert!(check_config_slots(&schema, "RoutingQueue.schema.json", &manifest).is_empty());
+    }
+
+    #[test]
+    fn test_unreadable_schemas_are_reported() {
+        let dir = std::env::temp_dir().join(format!("familiar-config-slots-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("Truncated.schema.json"), "{").unwrap();
+        std::fs::write(dir.join("Orphan.schema.json"), r#"{"allOf": [{"$ref": "Missing.schema.json"}]}"#).unwrap();
+        std::fs::write(dir.join("Queue.schema.json"), r#"{"properties": {"size": {"maxItems": "config:queues.size"}}}"#).unwrap();
+
+        let manifest = manifest(&[("queues.size", "integer")]);
+        let mut errors = check_schema_dir_slots(dir.to_str().unwrap(), &manifest);
+        std::fs::remove_dir_all(&dir).unwrap();
+        errors.sort_by(|a, b| a.schema_path.cmp(&b.schema_path));
+
+        let paths: Vec<&str> = errors.iter().map(|e| e.schema_path.as_str()).collect();
+        assert_eq!(paths, ["Orphan.schema.json", "Truncated.schema.json"]);
+        assert!(errors[0].message.starts_with("Failed to resolve inheritance: "));
+        assert!(errors[1].message.starts_with("Invalid JSON: "));
+    }
+
+    #[test]
+    fn test_slot_with_wrong_type() {
+        let manifest = manifest(&[
+            ("queues.routing_queue.capacity", "string"),
+            ("nodes.familiar_router.constraints.memory_threshold", "string"),
+            ("nodes.familiar_daemon.constraints.cpu_threshold", "number"),
+        ]);
+
+        let schema = load_inherited("RoutingQueue.schema.json");
+        let errors = check_config_slots(&schema, "RoutingQueue.schema.json", &manifest);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].message,
+            "Config slot 'queues.routing_queue.capacity' has the wrong type: the manifest declares 'string', but it is used where an integer is expected"
+        );
+
+        let schema = json!({
+            "x-familiar-constraints": {
+                "memory": "node.available_memory > config:nodes.familiar_router.constraints.memory_threshold",
+                "cpu": "node.cpu_usage < config:nodes.familiar_daemon.constraints.cpu_threshold"
+            }
+        });
+        let errors = check_config_slots(&schema, "familiar-router.node.json", &manifest);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.starts_with(
+            "Config slot 'nodes.familiar_router.constraints.memory_threshold' has the wrong type"
+        ));
+    }
+
+    #[test]
+    fn test_expected_slot_kind() {
+        let kind = |key, text: &str| {
+            let start = text.find("config:").unwrap();
+            let end = text[start..].find(' ').map_or(text.len(), |i| start + i);
+            expected_slot_kind(key, text, start..end)
+        };
+
+        assert_eq!(kind(Some("maxItems"), "config:a.b"), Some(SlotKind::Integer));
+        assert_eq!(kind(Some("timeout"), "config:a.b"), Some(SlotKind::Duration));
+        assert_eq!(kind(Some("timeout"), "config:routing.decision_timeout_ms"), Some(SlotKind::Integer));
+        assert_eq!(kind(Some("default"), "config:a.b"), None);
+        assert_eq!(kind(Some("cpu"), "node.cpu_cores >= config:a.b"), Some(SlotKind::Ordered));
+        assert_eq!(kind(Some("cpu"), "config:a.b > node.load"), Some(SlotKind::Ordered));
+        assert_eq!(kind(Some("region"), "node.region == config:a.b"), None);
+        assert!(SlotKind::Ordered.accepts("duration"));
+        assert!(!SlotKind::Integer.accepts("number"));
+    }
+
+    #[test]
+    fn test_merge_pgo_profiles() {
+        let profile_dir = std::env::temp_dir().join(format!("familiar-pgo-merge-{}", std::process::id()));
+        std::fs::create_dir_all(&profile_dir).unwrap();
+        for file in ["daemon-2.profraw", "daemon-1.profraw", "notes.txt"] {
+            std::fs::write(profile_dir.join(file), b"").unwrap();
+        }
+
+        let mut invoked = None;
+        let merged = merge_pgo_profiles(Path::new("/rustlib/bin/llvm-profdata"), &profile_dir, |program, args| {
+            invoked = Some((program.to_path_buf(), args.to_vec()));
+            Ok(true)
+        })
+        .unwrap();
+
+        let path = |file: &str| profile_dir.join(file).display().to_string();
+        let (program, args) = invoked.unwrap();
+        assert_eq!(merged, profile_dir.join("merged.profdata"));
+        assert_eq!(program, Path::new("/rustlib/bin/llvm-profdata"));
+        assert_eq!(
+            args,
+            vec![
+                "merge".to_string(),
+                "-o".to_string(),
+                path("merged.profdata"),
+                path("daemon-1.profraw"),
+                path("daemon-2.profraw"),
+            ]
+        );
+
+        // A failed merge is an error
+        assert!(merge_pgo_profiles(Path::new("llvm-profdata"), &profile_dir, |_, _| Ok(false)).is_err());
+
+        std::fs::remove_dir_all(&profile_dir).unwrap();
+    }
+
+    #[test]
+    fn test_merge_without_profiles_fails() {
+        let profile_dir = std::env::temp_dir().join(format!("familiar-pgo-empty-{}", std::process::id()));
+        std::fs::create_dir_all(&profile_dir).unwrap();
+
+        let err = merge_pgo_profiles(Path::new("llvm-profdata"), &profile_dir, |_, _| {
+            panic!("merge should not run without profiles")
+        })
+        .unwrap_err();
+        assert!(err.to_string().starts_with("No .profraw files found"));
+
+        std::fs::remove_dir_all(&profile_dir).unwrap();
+    }
+}
@@ -0,0 +1,179 @@
+//! Per-node circuit breakers
+//!
+//! A node's breaker opens after `failure_threshold` consecutive failed
+//! outcomes and stops it from receiving work. Once `recovery_timeout` has
+//! elapsed the breaker goes half-open and lets trial requests through;
+//! `success_threshold` successes close it again, while any failure reopens it.
+
+use crate::NodeId;
+use familiar_config::CircuitBreakerConfig;
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// State of a node's circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerState {
+    /// Node receives work normally
+    Closed,
+    /// Node is skipped until the cooldown elapses
+    Open,
+    /// Cooldown elapsed; trial requests are allowed through
+    HalfOpen,
+}
+
+/// A breaker state change, reported to the telemetry provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakerTransition {
+    pub node_id: NodeId,
+    pub from: BreakerState,
+    pub to: BreakerState,
+}
+
+#[derive(Debug, Clone)]
+struct NodeBreaker {
+    state: BreakerState,
+    consecutive_failures: u32,
+    consecutive_successes: u32,
+    opened_at: Option<Instant>,
+}
+
+impl Default for NodeBreaker {
+    fn default() -> Self {
+        Self {
+            state: BreakerState::Closed,
+            consecutive_failures: 0,
+            consecutive_successes: 0,
+            opened_at: None,
+        }
+    }
+}
+
+/// Circuit breakers for every node that has reported an outcome
+pub struct CircuitBreakers {
+    config: CircuitBreakerConfig,
+    nodes: HashMap<NodeId, NodeBreaker>,
+}
+
+impl CircuitBreakers {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            nodes: HashMap::new(),
+        }
+    }
+
+    /// Current state, treating an open breaker whose cooldown has elapsed as half-open
+    pub fn state(&self, node_id: &str) -> BreakerState {
+        match self.nodes.get(node_id) {
+            Some(breaker) if self.cooldown_elapsed(breaker) => BreakerState::HalfOpen,
+            Some(breaker) => breaker.state,
+            None => BreakerState::Closed,
+        }
+    }
+
+    /// Move an open breaker to half-open if its cooldown has elapsed
+    pub fn refresh(&mut self, node_id: &str) -> Option<BreakerTransition> {
+        let cooldown_elapsed = self.nodes.get(node_id).is_some_and(|b| self.cooldown_elapsed(b));
+        if !cooldown_elapsed {
+            return None;
+        }
+
+        let breaker = self.nodes.get_mut(node_id)?;
+        Some(transition(node_id, breaker, BreakerState::HalfOpen))
+    }
+
+    /// Record the outcome of work sent to a node
+    pub fn record(&mut self, node_id: &str, ok: bool) -> Vec<BreakerTransition> {
+        let mut transitions: Vec<_> = self.refresh(node_id).into_iter().collect();
+        let breaker = self.nodes.entry(node_id.to_string()).or_default();
+
+        if ok {
+            breaker.consecutive_failures = 0;
+            breaker.consecutive_successes += 1;
+            if breaker.state == BreakerState::HalfOpen
+                && breaker.consecutive_successes >= self.config.success_threshold
+            {
+                transitions.push(transition(node_id, breaker, BreakerState::Closed));
+            }
+        } else {
+            breaker.consecutive_successes = 0;
+            breaker.consecutive_failures += 1;
+            let should_open = match breaker.state {
+                BreakerState::Closed => breaker.consecutive_failures >= self.config.failure_threshold,
+                BreakerState::HalfOpen => true,
+                BreakerState::Open => false,
+            };
+            if should_open {
+                transitions.push(transition(node_id, breaker, BreakerState::Open));
+            }
+        }
+
+        transitions
+    }
+
+    fn cooldown_elapsed(&self, breaker: &NodeBreaker) -> bool {
+        breaker.state == BreakerState::Open
+            && breaker.opened_at.is_some_and(|at| at.elapsed() >= self.config.recovery_timeout)
+    }
+}
+
+fn transition(node_id: &str, breaker: &mut NodeBreaker, to: BreakerState) -> BreakerTransition {
+    let from = breaker.state;
+    breaker.state = to;
+    breaker.consecutive_failures = 0;
+    breaker.consecutive_successes = 0;
+    breaker.opened_at = (to == BreakerState::Open).then(Instant::now);
+
+    BreakerTransition {
+        node_id: node_id.to_string(),
+        from,
+        to,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn config(recovery_timeout: Duration) -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold: 2,
+            success_threshold: 2,
+            recovery_timeout,
+            ..CircuitBreakerConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_breaker_lifecycle() {
+        let mut breakers = CircuitBreakers::new(config(Duration::ZERO));
+
+        assert!(breakers.record("classifier", false).is_empty());
+        let opened = breakers.record("classifier", false);
+        assert_eq!(opened[0].to, BreakerState::Open);
+
+        // Zero cooldown: the next look goes straight to half-open
+        assert_eq!(breakers.state("classifier"), BreakerState::HalfOpen);
+        assert_eq!(breakers.refresh("classifier").unwrap().to, BreakerState::HalfOpen);
+
+        assert!(breakers.record("classifier", true).is_empty());
+        let closed = breakers.record("classifier", true);
+        assert_eq!(closed[0].to, BreakerState::Closed);
+        assert_eq!(breakers.state("classifier"), BreakerState::Closed);
+    }
+
+    #[test]
+    fn test_half_open_failure_reopens() {
+        let mut breakers = CircuitBreakers::new(config(Duration::from_secs(60)));
+        breakers.record("classifier", false);
+        breakers.record("classifier", false);
+        assert_eq!(breakers.state("classifier"), BreakerState::Open);
+        assert!(breakers.refresh("classifier").is_none());
+
+        breakers.nodes.get_mut("classifier").unwrap().state = BreakerState::HalfOpen;
+        let reopened = breakers.record("classifier", false);
+        assert_eq!(reopened[0].from, BreakerState::HalfOpen);
+        assert_eq!(reopened[0].to, BreakerState::Open);
+    }
+}
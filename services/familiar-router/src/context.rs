@@ -1,6 +1,36 @@
+use familiar_primitives::NormalizedFloat;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Names of the CEL variables a [`NodeContext`] provides. Constraints and
+/// routing policies refer to node state by these names.
+///
+/// CEL integers are 32-bit, so `available_memory` is a double (compare it
+/// against `2147483648.0`, not `2147483648`) and counts saturate at
+/// `i32::MAX`.
+pub mod vars {
+    pub const SCHEMA_VERSION: &str = "schema_version";
+    pub const AVAILABLE_MEMORY: &str = "available_memory";
+    pub const CPU_USAGE: &str = "cpu_usage";
+    pub const QUEUE_DEPTH: &str = "queue_depth";
+    pub const ACTIVE_JOBS: &str = "active_jobs";
+    pub const ACTIVE_CONNECTIONS: &str = "active_connections";
+    pub const HAS_GPU: &str = "has_gpu";
+    pub const ERROR_RATE: &str = "error_rate";
+
+    /// Every variable set for each context, before custom properties
+    pub const ALL: &[&str] = &[
+        SCHEMA_VERSION,
+        AVAILABLE_MEMORY,
+        CPU_USAGE,
+        QUEUE_DEPTH,
+        ACTIVE_JOBS,
+        ACTIVE_CONNECTIONS,
+        HAS_GPU,
+        ERROR_RATE,
+    ];
+}
+
 /// Runtime context for CEL evaluation during routing decisions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeContext {
@@ -19,6 +49,10 @@ pub struct NodeContext {
     /// Number of active jobs
     pub active_jobs: usize,
 
+    /// Number of open client connections
+    #[serde(default)]
+    pub active_connections: usize,
+
     /// Whether GPU is available
     pub has_gpu: bool,
 
@@ -38,12 +72,18 @@ impl NodeContext {
             cpu_usage: 0.0,
             queue_depth: 0,
             active_jobs: 0,
+            active_connections: 0,
             has_gpu: false,
             error_rate: 0.0,
             custom_properties: HashMap::new(),
         }
     }
 
+    /// Start building a context from typed node measurements
+    pub fn builder(schema_version: semver::Version) -> NodeContextBuilder {
+        NodeContextBuilder { context: Self::new(schema_version) }
+    }
+
     /// Get the current context (would integrate with monitoring systems)
     pub fn current() -> Self {
         // TODO: Integrate with actual monitoring/metrics systems
@@ -57,37 +97,42 @@ impl NodeContext {
         let mut context = HashMap::new();
 
         context.insert(
-            "schema_version".to_string(),
-            Value::String(self.schema_version.to_string())
+            vars::SCHEMA_VERSION.to_string(),
+            Value::from(self.schema_version.to_string())
         );
 
         context.insert(
-            "available_memory".to_string(),
-            Value::Int(self.available_memory as i64)
+            vars::AVAILABLE_MEMORY.to_string(),
+            Value::Float(self.available_memory as f64)
         );
 
         context.insert(
-            "cpu_usage".to_string(),
+            vars::CPU_USAGE.to_string(),
             Value::Float(self.cpu_usage)
         );
 
         context.insert(
-            "queue_depth".to_string(),
-            Value::Int(self.queue_depth as i64)
+            vars::QUEUE_DEPTH.to_string(),
+            cel_int(self.queue_depth)
         );
 
         context.insert(
-            "active_jobs".to_string(),
-            Value::Int(self.active_jobs as i64)
+            vars::ACTIVE_JOBS.to_string(),
+            cel_int(self.active_jobs)
         );
 
         context.insert(
-            "has_gpu".to_string(),
+            vars::ACTIVE_CONNECTIONS.to_string(),
+            cel_int(self.active_connections)
+        );
+
+        context.insert(
+            vars::HAS_GPU.to_string(),
             Value::Bool(self.has_gpu)
         );
 
         context.insert(
-            "error_rate".to_string(),
+            vars::ERROR_RATE.to_string(),
             Value::Float(self.error_rate)
         );
 
@@ -95,10 +140,13 @@ impl NodeContext {
         for (key, value) in &self.custom_properties {
             match value {
                 serde_json::Value::String(s) => {
-                    context.insert(key.clone(), Value::String(s.clone()));
+                    context.insert(key.clone(), Value::from(s.clone()));
+                }
+                serde_json::Value::Number(n) if n.as_i64().is_some_and(|i| i32::try_from(i).is_ok()) => {
+                    context.insert(key.clone(), Value::Int(n.as_i64().unwrap() as i32));
                 }
                 serde_json::Value::Number(n) if n.is_i64() => {
-                    context.insert(key.clone(), Value::Int(n.as_i64().unwrap()));
+                    context.insert(key.clone(), Value::Float(n.as_f64().unwrap()));
                 }
                 serde_json::Value::Number(n) if n.is_f64() => {
                     context.insert(key.clone(), Value::Float(n.as_f64().unwrap()));
@@ -108,7 +156,7 @@ impl NodeContext {
                 }
                 _ => {
                     // Convert to string for other types
-                    context.insert(key.clone(), Value::String(value.to_string()));
+                    context.insert(key.clone(), Value::from(value.to_string()));
                 }
             }
         }
@@ -116,3 +164,105 @@ impl NodeContext {
         context
     }
 }
+
+/// A count as a CEL integer, saturating at `i32::MAX`
+fn cel_int(n: usize) -> cel_interpreter::Value {
+    cel_interpreter::Value::Int(i32::try_from(n).unwrap_or(i32::MAX))
+}
+
+/// Builds a [`NodeContext`] from typed measurements, so callers never deal
+/// in CEL variable names
+#[derive(Debug, Clone)]
+pub struct NodeContextBuilder {
+    context: NodeContext,
+}
+
+impl NodeContextBuilder {
+    /// Memory available on the node
+    pub fn memory_bytes(mut self, bytes: u64) -> Self {
+        self.context.available_memory = bytes;
+        self
+    }
+
+    /// Share of the node's CPU in use
+    pub fn cpu_fraction(mut self, usage: NormalizedFloat) -> Self {
+        self.context.cpu_usage = usage.value();
+        self
+    }
+
+    pub fn queue_depth(mut self, depth: u32) -> Self {
+        self.context.queue_depth = depth as usize;
+        self
+    }
+
+    pub fn active_jobs(mut self, jobs: u32) -> Self {
+        self.context.active_jobs = jobs as usize;
+        self
+    }
+
+    pub fn active_connections(mut self, connections: u32) -> Self {
+        self.context.active_connections = connections as usize;
+        self
+    }
+
+    pub fn has_gpu(mut self, has_gpu: bool) -> Self {
+        self.context.has_gpu = has_gpu;
+        self
+    }
+
+    /// Share of recent requests that failed
+    pub fn error_rate(mut self, rate: NormalizedFloat) -> Self {
+        self.context.error_rate = rate.value();
+        self
+    }
+
+    /// A node-specific property, exposed to CEL under `key`
+    pub fn custom_property(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.context.custom_properties.insert(key.into(), value);
+        self
+    }
+
+    pub fn build(self) -> NodeContext {
+        self.context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CALEvaluator;
+
+    #[test]
+    fn test_builder_sets_every_policy_variable() {
+        let ctx = NodeContext::builder(semver::Version::new(1, 2, 0))
+            .memory_bytes(4 * 1024 * 1024 * 1024)
+            .cpu_fraction(NormalizedFloat::new(0.35).unwrap())
+            .queue_depth(12)
+            .active_jobs(3)
+            .active_connections(40)
+            .has_gpu(true)
+            .error_rate(NormalizedFloat::new(0.01).unwrap())
+            .build();
+
+        let cel = ctx.to_cel_context();
+        for var in vars::ALL {
+            assert!(cel.contains_key(*var), "context is missing '{}'", var);
+        }
+
+        let policy = "has_gpu && available_memory > 2147483648.0 && cpu_usage < 0.8 \
+            && queue_depth < 100 && active_connections < 500 && error_rate < 0.05 \
+            && schema_version == '1.2.0' ? 'gpu-pool' : 'default-pool'";
+        let evaluator = CALEvaluator::new();
+        assert_eq!(evaluator.evaluate_routing_policy(policy, &ctx).unwrap(), "gpu-pool");
+    }
+
+    #[test]
+    fn test_contexts_without_connections_still_deserialize() {
+        let ctx = NodeContext::builder(semver::Version::new(1, 2, 0)).active_connections(7).build();
+        let mut json = serde_json::to_value(&ctx).unwrap();
+        json.as_object_mut().unwrap().remove(vars::ACTIVE_CONNECTIONS);
+
+        let ctx: NodeContext = serde_json::from_value(json).unwrap();
+        assert_eq!(ctx.active_connections, 0);
+    }
+}
@@ -1,27 +1,143 @@
 use crate::{NodeContext, RouterError, RouterResult};
-use cel_interpreter::{Context, Program, Value};
+use cel_interpreter::extractors::Arguments;
+use cel_interpreter::{Context, ExecutionError, Program, Value};
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// CEL builtins that custom functions may not shadow
+const RESERVED_FUNCTIONS: &[&str] = &[
+    "all", "bool", "bytes", "contains", "double", "duration", "dyn", "endsWith", "exists",
+    "exists_one", "filter", "has", "int", "map", "matches", "max", "min", "size",
+    "startsWith", "string", "timestamp", "type", "uint",
+];
+
+/// A function registered for use in routing policies and constraints
+type CustomFunction = Arc<dyn Fn(&[Value]) -> RouterResult<Value> + Send + Sync>;
+
+thread_local! {
+    /// Compiled programs by expression text. `Program` holds `Rc`s, so it
+    /// can't be shared between threads; each thread compiles its own copy.
+    static PROGRAMS: RefCell<HashMap<String, Rc<Program>>> = RefCell::new(HashMap::new());
+}
 
 /// CEL evaluator for routing decisions
+///
+/// Compiled programs are cached by expression text, so each distinct
+/// expression is only compiled once per thread no matter how often it is
+/// evaluated.
 pub struct CALEvaluator {
-    context: Context,
+    functions: HashMap<String, CustomFunction>,
+    compiled: Mutex<HashSet<String>>,
+    cache_hits: AtomicUsize,
+    cache_misses: AtomicUsize,
 }
 
 impl CALEvaluator {
     /// Create a new evaluator
     pub fn new() -> Self {
         Self {
-            context: Context::default(),
+            functions: HashMap::new(),
+            compiled: Mutex::new(HashSet::new()),
+            cache_hits: AtomicUsize::new(0),
+            cache_misses: AtomicUsize::new(0),
         }
     }
 
-    /// Evaluate a constraint expression (returns boolean)
-    pub fn evaluate_constraint(&self, expression: &str, ctx: &NodeContext) -> RouterResult<bool> {
-        let program = Program::compile(expression)
+    /// Get the compiled program for an expression, compiling it on first use
+    pub fn compile(&self, expression: &str) -> RouterResult<Rc<Program>> {
+        if let Some(program) = PROGRAMS.with(|programs| programs.borrow().get(expression).cloned()) {
+            self.cache_hits.fetch_add(1, Ordering::Relaxed);
+            self.compiled.lock().unwrap_or_else(PoisonError::into_inner).insert(expression.to_string());
+            return Ok(program);
+        }
+
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+        let program = catch_panic(|| Program::compile(expression))
+            .and_then(|compiled| compiled.map_err(|e| e.to_string()))
+            .map(Rc::new)
             .map_err(|e| RouterError::InvalidPolicy(format!("CEL compilation: {}", e)))?;
+        PROGRAMS.with(|programs| programs.borrow_mut().insert(expression.to_string(), program.clone()));
+        self.compiled.lock().unwrap_or_else(PoisonError::into_inner).insert(expression.to_string());
+        Ok(program)
+    }
+
+    /// Number of distinct expressions this evaluator has compiled
+    pub fn cached_programs(&self) -> usize {
+        self.compiled.lock().unwrap_or_else(PoisonError::into_inner).len()
+    }
+
+    /// Compile cache (hits, misses) since the evaluator was created
+    pub fn cache_stats(&self) -> (usize, usize) {
+        (
+            self.cache_hits.load(Ordering::Relaxed),
+            self.cache_misses.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Make a custom function callable from routing policies and constraints.
+    ///
+    /// Fails if `name` isn't a valid identifier or shadows a CEL builtin.
+    pub fn register_function<F>(&mut self, name: &str, f: F) -> RouterResult<()>
+    where
+        F: Fn(&[Value]) -> RouterResult<Value> + Send + Sync + 'static,
+    {
+        let valid_identifier = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_identifier {
+            return Err(RouterError::InvalidPolicy(
+                format!("'{}' is not a valid CEL function name", name)
+            ));
+        }
+        if RESERVED_FUNCTIONS.contains(&name) {
+            return Err(RouterError::InvalidPolicy(
+                format!("'{}' is a reserved CEL builtin", name)
+            ));
+        }
 
-        let cel_context = ctx.to_cel_context();
-        let result = program.execute(&self.context, &cel_context)
-            .map_err(|e| RouterError::InvalidPolicy(format!("CEL execution: {}", e)))?;
+        self.functions.insert(name.to_string(), Arc::new(f));
+        Ok(())
+    }
+
+    /// The evaluation context for `ctx`: the builtins, the registered
+    /// functions and the node's variables
+    fn context(&self, ctx: &NodeContext) -> Context<'static> {
+        let mut context = Context::default();
+        for (name, f) in &self.functions {
+            let (function_name, f) = (name.clone(), f.clone());
+            context.add_function(name, move |Arguments(args): Arguments| {
+                f(&args).map_err(|e| ExecutionError::function_error(&function_name, &e.to_string()))
+            });
+        }
+        for (name, value) in ctx.to_cel_context() {
+            context.add_variable(name, value);
+        }
+        context
+    }
+
+    /// Run an expression against a node. A reference to a function or
+    /// variable the evaluator doesn't provide is a CEL evaluation error;
+    /// anything else that fails is an invalid policy.
+    fn execute(&self, expression: &str, ctx: &NodeContext) -> RouterResult<Value> {
+        let program = self.compile(expression)?;
+        let context = self.context(ctx);
+        match catch_panic(|| program.execute(&context)) {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(e @ ExecutionError::UndeclaredReference(_))) => {
+                Err(RouterError::Celeval(format!("'{}': {}", expression, e)))
+            }
+            Ok(Err(e)) => Err(RouterError::InvalidPolicy(format!("CEL execution: {}", e))),
+            Err(e) => Err(RouterError::InvalidPolicy(format!("CEL execution: {}", e))),
+        }
+    }
+
+    /// Evaluate a constraint expression (returns boolean)
+    pub fn evaluate_constraint(&self, expression: &str, ctx: &NodeContext) -> RouterResult<bool> {
+        let result = self.execute(expression, ctx)?;
 
         match result {
             Value::Bool(b) => Ok(b),
@@ -33,15 +149,10 @@ impl CALEvaluator {
 
     /// Evaluate a routing policy expression (returns string)
     pub fn evaluate_routing_policy(&self, expression: &str, ctx: &NodeContext) -> RouterResult<String> {
-        let program = Program::compile(expression)
-            .map_err(|e| RouterError::InvalidPolicy(format!("CEL compilation: {}", e)))?;
-
-        let cel_context = ctx.to_cel_context();
-        let result = program.execute(&self.context, &cel_context)
-            .map_err(|e| RouterError::InvalidPolicy(format!("CEL execution: {}", e)))?;
+        let result = self.execute(expression, ctx)?;
 
         match result {
-            Value::String(s) => Ok(s),
+            Value::String(s) => Ok(s.to_string()),
             _ => Err(RouterError::InvalidPolicy(
                 format!("Routing policy '{}' did not evaluate to string", expression)
             ))
@@ -50,12 +161,7 @@ impl CALEvaluator {
 
     /// Evaluate a numeric expression (for metrics and thresholds)
     pub fn evaluate_numeric(&self, expression: &str, ctx: &NodeContext) -> RouterResult<f64> {
-        let program = Program::compile(expression)
-            .map_err(|e| RouterError::InvalidPolicy(format!("CEL compilation: {}", e)))?;
-
-        let cel_context = ctx.to_cel_context();
-        let result = program.execute(&self.context, &cel_context)
-            .map_err(|e| RouterError::InvalidPolicy(format!("CEL execution: {}", e)))?;
+        let result = self.execute(expression, ctx)?;
 
         match result {
             Value::Int(i) => Ok(i as f64),
@@ -67,6 +173,18 @@ impl CALEvaluator {
     }
 }
 
+/// Run `f`, turning a panic into its message. cel-interpreter 0.4 panics on
+/// integer literals outside `i32` and on comparing values of different types.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "evaluation panicked".to_string())
+}
+
 impl Default for CALEvaluator {
     fn default() -> Self {
         Self::new()
@@ -83,8 +201,8 @@ mod tests {
         let mut ctx = NodeContext::new(semver::Version::parse("1.2.0").unwrap());
         ctx.available_memory = 4 * 1024 * 1024 * 1024; // 4Gi
 
-        assert!(evaluator.evaluate_constraint("available_memory > 2147483648", &ctx).unwrap());
-        assert!(!evaluator.evaluate_constraint("available_memory > 8589934592", &ctx).unwrap());
+        assert!(evaluator.evaluate_constraint("available_memory > 2147483648.0", &ctx).unwrap());
+        assert!(!evaluator.evaluate_constraint("available_memory > 8589934592.0", &ctx).unwrap());
     }
 
     #[test]
@@ -95,4 +213,72 @@ mod tests {
         let result = evaluator.evaluate_routing_policy("'gpu-pool'", &ctx).unwrap();
         assert_eq!(result, "gpu-pool");
     }
+
+    #[test]
+    fn test_compiled_programs_are_cached() {
+        let evaluator = CALEvaluator::new();
+        let mut ctx = NodeContext::new(semver::Version::parse("1.2.0").unwrap());
+        ctx.cpu_usage = 0.4;
+
+        let first = evaluator.evaluate_constraint("cpu_usage < 0.8", &ctx).unwrap();
+        assert_eq!(evaluator.cache_stats(), (0, 1));
+
+        // Second evaluation reuses the compiled program and gives the same result
+        let second = evaluator.evaluate_constraint("cpu_usage < 0.8", &ctx).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(evaluator.cache_stats(), (1, 1));
+        assert_eq!(evaluator.cached_programs(), 1);
+    }
+
+    #[test]
+    fn test_cache_is_shared_across_threads_safely() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CALEvaluator>();
+
+        let evaluator = Arc::new(CALEvaluator::new());
+        let ctx = NodeContext::new(semver::Version::parse("1.2.0").unwrap());
+        assert!(evaluator.evaluate_constraint("queue_depth < 10", &ctx).unwrap());
+
+        // Another thread compiles its own copy of the program
+        let shared = evaluator.clone();
+        std::thread::spawn(move || assert!(shared.evaluate_constraint("queue_depth < 10", &ctx).unwrap()))
+            .join()
+            .unwrap();
+        assert_eq!(evaluator.cache_stats(), (0, 2));
+        assert_eq!(evaluator.cached_programs(), 1);
+    }
+
+    #[test]
+    fn test_invalid_policies() {
+        let evaluator = CALEvaluator::new();
+        let ctx = NodeContext::new(semver::Version::parse("1.2.0").unwrap());
+
+        // Syntax error, an integer literal CEL can't hold, comparing a double
+        // with an int, and a constraint that isn't boolean
+        for expression in ["cpu_usage <", "queue_depth < 4294967296", "cpu_usage < 1", "cpu_usage"] {
+            let result = evaluator.evaluate_constraint(expression, &ctx);
+            assert!(matches!(result, Err(RouterError::InvalidPolicy(_))), "{}: {:?}", expression, result);
+        }
+    }
+
+    #[test]
+    fn test_custom_functions() {
+        let mut evaluator = CALEvaluator::new();
+        evaluator.register_function("queue_depth_over", |args| match args {
+            [Value::Int(depth), Value::Int(threshold)] => Ok(Value::Bool(depth > threshold)),
+            _ => Err(RouterError::InvalidPolicy("queue_depth_over(depth, threshold)".to_string())),
+        }).unwrap();
+
+        let mut ctx = NodeContext::new(semver::Version::parse("1.2.0").unwrap());
+        ctx.queue_depth = 50;
+
+        let policy = "queue_depth_over(queue_depth, 10) ? 'overflow-queue' : 'daemon-queue'";
+        assert_eq!(evaluator.evaluate_routing_policy(policy, &ctx).unwrap(), "overflow-queue");
+
+        let unknown = evaluator.evaluate_constraint("time_of_day_utc() > 12", &ctx);
+        assert!(matches!(unknown, Err(RouterError::Celeval(_))));
+
+        assert!(evaluator.register_function("size", |_| Ok(Value::Null)).is_err());
+        assert!(evaluator.register_function("not a name", |_| Ok(Value::Null)).is_err());
+    }
 }
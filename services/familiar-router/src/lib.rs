@@ -3,15 +3,17 @@
 //! This crate provides intelligent routing decisions based on CEL expressions
 //! defined in schema constraints and routing policies.
 
+pub mod circuit_breaker;
 pub mod context;
 pub mod evaluator;
 pub mod router;
 pub mod resource_tracker;
 pub mod telemetry;
 
-pub use context::NodeContext;
+pub use circuit_breaker::{BreakerState, BreakerTransition};
+pub use context::{NodeContext, NodeContextBuilder};
 pub use evaluator::CALEvaluator;
-pub use router::{RouteDecision, Router, TelemetryProvider, TelemetrySnapshot};
+pub use router::{DecisionOutcome, DecisionRecord, RouteDecision, RouteTarget, Router, RoutingTrace, TelemetryProvider, TelemetrySnapshot, TieBreak};
 pub use resource_tracker::{ResourceTracker, ResourceRequirements, ResourceLease, ResourceUtilization};
 pub use telemetry::MockTelemetryProvider;
 
@@ -35,7 +37,159 @@ pub enum RouterError {
 
     #[error("Node constraint violation: {0}")]
     ConstraintViolation(String),
+
+    #[error("Circuit breaker open for node: {0}")]
+    CircuitOpen(String),
+
+    #[error("Routing decision timeout exceeded ({budget:?}) after {} candidate(s)", .trace.attempts.len())]
+    DecisionTimeout {
+        budget: std::time::Duration,
+        /// Candidates tried before the budget ran out
+        trace: router::RoutingTrace,
+    },
+}
+
+impl RouterError {
+    /// Stable machine-readable code for the failure category, e.g. for
+    /// Windmill flows to branch on
+    pub fn kind(&self) -> &'static str {
+        match self {
+            RouterError::Celeval(_) => "cel_evaluation",
+            RouterError::Json(_) => "invalid_json",
+            RouterError::RouteNotFound(_) => "route_not_found",
+            RouterError::InvalidPolicy(_) => "invalid_policy",
+            RouterError::InsufficientResources(_) => "insufficient_resources",
+            RouterError::ConstraintViolation(_) => "constraint_violation",
+            RouterError::CircuitOpen(_) => "circuit_open",
+            RouterError::DecisionTimeout { .. } => "decision_timeout",
+        }
+    }
+
+    /// The variant's own data, as structured JSON
+    fn detail(&self) -> serde_json::Value {
+        use serde_json::json;
+
+        match self {
+            RouterError::Celeval(reason)
+            | RouterError::InvalidPolicy(reason)
+            | RouterError::InsufficientResources(reason)
+            | RouterError::ConstraintViolation(reason) => json!({ "reason": reason }),
+            RouterError::Json(e) => json!({
+                "line": e.line(),
+                "column": e.column(),
+                "category": format!("{:?}", e.classify()).to_lowercase(),
+            }),
+            RouterError::RouteNotFound(trigger) => json!({ "trigger": trigger }),
+            RouterError::CircuitOpen(node_id) => json!({ "node_id": node_id }),
+            RouterError::DecisionTimeout { budget, trace } => json!({
+                "budget_ms": budget.as_millis() as u64,
+                "attempts": trace.attempts,
+            }),
+        }
+    }
+}
+
+/// Serializes as `{ "kind", "message", "detail" }` so the failure category
+/// survives being passed along as JSON
+impl serde::Serialize for RouterError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("RouterError", 3)?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("detail", &self.detail())?;
+        state.end()
+    }
 }
 
+/// Identifier of a node, as used in telemetry and capacity registration
+pub type NodeId = String;
+
 /// Result type for routing operations
 pub type RouterResult<T> = Result<T, RouterError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_error_json_shape() {
+        let reason = |kind: &str, message: &str, reason: &str| {
+            json!({ "kind": kind, "message": message, "detail": { "reason": reason } })
+        };
+        let cases = [
+            (
+                RouterError::Celeval("'cpu_usage <': parse error".to_string()),
+                reason("cel_evaluation", "CEL evaluation error: 'cpu_usage <': parse error", "'cpu_usage <': parse error"),
+            ),
+            (
+                RouterError::InvalidPolicy("Unknown node type: gpu".to_string()),
+                reason("invalid_policy", "Invalid routing policy: Unknown node type: gpu", "Unknown node type: gpu"),
+            ),
+            (
+                RouterError::InsufficientResources("node full".to_string()),
+                reason("insufficient_resources", "Insufficient resources: node full", "node full"),
+            ),
+            (
+                RouterError::ConstraintViolation("All candidate nodes rejected".to_string()),
+                reason(
+                    "constraint_violation",
+                    "Node constraint violation: All candidate nodes rejected",
+                    "All candidate nodes rejected",
+                ),
+            ),
+            (
+                RouterError::RouteNotFound("weave".to_string()),
+                json!({
+                    "kind": "route_not_found",
+                    "message": "Route not found for trigger: weave",
+                    "detail": { "trigger": "weave" },
+                }),
+            ),
+            (
+                RouterError::CircuitOpen("familiar-worker".to_string()),
+                json!({
+                    "kind": "circuit_open",
+                    "message": "Circuit breaker open for node: familiar-worker",
+                    "detail": { "node_id": "familiar-worker" },
+                }),
+            ),
+            (
+                RouterError::from(serde_json::from_str::<serde_json::Value>("{\n  \"a\": }").unwrap_err()),
+                json!({
+                    "kind": "invalid_json",
+                    "message": "JSON parsing error: expected value at line 2 column 8",
+                    "detail": { "line": 2, "column": 8, "category": "syntax" },
+                }),
+            ),
+            (
+                RouterError::DecisionTimeout {
+                    budget: std::time::Duration::from_millis(100),
+                    trace: router::RoutingTrace {
+                        attempts: vec![router::RouteAttempt {
+                            node_id: "familiar-worker".to_string(),
+                            rejection: Some("Circuit breaker open for node: familiar-worker".to_string()),
+                        }],
+                    },
+                },
+                json!({
+                    "kind": "decision_timeout",
+                    "message": "Routing decision timeout exceeded (100ms) after 1 candidate(s)",
+                    "detail": {
+                        "budget_ms": 100,
+                        "attempts": [{
+                            "node_id": "familiar-worker",
+                            "rejection": "Circuit breaker open for node: familiar-worker",
+                        }],
+                    },
+                }),
+            ),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(serde_json::to_value(&error).unwrap(), expected, "{}", error.kind());
+        }
+    }
+}
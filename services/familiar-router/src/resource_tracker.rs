@@ -1,17 +1,94 @@
 use crate::RouterResult;
 use std::collections::HashMap;
+use std::iter::Sum;
+use std::ops::{Add, Sub};
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+use std::time::{Duration, Instant};
 
 /// Resource requirements for a task
-#[derive(Debug, Clone)]
+///
+/// The same shape describes a node's capacity and what is left of it. Adding
+/// and subtracting work per dimension: subtraction saturates at zero, and an
+/// optional dimension that is `None` on the left stays `None`, since the
+/// node doesn't have it.
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct ResourceRequirements {
     pub memory_bytes: u64,
     pub cpu_cores: f64,
+    /// `None` means no GPU, which is the same as zero GPU memory
     pub gpu_memory_bytes: Option<u64>,
+    /// `None` means bandwidth isn't tracked, so it never constrains a fit
     pub network_bandwidth_mbps: Option<u32>,
 }
 
+impl ResourceRequirements {
+    /// Whether these requirements fit in the headroom `util` has left
+    ///
+    /// Memory and CPU must fit in what remains. A GPU requirement only fits
+    /// on a node with enough GPU memory left, and bandwidth is checked only
+    /// when the node declares it.
+    pub fn fits_in(&self, util: &ResourceUtilization) -> bool {
+        let remaining = util.remaining();
+        let fits_bandwidth = match (self.network_bandwidth_mbps, remaining.network_bandwidth_mbps) {
+            (Some(needed), Some(left)) => needed <= left,
+            _ => true,
+        };
+
+        self.memory_bytes <= remaining.memory_bytes
+            && self.cpu_cores <= remaining.cpu_cores
+            && self.gpu_memory_bytes.unwrap_or(0) <= remaining.gpu_memory_bytes.unwrap_or(0)
+            && fits_bandwidth
+    }
+}
+
+impl Add for ResourceRequirements {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        fn add_optional<T: Add<Output = T>>(lhs: Option<T>, rhs: Option<T>) -> Option<T> {
+            match (lhs, rhs) {
+                (Some(lhs), Some(rhs)) => Some(lhs + rhs),
+                (lhs, rhs) => lhs.or(rhs),
+            }
+        }
+
+        Self {
+            memory_bytes: self.memory_bytes + rhs.memory_bytes,
+            cpu_cores: self.cpu_cores + rhs.cpu_cores,
+            gpu_memory_bytes: add_optional(self.gpu_memory_bytes, rhs.gpu_memory_bytes),
+            network_bandwidth_mbps: add_optional(self.network_bandwidth_mbps, rhs.network_bandwidth_mbps),
+        }
+    }
+}
+
+impl Sub for ResourceRequirements {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            memory_bytes: self.memory_bytes.saturating_sub(rhs.memory_bytes),
+            cpu_cores: (self.cpu_cores - rhs.cpu_cores).max(0.0),
+            gpu_memory_bytes: self.gpu_memory_bytes
+                .map(|gpu| gpu.saturating_sub(rhs.gpu_memory_bytes.unwrap_or(0))),
+            network_bandwidth_mbps: self.network_bandwidth_mbps
+                .map(|bandwidth| bandwidth.saturating_sub(rhs.network_bandwidth_mbps.unwrap_or(0))),
+        }
+    }
+}
+
+impl Sum for ResourceRequirements {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
 /// Resource lease information for preventing OOM and resource conflicts
-#[derive(Debug, Clone)]
+///
+/// Leases are scope-bound: dropping one (including during a panic unwind)
+/// returns its reservation to the tracker. Call [`ResourceLease::detach`] to
+/// keep the reservation past the lease's scope; it then has to be released
+/// explicitly with [`ResourceTracker::release_resources`] or it expires.
+#[derive(Debug)]
 pub struct ResourceLease {
     pub task_id: String,
     pub node_id: String,
@@ -19,92 +96,196 @@ pub struct ResourceLease {
     pub resources_reserved: ResourceRequirements,
     pub leased_at: std::time::Instant,
     pub lease_id: String, // Unique identifier for this lease
+    /// When the reservation lapses and may be reaped, for leases taken with a TTL
+    pub expires_at: Option<Instant>,
+    tracker: Weak<Mutex<TrackerState>>,
 }
 
-/// Resource tracker to prevent over-subscription and OOM
-pub struct ResourceTracker {
+impl ResourceLease {
+    /// Keep the reservation after this lease is dropped, returning its ID
+    pub fn detach(mut self) -> String {
+        self.tracker = Weak::new();
+        std::mem::take(&mut self.lease_id)
+    }
+}
+
+impl Drop for ResourceLease {
+    fn drop(&mut self) {
+        let Some(state) = self.tracker.upgrade() else {
+            return;
+        };
+        // Never panic in drop, even if another thread poisoned the lock
+        let mut state = state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(leases) = state.active_leases.get_mut(&self.node_id) {
+            leases.retain(|lease| lease.lease_id != self.lease_id);
+        }
+    }
+}
+
+/// A reservation as recorded by the tracker
+#[derive(Debug, Clone)]
+struct LeaseRecord {
+    task_id: String,
+    node_id: String,
+    estimated_duration_ms: u64,
+    resources_reserved: ResourceRequirements,
+    leased_at: std::time::Instant,
+    lease_id: String,
+    expires_at: Option<Instant>,
+}
+
+impl LeaseRecord {
+    fn is_stale(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
     node_capacities: HashMap<String, ResourceRequirements>,
-    active_leases: HashMap<String, Vec<ResourceLease>>,
+    active_leases: HashMap<String, Vec<LeaseRecord>>,
     lease_counter: u64, // For generating unique lease IDs
 }
 
+/// Resource tracker to prevent over-subscription and OOM
+pub struct ResourceTracker {
+    state: Arc<Mutex<TrackerState>>,
+}
+
 impl ResourceTracker {
     /// Create a new resource tracker
     pub fn new() -> Self {
         Self {
-            node_capacities: HashMap::new(),
-            active_leases: HashMap::new(),
-            lease_counter: 0,
+            state: Arc::new(Mutex::new(TrackerState::default())),
         }
     }
 
+    fn state(&self) -> MutexGuard<'_, TrackerState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     /// Register a node's total capacity
     pub fn register_node_capacity(&mut self, node_id: &str, capacity: ResourceRequirements) {
-        self.node_capacities.insert(node_id.to_string(), capacity);
+        self.state().node_capacities.insert(node_id.to_string(), capacity);
+    }
+
+    /// Ids of all nodes with registered capacity, sorted
+    pub fn nodes(&self) -> Vec<String> {
+        let mut nodes: Vec<String> = self.state().node_capacities.keys().cloned().collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
+    /// Registered capacity for a node
+    pub fn capacity(&self, node_id: &str) -> Option<ResourceRequirements> {
+        self.state().node_capacities.get(node_id).cloned()
     }
 
     /// Check if a node can accommodate additional resource requirements
     pub fn can_accommodate(&self, node_id: &str, requirements: &ResourceRequirements) -> RouterResult<bool> {
-        let capacity = self.node_capacities.get(node_id)
-            .ok_or_else(|| crate::RouterError::InvalidPolicy(
-                format!("No capacity registered for node: {}", node_id)
-            ))?;
+        can_accommodate(&self.state(), node_id, requirements)
+    }
 
-        let active_leases = self.active_leases.get(node_id)
-            .map(|leases| leases.as_slice())
-            .unwrap_or(&[]);
+    /// Lease resources for a task. The reservation is held until the returned
+    /// lease is dropped.
+    pub fn acquire(
+        &mut self,
+        task_id: String,
+        node_id: String,
+        requirements: ResourceRequirements,
+        estimated_duration_ms: u64
+    ) -> RouterResult<ResourceLease> {
+        self.acquire_until(task_id, node_id, requirements, estimated_duration_ms, None)
+    }
 
-        let total_reserved = self.calculate_total_reserved(active_leases);
+    /// Lease resources on a node that lapse after `ttl`, so a holder that
+    /// crashes without releasing them can't starve the node. Lapsed leases
+    /// are reclaimed by [`ResourceTracker::reap_expired`].
+    pub fn reserve_with_ttl(
+        &mut self,
+        node_id: &str,
+        requirements: ResourceRequirements,
+        ttl: Duration,
+    ) -> RouterResult<ResourceLease> {
+        let task_id = format!("ttl_reservation_{}", node_id);
+        let ttl_ms = ttl.as_millis() as u64;
+        self.acquire_until(task_id, node_id.to_string(), requirements, ttl_ms, Some(Instant::now() + ttl))
+    }
 
-        // Check if adding these requirements would exceed capacity
-        let would_exceed_memory = total_reserved.memory_bytes + requirements.memory_bytes > capacity.memory_bytes;
-        let would_exceed_cpu = total_reserved.cpu_cores + requirements.cpu_cores > capacity.cpu_cores;
+    /// Reclaim every lease whose TTL had passed at `now`, returning how many were reaped
+    pub fn reap_expired(&mut self, now: Instant) -> usize {
+        let mut reaped = 0;
 
-        let would_exceed_gpu = if let (Some(req_gpu), Some(cap_gpu)) = (requirements.gpu_memory_bytes, capacity.gpu_memory_bytes) {
-            total_reserved.gpu_memory_bytes.unwrap_or(0) + req_gpu > cap_gpu
-        } else {
-            false
-        };
+        for leases in self.state().active_leases.values_mut() {
+            let before = leases.len();
+            leases.retain(|lease| !lease.is_stale(now));
+            reaped += before - leases.len();
+        }
 
-        Ok(!(would_exceed_memory || would_exceed_cpu || would_exceed_gpu))
+        if reaped > 0 {
+            tracing::warn!("Reaped {} expired resource leases", reaped);
+        }
+        reaped
     }
 
-    /// Lease resources for a task
-    pub fn lease_resources(
+    fn acquire_until(
         &mut self,
         task_id: String,
         node_id: String,
         requirements: ResourceRequirements,
-        estimated_duration_ms: u64
-    ) -> RouterResult<String> {
-        if !self.can_accommodate(&node_id, &requirements)? {
+        estimated_duration_ms: u64,
+        expires_at: Option<Instant>,
+    ) -> RouterResult<ResourceLease> {
+        let mut state = self.state();
+        if !can_accommodate(&state, &node_id, &requirements)? {
             return Err(crate::RouterError::InsufficientResources(
                 format!("Cannot lease resources for task {} on node {}", task_id, node_id)
             ));
         }
 
-        self.lease_counter += 1;
-        let lease_id = format!("lease_{}", self.lease_counter);
-
-        let lease = ResourceLease {
+        state.lease_counter += 1;
+        let record = LeaseRecord {
             task_id,
             node_id: node_id.clone(),
             estimated_duration_ms,
             resources_reserved: requirements,
             leased_at: std::time::Instant::now(),
-            lease_id: lease_id.clone(),
+            lease_id: format!("lease_{}", state.lease_counter),
+            expires_at,
         };
 
-        self.active_leases.entry(node_id)
-            .or_insert_with(Vec::new)
-            .push(lease);
+        state.active_leases.entry(node_id)
+            .or_default()
+            .push(record.clone());
+
+        Ok(ResourceLease {
+            task_id: record.task_id,
+            node_id: record.node_id,
+            estimated_duration_ms: record.estimated_duration_ms,
+            resources_reserved: record.resources_reserved,
+            leased_at: record.leased_at,
+            lease_id: record.lease_id,
+            expires_at: record.expires_at,
+            tracker: Arc::downgrade(&self.state),
+        })
+    }
 
-        Ok(lease_id)
+    /// Lease resources for a task, returning the lease ID. The reservation is
+    /// held until [`ResourceTracker::release_resources`] is called or it expires.
+    pub fn lease_resources(
+        &mut self,
+        task_id: String,
+        node_id: String,
+        requirements: ResourceRequirements,
+        estimated_duration_ms: u64
+    ) -> RouterResult<String> {
+        self.acquire(task_id, node_id, requirements, estimated_duration_ms)
+            .map(ResourceLease::detach)
     }
 
     /// Release resources when a task completes
     pub fn release_resources(&mut self, node_id: &str, lease_id: &str) -> RouterResult<()> {
-        if let Some(leases) = self.active_leases.get_mut(node_id) {
+        if let Some(leases) = self.state().active_leases.get_mut(node_id) {
             let initial_len = leases.len();
             leases.retain(|lease| lease.lease_id != lease_id);
 
@@ -126,7 +307,7 @@ impl ResourceTracker {
     pub fn cleanup_expired_leases(&mut self) {
         let now = std::time::Instant::now();
 
-        for leases in self.active_leases.values_mut() {
+        for leases in self.state().active_leases.values_mut() {
             leases.retain(|lease| {
                 let elapsed = now.duration_since(lease.leased_at);
                 let is_expired = elapsed.as_millis() as u64 > lease.estimated_duration_ms * 2; // 2x grace period
@@ -141,16 +322,15 @@ impl ResourceTracker {
 
     /// Get current utilization percentage for a node
     pub fn get_utilization(&self, node_id: &str) -> RouterResult<ResourceUtilization> {
-        let capacity = self.node_capacities.get(node_id)
+        let state = self.state();
+        let capacity = state.node_capacities.get(node_id)
             .ok_or_else(|| crate::RouterError::InvalidPolicy(
                 format!("No capacity registered for node: {}", node_id)
             ))?;
 
-        let active_leases = self.active_leases.get(node_id)
-            .map(|leases| leases.as_slice())
-            .unwrap_or(&[]);
-
-        let reserved = self.calculate_total_reserved(active_leases);
+        let leases = leases_for(&state, node_id);
+        let reserved = total_reserved(leases);
+        let stale = total_reserved(&stale_leases(leases, Instant::now()));
 
         Ok(ResourceUtilization {
             memory_percent: (reserved.memory_bytes as f64 / capacity.memory_bytes as f64) * 100.0,
@@ -159,37 +339,139 @@ impl ResourceTracker {
                 (Some(reserved), Some(capacity)) => Some((reserved as f64 / capacity as f64) * 100.0),
                 _ => None,
             },
+            stale_memory_percent: (stale.memory_bytes as f64 / capacity.memory_bytes as f64) * 100.0,
+            stale_cpu_percent: (stale.cpu_cores / capacity.cpu_cores) * 100.0,
+            capacity: capacity.clone(),
+            reserved,
         })
     }
 
-    /// Calculate total reserved resources across all active leases
-    fn calculate_total_reserved(&self, leases: &[ResourceLease]) -> ResourceRequirements {
-        let mut total = ResourceRequirements {
-            memory_bytes: 0,
-            cpu_cores: 0.0,
-            gpu_memory_bytes: Some(0),
-            network_bandwidth_mbps: None,
-        };
+    /// Percentage of total registered capacity, across all nodes, that is not
+    /// reserved by a lease. 100% everywhere when nothing is leased.
+    pub fn available(&self) -> ResourceUtilization {
+        let state = self.state();
+        let mut capacity = ResourceRequirements::default();
+        let mut reserved = total_reserved(&[]);
+        let mut stale = total_reserved(&[]);
+        let mut has_gpu = false;
+        let now = Instant::now();
+
+        for (node_id, node_capacity) in &state.node_capacities {
+            capacity.memory_bytes += node_capacity.memory_bytes;
+            capacity.cpu_cores += node_capacity.cpu_cores;
+
+            let node_reserved = total_reserved(leases_for(&state, node_id));
+            reserved.memory_bytes += node_reserved.memory_bytes;
+            reserved.cpu_cores += node_reserved.cpu_cores;
+
+            let node_stale = total_reserved(&stale_leases(leases_for(&state, node_id), now));
+            stale.memory_bytes += node_stale.memory_bytes;
+            stale.cpu_cores += node_stale.cpu_cores;
+
+            if let Some(gpu) = node_capacity.gpu_memory_bytes {
+                has_gpu = true;
+                capacity.gpu_memory_bytes = Some(capacity.gpu_memory_bytes.unwrap_or(0) + gpu);
+                reserved.gpu_memory_bytes = Some(
+                    reserved.gpu_memory_bytes.unwrap_or(0) + node_reserved.gpu_memory_bytes.unwrap_or(0)
+                );
+            }
+        }
 
-        for lease in leases {
-            total.memory_bytes += lease.resources_reserved.memory_bytes;
-            total.cpu_cores += lease.resources_reserved.cpu_cores;
+        let free_percent = |reserved: f64, capacity: f64| {
+            if capacity > 0.0 { (1.0 - reserved / capacity) * 100.0 } else { 100.0 }
+        };
+        let held_percent = |held: f64, capacity: f64| {
+            if capacity > 0.0 { held / capacity * 100.0 } else { 0.0 }
+        };
 
-            if let (Some(total_gpu), Some(lease_gpu)) = (total.gpu_memory_bytes, lease.resources_reserved.gpu_memory_bytes) {
-                total.gpu_memory_bytes = Some(total_gpu + lease_gpu);
-            }
+        ResourceUtilization {
+            memory_percent: free_percent(reserved.memory_bytes as f64, capacity.memory_bytes as f64),
+            cpu_percent: free_percent(reserved.cpu_cores, capacity.cpu_cores),
+            gpu_percent: has_gpu.then(|| free_percent(
+                reserved.gpu_memory_bytes.unwrap_or(0) as f64,
+                capacity.gpu_memory_bytes.unwrap_or(0) as f64,
+            )),
+            stale_memory_percent: held_percent(stale.memory_bytes as f64, capacity.memory_bytes as f64),
+            stale_cpu_percent: held_percent(stale.cpu_cores, capacity.cpu_cores),
+            capacity,
+            reserved,
         }
+    }
+}
 
-        total
+impl Default for ResourceTracker {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
+fn leases_for<'a>(state: &'a TrackerState, node_id: &str) -> &'a [LeaseRecord] {
+    state.active_leases.get(node_id)
+        .map(|leases| leases.as_slice())
+        .unwrap_or(&[])
+}
+
+fn stale_leases(leases: &[LeaseRecord], now: Instant) -> Vec<LeaseRecord> {
+    leases.iter().filter(|lease| lease.is_stale(now)).cloned().collect()
+}
+
+fn can_accommodate(state: &TrackerState, node_id: &str, requirements: &ResourceRequirements) -> RouterResult<bool> {
+    let capacity = state.node_capacities.get(node_id)
+        .ok_or_else(|| crate::RouterError::InvalidPolicy(
+            format!("No capacity registered for node: {}", node_id)
+        ))?;
+
+    let total_reserved = total_reserved(leases_for(state, node_id));
+
+    // Check if adding these requirements would exceed capacity
+    let would_exceed_memory = total_reserved.memory_bytes + requirements.memory_bytes > capacity.memory_bytes;
+    let would_exceed_cpu = total_reserved.cpu_cores + requirements.cpu_cores > capacity.cpu_cores;
+
+    let would_exceed_gpu = if let (Some(req_gpu), Some(cap_gpu)) = (requirements.gpu_memory_bytes, capacity.gpu_memory_bytes) {
+        total_reserved.gpu_memory_bytes.unwrap_or(0) + req_gpu > cap_gpu
+    } else {
+        false
+    };
+
+    Ok(!(would_exceed_memory || would_exceed_cpu || would_exceed_gpu))
+}
+
+/// Calculate total reserved resources across all active leases
+fn total_reserved(leases: &[LeaseRecord]) -> ResourceRequirements {
+    let empty = ResourceRequirements {
+        gpu_memory_bytes: Some(0),
+        ..ResourceRequirements::default()
+    };
+    empty + leases.iter().map(|lease| lease.resources_reserved.clone()).sum()
+}
+
 /// Current resource utilization percentages
 #[derive(Debug, Clone)]
 pub struct ResourceUtilization {
     pub memory_percent: f64,
     pub cpu_percent: f64,
     pub gpu_percent: Option<f64>,
+    /// Share of memory held by leases whose TTL has passed but haven't been reaped
+    pub stale_memory_percent: f64,
+    /// Share of CPU held by leases whose TTL has passed but haven't been reaped
+    pub stale_cpu_percent: f64,
+    /// Capacity the percentages are relative to
+    pub capacity: ResourceRequirements,
+    /// Resources held by leases, including stale ones
+    pub reserved: ResourceRequirements,
+}
+
+impl ResourceUtilization {
+    /// Fraction of the most constrained resource still free (0.0 to 1.0)
+    pub fn headroom(&self) -> f64 {
+        let busiest = self.memory_percent.max(self.cpu_percent).max(self.gpu_percent.unwrap_or(0.0));
+        (1.0 - busiest / 100.0).clamp(0.0, 1.0)
+    }
+
+    /// Resources not yet reserved, in the same units as the capacity
+    pub fn remaining(&self) -> ResourceRequirements {
+        self.capacity.clone() - self.reserved.clone()
+    }
 }
 
 #[cfg(test)]
@@ -237,4 +519,131 @@ mod tests {
         let utilization = tracker.get_utilization("node1").unwrap();
         assert_eq!(utilization.memory_percent, 0.0);
     }
+
+    fn tracker_with_node() -> ResourceTracker {
+        let mut tracker = ResourceTracker::new();
+        tracker.register_node_capacity("node1", ResourceRequirements {
+            memory_bytes: 4 * 1024 * 1024 * 1024,
+            cpu_cores: 4.0,
+            gpu_memory_bytes: None,
+            network_bandwidth_mbps: None,
+        });
+        tracker
+    }
+
+    fn one_core() -> ResourceRequirements {
+        ResourceRequirements {
+            memory_bytes: 1024 * 1024 * 1024,
+            cpu_cores: 1.0,
+            gpu_memory_bytes: None,
+            network_bandwidth_mbps: None,
+        }
+    }
+
+    #[test]
+    fn test_dropped_lease_restores_capacity() {
+        let mut tracker = tracker_with_node();
+
+        {
+            let _lease = tracker.acquire("task1".to_string(), "node1".to_string(), one_core(), 30000).unwrap();
+            assert_eq!(tracker.available().cpu_percent, 75.0);
+        }
+        assert_eq!(tracker.available().cpu_percent, 100.0);
+        assert_eq!(tracker.available().memory_percent, 100.0);
+
+        // Detached leases outlive their scope until released
+        let lease_id = tracker.acquire("task2".to_string(), "node1".to_string(), one_core(), 30000)
+            .unwrap()
+            .detach();
+        assert_eq!(tracker.available().cpu_percent, 75.0);
+        tracker.release_resources("node1", &lease_id).unwrap();
+        assert_eq!(tracker.available().cpu_percent, 100.0);
+    }
+
+    #[test]
+    fn test_reap_expired_ttl_reservations() {
+        let mut tracker = tracker_with_node();
+        let ttl = Duration::from_secs(5);
+
+        // A holder that never drops its lease, like a crashed worker
+        let lease = tracker.reserve_with_ttl("node1", one_core(), ttl).unwrap();
+        let expires_at = lease.expires_at.unwrap();
+        std::mem::forget(lease);
+
+        let utilization = tracker.get_utilization("node1").unwrap();
+        assert_eq!(utilization.cpu_percent, 25.0);
+        assert_eq!(utilization.stale_cpu_percent, 0.0);
+
+        // Nothing has lapsed yet
+        assert_eq!(tracker.reap_expired(expires_at - Duration::from_secs(1)), 0);
+        assert_eq!(tracker.available().cpu_percent, 75.0);
+
+        // Advance the clock past the TTL
+        assert_eq!(tracker.reap_expired(expires_at + Duration::from_millis(1)), 1);
+        assert_eq!(tracker.available().cpu_percent, 100.0);
+        assert_eq!(tracker.reap_expired(expires_at + ttl), 0);
+    }
+
+    #[test]
+    fn test_requirement_that_just_fits() {
+        let mut tracker = tracker_with_node();
+        let _lease = tracker.acquire("task1".to_string(), "node1".to_string(), one_core(), 30000).unwrap();
+
+        // Exactly the 3Gi and 3 cores left
+        let rest = one_core() + one_core() + one_core();
+        let utilization = tracker.get_utilization("node1").unwrap();
+        assert_eq!(utilization.remaining(), rest);
+        assert!(rest.fits_in(&utilization));
+        assert!(tracker.can_accommodate("node1", &rest).unwrap());
+    }
+
+    #[test]
+    fn test_requirement_overflowing_one_dimension() {
+        let mut tracker = tracker_with_node();
+        let _lease = tracker.acquire("task1".to_string(), "node1".to_string(), one_core(), 30000).unwrap();
+        let utilization = tracker.get_utilization("node1").unwrap();
+
+        // Memory fits, but one core too many
+        let too_many_cores = ResourceRequirements { cpu_cores: 4.0, ..one_core() };
+        assert!(!too_many_cores.fits_in(&utilization));
+
+        // No GPU on the node, so any GPU memory overflows
+        let needs_gpu = ResourceRequirements { gpu_memory_bytes: Some(1), ..one_core() };
+        assert!(!needs_gpu.fits_in(&utilization));
+
+        // Bandwidth isn't registered for the node, so it never constrains
+        let needs_bandwidth = ResourceRequirements { network_bandwidth_mbps: Some(10_000), ..one_core() };
+        assert!(needs_bandwidth.fits_in(&utilization));
+    }
+
+    #[test]
+    fn test_requirement_arithmetic() {
+        let batch: ResourceRequirements = [
+            one_core(),
+            ResourceRequirements { gpu_memory_bytes: Some(512), network_bandwidth_mbps: Some(100), ..one_core() },
+        ].into_iter().sum();
+        assert_eq!(batch, ResourceRequirements {
+            memory_bytes: 2 * 1024 * 1024 * 1024,
+            cpu_cores: 2.0,
+            gpu_memory_bytes: Some(512),
+            network_bandwidth_mbps: Some(100),
+        });
+
+        // Subtraction saturates, and keeps dimensions the left side lacks absent
+        let left = one_core() - batch;
+        assert_eq!(left, ResourceRequirements::default());
+    }
+
+    #[test]
+    fn test_lease_released_on_panic_unwind() {
+        let mut tracker = tracker_with_node();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _lease = tracker.acquire("task1".to_string(), "node1".to_string(), one_core(), 30000).unwrap();
+            panic!("routing failed mid-flight");
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(tracker.available().cpu_percent, 100.0);
+    }
 }
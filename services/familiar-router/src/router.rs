@@ -1,11 +1,30 @@
-use crate::{CALEvaluator, NodeContext, ResourceTracker, RouterResult, ResourceRequirements};
-use std::collections::HashMap;
+use crate::circuit_breaker::{BreakerState, BreakerTransition, CircuitBreakers};
+use crate::{CALEvaluator, NodeContext, NodeId, ResourceTracker, ResourceUtilization, RouterResult, ResourceRequirements};
+use familiar_config::{CircuitBreakerConfig, TracingConfig};
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Time a routing decision may take, matching the `routing.decision_timeout_ms`
+/// manifest default
+pub const DEFAULT_DECISION_TIMEOUT: Duration = Duration::from_millis(5000);
 
 /// Telemetry provider interface for consistent data
+///
+/// Providers are async, so a backend can fetch telemetry over the network
+/// without blocking the routing path; the router awaits
+/// [`TelemetryProvider::snapshot`] within its decision timeout. Tests can use
+/// the in-process [`MockTelemetryProvider`](crate::MockTelemetryProvider).
 #[async_trait::async_trait]
 pub trait TelemetryProvider: Send + Sync {
     async fn get_node_context(&self, node_id: &str) -> RouterResult<NodeContext>;
     async fn snapshot(&self) -> RouterResult<TelemetrySnapshot>;
+
+    /// Called when a node's circuit breaker changes state
+    fn on_breaker_transition(&self, _transition: &BreakerTransition) {}
 }
 
 /// Snapshot of telemetry data for consistency
@@ -13,6 +32,24 @@ pub trait TelemetryProvider: Send + Sync {
 pub struct TelemetrySnapshot {
     pub node_states: HashMap<String, NodeContext>,
     pub captured_at: std::time::Instant,
+
+    /// Routing decisions made per node, filled in by [`Router::telemetry_snapshot`]
+    pub route_decisions: HashMap<String, u64>,
+
+    /// Lease utilization per node, filled in by [`Router::telemetry_snapshot`]
+    pub utilization: HashMap<String, ResourceUtilization>,
+}
+
+impl TelemetrySnapshot {
+    /// Snapshot of node states captured now, with no router-side metrics
+    pub fn new(node_states: HashMap<String, NodeContext>) -> Self {
+        Self {
+            node_states,
+            captured_at: std::time::Instant::now(),
+            route_decisions: HashMap::new(),
+            utilization: HashMap::new(),
+        }
+    }
 }
 
 /// Decision made by the router about where to send a request
@@ -29,6 +66,176 @@ pub struct RouteDecision {
 
     /// Resource lease ID for tracking
     pub lease_id: Option<String>,
+
+    /// Node(s) the request is bound for
+    pub target: RouteTarget,
+
+    /// Candidate nodes tried before settling on the target
+    pub trace: RoutingTrace,
+}
+
+impl RouteDecision {
+    /// The node the request was routed to, if a single node was chosen
+    pub fn routed_to(&self) -> Option<&str> {
+        match &self.target {
+            RouteTarget::Single(node_id) => Some(node_id),
+            RouteTarget::Weighted(_) => None,
+        }
+    }
+
+    /// Nodes that were tried and rejected before the chosen one, in order
+    pub fn fallback_path(&self) -> Vec<&str> {
+        self.trace.attempts.iter()
+            .filter(|attempt| attempt.rejection.is_some())
+            .map(|attempt| attempt.node_id.as_str())
+            .collect()
+    }
+}
+
+/// Candidate nodes considered for a request, in the order they were tried
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RoutingTrace {
+    pub attempts: Vec<RouteAttempt>,
+}
+
+/// One candidate node and why it was rejected, if it was
+#[derive(Debug, Clone, Serialize)]
+pub struct RouteAttempt {
+    pub node_id: NodeId,
+    pub rejection: Option<String>,
+}
+
+/// Where a routed request goes
+#[derive(Debug, Clone, PartialEq)]
+pub enum RouteTarget {
+    /// A single node was chosen
+    Single(NodeId),
+
+    /// Work is spread across nodes in proportion to their weights
+    Weighted(Vec<(NodeId, f64)>),
+}
+
+/// A routing decision kept by the router for post-mortems, whether or not
+/// it found a route
+#[derive(Debug, Clone)]
+pub struct DecisionRecord {
+    pub trigger: String,
+
+    /// Candidates tried, up to the decision or the failure
+    pub trace: RoutingTrace,
+
+    pub outcome: DecisionOutcome,
+}
+
+impl DecisionRecord {
+    /// The decision, if the request was routed
+    pub fn decision(&self) -> Option<&RouteDecision> {
+        match &self.outcome {
+            DecisionOutcome::Routed { decision, .. } => Some(decision.as_ref()),
+            DecisionOutcome::Failed(_) => None,
+        }
+    }
+}
+
+/// How a recorded routing decision ended
+#[derive(Debug, Clone)]
+pub enum DecisionOutcome {
+    /// Routed, with the chosen node's telemetry as the decision saw it
+    Routed {
+        node_context: NodeContext,
+        decision: Box<RouteDecision>,
+    },
+
+    /// No route; the error `route` returned, as text
+    Failed(String),
+}
+
+/// The most recent decisions, oldest first, up to `capacity`
+#[derive(Debug, Default)]
+struct DecisionHistory {
+    records: VecDeque<DecisionRecord>,
+    capacity: usize,
+    enabled: bool,
+}
+
+impl DecisionHistory {
+    fn is_recording(&self) -> bool {
+        self.enabled && self.capacity > 0
+    }
+
+    fn record(&mut self, record: DecisionRecord) {
+        if !self.is_recording() {
+            return;
+        }
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+}
+
+/// How [`Router::route`] picks among candidates that all pass their checks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TieBreak {
+    /// The first passing candidate in declared order: the preferred node,
+    /// then the trigger's fallbacks in the order given. Later candidates
+    /// aren't checked once one passes.
+    FirstDeclared,
+
+    /// The passing candidate with the most resource headroom; equal
+    /// headroom goes to the earlier-declared one
+    LeastLoaded,
+
+    /// A passing candidate picked at random from an RNG seeded with this
+    /// value, so the sequence of picks repeats from run to run
+    Random(u64),
+}
+
+/// [`TieBreak`] with the RNG it needs
+enum TieBreakState {
+    FirstDeclared,
+    LeastLoaded,
+    Random(Box<StdRng>),
+}
+
+impl From<TieBreak> for TieBreakState {
+    fn from(strategy: TieBreak) -> Self {
+        match strategy {
+            TieBreak::FirstDeclared => TieBreakState::FirstDeclared,
+            TieBreak::LeastLoaded => TieBreakState::LeastLoaded,
+            TieBreak::Random(seed) => TieBreakState::Random(Box::new(StdRng::seed_from_u64(seed))),
+        }
+    }
+}
+
+impl TieBreakState {
+    /// Pick one of the candidates that passed, given in declared order
+    fn pick(
+        &mut self,
+        resource_tracker: &ResourceTracker,
+        mut passing: Vec<(NodeId, NodeContext)>,
+    ) -> RouterResult<Option<(NodeId, NodeContext)>> {
+        if passing.is_empty() {
+            return Ok(None);
+        }
+
+        let index = match self {
+            TieBreakState::FirstDeclared => 0,
+            TieBreakState::LeastLoaded => {
+                let mut best = (0, f64::MIN);
+                for (index, (node_id, _)) in passing.iter().enumerate() {
+                    let headroom = resource_tracker.get_utilization(node_id)?.headroom();
+                    if headroom > best.1 {
+                        best = (index, headroom);
+                    }
+                }
+                best.0
+            }
+            TieBreakState::Random(rng) => rng.gen_range(0..passing.len()),
+        };
+
+        Ok(Some(passing.swap_remove(index)))
+    }
 }
 
 /// Main router for making intelligent routing decisions
@@ -36,6 +243,14 @@ pub struct Router {
     evaluator: CALEvaluator,
     resource_tracker: ResourceTracker,
     telemetry_provider: Box<dyn TelemetryProvider>,
+    routes: HashMap<String, RouteConfig>,
+    node_constraints: HashMap<NodeId, Vec<String>>,
+    breakers: CircuitBreakers,
+    route_decisions: HashMap<NodeId, u64>,
+    rng: Mutex<Box<dyn RngCore + Send>>,
+    decision_timeout: Duration,
+    tiebreak: TieBreakState,
+    history: DecisionHistory,
 }
 
 #[derive(Debug, Clone)]
@@ -43,6 +258,7 @@ struct RouteConfig {
     trigger: String,
     routing_policy: Option<String>,
     default_queue: Option<String>,
+    fallbacks: Vec<NodeId>,
 }
 
 impl Router {
@@ -52,9 +268,107 @@ impl Router {
             evaluator: CALEvaluator::new(),
             resource_tracker: ResourceTracker::new(),
             telemetry_provider,
+            routes: HashMap::new(),
+            node_constraints: HashMap::new(),
+            breakers: CircuitBreakers::new(CircuitBreakerConfig::default()),
+            route_decisions: HashMap::new(),
+            rng: Mutex::new(Box::new(StdRng::from_entropy())),
+            decision_timeout: DEFAULT_DECISION_TIMEOUT,
+            tiebreak: TieBreakState::FirstDeclared,
+            history: DecisionHistory::default(),
         }
     }
 
+    /// Keep the last `config.decision_history` decisions for
+    /// [`Router::recent_decisions`] (typically `config.observability.tracing`).
+    /// A capacity of 0 keeps none.
+    pub fn with_tracing_config(mut self, config: TracingConfig) -> Self {
+        self.history = DecisionHistory {
+            records: VecDeque::with_capacity(config.decision_history),
+            capacity: config.decision_history,
+            enabled: true,
+        };
+        self
+    }
+
+    /// Pause or resume recording decisions, keeping those already recorded
+    pub fn set_decision_recording(&mut self, enabled: bool) {
+        self.history.enabled = enabled;
+    }
+
+    /// Traces of up to the last `n` recorded decisions, oldest first
+    pub fn recent_decisions(&self, n: usize) -> Vec<RoutingTrace> {
+        self.recent_records(n).map(|record| record.trace.clone()).collect()
+    }
+
+    /// Up to the last `n` recorded decisions with the node context and
+    /// decision (or error) each trace led to, oldest first
+    pub fn recent_decision_records(&self, n: usize) -> Vec<DecisionRecord> {
+        self.recent_records(n).cloned().collect()
+    }
+
+    fn recent_records(&self, n: usize) -> impl Iterator<Item = &DecisionRecord> {
+        let skip = self.history.records.len().saturating_sub(n);
+        self.history.records.iter().skip(skip)
+    }
+
+    /// Choose how to pick among candidates that all pass (default
+    /// [`TieBreak::FirstDeclared`])
+    pub fn with_tiebreak(mut self, strategy: TieBreak) -> Self {
+        self.tiebreak = strategy.into();
+        self
+    }
+
+    /// Give up on a routing decision that takes longer than `timeout`
+    /// (typically `routing.decision_timeout_ms`)
+    pub fn with_decision_timeout(mut self, timeout: Duration) -> Self {
+        self.decision_timeout = timeout;
+        self
+    }
+
+    /// Use the given thresholds and cooldown for node circuit breakers
+    /// (typically `config.observability.circuit_breaker`)
+    pub fn with_breaker_config(mut self, config: CircuitBreakerConfig) -> Self {
+        self.breakers = CircuitBreakers::new(config);
+        self
+    }
+
+    /// Report whether work sent to a node succeeded, driving its circuit breaker
+    pub fn report_outcome(&mut self, node: NodeId, ok: bool) {
+        for transition in self.breakers.record(&node, ok) {
+            self.notify_transition(&transition);
+        }
+    }
+
+    /// Provider telemetry plus the router's own decision counts and lease
+    /// utilization, e.g. for [`TelemetrySnapshot::to_prometheus`]
+    pub async fn telemetry_snapshot(&self) -> RouterResult<TelemetrySnapshot> {
+        let mut snapshot = self.telemetry_provider.snapshot().await?;
+        snapshot.route_decisions = self.route_decisions.clone();
+        for node_id in self.resource_tracker.nodes() {
+            let utilization = self.resource_tracker.get_utilization(&node_id)?;
+            snapshot.utilization.insert(node_id, utilization);
+        }
+        Ok(snapshot)
+    }
+
+    /// Current circuit breaker state for a node
+    pub fn breaker_state(&self, node: &str) -> BreakerState {
+        self.breakers.state(node)
+    }
+
+    fn notify_transition(&self, transition: &BreakerTransition) {
+        tracing::info!("Circuit breaker for {} moved {:?} -> {:?}",
+                       transition.node_id, transition.from, transition.to);
+        self.telemetry_provider.on_breaker_transition(transition);
+    }
+
+    /// Use the given RNG for weighted routing (e.g. a seeded one in tests)
+    pub fn with_rng(mut self, rng: impl RngCore + Send + 'static) -> Self {
+        self.rng = Mutex::new(Box::new(rng));
+        self
+    }
+
     /// Register a node with its resource capacity
     pub fn register_node_capacity(&mut self, node_id: &str, capacity: ResourceRequirements) {
         self.resource_tracker.register_node_capacity(node_id, capacity);
@@ -66,16 +380,92 @@ impl Router {
             trigger: trigger.to_string(),
             routing_policy: routing_policy.map(|s| s.to_string()),
             default_queue: default_queue.map(|s| s.to_string()),
+            fallbacks: Vec::new(),
         };
 
-        // For simplicity, we'll handle routes dynamically in the routing logic
-        // In a real implementation, this would build a routing table
+        self.routes.insert(config.trigger.clone(), config);
+    }
+
+    /// Declare the nodes to try, in order, when the preferred node for a
+    /// trigger is rejected
+    pub fn set_fallbacks(&mut self, trigger: &str, fallbacks: &[&str]) -> RouterResult<()> {
+        let route = self.routes.get_mut(trigger)
+            .ok_or_else(|| crate::RouterError::RouteNotFound(trigger.to_string()))?;
+        route.fallbacks = fallbacks.iter().map(|node_id| node_id.to_string()).collect();
+        Ok(())
+    }
+
+    /// Add a CEL constraint a node's telemetry must satisfy to receive work
+    pub fn add_node_constraint(&mut self, node_id: &str, expression: &str) {
+        self.node_constraints.entry(node_id.to_string())
+            .or_default()
+            .push(expression.to_string());
+    }
+
+    /// Make a custom function available to routing policies and node constraints
+    pub fn register_function<F>(&mut self, name: &str, f: F) -> RouterResult<()>
+    where
+        F: Fn(&[cel_interpreter::Value]) -> RouterResult<cel_interpreter::Value> + Send + Sync + 'static,
+    {
+        self.evaluator.register_function(name, f)
+    }
+
+    /// Compile every registered routing policy up front so the first
+    /// requests don't pay for CEL compilation
+    pub fn precompile_all(&mut self) -> RouterResult<()> {
+        let policies = self.routes.values().filter_map(|r| r.routing_policy.as_deref());
+        for expression in policies.chain(self.node_constraints.values().flatten().map(String::as_str)) {
+            self.evaluator.compile(expression)?;
+        }
+        Ok(())
+    }
+
+    /// CEL compile cache (hits, misses)
+    pub fn cache_stats(&self) -> (usize, usize) {
+        self.evaluator.cache_stats()
     }
 
     /// Make a routing decision for a given trigger and input
+    ///
+    /// Candidates are the size-based preferred node followed by the
+    /// trigger's fallbacks, checked in that order; the router's [`TieBreak`]
+    /// decides between those that pass.
+    ///
+    /// Fails with [`RouterError::DecisionTimeout`](crate::RouterError::DecisionTimeout)
+    /// once the decision outlasts the router's decision timeout. CEL isn't
+    /// interrupted mid-evaluation, so the budget is checked between steps.
+    ///
+    /// Failures are recorded in the decision history alongside routes.
     pub async fn route(&mut self, trigger: &str, input: &serde_json::Value) -> RouterResult<RouteDecision> {
+        let mut trace = RoutingTrace::default();
+        let result = self.decide(trigger, input, &mut trace).await;
+        if self.history.is_recording() {
+            let outcome = match &result {
+                Ok((decision, node_context)) => DecisionOutcome::Routed {
+                    node_context: node_context.clone(),
+                    decision: Box::new(decision.clone()),
+                },
+                Err(e) => DecisionOutcome::Failed(e.to_string()),
+            };
+            self.history.record(DecisionRecord { trigger: trigger.to_string(), trace, outcome });
+        }
+        result.map(|(decision, _)| decision)
+    }
+
+    /// [`Router::route`], returning the chosen node's telemetry alongside the
+    /// decision and leaving the candidates tried in `trace`
+    async fn decide(
+        &mut self,
+        trigger: &str,
+        input: &serde_json::Value,
+        trace: &mut RoutingTrace,
+    ) -> RouterResult<(RouteDecision, NodeContext)> {
+        let started = Instant::now();
+
         // Get current telemetry snapshot for consistent evaluation
-        let snapshot = self.telemetry_provider.snapshot().await?;
+        let snapshot = tokio::time::timeout(self.decision_timeout, self.telemetry_provider.snapshot())
+            .await
+            .map_err(|_| self.decision_timeout_error(RoutingTrace::default()))??;
 
         // Find suitable nodes based on routing policies
         // This is a simplified implementation - in reality, this would
@@ -89,46 +479,88 @@ impl Router {
             "familiar-worker" // Standard node for smaller inputs
         };
 
-        // Check if target node can accept work
-        let node_context = snapshot.node_states.get(target_node)
-            .ok_or_else(|| crate::RouterError::InvalidPolicy(
-                format!("No telemetry data for node: {}", target_node)
-            ))?;
+        // Walk the preferred node, then the trigger's fallbacks, taking the
+        // first one whose telemetry, constraints and capacity all check out
+        let route = self.routes.get(trigger);
+        let mut candidates = vec![target_node.to_string()];
+        for fallback in route.map(|r| r.fallbacks.as_slice()).unwrap_or_default() {
+            if !candidates.contains(fallback) {
+                candidates.push(fallback.clone());
+            }
+        }
 
-        // Check resource availability (simplified requirements)
-        let requirements = ResourceRequirements {
-            memory_bytes: 256 * 1024 * 1024, // 256Mi baseline
-            cpu_cores: 0.1,
-            gpu_memory_bytes: None,
-            network_bandwidth_mbps: None,
-        };
+        let requirements = baseline_requirements();
+        let mut rejections = Vec::new();
+        let mut passing = Vec::new();
 
-        if !self.resource_tracker.can_accommodate(target_node, &requirements)? {
-            return Err(crate::RouterError::InsufficientResources(
-                format!("Insufficient resources on node {}", target_node)
-            ));
+        for candidate in candidates {
+            self.check_decision_budget(started, trace)?;
+
+            if let Some(transition) = self.breakers.refresh(&candidate) {
+                self.notify_transition(&transition);
+            }
+
+            match self.check_candidate(&candidate, &snapshot, &requirements) {
+                Ok(node_context) => {
+                    trace.attempts.push(RouteAttempt { node_id: candidate.clone(), rejection: None });
+                    passing.push((candidate, node_context));
+                    if matches!(self.tiebreak, TieBreakState::FirstDeclared) {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    trace.attempts.push(RouteAttempt { node_id: candidate.clone(), rejection: Some(e.to_string()) });
+                    rejections.push((candidate, e));
+                }
+            }
         }
 
-        // Lease resources
-        let lease_id = self.resource_tracker.lease_resources(
+        let Some((target_node, node_context)) = self.tiebreak.pick(&self.resource_tracker, passing)? else {
+            // With a single candidate, surface its error as-is
+            if rejections.len() == 1 {
+                return Err(rejections.remove(0).1);
+            }
+            let reasons: Vec<String> = rejections.iter()
+                .map(|(node_id, e)| format!("{}: {}", node_id, e))
+                .collect();
+            return Err(crate::RouterError::ConstraintViolation(
+                format!("All candidate nodes rejected ({})", reasons.join("; "))
+            ));
+        };
+        let target_node = target_node.as_str();
+        self.check_decision_budget(started, trace)?;
+
+        // Lease resources. The lease is only detached once the decision is
+        // complete, so an error below returns the reservation.
+        let lease = self.resource_tracker.acquire(
             format!("{}_{}", trigger, chrono::Utc::now().timestamp()),
             target_node.to_string(),
             requirements,
             30000 // 30 second estimated duration
         )?;
 
-        // Determine queue based on node
-        let queue = match target_node {
-            "familiar-daemon" => "daemon-queue",
-            "familiar-worker" => "worker-queue",
-            "classifier" => "classifier-queue",
-            _ => return Err(crate::RouterError::InvalidPolicy(
-                format!("Unknown node type: {}", target_node)
-            )),
+        // Determine queue: the trigger's routing policy wins, then its default
+        // queue, then the node's own queue
+        let queue = if let Some(policy) = route.and_then(|r| r.routing_policy.as_deref()) {
+            self.evaluator.evaluate_routing_policy(policy, &node_context)?
+        } else if let Some(default_queue) = route.and_then(|r| r.default_queue.clone()) {
+            default_queue
+        } else {
+            match target_node {
+                "familiar-daemon" => "daemon-queue",
+                "familiar-worker" => "worker-queue",
+                "classifier" => "classifier-queue",
+                _ => return Err(crate::RouterError::InvalidPolicy(
+                    format!("Unknown node type: {}", target_node)
+                )),
+            }.to_string()
         };
+        self.check_decision_budget(started, trace)?;
+
+        *self.route_decisions.entry(target_node.to_string()).or_default() += 1;
 
-        Ok(RouteDecision {
-            queue: queue.to_string(),
+        let decision = RouteDecision {
+            queue,
             priority: None,
             metadata: {
                 let mut meta = HashMap::new();
@@ -137,8 +569,114 @@ impl Router {
                 meta.insert("routing_strategy".to_string(), serde_json::json!("size_based"));
                 meta
             },
-            lease_id: Some(lease_id),
-        })
+            lease_id: Some(lease.detach()),
+            target: RouteTarget::Single(target_node.to_string()),
+            trace: trace.clone(),
+        };
+        Ok((decision, node_context))
+    }
+
+    /// Fail, keeping the candidates tried so far, once a decision that
+    /// began at `started` has outlasted the decision timeout
+    fn check_decision_budget(&self, started: Instant, trace: &RoutingTrace) -> RouterResult<()> {
+        if started.elapsed() > self.decision_timeout {
+            return Err(self.decision_timeout_error(trace.clone()));
+        }
+        Ok(())
+    }
+
+    fn decision_timeout_error(&self, trace: RoutingTrace) -> crate::RouterError {
+        crate::RouterError::DecisionTimeout { budget: self.decision_timeout, trace }
+    }
+
+    /// Check that a node's breaker isn't open, that it has telemetry, passes
+    /// its constraints and has room for the request
+    fn check_candidate(
+        &self,
+        node_id: &str,
+        snapshot: &TelemetrySnapshot,
+        requirements: &ResourceRequirements,
+    ) -> RouterResult<NodeContext> {
+        if self.breakers.state(node_id) == BreakerState::Open {
+            return Err(crate::RouterError::CircuitOpen(node_id.to_string()));
+        }
+
+        let node_context = snapshot.node_states.get(node_id)
+            .ok_or_else(|| crate::RouterError::InvalidPolicy(
+                format!("No telemetry data for node: {}", node_id)
+            ))?;
+
+        for constraint in self.node_constraints.get(node_id).into_iter().flatten() {
+            if !self.evaluator.evaluate_constraint(constraint, node_context)? {
+                return Err(crate::RouterError::ConstraintViolation(
+                    format!("{} failed on node {}", constraint, node_id)
+                ));
+            }
+        }
+
+        if !self.resource_tracker.can_accommodate(node_id, requirements)? {
+            return Err(crate::RouterError::InsufficientResources(
+                format!("Insufficient resources on node {}", node_id)
+            ));
+        }
+
+        Ok(node_context.clone())
+    }
+
+    /// Weights for every registered node that can take more work.
+    ///
+    /// A node's weight is its headroom: the free fraction of its most
+    /// utilized resource. Nodes that can't fit the baseline requirements
+    /// are left out, as are nodes without GPU capacity when `ctx` needs a GPU.
+    pub fn weighted_targets(&self, ctx: &NodeContext) -> RouterResult<RouteTarget> {
+        self.node_weights(ctx).map(RouteTarget::Weighted)
+    }
+
+    /// Pick a node at random, favouring less-loaded nodes (see [`Router::weighted_targets`])
+    pub fn route_weighted(&self, ctx: &NodeContext) -> RouterResult<NodeId> {
+        let weights = self.node_weights(ctx)?;
+        let total: f64 = weights.iter().map(|(_, weight)| weight).sum();
+        let mut sample = self.rng.lock().unwrap_or_else(PoisonError::into_inner).gen::<f64>() * total;
+
+        for (node_id, weight) in &weights {
+            if sample < *weight {
+                return Ok(node_id.clone());
+            }
+            sample -= weight;
+        }
+
+        // Floating point rounding can leave a sliver past the last bucket
+        Ok(weights[weights.len() - 1].0.clone())
+    }
+
+    fn node_weights(&self, ctx: &NodeContext) -> RouterResult<Vec<(NodeId, f64)>> {
+        let requirements = baseline_requirements();
+        let mut weights = Vec::new();
+
+        for node_id in self.resource_tracker.nodes() {
+            let has_gpu = self.resource_tracker.capacity(&node_id)
+                .is_some_and(|capacity| capacity.gpu_memory_bytes.is_some());
+            if ctx.has_gpu && !has_gpu {
+                continue;
+            }
+
+            if !self.resource_tracker.can_accommodate(&node_id, &requirements)? {
+                continue;
+            }
+
+            let weight = self.resource_tracker.get_utilization(&node_id)?.headroom();
+            if weight > 0.0 {
+                weights.push((node_id, weight));
+            }
+        }
+
+        if weights.is_empty() {
+            return Err(crate::RouterError::InsufficientResources(
+                "All candidate nodes are saturated".to_string()
+            ));
+        }
+
+        Ok(weights)
     }
 
     /// Release resources when a task completes
@@ -157,6 +695,16 @@ impl Router {
     }
 }
 
+/// Resources reserved for a single routed request
+fn baseline_requirements() -> ResourceRequirements {
+    ResourceRequirements {
+        memory_bytes: 256 * 1024 * 1024, // 256Mi baseline
+        cpu_cores: 0.1,
+        gpu_memory_bytes: None,
+        network_bandwidth_mbps: None,
+    }
+}
+
 /// Estimate input size for routing decisions
 fn estimate_input_size(input: &serde_json::Value) -> usize {
     // Simple estimation - in practice, this would be more sophisticated
@@ -166,7 +714,7 @@ fn estimate_input_size(input: &serde_json::Value) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
 
     // Mock telemetry provider for testing
     struct MockTelemetryProvider {
@@ -183,10 +731,7 @@ mod tests {
         }
 
         async fn snapshot(&self) -> RouterResult<TelemetrySnapshot> {
-            Ok(TelemetrySnapshot {
-                node_states: self.node_states.clone(),
-                captured_at: std::time::Instant::now(),
-            })
+            Ok(TelemetrySnapshot::new(self.node_states.clone()))
         }
     }
 
@@ -205,6 +750,7 @@ mod tests {
                 cpu_usage: 0.5,
                 queue_depth: 10,
                 active_jobs: 2,
+                active_connections: 0,
                 has_gpu: false,
                 error_rate: 0.01,
                 custom_properties: HashMap::new(),
@@ -223,6 +769,10 @@ mod tests {
         };
         router.register_node_capacity("familiar-daemon", capacity);
 
+        // Only the daemon reports telemetry, so small inputs fall back to it
+        router.add_route("test-trigger", None, None);
+        router.set_fallbacks("test-trigger", &["familiar-daemon"]).unwrap();
+
         // Test routing a small input
         let small_input = serde_json::json!({"data": "small"});
         let decision = router.route("test-trigger", &small_input).await.unwrap();
@@ -234,4 +784,461 @@ mod tests {
         let utilization = router.get_node_utilization("familiar-daemon").unwrap();
         assert!(utilization.memory_percent > 0.0); // Should have leased some memory
     }
+
+    fn node_state(cpu_usage: f64) -> NodeContext {
+        let mut ctx = NodeContext::new(semver::Version::parse("1.2.0").unwrap());
+        ctx.available_memory = 4 * 1024 * 1024 * 1024;
+        ctx.cpu_usage = cpu_usage;
+        ctx
+    }
+
+    fn fallback_router() -> Router {
+        let mut node_states = HashMap::new();
+        node_states.insert("familiar-worker".to_string(), node_state(0.95));
+        node_states.insert("familiar-daemon".to_string(), node_state(0.2));
+
+        let mut router = Router::new(Box::new(MockTelemetryProvider { node_states }));
+        for node_id in ["familiar-worker", "familiar-daemon"] {
+            router.register_node_capacity(node_id, ResourceRequirements {
+                memory_bytes: 8 * 1024 * 1024 * 1024,
+                cpu_cores: 4.0,
+                gpu_memory_bytes: None,
+                network_bandwidth_mbps: None,
+            });
+            router.add_node_constraint(node_id, "cpu_usage < 0.8");
+        }
+        router.add_route("test-trigger", None, None);
+        router
+    }
+
+    struct SlowTelemetry {
+        inner: MockTelemetryProvider,
+        delay: Duration,
+    }
+
+    #[async_trait::async_trait]
+    impl TelemetryProvider for SlowTelemetry {
+        async fn get_node_context(&self, node_id: &str) -> RouterResult<NodeContext> {
+            self.inner.get_node_context(node_id).await
+        }
+
+        async fn snapshot(&self) -> RouterResult<TelemetrySnapshot> {
+            tokio::time::sleep(self.delay).await;
+            self.inner.snapshot().await
+        }
+    }
+
+    /// Serves a canned snapshot from another task, like a network backend
+    struct CannedTelemetry {
+        snapshot: TelemetrySnapshot,
+    }
+
+    #[async_trait::async_trait]
+    impl TelemetryProvider for CannedTelemetry {
+        async fn get_node_context(&self, node_id: &str) -> RouterResult<NodeContext> {
+            self.snapshot.node_states.get(node_id).cloned()
+                .ok_or_else(|| crate::RouterError::InvalidPolicy(
+                    format!("Node not found: {}", node_id)
+                ))
+        }
+
+        async fn snapshot(&self) -> RouterResult<TelemetrySnapshot> {
+            let snapshot = self.snapshot.clone();
+            Ok(tokio::spawn(async move { snapshot }).await.unwrap())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routing_with_async_telemetry() {
+        let mut node_states = HashMap::new();
+        node_states.insert("familiar-worker".to_string(), node_state(0.2));
+        let telemetry = CannedTelemetry { snapshot: TelemetrySnapshot::new(node_states) };
+
+        let mut router = Router::new(Box::new(telemetry));
+        router.register_node_capacity("familiar-worker", ResourceRequirements {
+            memory_bytes: 8 * 1024 * 1024 * 1024,
+            cpu_cores: 4.0,
+            gpu_memory_bytes: None,
+            network_bandwidth_mbps: None,
+        });
+        router.add_route("test-trigger", None, None);
+
+        let decision = router.route("test-trigger", &serde_json::json!({"data": "small"})).await.unwrap();
+        assert_eq!(decision.routed_to(), Some("familiar-worker"));
+        assert!(decision.lease_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_slow_telemetry_trips_decision_timeout() {
+        let mut node_states = HashMap::new();
+        node_states.insert("familiar-worker".to_string(), node_state(0.2));
+        let telemetry = SlowTelemetry {
+            inner: MockTelemetryProvider { node_states },
+            delay: Duration::from_secs(5),
+        };
+        let mut router = Router::new(Box::new(telemetry)).with_decision_timeout(Duration::from_millis(20));
+        router.add_route("test-trigger", None, None);
+
+        let started = Instant::now();
+        let result = router.route("test-trigger", &serde_json::json!({"data": "small"})).await;
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        match result {
+            Err(crate::RouterError::DecisionTimeout { budget, trace }) => {
+                assert_eq!(budget, Duration::from_millis(20));
+                assert!(trace.attempts.is_empty());
+            }
+            other => panic!("expected a decision timeout, got {:?}", other.map(|d| d.queue)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decision_timeout_keeps_partial_trace() {
+        let mut router = fallback_router().with_decision_timeout(Duration::from_millis(250));
+        router.set_fallbacks("test-trigger", &["familiar-daemon"]).unwrap();
+        router.register_function("slow_probe", |_| {
+            std::thread::sleep(Duration::from_millis(400));
+            Ok(cel_interpreter::Value::Bool(true))
+        }).unwrap();
+        router.add_node_constraint("familiar-daemon", "slow_probe()");
+
+        let result = router.route("test-trigger", &serde_json::json!({"data": "small"})).await;
+
+        // The worker is rejected and the daemon accepted, but checking the
+        // daemon used up the budget
+        let Err(crate::RouterError::DecisionTimeout { trace, .. }) = result else {
+            panic!("expected a decision timeout");
+        };
+        let tried: Vec<_> = trace.attempts.iter().map(|a| a.node_id.as_str()).collect();
+        assert_eq!(tried, vec!["familiar-worker", "familiar-daemon"]);
+        assert!(trace.attempts[0].rejection.is_some());
+        assert!(trace.attempts[1].rejection.is_none());
+
+        // No lease is left behind
+        assert_eq!(router.get_node_utilization("familiar-daemon").unwrap().memory_percent, 0.0);
+    }
+
+    /// Router where both candidates pass and have the same capacity
+    fn tiebreak_router(strategy: TieBreak) -> Router {
+        let mut node_states = HashMap::new();
+        node_states.insert("familiar-worker".to_string(), node_state(0.2));
+        node_states.insert("familiar-daemon".to_string(), node_state(0.2));
+
+        let mut router = Router::new(Box::new(MockTelemetryProvider { node_states })).with_tiebreak(strategy);
+        for node_id in ["familiar-worker", "familiar-daemon"] {
+            router.register_node_capacity(node_id, ResourceRequirements {
+                memory_bytes: 8 * 1024 * 1024 * 1024,
+                cpu_cores: 4.0,
+                gpu_memory_bytes: None,
+                network_bandwidth_mbps: None,
+            });
+        }
+        router.add_route("test-trigger", None, None);
+        router.set_fallbacks("test-trigger", &["familiar-daemon"]).unwrap();
+        router
+    }
+
+    async fn decisions(router: &mut Router, count: usize) -> Vec<String> {
+        let mut routed = Vec::new();
+        for _ in 0..count {
+            let decision = router.route("test-trigger", &serde_json::json!({"data": "small"})).await.unwrap();
+            routed.push(decision.routed_to().unwrap().to_string());
+        }
+        routed
+    }
+
+    fn decision_history(capacity: usize) -> TracingConfig {
+        TracingConfig { decision_history: capacity, ..TracingConfig::default() }
+    }
+
+    #[tokio::test]
+    async fn test_recent_decisions_keep_the_latest_in_order() {
+        let capacity = 3;
+        for routes in [2, 5] {
+            let mut router = tiebreak_router(TieBreak::LeastLoaded).with_tracing_config(decision_history(capacity));
+            let routed = decisions(&mut router, routes).await;
+
+            let traces = router.recent_decisions(10);
+            assert_eq!(traces.len(), routes.min(capacity));
+            // Both candidates pass, and the least loaded of them is picked
+            for trace in &traces {
+                let tried: Vec<_> = trace.attempts.iter().map(|a| a.node_id.as_str()).collect();
+                assert_eq!(tried, ["familiar-worker", "familiar-daemon"]);
+            }
+
+            let recent = router.recent_decision_records(10);
+            assert_eq!(recent.len(), traces.len());
+            let recorded: Vec<_> = recent.iter().map(|r| r.decision().unwrap().routed_to().unwrap()).collect();
+            assert_eq!(recorded, routed[routed.len() - recent.len()..]);
+            let lease_ids: Vec<_> = recent.iter().map(|r| r.decision().unwrap().lease_id.clone().unwrap()).collect();
+            let expected: Vec<_> = (routes - recent.len() + 1..=routes).map(|n| format!("lease_{}", n)).collect();
+            assert_eq!(lease_ids, expected);
+
+            assert!(recent.iter().all(|r| r.trigger == "test-trigger" && matches!(
+                &r.outcome,
+                DecisionOutcome::Routed { node_context, .. } if node_context.cpu_usage == 0.2
+            )));
+            assert_eq!(
+                router.recent_decision_records(1)[0].decision().unwrap().lease_id,
+                recent.last().unwrap().decision().unwrap().lease_id
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decision_recording_toggles() {
+        // Off unless the router is given a history
+        let mut router = tiebreak_router(TieBreak::FirstDeclared);
+        decisions(&mut router, 2).await;
+        assert!(router.recent_decisions(10).is_empty());
+
+        let mut router = tiebreak_router(TieBreak::FirstDeclared).with_tracing_config(decision_history(8));
+        decisions(&mut router, 2).await;
+        router.set_decision_recording(false);
+        decisions(&mut router, 2).await;
+        assert_eq!(router.recent_decisions(10).len(), 2);
+
+        router.set_decision_recording(true);
+        decisions(&mut router, 1).await;
+        assert_eq!(router.recent_decisions(10).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_identical_routers_make_identical_decisions() {
+        for strategy in [TieBreak::FirstDeclared, TieBreak::LeastLoaded, TieBreak::Random(7)] {
+            let first = decisions(&mut tiebreak_router(strategy), 8).await;
+            let second = decisions(&mut tiebreak_router(strategy), 8).await;
+            assert_eq!(first, second, "{:?}", strategy);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tiebreak_strategies() {
+        // The preferred node wins without the fallback being checked
+        let mut router = tiebreak_router(TieBreak::FirstDeclared);
+        let decision = router.route("test-trigger", &serde_json::json!({"data": "small"})).await.unwrap();
+        assert_eq!(decision.routed_to(), Some("familiar-worker"));
+        assert_eq!(decision.trace.attempts.len(), 1);
+
+        // Every candidate is checked. Equal headroom goes to the worker,
+        // whose lease then leaves the daemon with more
+        let mut router = tiebreak_router(TieBreak::LeastLoaded);
+        let decision = router.route("test-trigger", &serde_json::json!({"data": "small"})).await.unwrap();
+        assert_eq!(decision.routed_to(), Some("familiar-worker"));
+        assert_eq!(decision.trace.attempts.len(), 2);
+        assert!(decision.fallback_path().is_empty());
+        assert_eq!(decisions(&mut router, 3).await, vec!["familiar-daemon", "familiar-worker", "familiar-daemon"]);
+
+        let routed = decisions(&mut tiebreak_router(TieBreak::Random(7)), 32).await;
+        assert!(routed.iter().any(|node| node == "familiar-worker"));
+        assert!(routed.iter().any(|node| node == "familiar-daemon"));
+    }
+
+    #[tokio::test]
+    async fn test_fallback_when_preferred_node_rejected() {
+        let mut router = fallback_router();
+        router.set_fallbacks("test-trigger", &["familiar-daemon"]).unwrap();
+
+        // Small input prefers familiar-worker, whose CPU is over the constraint
+        let decision = router.route("test-trigger", &serde_json::json!({"data": "small"})).await.unwrap();
+
+        assert_eq!(decision.routed_to(), Some("familiar-daemon"));
+        assert_eq!(decision.fallback_path(), vec!["familiar-worker"]);
+        assert_eq!(decision.queue, "daemon-queue");
+        assert!(decision.trace.attempts[0].rejection.as_deref().unwrap().contains("cpu_usage < 0.8"));
+    }
+
+    struct RecordingTelemetry {
+        inner: MockTelemetryProvider,
+        transitions: Arc<Mutex<Vec<BreakerTransition>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TelemetryProvider for RecordingTelemetry {
+        async fn get_node_context(&self, node_id: &str) -> RouterResult<NodeContext> {
+            self.inner.get_node_context(node_id).await
+        }
+
+        async fn snapshot(&self) -> RouterResult<TelemetrySnapshot> {
+            self.inner.snapshot().await
+        }
+
+        fn on_breaker_transition(&self, transition: &BreakerTransition) {
+            self.transitions.lock().unwrap().push(transition.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_open_breaker_skips_node_until_cooldown() {
+        let mut node_states = HashMap::new();
+        node_states.insert("familiar-worker".to_string(), node_state(0.2));
+        node_states.insert("familiar-daemon".to_string(), node_state(0.2));
+
+        let transitions = Arc::new(Mutex::new(Vec::new()));
+        let telemetry = RecordingTelemetry {
+            inner: MockTelemetryProvider { node_states },
+            transitions: transitions.clone(),
+        };
+        let mut router = Router::new(Box::new(telemetry)).with_breaker_config(CircuitBreakerConfig {
+            failure_threshold: 2,
+            success_threshold: 1,
+            recovery_timeout: std::time::Duration::from_millis(50),
+            ..CircuitBreakerConfig::default()
+        });
+        for node_id in ["familiar-worker", "familiar-daemon"] {
+            router.register_node_capacity(node_id, ResourceRequirements {
+                memory_bytes: 8 * 1024 * 1024 * 1024,
+                cpu_cores: 4.0,
+                gpu_memory_bytes: None,
+                network_bandwidth_mbps: None,
+            });
+        }
+        router.add_route("test-trigger", None, None);
+        router.set_fallbacks("test-trigger", &["familiar-daemon"]).unwrap();
+
+        let input = serde_json::json!({"data": "small"});
+        router.report_outcome("familiar-worker".to_string(), false);
+        assert_eq!(router.breaker_state("familiar-worker"), BreakerState::Closed);
+        router.report_outcome("familiar-worker".to_string(), false);
+        assert_eq!(router.breaker_state("familiar-worker"), BreakerState::Open);
+
+        let decision = router.route("test-trigger", &input).await.unwrap();
+        assert_eq!(decision.routed_to(), Some("familiar-daemon"));
+
+        tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+        let decision = router.route("test-trigger", &input).await.unwrap();
+        assert_eq!(decision.routed_to(), Some("familiar-worker"));
+        assert_eq!(router.breaker_state("familiar-worker"), BreakerState::HalfOpen);
+
+        router.report_outcome("familiar-worker".to_string(), true);
+        assert_eq!(router.breaker_state("familiar-worker"), BreakerState::Closed);
+
+        let seen: Vec<_> = transitions.lock().unwrap().iter().map(|t| t.to).collect();
+        assert_eq!(seen, vec![BreakerState::Open, BreakerState::HalfOpen, BreakerState::Closed]);
+    }
+
+    #[tokio::test]
+    async fn test_policy_with_custom_function() {
+        let mut router = fallback_router();
+        router.register_function("busy", |args| match args {
+            [cel_interpreter::Value::Float(cpu)] => Ok(cel_interpreter::Value::Bool(*cpu > 0.5)),
+            _ => Err(crate::RouterError::InvalidPolicy("busy(cpu_usage)".to_string())),
+        }).unwrap();
+        router.add_route("busy-trigger", Some("busy(cpu_usage) ? 'overflow-queue' : 'fast-queue'"), None);
+        router.set_fallbacks("busy-trigger", &["familiar-daemon"]).unwrap();
+
+        let decision = router.route("busy-trigger", &serde_json::json!({"data": "small"})).await.unwrap();
+        assert_eq!(decision.routed_to(), Some("familiar-daemon"));
+        assert_eq!(decision.queue, "fast-queue");
+    }
+
+    #[tokio::test]
+    async fn test_all_candidates_rejected_aggregates_reasons() {
+        let mut router = fallback_router();
+        router.set_fallbacks("test-trigger", &["familiar-daemon", "classifier"]).unwrap();
+        router.add_node_constraint("familiar-daemon", "queue_depth > 100");
+
+        let err = router.route("test-trigger", &serde_json::json!({"data": "small"})).await.unwrap_err();
+        let message = err.to_string();
+
+        assert!(matches!(err, crate::RouterError::ConstraintViolation(_)));
+        assert!(message.contains("familiar-worker: "));
+        assert!(message.contains("familiar-daemon: "));
+        assert!(message.contains("classifier: "));
+    }
+
+    #[tokio::test]
+    async fn test_failed_route_is_recorded_with_its_trace() {
+        let mut router = fallback_router().with_tracing_config(decision_history(4));
+        router.set_fallbacks("test-trigger", &["familiar-daemon", "classifier"]).unwrap();
+        router.add_node_constraint("familiar-daemon", "queue_depth > 100");
+
+        let err = router.route("test-trigger", &serde_json::json!({"data": "small"})).await.unwrap_err();
+
+        let recent = router.recent_decision_records(10);
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].trigger, "test-trigger");
+        assert!(recent[0].decision().is_none());
+        assert!(matches!(&recent[0].outcome, DecisionOutcome::Failed(message) if *message == err.to_string()));
+        let traces = router.recent_decisions(10);
+        let tried: Vec<_> = traces[0].attempts.iter().map(|a| a.node_id.as_str()).collect();
+        assert_eq!(tried, ["familiar-worker", "familiar-daemon", "classifier"]);
+        assert!(traces[0].attempts.iter().all(|a| a.rejection.is_some()));
+    }
+
+    fn weighted_router() -> Router {
+        let telemetry = Box::new(MockTelemetryProvider { node_states: HashMap::new() });
+        let mut router = Router::new(telemetry).with_rng(StdRng::seed_from_u64(7));
+
+        for node_id in ["daemon-a", "daemon-b"] {
+            router.register_node_capacity(node_id, ResourceRequirements {
+                memory_bytes: 1024 * 1024 * 1024, // 1Gi
+                cpu_cores: 1.0,
+                gpu_memory_bytes: None,
+                network_bandwidth_mbps: None,
+            });
+        }
+        router
+    }
+
+    #[test]
+    fn test_weighted_routing_prefers_less_loaded_nodes() {
+        let mut router = weighted_router();
+
+        // Load daemon-a to 75% memory
+        router.resource_tracker.lease_resources(
+            "busy".to_string(),
+            "daemon-a".to_string(),
+            ResourceRequirements {
+                memory_bytes: 768 * 1024 * 1024,
+                cpu_cores: 0.1,
+                gpu_memory_bytes: None,
+                network_bandwidth_mbps: None,
+            },
+            30000,
+        ).unwrap();
+
+        let ctx = NodeContext::current();
+        let picks_b = (0..1000)
+            .filter(|_| router.route_weighted(&ctx).unwrap() == "daemon-b")
+            .count();
+
+        // daemon-b has 4x the headroom, so it should get roughly 80% of the work
+        assert!((700..900).contains(&picks_b), "daemon-b picked {} times", picks_b);
+    }
+
+    #[test]
+    fn test_weighted_routing_survives_a_poisoned_rng() {
+        let router = weighted_router();
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _rng = router.rng.lock().unwrap();
+            panic!("poison the rng lock");
+        }));
+        assert!(router.rng.is_poisoned());
+
+        let picked = router.route_weighted(&NodeContext::current()).unwrap();
+        assert!(["daemon-a", "daemon-b"].contains(&picked.as_str()));
+    }
+
+    #[test]
+    fn test_weighted_routing_fails_when_saturated() {
+        let mut router = weighted_router();
+
+        for node_id in ["daemon-a", "daemon-b"] {
+            router.resource_tracker.lease_resources(
+                "fill".to_string(),
+                node_id.to_string(),
+                ResourceRequirements {
+                    memory_bytes: 1024 * 1024 * 1024,
+                    cpu_cores: 0.5,
+                    gpu_memory_bytes: None,
+                    network_bandwidth_mbps: None,
+                },
+                30000,
+            ).unwrap();
+        }
+
+        let result = router.route_weighted(&NodeContext::current());
+        assert!(matches!(result, Err(crate::RouterError::InsufficientResources(_))));
+    }
 }
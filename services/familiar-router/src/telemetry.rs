@@ -3,7 +3,7 @@
 //! This module provides interfaces and implementations for telemetry
 //! data sources used in routing decisions.
 
-use crate::{NodeContext, RouterResult, TelemetryProvider, TelemetrySnapshot};
+use crate::{NodeContext, ResourceUtilization, RouterResult, TelemetryProvider, TelemetrySnapshot};
 use std::collections::HashMap;
 
 /// Mock telemetry provider for testing
@@ -23,6 +23,12 @@ impl MockTelemetryProvider {
     }
 }
 
+impl Default for MockTelemetryProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait::async_trait]
 impl TelemetryProvider for MockTelemetryProvider {
     async fn get_node_context(&self, node_id: &str) -> RouterResult<NodeContext> {
@@ -33,10 +39,7 @@ impl TelemetryProvider for MockTelemetryProvider {
     }
 
     async fn snapshot(&self) -> RouterResult<TelemetrySnapshot> {
-        Ok(TelemetrySnapshot {
-            node_states: self.node_states.clone(),
-            captured_at: std::time::Instant::now(),
-        })
+        Ok(TelemetrySnapshot::new(self.node_states.clone()))
     }
 }
 
@@ -58,6 +61,12 @@ impl InMemoryTelemetryProvider {
     }
 }
 
+impl Default for InMemoryTelemetryProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[async_trait::async_trait]
 impl TelemetryProvider for InMemoryTelemetryProvider {
     async fn get_node_context(&self, node_id: &str) -> RouterResult<NodeContext> {
@@ -70,9 +79,137 @@ impl TelemetryProvider for InMemoryTelemetryProvider {
 
     async fn snapshot(&self) -> RouterResult<TelemetrySnapshot> {
         let states = self.node_states.read().unwrap();
-        Ok(TelemetrySnapshot {
-            node_states: states.clone(),
-            captured_at: std::time::Instant::now(),
-        })
+        Ok(TelemetrySnapshot::new(states.clone()))
+    }
+}
+
+impl TelemetrySnapshot {
+    /// Render the snapshot in the Prometheus text exposition format.
+    ///
+    /// Every metric is prefixed `familiar_router_` and labelled with `node`.
+    /// Nodes are sorted so the output is stable; families without samples
+    /// are left out.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        let mut nodes: Vec<_> = self.node_states.iter().collect();
+        nodes.sort_by(|a, b| a.0.cmp(b.0));
+        let node_gauges: [Family<NodeContext>; 5] = [
+            ("node_available_memory_bytes", "Memory available on the node", |n| Some(n.available_memory as f64)),
+            ("node_cpu_usage_ratio", "CPU usage of the node (0 to 1)", |n| Some(n.cpu_usage)),
+            ("node_queue_depth", "Jobs queued on the node", |n| Some(n.queue_depth as f64)),
+            ("node_active_jobs", "Jobs running on the node", |n| Some(n.active_jobs as f64)),
+            ("node_error_rate_ratio", "Error rate of the node (0 to 1)", |n| Some(n.error_rate)),
+        ];
+        for (name, help, value) in node_gauges {
+            write_family(&mut out, name, help, "gauge", samples(&nodes, value));
+        }
+
+        let mut decisions: Vec<_> = self.route_decisions.iter().collect();
+        decisions.sort_by(|a, b| a.0.cmp(b.0));
+        let counts = decisions.iter().map(|(node_id, count)| (node_id.as_str(), **count as f64)).collect();
+        write_family(&mut out, "route_decisions_total", "Requests routed to the node", "counter", counts);
+
+        let mut utilization: Vec<_> = self.utilization.iter().collect();
+        utilization.sort_by(|a, b| a.0.cmp(b.0));
+        let utilization_gauges: [Family<ResourceUtilization>; 3] = [
+            ("memory_utilization_percent", "Share of node memory reserved by leases", |u| Some(u.memory_percent)),
+            ("cpu_utilization_percent", "Share of node CPU reserved by leases", |u| Some(u.cpu_percent)),
+            ("gpu_utilization_percent", "Share of node GPU memory reserved by leases", |u| u.gpu_percent),
+        ];
+        for (name, help, value) in utilization_gauges {
+            write_family(&mut out, name, help, "gauge", samples(&utilization, value));
+        }
+
+        out
+    }
+}
+
+/// Metric name, help text and how to read a sample from a node's value
+type Family<T> = (&'static str, &'static str, fn(&T) -> Option<f64>);
+
+fn samples<'a, T>(nodes: &[(&'a String, &T)], value: fn(&T) -> Option<f64>) -> Vec<(&'a str, f64)> {
+    nodes.iter()
+        .filter_map(|(node_id, item)| value(item).map(|v| (node_id.as_str(), v)))
+        .collect()
+}
+
+fn write_family(out: &mut String, name: &str, help: &str, kind: &str, samples: Vec<(&str, f64)>) {
+    use std::fmt::Write;
+
+    if samples.is_empty() {
+        return;
+    }
+
+    let _ = writeln!(out, "# HELP familiar_router_{} {}", name, help);
+    let _ = writeln!(out, "# TYPE familiar_router_{} {}", name, kind);
+    for (node_id, value) in samples {
+        let _ = writeln!(out, "familiar_router_{}{{node=\"{}\"}} {}", name, escape_label(node_id), value);
+    }
+}
+
+/// Escape a label value per the exposition format
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ResourceRequirements;
+
+    #[tokio::test]
+    async fn test_prometheus_exposition() {
+        let mut provider = MockTelemetryProvider::new();
+        let mut ctx = NodeContext::new(semver::Version::parse("1.2.0").unwrap());
+        ctx.available_memory = 1024;
+        ctx.cpu_usage = 0.5;
+        ctx.queue_depth = 3;
+        provider.add_node("familiar-daemon", ctx);
+
+        let mut snapshot = provider.snapshot().await.unwrap();
+        snapshot.route_decisions.insert("familiar-daemon".to_string(), 7);
+        snapshot.utilization.insert("familiar-daemon".to_string(), ResourceUtilization {
+            memory_percent: 25.0,
+            cpu_percent: 12.5,
+            gpu_percent: None,
+            stale_memory_percent: 0.0,
+            stale_cpu_percent: 0.0,
+            capacity: ResourceRequirements::default(),
+            reserved: ResourceRequirements::default(),
+        });
+
+        let expected = "\
+# HELP familiar_router_node_available_memory_bytes Memory available on the node
+# TYPE familiar_router_node_available_memory_bytes gauge
+familiar_router_node_available_memory_bytes{node=\"familiar-daemon\"} 1024
+# HELP familiar_router_node_cpu_usage_ratio CPU usage of the node (0 to 1)
+# TYPE familiar_router_node_cpu_usage_ratio gauge
+familiar_router_node_cpu_usage_ratio{node=\"familiar-daemon\"} 0.5
+# HELP familiar_router_node_queue_depth Jobs queued on the node
+# TYPE familiar_router_node_queue_depth gauge
+familiar_router_node_queue_depth{node=\"familiar-daemon\"} 3
+# HELP familiar_router_node_active_jobs Jobs running on the node
+# TYPE familiar_router_node_active_jobs gauge
+familiar_router_node_active_jobs{node=\"familiar-daemon\"} 0
+# HELP familiar_router_node_error_rate_ratio Error rate of the node (0 to 1)
+# TYPE familiar_router_node_error_rate_ratio gauge
+familiar_router_node_error_rate_ratio{node=\"familiar-daemon\"} 0
+# HELP familiar_router_route_decisions_total Requests routed to the node
+# TYPE familiar_router_route_decisions_total counter
+familiar_router_route_decisions_total{node=\"familiar-daemon\"} 7
+# HELP familiar_router_memory_utilization_percent Share of node memory reserved by leases
+# TYPE familiar_router_memory_utilization_percent gauge
+familiar_router_memory_utilization_percent{node=\"familiar-daemon\"} 25
+# HELP familiar_router_cpu_utilization_percent Share of node CPU reserved by leases
+# TYPE familiar_router_cpu_utilization_percent gauge
+familiar_router_cpu_utilization_percent{node=\"familiar-daemon\"} 12.5
+";
+        assert_eq!(snapshot.to_prometheus(), expected);
+    }
+
+    #[test]
+    fn test_label_escaping() {
+        assert_eq!(escape_label(r#"a"b\c"#), r#"a\"b\\c"#);
     }
 }